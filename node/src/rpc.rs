@@ -18,6 +18,7 @@ use pallet_mmr_rpc::{Mmr, MmrApiServer};
 use sc_client_api::{
     backend::{AuxStore, Backend, StateBackend, StorageProvider},
     client::BlockchainEvents,
+    BlockBackend,
 };
 use sc_consensus_babe::{BabeConfiguration, Epoch};
 use sc_consensus_babe_rpc::{Babe, BabeApiServer};
@@ -129,7 +130,7 @@ where
     B::State: StateBackend<sp_runtime::traits::HashFor<Block>>,
     C: ProvideRuntimeApi<Block> + StorageProvider<Block, B> + AuxStore,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
-    C: BlockchainEvents<Block>,
+    C: BlockchainEvents<Block> + BlockBackend<Block>,
     C: Send + Sync + 'static,
     C::Api: BabeApi<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
@@ -1,6 +1,10 @@
 use core::{fmt::Debug, marker::PhantomData};
 use dock_core::{
-    accumulator, common::TypesAndLimits, offchain_signatures, trust_registry::*, util::IncId,
+    accumulator,
+    common::{CurveType, Types, TypesAndLimits},
+    offchain_signatures,
+    trust_registry::*,
+    util::IncId,
 };
 pub use dock_core::{
     did::{self, Config},
@@ -11,9 +15,13 @@ use jsonrpsee::{
     proc_macros::rpc,
     types::{error::CallError, ErrorObject},
 };
-use sp_api::ProvideRuntimeApi;
+use sc_client_api::BlockBackend;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, NumberFor, UniqueSaturatedFrom, UniqueSaturatedInto},
+};
 use std::{
     collections::{BTreeMap, BTreeSet},
     sync::Arc,
@@ -123,12 +131,59 @@ where
         at: Option<BlockHash>,
     ) -> RpcResult<Option<accumulator::AccumPublicKeyWithParams<T::T>>>;
 
+    /// Returns the accumulated value with public key and params along with the amount of the members of the universal
+    /// accumulator (`None` if the members aren't counted). If the `version` (block number or snapshot index)
+    /// is supplied, they're taken from the corresponding snapshot kept on-chain.
     #[method(name = "core_mods_accumulatorWithPublicKeyAndParams")]
     async fn accumulator_with_public_key_and_params(
         &self,
         id: accumulator::AccumulatorId,
         at: Option<BlockHash>,
-    ) -> RpcResult<Option<(Vec<u8>, Option<accumulator::AccumPublicKeyWithParams<T::T>>)>>;
+        version: Option<accumulator::AccumulatorVersion<T::T>>,
+    ) -> RpcResult<
+        Option<(
+            Vec<u8>,
+            Option<accumulator::AccumPublicKeyWithParams<T::T>>,
+            Option<u64>,
+        )>,
+    >;
+
+    /// Returns updates of the accumulator grouped by up to `limit` (capped by the runtime) blocks having them along with
+    /// the sequential numbers of these blocks, starting after the block with the `after` sequential number.
+    /// Pass the sequential number of the last returned block as `after` to fetch the next page.
+    /// Only the recent blocks kept by the runtime are returned. Requires the node to keep the state
+    /// of the historical blocks (archive mode) to read their events.
+    #[method(name = "core_mods_accumulatorUpdates")]
+    async fn accumulator_updates(
+        &self,
+        id: accumulator::AccumulatorId,
+        after: Option<u64>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<
+        Vec<(
+            u64,
+            <T::T as Types>::BlockNumber,
+            Vec<accumulator::AccumulatorUpdateDetails>,
+        )>,
+    >;
+
+    /// Returns up to `limit` (capped by the runtime) accumulators owned by the supplied DID along with their update info,
+    /// starting after the accumulator with the `after` id.
+    /// Pass the id of the last returned accumulator as `after` to fetch the next page.
+    #[method(name = "core_mods_accumulatorsByOwner")]
+    async fn accumulators_by_owner(
+        &self,
+        owner: accumulator::AccumulatorOwner,
+        after: Option<accumulator::AccumulatorId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<
+        Vec<(
+            accumulator::AccumulatorId,
+            accumulator::AccumulatorWithUpdateInfo<T::T>,
+        )>,
+    >;
 
     #[method(name = "trustRegistry_schemaMetadata")]
     async fn schema_metadata(
@@ -246,6 +301,350 @@ where
         did: did::Did,
         at: Option<BlockHash>,
     ) -> RpcResult<BTreeMap<IncId, offchain_signatures::BBDT16PublicKeyWithParams<T::T>>>;
+
+    #[method(name = "core_mods_bbsIetfPublicKey")]
+    async fn bbs_ietf_public_key(
+        &self,
+        id: offchain_signatures::SignaturePublicKeyStorageKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<offchain_signatures::BBSIetfPublicKey<T::T>>>;
+
+    #[method(name = "core_mods_bbsIetfPublicKeysByDid")]
+    async fn bbs_ietf_public_keys_by_did(
+        &self,
+        did: did::Did,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::BBSIetfPublicKey<T::T>>>;
+
+    #[method(name = "core_mods_ecdsaSdPublicKey")]
+    async fn ecdsa_sd_public_key(
+        &self,
+        id: offchain_signatures::SignaturePublicKeyStorageKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<offchain_signatures::EcdsaSdPublicKey<T::T>>>;
+
+    #[method(name = "core_mods_ecdsaSdPublicKeysByDid")]
+    async fn ecdsa_sd_public_keys_by_did(
+        &self,
+        did: did::Did,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::EcdsaSdPublicKey<T::T>>>;
+
+    #[method(name = "core_mods_ed25519SdPublicKey")]
+    async fn ed25519_sd_public_key(
+        &self,
+        id: offchain_signatures::SignaturePublicKeyStorageKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<offchain_signatures::Ed25519SdPublicKey<T::T>>>;
+
+    #[method(name = "core_mods_ed25519SdPublicKeysByDid")]
+    async fn ed25519_sd_public_keys_by_did(
+        &self,
+        did: did::Did,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::Ed25519SdPublicKey<T::T>>>;
+
+    #[method(name = "core_mods_offchainPublicKeyWithParams")]
+    async fn offchain_public_key_with_params(
+        &self,
+        id: offchain_signatures::SignaturePublicKeyStorageKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<offchain_signatures::OffchainPublicKeyWithParams<T::T>>>;
+
+    #[method(name = "core_mods_offchainPublicKeysByDid")]
+    async fn offchain_public_keys_by_did(
+        &self,
+        did: did::Did,
+        scheme: Option<offchain_signatures::OffchainSignatureScheme>,
+        curve_type: Option<CurveType>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::OffchainPublicKeyWithParams<T::T>>>;
+
+    #[method(name = "core_mods_offchainParamsByOwner")]
+    async fn offchain_params_by_owner(
+        &self,
+        owner: offchain_signatures::SignatureParamsOwner,
+        scheme: Option<offchain_signatures::OffchainSignatureScheme>,
+        curve_type: Option<CurveType>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::OffchainSignatureParams<T::T>>>;
+
+    #[method(name = "core_mods_thresholdIssuerGroup")]
+    async fn threshold_issuer_group(
+        &self,
+        id: offchain_signatures::ThresholdIssuerGroupStorageKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<offchain_signatures::ThresholdIssuerGroup<T::T>>>;
+
+    #[method(name = "core_mods_thresholdIssuerGroupsByDid")]
+    async fn threshold_issuer_groups_by_did(
+        &self,
+        did: did::Did,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::ThresholdIssuerGroup<T::T>>>;
+
+    #[method(name = "core_mods_offchainPublicKeyRevokedAt")]
+    async fn offchain_public_key_revoked_at(
+        &self,
+        id: offchain_signatures::SignaturePublicKeyStorageKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<<T::T as Types>::BlockNumber>>;
+
+    /// Returns up to `limit` shared params having supplied signature scheme and curve type starting after
+    /// the params referenced by `after`. Pass the reference of the last returned params as `after` to fetch the next page.
+    #[method(name = "core_mods_sharedOffchainParams")]
+    async fn shared_offchain_params(
+        &self,
+        scheme: Option<offchain_signatures::OffchainSignatureScheme>,
+        curve_type: Option<CurveType>,
+        after: Option<offchain_signatures::SignatureParamsStorageKey>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<
+        Vec<(
+            offchain_signatures::SignatureParamsStorageKey,
+            offchain_signatures::OffchainSignatureParams<T::T>,
+        )>,
+    >;
+
+    #[method(name = "core_mods_offchainParamsReferences")]
+    async fn offchain_params_references(
+        &self,
+        params_ref: offchain_signatures::SignatureParamsStorageKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<u32>;
+
+    /// Returns public keys of the supplied DID referencing offchain signature params that don't exist anymore.
+    #[method(name = "core_mods_offchainDanglingParamsReferences")]
+    async fn offchain_dangling_params_references(
+        &self,
+        did: did::Did,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::SignatureParamsStorageKey>>;
+
+    #[method(name = "core_mods_accumulatorParamsReferences")]
+    async fn accumulator_params_references(
+        &self,
+        params_ref: accumulator::AccumParametersStorageKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<u32>;
+
+    /// Returns accumulator public keys of the supplied owner referencing params that don't exist anymore.
+    #[method(name = "core_mods_accumulatorDanglingParamsReferences")]
+    async fn accumulator_dangling_params_references(
+        &self,
+        owner: accumulator::AccumulatorOwner,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BTreeMap<IncId, accumulator::AccumParametersStorageKey>>;
+
+    /// Returns the fee payable by a `Verifier` for verifying a credential of the given schema issued by the supplied `Issuer`.
+    #[method(name = "trustRegistry_verificationFee")]
+    async fn verification_fee(
+        &self,
+        registry_id: TrustRegistryId,
+        schema_id: TrustRegistrySchemaId,
+        issuer: Issuer,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<VerificationFee>>;
+
+    /// Returns registries recognized by the given `TrustRegistry` which accepted the recognition.
+    #[method(name = "trustRegistry_recognizedRegistries")]
+    async fn recognized_registries(
+        &self,
+        registry_id: TrustRegistryId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BTreeSet<TrustRegistryId>>;
+
+    /// Returns the shortest chain of recognized registries leading from the given `TrustRegistry`
+    /// to the one authorizing the `Issuer` for the supplied schema.
+    #[method(name = "trustRegistry_issuerAuthorizationPath")]
+    async fn issuer_authorization_path(
+        &self,
+        registry_id: TrustRegistryId,
+        schema_id: TrustRegistrySchemaId,
+        issuer: Issuer,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Vec<TrustRegistryId>>>;
+
+    /// Returns up to `limit` (capped by the runtime) pending participation requests of the given `TrustRegistry`
+    /// starting after the request of the `after` participant.
+    /// Pass the last returned participant as `after` to fetch the next page.
+    #[method(name = "trustRegistry_participationRequests")]
+    async fn participation_requests(
+        &self,
+        registry_id: TrustRegistryId,
+        after: Option<IssuerOrVerifier>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(IssuerOrVerifier, ParticipationRequest<T::T>)>>;
+
+    /// Returns up to `limit` (capped by the runtime) registries matching the query starting after the registry with the `after` id.
+    /// Pass the id of the last returned registry as `after` to fetch the next page.
+    #[method(name = "trustRegistry_registriesInfoByPaginated")]
+    async fn registries_info_by_paginated(
+        &self,
+        by: QueryTrustRegistriesBy,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(TrustRegistryId, TrustRegistryInfo<T::T>)>>;
+
+    /// Returns up to `limit` (capped by the runtime) schemas metadata of the registry matching the filter starting after the schema with the `after` id.
+    /// Pass the id of the last returned schema as `after` to fetch the next page.
+    #[method(name = "trustRegistry_allRegistrySchemaMetadataPaginated")]
+    async fn all_registry_schema_metadata_paginated(
+        &self,
+        registry_id: TrustRegistryId,
+        filter: SchemaMetadataFilter,
+        after: Option<TrustRegistrySchemaId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<
+        Vec<(
+            TrustRegistrySchemaId,
+            AggregatedTrustRegistrySchemaMetadata<T::T>,
+        )>,
+    >;
+
+    /// Returns up to `limit` (capped by the runtime) schemas issuers of the registry starting after the schema with the `after` id.
+    #[method(name = "trustRegistry_allRegistrySchemaIssuersPaginated")]
+    async fn all_registry_schema_issuers_paginated(
+        &self,
+        registry_id: TrustRegistryId,
+        after: Option<TrustRegistrySchemaId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<
+        Vec<(
+            TrustRegistrySchemaId,
+            AggregatedTrustRegistrySchemaIssuers<T::T>,
+        )>,
+    >;
+
+    /// Returns up to `limit` (capped by the runtime) schemas verifiers of the registry starting after the schema with the `after` id.
+    #[method(name = "trustRegistry_allRegistrySchemaVerifiersPaginated")]
+    async fn all_registry_schema_verifiers_paginated(
+        &self,
+        registry_id: TrustRegistryId,
+        after: Option<TrustRegistrySchemaId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(TrustRegistrySchemaId, TrustRegistrySchemaVerifiers<T::T>)>>;
+
+    /// Returns up to `limit` (capped by the runtime) metadata entries of the schema starting after the registry with the `after` id.
+    /// Registries are returned in their storage order, pass the id of the last returned registry as `after` to fetch the next page.
+    #[method(name = "trustRegistry_schemaMetadataPaginated")]
+    async fn schema_metadata_paginated(
+        &self,
+        schema_id: TrustRegistrySchemaId,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(TrustRegistryId, AggregatedTrustRegistrySchemaMetadata<T::T>)>>;
+
+    /// Returns up to `limit` (capped by the runtime) issuers entries of the schema starting after the registry with the `after` id.
+    #[method(name = "trustRegistry_schemaIssuersPaginated")]
+    async fn schema_issuers_paginated(
+        &self,
+        schema_id: TrustRegistrySchemaId,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(TrustRegistryId, AggregatedTrustRegistrySchemaIssuers<T::T>)>>;
+
+    /// Returns up to `limit` (capped by the runtime) verifiers entries of the schema starting after the registry with the `after` id.
+    #[method(name = "trustRegistry_schemaVerifiersPaginated")]
+    async fn schema_verifiers_paginated(
+        &self,
+        schema_id: TrustRegistrySchemaId,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(TrustRegistryId, TrustRegistrySchemaVerifiers<T::T>)>>;
+
+    /// Returns up to `limit` (capped by the runtime) ids of the registries matching the query starting after the registry with the `after` id.
+    #[method(name = "trustRegistry_registriesIdsByPaginated")]
+    async fn registries_ids_by_paginated(
+        &self,
+        by: QueryTrustRegistriesBy,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<TrustRegistryId>>;
+
+    /// ToIP Trust Registry Query Protocol (TRQP) authorization query.
+    /// Returns the entity authorizations for the action over the resource along with governance frameworks of the trust registries.
+    #[method(name = "trqp_authorization")]
+    async fn trqp_authorization(
+        &self,
+        query: TrqpAuthorizationQuery,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<TrqpAuthorization<T::T>>>;
+
+    /// Returns governance framework versions history of the given `TrustRegistry` ordered by their effective blocks.
+    #[method(name = "trustRegistry_govFrameworkVersions")]
+    async fn gov_framework_versions(
+        &self,
+        registry_id: TrustRegistryId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<GovFrameworkVersion<T::T>>>;
+
+    /// Returns governance framework version of the given `TrustRegistry` in force at the supplied block.
+    /// Returns `None` if that version was pruned from the history.
+    #[method(name = "trustRegistry_govFrameworkVersionAt")]
+    async fn gov_framework_version_at(
+        &self,
+        registry_id: TrustRegistryId,
+        block_number: <T::T as Types>::BlockNumber,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<GovFrameworkVersion<T::T>>>;
+
+    /// Returns delegations of the given `Issuer` in the `TrustRegistry` along with their scopes.
+    #[method(name = "trustRegistry_issuerDelegations")]
+    async fn issuer_delegations(
+        &self,
+        registry_id: TrustRegistryId,
+        issuer: Issuer,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BTreeMap<Issuer, DelegationScope<T::T>>>;
+
+    /// Returns definition of the schema with the given id along with its JSON Schema document if it's stored on-chain.
+    #[method(name = "trustRegistry_schemaDefinition")]
+    async fn schema_definition(
+        &self,
+        id: TrustRegistrySchemaId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<AggregatedTrustRegistrySchemaDefinition<T::T>>>;
+
+    /// Returns schema metadata in the registry as of the block `as_of` (defaults to the best block) along with
+    /// the governance framework version in force at that block.
+    /// Requires an archive node (`--state-pruning archive`): other nodes only keep the state of the recent blocks
+    /// and fail to answer for the older ones.
+    #[method(name = "trustRegistry_schemaMetadataInRegistryAsOf")]
+    async fn schema_metadata_in_registry_as_of(
+        &self,
+        id: TrustRegistrySchemaId,
+        registry_id: TrustRegistryId,
+        as_of: Option<<T::T as Types>::BlockNumber>,
+    ) -> RpcResult<(
+        Option<AggregatedTrustRegistrySchemaMetadata<T::T>>,
+        Option<GovFrameworkVersion<T::T>>,
+    )>;
+
+    /// Returns schema `Issuer`s in the registry as of the block `as_of` (defaults to the best block) along with
+    /// the governance framework version in force at that block.
+    /// Requires an archive node (`--state-pruning archive`): other nodes only keep the state of the recent blocks
+    /// and fail to answer for the older ones.
+    #[method(name = "trustRegistry_schemaIssuersInRegistryAsOf")]
+    async fn schema_issuers_in_registry_as_of(
+        &self,
+        id: TrustRegistrySchemaId,
+        registry_id: TrustRegistryId,
+        as_of: Option<<T::T as Types>::BlockNumber>,
+    ) -> RpcResult<(
+        Option<AggregatedTrustRegistrySchemaIssuers<T::T>>,
+        Option<GovFrameworkVersion<T::T>>,
+    )>;
 }
 
 /// A struct that implements the [`CoreModsApi`].
@@ -264,6 +663,29 @@ impl<C, P> CoreMods<C, P> {
     }
 }
 
+impl<C, Block> CoreMods<C, Block>
+where
+    Block: BlockT,
+    C: HeaderBackend<Block>,
+{
+    /// Returns hash of the block with the supplied number.
+    fn block_hash<N: UniqueSaturatedInto<u64>>(&self, number: N) -> RpcResult<Block::Hash> {
+        let number = NumberFor::<Block>::unique_saturated_from(number.unique_saturated_into());
+
+        self.client
+            .hash(number)
+            .map_err(Error)?
+            .ok_or_else(|| Error(format!("Block #{} not found", number)).into())
+    }
+
+    /// Returns number of the best block.
+    fn best_number<N: UniqueSaturatedFrom<u64>>(&self) -> N {
+        N::unique_saturated_from(UniqueSaturatedInto::<u64>::unique_saturated_into(
+            self.client.info().best_number,
+        ))
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Error<T>(T);
 
@@ -285,6 +707,7 @@ where
     Block: BlockT,
     T: ConfigWrapper,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C: BlockBackend<Block>,
     C::Api: CoreModsRuntimeApi<Block, T::T>,
 {
     async fn did_details(
@@ -463,12 +886,102 @@ where
         &self,
         id: accumulator::AccumulatorId,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> RpcResult<Option<(Vec<u8>, Option<accumulator::AccumPublicKeyWithParams<T::T>>)>> {
+        version: Option<accumulator::AccumulatorVersion<T::T>>,
+    ) -> RpcResult<
+        Option<(
+            Vec<u8>,
+            Option<accumulator::AccumPublicKeyWithParams<T::T>>,
+            Option<u64>,
+        )>,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        let api_version = api
+            .api_version::<dyn CoreModsRuntimeApi<Block, T::T>>(&at)
+            .map_err(Error)?
+            .unwrap_or_default();
+
+        if api_version < 2 {
+            // Runtimes before version 2 have neither snapshots nor the member count.
+            if version.is_some() {
+                return Err(Error("Accumulator versions aren't supported by the runtime").into());
+            }
+
+            #[allow(deprecated)]
+            let res = api.accumulator_with_public_key_and_params_before_version_2(&at, id);
+
+            return res
+                .map(|res| {
+                    res.map(|(accumulated, key_with_params)| (accumulated, key_with_params, None))
+                })
+                .map_err(Error)
+                .map_err(Into::into);
+        }
+
+        api.accumulator_with_public_key_and_params(&at, id, version)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn accumulator_updates(
+        &self,
+        id: accumulator::AccumulatorId,
+        after: Option<u64>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<
+        Vec<(
+            u64,
+            <T::T as Types>::BlockNumber,
+            Vec<accumulator::AccumulatorUpdateDetails>,
+        )>,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        let blocks = api
+            .accumulator_update_blocks(&at, id, after, limit)
+            .map_err(Error)?;
+
+        // Updates are read from the extrinsics of each block recorded in the `AccumulatorUpdateBlocks`.
+        blocks
+            .into_iter()
+            .map(|(idx, block)| -> RpcResult<_> {
+                let at = BlockId::hash(self.block_hash(block)?);
+                let extrinsics = self
+                    .client
+                    .block_body(&at)
+                    .map_err(Error)?
+                    .ok_or_else(|| Error(format!("Body of the block #{} not found", block)))?;
+                let updates = api
+                    .accumulator_updates_in_block(&at, id, extrinsics)
+                    .map_err(Error)?;
+
+                Ok((idx, block, updates))
+            })
+            .collect()
+    }
+
+    async fn accumulators_by_owner(
+        &self,
+        owner: accumulator::AccumulatorOwner,
+        after: Option<accumulator::AccumulatorId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<
+        Vec<(
+            accumulator::AccumulatorId,
+            accumulator::AccumulatorWithUpdateInfo<T::T>,
+        )>,
+    > {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(||
             // If the block hash is not supplied assume the best block.
             self.client.info().best_hash));
-        api.accumulator_with_public_key_and_params(&at, id)
+        api.accumulators_by_owner(&at, owner, after, limit)
             .map_err(Error)
             .map_err(Into::into)
     }
@@ -704,4 +1217,577 @@ where
             .map_err(Error)
             .map_err(Into::into)
     }
+
+    async fn bbs_ietf_public_key(
+        &self,
+        id: offchain_signatures::SignaturePublicKeyStorageKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<offchain_signatures::BBSIetfPublicKey<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.bbs_ietf_public_key(&at, id)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn bbs_ietf_public_keys_by_did(
+        &self,
+        did: did::Did,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::BBSIetfPublicKey<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.bbs_ietf_public_keys_by_did(&at, did)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn ecdsa_sd_public_key(
+        &self,
+        id: offchain_signatures::SignaturePublicKeyStorageKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<offchain_signatures::EcdsaSdPublicKey<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.ecdsa_sd_public_key(&at, id)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn ecdsa_sd_public_keys_by_did(
+        &self,
+        did: did::Did,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::EcdsaSdPublicKey<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.ecdsa_sd_public_keys_by_did(&at, did)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn ed25519_sd_public_key(
+        &self,
+        id: offchain_signatures::SignaturePublicKeyStorageKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<offchain_signatures::Ed25519SdPublicKey<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.ed25519_sd_public_key(&at, id)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn ed25519_sd_public_keys_by_did(
+        &self,
+        did: did::Did,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::Ed25519SdPublicKey<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.ed25519_sd_public_keys_by_did(&at, did)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn offchain_public_key_with_params(
+        &self,
+        id: offchain_signatures::SignaturePublicKeyStorageKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<offchain_signatures::OffchainPublicKeyWithParams<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.offchain_public_key_with_params(&at, id)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn offchain_public_keys_by_did(
+        &self,
+        did: did::Did,
+        scheme: Option<offchain_signatures::OffchainSignatureScheme>,
+        curve_type: Option<CurveType>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::OffchainPublicKeyWithParams<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.offchain_public_keys_by_did(&at, did, scheme, curve_type)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn offchain_params_by_owner(
+        &self,
+        owner: offchain_signatures::SignatureParamsOwner,
+        scheme: Option<offchain_signatures::OffchainSignatureScheme>,
+        curve_type: Option<CurveType>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::OffchainSignatureParams<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.offchain_params_by_owner(&at, owner, scheme, curve_type)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn threshold_issuer_group(
+        &self,
+        id: offchain_signatures::ThresholdIssuerGroupStorageKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<offchain_signatures::ThresholdIssuerGroup<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.threshold_issuer_group(&at, id)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn threshold_issuer_groups_by_did(
+        &self,
+        did: did::Did,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::ThresholdIssuerGroup<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.threshold_issuer_groups_by_did(&at, did)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn offchain_public_key_revoked_at(
+        &self,
+        id: offchain_signatures::SignaturePublicKeyStorageKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<<T::T as Types>::BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.offchain_public_key_revoked_at(&at, id)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn shared_offchain_params(
+        &self,
+        scheme: Option<offchain_signatures::OffchainSignatureScheme>,
+        curve_type: Option<CurveType>,
+        after: Option<offchain_signatures::SignatureParamsStorageKey>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<
+        Vec<(
+            offchain_signatures::SignatureParamsStorageKey,
+            offchain_signatures::OffchainSignatureParams<T::T>,
+        )>,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.shared_offchain_params(&at, scheme, curve_type, after, limit)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn offchain_params_references(
+        &self,
+        params_ref: offchain_signatures::SignatureParamsStorageKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u32> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.offchain_params_references(&at, params_ref)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn offchain_dangling_params_references(
+        &self,
+        did: did::Did,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BTreeMap<IncId, offchain_signatures::SignatureParamsStorageKey>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.offchain_dangling_params_references(&at, did)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn accumulator_params_references(
+        &self,
+        params_ref: accumulator::AccumParametersStorageKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u32> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.accumulator_params_references(&at, params_ref)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn accumulator_dangling_params_references(
+        &self,
+        owner: accumulator::AccumulatorOwner,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BTreeMap<IncId, accumulator::AccumParametersStorageKey>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.accumulator_dangling_params_references(&at, owner)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn verification_fee(
+        &self,
+        registry_id: TrustRegistryId,
+        schema_id: TrustRegistrySchemaId,
+        issuer: Issuer,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<VerificationFee>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.verification_fee(&at, registry_id, schema_id, issuer)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn recognized_registries(
+        &self,
+        registry_id: TrustRegistryId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BTreeSet<TrustRegistryId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.recognized_registries(&at, registry_id)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn issuer_authorization_path(
+        &self,
+        registry_id: TrustRegistryId,
+        schema_id: TrustRegistrySchemaId,
+        issuer: Issuer,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Vec<TrustRegistryId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.issuer_authorization_path(&at, registry_id, schema_id, issuer)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn participation_requests(
+        &self,
+        registry_id: TrustRegistryId,
+        after: Option<IssuerOrVerifier>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(IssuerOrVerifier, ParticipationRequest<T::T>)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.participation_requests(&at, registry_id, after, limit)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn registries_info_by_paginated(
+        &self,
+        by: QueryTrustRegistriesBy,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(TrustRegistryId, TrustRegistryInfo<T::T>)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.registries_info_by_paginated(&at, by, after, limit)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn all_registry_schema_metadata_paginated(
+        &self,
+        registry_id: TrustRegistryId,
+        filter: SchemaMetadataFilter,
+        after: Option<TrustRegistrySchemaId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<
+        Vec<(
+            TrustRegistrySchemaId,
+            AggregatedTrustRegistrySchemaMetadata<T::T>,
+        )>,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.all_registry_schema_metadata_paginated(&at, registry_id, filter, after, limit)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn all_registry_schema_issuers_paginated(
+        &self,
+        registry_id: TrustRegistryId,
+        after: Option<TrustRegistrySchemaId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<
+        Vec<(
+            TrustRegistrySchemaId,
+            AggregatedTrustRegistrySchemaIssuers<T::T>,
+        )>,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.all_registry_schema_issuers_paginated(&at, registry_id, after, limit)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn all_registry_schema_verifiers_paginated(
+        &self,
+        registry_id: TrustRegistryId,
+        after: Option<TrustRegistrySchemaId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(TrustRegistrySchemaId, TrustRegistrySchemaVerifiers<T::T>)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.all_registry_schema_verifiers_paginated(&at, registry_id, after, limit)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn schema_metadata_paginated(
+        &self,
+        schema_id: TrustRegistrySchemaId,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(TrustRegistryId, AggregatedTrustRegistrySchemaMetadata<T::T>)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.schema_metadata_paginated(&at, schema_id, after, limit)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn schema_issuers_paginated(
+        &self,
+        schema_id: TrustRegistrySchemaId,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(TrustRegistryId, AggregatedTrustRegistrySchemaIssuers<T::T>)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.schema_issuers_paginated(&at, schema_id, after, limit)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn schema_verifiers_paginated(
+        &self,
+        schema_id: TrustRegistrySchemaId,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(TrustRegistryId, TrustRegistrySchemaVerifiers<T::T>)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.schema_verifiers_paginated(&at, schema_id, after, limit)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn registries_ids_by_paginated(
+        &self,
+        by: QueryTrustRegistriesBy,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<TrustRegistryId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.registries_ids_by_paginated(&at, by, after, limit)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn trqp_authorization(
+        &self,
+        query: TrqpAuthorizationQuery,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<TrqpAuthorization<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.trqp_authorization(&at, query)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn gov_framework_versions(
+        &self,
+        registry_id: TrustRegistryId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<GovFrameworkVersion<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.gov_framework_versions(&at, registry_id)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn gov_framework_version_at(
+        &self,
+        registry_id: TrustRegistryId,
+        block_number: <T::T as Types>::BlockNumber,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<GovFrameworkVersion<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.gov_framework_version_at(&at, registry_id, block_number)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn issuer_delegations(
+        &self,
+        registry_id: TrustRegistryId,
+        issuer: Issuer,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BTreeMap<Issuer, DelegationScope<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.issuer_delegations(&at, registry_id, issuer)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn schema_definition(
+        &self,
+        id: TrustRegistrySchemaId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<AggregatedTrustRegistrySchemaDefinition<T::T>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+        api.schema_definition(&at, id)
+            .map_err(Error)
+            .map_err(Into::into)
+    }
+
+    async fn schema_metadata_in_registry_as_of(
+        &self,
+        id: TrustRegistrySchemaId,
+        registry_id: TrustRegistryId,
+        as_of: Option<<T::T as Types>::BlockNumber>,
+    ) -> RpcResult<(
+        Option<AggregatedTrustRegistrySchemaMetadata<T::T>>,
+        Option<GovFrameworkVersion<T::T>>,
+    )> {
+        let api = self.client.runtime_api();
+        let block = as_of.unwrap_or_else(||
+            // If the block number is not supplied assume the best block.
+            self.best_number());
+        let at = BlockId::hash(self.block_hash(block)?);
+
+        let metadata = api
+            .schema_metadata_in_registry(&at, id, registry_id)
+            .map_err(Error)?;
+        let gov_framework = api
+            .gov_framework_version_at(&at, registry_id, block)
+            .map_err(Error)?;
+
+        Ok((metadata, gov_framework))
+    }
+
+    async fn schema_issuers_in_registry_as_of(
+        &self,
+        id: TrustRegistrySchemaId,
+        registry_id: TrustRegistryId,
+        as_of: Option<<T::T as Types>::BlockNumber>,
+    ) -> RpcResult<(
+        Option<AggregatedTrustRegistrySchemaIssuers<T::T>>,
+        Option<GovFrameworkVersion<T::T>>,
+    )> {
+        let api = self.client.runtime_api();
+        let block = as_of.unwrap_or_else(||
+            // If the block number is not supplied assume the best block.
+            self.best_number());
+        let at = BlockId::hash(self.block_hash(block)?);
+
+        let issuers = api
+            .schema_issuers_in_registry(&at, id, registry_id)
+            .map_err(Error)?;
+        let gov_framework = api
+            .gov_framework_version_at(&at, registry_id, block)
+            .map_err(Error)?;
+
+        Ok((issuers, gov_framework))
+    }
 }
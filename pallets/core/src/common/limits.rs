@@ -48,6 +48,14 @@ pub trait Limits: Clone + Eq {
     /// Maximum byte size of the `PS` public key. This depends on the chosen elliptic curve and the number
     /// of messages that can be signed.
     type MaxPSPublicKeySize: Size;
+    /// Maximum byte size of the IETF `BBS` (fixed size) public key. This depends only on the chosen ciphersuite.
+    type MaxBBSIetfPublicKeySize: Size;
+    /// Maximum byte size of the `ECDSA-SD` public key. This depends only on the chosen elliptic curve.
+    type MaxEcdsaSdPublicKeySize: Size;
+    /// Maximum byte size of the selective disclosure `Ed25519` (fixed size) public key.
+    type MaxEd25519SdPublicKeySize: Size;
+    /// Max amount of the participants in a single threshold issuer group.
+    type MaxThresholdIssuerParticipants: Size;
 
     /// Max amount of master members per a single `Membership`.
     type MaxMasterMembers: Size;
@@ -87,6 +95,8 @@ pub trait Limits: Clone + Eq {
     type MaxRegistryParticipantDescriptionSize: Size;
     /// Max size of the `TrustRegistry` participant's logo.
     type MaxRegistryParticipantLogoSize: Size;
+    /// Max no of registries recognized by a single registry.
+    type MaxRecognizedRegistries: Size;
 }
 
 type Zero = ConstU32<0>;
@@ -117,7 +127,11 @@ impl Limits for () {
     type MaxBBSPublicKeySize = NoLimit;
     type MaxBBSPlusPublicKeySize = NoLimit;
     type MaxBBDT16PublicKeySize = NoLimit;
+    type MaxBBSIetfPublicKeySize = NoLimit;
+    type MaxEcdsaSdPublicKeySize = NoLimit;
+    type MaxEd25519SdPublicKeySize = NoLimit;
     type MaxPSPublicKeySize = NoLimit;
+    type MaxThresholdIssuerParticipants = NoLimit;
 
     type MaxMasterMembers = NoLimit;
     type MaxPolicyControllers = NoLimit;
@@ -139,4 +153,5 @@ impl Limits for () {
     type MaxRegistryParticipantOrgNameSize = NoLimit;
     type MaxRegistryParticipantDescriptionSize = NoLimit;
     type MaxRegistryParticipantLogoSize = NoLimit;
+    type MaxRecognizedRegistries = NoLimit;
 }
@@ -59,6 +59,10 @@ impl<T: Types, L: Limits> Limits for CombineTypesAndLimits<T, L> {
     type MaxBBSPlusPublicKeySize = L::MaxBBSPlusPublicKeySize;
     type MaxPSPublicKeySize = L::MaxPSPublicKeySize;
     type MaxBBDT16PublicKeySize = L::MaxBBDT16PublicKeySize;
+    type MaxBBSIetfPublicKeySize = L::MaxBBSIetfPublicKeySize;
+    type MaxEcdsaSdPublicKeySize = L::MaxEcdsaSdPublicKeySize;
+    type MaxEd25519SdPublicKeySize = L::MaxEd25519SdPublicKeySize;
+    type MaxThresholdIssuerParticipants = L::MaxThresholdIssuerParticipants;
 
     type MaxMasterMembers = L::MaxMasterMembers;
     type MaxPolicyControllers = L::MaxPolicyControllers;
@@ -80,4 +84,5 @@ impl<T: Types, L: Limits> Limits for CombineTypesAndLimits<T, L> {
     type MaxRegistryParticipantOrgNameSize = L::MaxRegistryParticipantOrgNameSize;
     type MaxRegistryParticipantLogoSize = L::MaxRegistryParticipantLogoSize;
     type MaxRegistryParticipantDescriptionSize = L::MaxRegistryParticipantDescriptionSize;
+    type MaxRecognizedRegistries = L::MaxRecognizedRegistries;
 }
@@ -42,7 +42,31 @@ crate::def_state_change! {
         trust_registry::SuspendIssuers,
         trust_registry::UnsuspendIssuers,
         trust_registry::ChangeParticipants,
-        trust_registry::SetParticipantInformation
+        trust_registry::SetParticipantInformation,
+        accumulator::UpdateAccumulatorPublicKey,
+        accumulator::TransferAccumulator,
+        offchain_signatures::AddThresholdIssuerGroup,
+        offchain_signatures::UpdateThresholdIssuerGroup,
+        offchain_signatures::RevokeOffchainSignaturePublicKey,
+        offchain_signatures::ShareOffchainSignatureParams,
+        trust_registry::RemoveTrustRegistry,
+        trust_registry::TransferTrustRegistry,
+        trust_registry::SetFeePayoutAccount,
+        trust_registry::SettleVerificationFee,
+        trust_registry::SetIssuersAccreditation,
+        trust_registry::UpdateRecognizedRegistries,
+        trust_registry::UpdateRecognitionAcceptances,
+        trust_registry::RequestParticipation,
+        trust_registry::ResolveParticipationRequests,
+        trust_registry::AddGovFrameworkVersion,
+        trust_registry::UpdateDelegations,
+        trust_registry::RevokeDelegations,
+        trust_registry::AddSchemaDefinition,
+        accumulator::AcceptAccumulatorTransfer,
+        offchain_signatures::ForceRemoveOffchainSignatureParams,
+        accumulator::ForceRemoveAccumulatorParams,
+        trust_registry::AcceptTrustRegistryTransfer,
+        offchain_signatures::RemoveThresholdIssuerGroup
 }
 
 /// Converts the given entity to the state change.
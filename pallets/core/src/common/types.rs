@@ -11,4 +11,10 @@ pub use crate::util::Types;
 pub enum CurveType {
     /// BLS12-381
     Bls12381,
+    /// NIST P-256 (secp256r1)
+    P256,
+    /// NIST P-384 (secp384r1)
+    P384,
+    /// Edwards25519
+    Ed25519,
 }
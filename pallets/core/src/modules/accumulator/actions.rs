@@ -44,6 +44,21 @@ pub struct RemoveAccumulatorParams<T: TypesAndLimits> {
     pub nonce: T::BlockNumber,
 }
 
+/// Removes the params even if they're referenced by public keys leaving these references dangling.
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, DebugNoBound, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct ForceRemoveAccumulatorParams<T: TypesAndLimits> {
+    pub params_ref: AccumParametersStorageKey,
+    pub nonce: T::BlockNumber,
+}
+
 #[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, DebugNoBound, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
@@ -107,11 +122,63 @@ pub struct UpdateAccumulator<T: TypesAndLimits> {
     pub nonce: T::BlockNumber,
 }
 
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, DebugNoBound, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct UpdateAccumulatorPublicKey<T: TypesAndLimits> {
+    pub id: AccumulatorId,
+    /// Identifier of the owner's public key to be used by the accumulator.
+    pub key_id: IncId,
+    /// Next valid nonce, i.e. 1 greater than currently stored
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, DebugNoBound, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct TransferAccumulator<T: TypesAndLimits> {
+    pub id: AccumulatorId,
+    /// Public key of the new owner to be used by the accumulator.
+    pub new_key_ref: AccumPublicKeyStorageKey,
+    /// Next valid nonce, i.e. 1 greater than currently stored
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, DebugNoBound, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct AcceptAccumulatorTransfer<T: TypesAndLimits> {
+    pub id: AccumulatorId,
+    /// Next valid nonce, i.e. 1 greater than currently stored
+    pub nonce: T::BlockNumber,
+}
+
 crate::impl_action_with_nonce! {
     for AccumulatorId:
         AddAccumulator with 1 as len, id as target,
         UpdateAccumulator with 1 as len, id as target,
-        RemoveAccumulator with 1 as len, id as target
+        RemoveAccumulator with 1 as len, id as target,
+        UpdateAccumulatorPublicKey with 1 as len, id as target,
+        TransferAccumulator with 1 as len, id as target,
+        AcceptAccumulatorTransfer with 1 as len, id as target
 }
 
 crate::impl_action_with_nonce! {
@@ -121,7 +188,8 @@ crate::impl_action_with_nonce! {
 
 crate::impl_action_with_nonce! {
     for AccumParametersStorageKey:
-        RemoveAccumulatorParams with 1 as len, params_ref as target
+        RemoveAccumulatorParams with 1 as len, params_ref as target,
+        ForceRemoveAccumulatorParams with 1 as len, params_ref as target
 }
 
 crate::impl_action_with_nonce! {
@@ -2,8 +2,10 @@ use super::*;
 use crate::{
     common::{state_change::ToStateChange, IntermediateError},
     did::{Did, DidSignature, UncheckedDidKey},
-    util::{Action, IncId},
+    util::{compressed, Action, IncId},
 };
+use ark_bls12_381::{Fr, G1Affine, G2Affine};
+use ark_ec::{AffineRepr, CurveGroup};
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 #[cfg(not(feature = "std"))]
@@ -259,9 +261,13 @@ crate::bench_with_all_pairs! {
         let did = Did([1; Did::BYTE_SIZE]);
 
         let public = pair.public();
-        let accumulator = Accumulator::Positive(AccumulatorCommon {
-            accumulated: vec![3; MAX_ACC as usize].try_into().unwrap(),
-            key_ref: AccumPublicKeyStorageKey(AccumulatorOwner(did.into()), 1u8.try_into().unwrap()),
+        // Universal accumulator has its members counted thus being the worst case.
+        let accumulator = Accumulator::Universal(UniversalAccumulator {
+            common: AccumulatorCommon {
+                accumulated: vec![3; MAX_ACC as usize].try_into().unwrap(),
+                key_ref: AccumPublicKeyStorageKey(AccumulatorOwner(did.into()), 1u8.try_into().unwrap()),
+            },
+            max_size: u64::MAX,
         });
 
         crate::did::Pallet::<T>::new_onchain_(
@@ -305,6 +311,8 @@ crate::bench_with_all_pairs! {
             accumulator,
             nonce: 1u8.into()
         }.modify_removable::<T, _, _, _, _>(|action, acc| Pallet::<T>::add_accumulator_(action, acc, AccumulatorOwner(did.into())).map_err(IntermediateError::<T>::from)).unwrap();
+        // Make sure the removals don't underflow the amount of the members.
+        Accumulators::<T>::mutate(acc_id, |acc| acc.as_mut().unwrap().member_count = Some(d.into()));
 
         let new_accumulated = vec![3; a as usize];
         let up_acc = UpdateAccumulator {
@@ -387,4 +395,189 @@ crate::bench_with_all_pairs! {
     verify {
         assert!(Accumulators::<T>::get(acc_id).is_none());
     }
+
+    update_accumulator_public_key_sr25519 for sr25519, update_accumulator_public_key_ed25519 for ed25519, update_accumulator_public_key_secp256k1 for secp256k1 {
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([1; Did::BYTE_SIZE]);
+        let public = pair.public();
+        let new_owner = AccumulatorOwner(Did([2; Did::BYTE_SIZE]).into());
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        for owner in [AccumulatorOwner(did.into()), AccumulatorOwner(did.into()), new_owner] {
+            ActionWithNonceWrapper::<T, _, _>::new(
+                1u8.into(),
+                owner,
+                AddAccumulatorPublicKey {
+                    public_key: AccumulatorPublicKey {
+                        curve_type: CurveType::Bls12381,
+                        bytes: vec![3; MAX_KEY as usize].try_into().unwrap(),
+                        params_ref: None
+                    },
+                    nonce: 1u8.into()
+                }
+            ).modify::<T, _, _, _, _>(|action, counters| Pallet::<T>::add_public_key_(action.action, counters, owner).map_err(IntermediateError::<T>::from)).unwrap();
+        }
+
+        let acc_id: AccumulatorId = AccumulatorId([3; 32]);
+        AddAccumulator {
+            id: acc_id,
+            accumulator: Accumulator::Positive(AccumulatorCommon {
+                accumulated: vec![3; MAX_ACC as usize].try_into().unwrap(),
+                key_ref: AccumPublicKeyStorageKey(AccumulatorOwner(did.into()), 1u8.into()),
+            }),
+            nonce: 1u8.into()
+        }.modify_removable::<T, _, _, _, _>(|action, acc| Pallet::<T>::add_accumulator_(action, acc, AccumulatorOwner(did.into())).map_err(IntermediateError::<T>::from)).unwrap();
+
+        let update_key = UpdateAccumulatorPublicKey {
+            id: acc_id,
+            key_id: 2u8.into(),
+            nonce: 1u8.into()
+        };
+
+        let sig = pair.sign(&update_key.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: update_accumulator_public_key(RawOrigin::Signed(caller), update_key, signature)
+    verify {
+        assert_eq!(Accumulators::<T>::get(acc_id).unwrap().accumulator.key_ref(), AccumPublicKeyStorageKey(AccumulatorOwner(did.into()), 2u8.into()));
+    }
+
+    transfer_accumulator_sr25519 for sr25519, transfer_accumulator_ed25519 for ed25519, transfer_accumulator_secp256k1 for secp256k1 {
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([1; Did::BYTE_SIZE]);
+        let public = pair.public();
+        let new_owner = AccumulatorOwner(Did([2; Did::BYTE_SIZE]).into());
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        for owner in [AccumulatorOwner(did.into()), AccumulatorOwner(did.into()), new_owner] {
+            ActionWithNonceWrapper::<T, _, _>::new(
+                1u8.into(),
+                owner,
+                AddAccumulatorPublicKey {
+                    public_key: AccumulatorPublicKey {
+                        curve_type: CurveType::Bls12381,
+                        bytes: vec![3; MAX_KEY as usize].try_into().unwrap(),
+                        params_ref: None
+                    },
+                    nonce: 1u8.into()
+                }
+            ).modify::<T, _, _, _, _>(|action, counters| Pallet::<T>::add_public_key_(action.action, counters, owner).map_err(IntermediateError::<T>::from)).unwrap();
+        }
+
+        let acc_id: AccumulatorId = AccumulatorId([4; 32]);
+        AddAccumulator {
+            id: acc_id,
+            accumulator: Accumulator::Positive(AccumulatorCommon {
+                accumulated: vec![3; MAX_ACC as usize].try_into().unwrap(),
+                key_ref: AccumPublicKeyStorageKey(AccumulatorOwner(did.into()), 1u8.into()),
+            }),
+            nonce: 1u8.into()
+        }.modify_removable::<T, _, _, _, _>(|action, acc| Pallet::<T>::add_accumulator_(action, acc, AccumulatorOwner(did.into())).map_err(IntermediateError::<T>::from)).unwrap();
+
+        let transfer = TransferAccumulator {
+            id: acc_id,
+            new_key_ref: AccumPublicKeyStorageKey(new_owner, 1u8.into()),
+            nonce: 1u8.into()
+        };
+
+        let sig = pair.sign(&transfer.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: transfer_accumulator(RawOrigin::Signed(caller), transfer, signature)
+    verify {
+        assert_eq!(PendingAccumulatorTransfers::<T>::get(acc_id), Some(AccumPublicKeyStorageKey(new_owner, 1u8.into())));
+    }
+
+    accept_accumulator_transfer_sr25519 for sr25519, accept_accumulator_transfer_ed25519 for ed25519, accept_accumulator_transfer_secp256k1 for secp256k1 {
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([2; Did::BYTE_SIZE]);
+        let public = pair.public();
+        let owner = AccumulatorOwner(Did([1; Did::BYTE_SIZE]).into());
+        let new_owner = AccumulatorOwner(did.into());
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        for owner in [owner, owner, new_owner] {
+            ActionWithNonceWrapper::<T, _, _>::new(
+                1u8.into(),
+                owner,
+                AddAccumulatorPublicKey {
+                    public_key: AccumulatorPublicKey {
+                        curve_type: CurveType::Bls12381,
+                        bytes: vec![3; MAX_KEY as usize].try_into().unwrap(),
+                        params_ref: None
+                    },
+                    nonce: 1u8.into()
+                }
+            ).modify::<T, _, _, _, _>(|action, counters| Pallet::<T>::add_public_key_(action.action, counters, owner).map_err(IntermediateError::<T>::from)).unwrap();
+        }
+
+        let acc_id: AccumulatorId = AccumulatorId([4; 32]);
+        AddAccumulator {
+            id: acc_id,
+            accumulator: Accumulator::Positive(AccumulatorCommon {
+                accumulated: vec![3; MAX_ACC as usize].try_into().unwrap(),
+                key_ref: AccumPublicKeyStorageKey(owner, 1u8.into()),
+            }),
+            nonce: 1u8.into()
+        }.modify_removable::<T, _, _, _, _>(|action, acc| Pallet::<T>::add_accumulator_(action, acc, owner).map_err(IntermediateError::<T>::from)).unwrap();
+        PendingAccumulatorTransfers::<T>::insert(acc_id, AccumPublicKeyStorageKey(new_owner, 1u8.into()));
+
+        let accept = AcceptAccumulatorTransfer {
+            id: acc_id,
+            nonce: 1u8.into()
+        };
+
+        let sig = pair.sign(&accept.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: accept_accumulator_transfer(RawOrigin::Signed(caller), accept, signature)
+    verify {
+        assert_eq!(*Accumulators::<T>::get(acc_id).unwrap().accumulator.owner_did(), new_owner);
+        assert!(PendingAccumulatorTransfers::<T>::get(acc_id).is_none());
+    };
+
+    standard:
+    verify_update {
+        let owner = AccumulatorOwner(Did([1; Did::BYTE_SIZE]).into());
+        let secret_key = Fr::from(11u64);
+        let (p, p_tilde) = (G1Affine::generator(), G2Affine::generator());
+
+        AccumulatorParams::<T>::insert(owner, IncId::from(1u8), AccumulatorParameters {
+            curve_type: CurveType::Bls12381,
+            bytes: compressed((p, p_tilde)).try_into().unwrap(),
+            label: None,
+        });
+        AccumulatorKeys::<T>::insert(owner, IncId::from(1u8), AccumulatorPublicKey {
+            curve_type: CurveType::Bls12381,
+            bytes: compressed((p_tilde * secret_key).into_affine()).try_into().unwrap(),
+            params_ref: Some(AccumParametersStorageKey(owner, IncId::from(1u8))),
+        });
+
+        // KB universal accumulator requires two pairing checks thus being the worst case.
+        let member = Fr::from(5u64);
+        let (mem, non_mem) = ((p * Fr::from(3u64)).into_affine(), (p * Fr::from(7u64)).into_affine());
+        let accumulator = Accumulator::<T>::KBUniversal(AccumulatorCommon {
+            accumulated: compressed((mem, (non_mem * (member + secret_key)).into_affine())).try_into().unwrap(),
+            key_ref: AccumPublicKeyStorageKey(owner, IncId::from(1u8)),
+        });
+        let new_accumulated = compressed(((mem * (member + secret_key)).into_affine(), non_mem));
+        let additions: Vec<Bytes> = vec![compressed(member).into()];
+    }: {
+        Pallet::<T>::verify_update(&accumulator, &new_accumulated, &additions, &[]).unwrap();
+    }
 }
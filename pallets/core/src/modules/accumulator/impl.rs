@@ -1,5 +1,7 @@
 use super::*;
 use crate::deposit_indexed_event;
+use frame_support::traits::IsSubType;
+use frame_system::Phase;
 
 impl<T: Config> Pallet<T> {
     pub(super) fn add_params_(
@@ -7,6 +9,10 @@ impl<T: Config> Pallet<T> {
         StoredAccumulatorOwnerCounters { params_counter, .. }: &mut StoredAccumulatorOwnerCounters,
         owner: AccumulatorOwner,
     ) -> DispatchResult {
+        ensure!(
+            params.is_curve_supported(),
+            Error::<T>::UnsupportedCurveType
+        );
         AccumulatorParams::<T>::insert(owner, params_counter.inc(), params);
 
         Self::deposit_event(Event::ParamsAdded(owner, *params_counter));
@@ -18,11 +24,19 @@ impl<T: Config> Pallet<T> {
         StoredAccumulatorOwnerCounters { key_counter, .. }: &mut StoredAccumulatorOwnerCounters,
         owner: AccumulatorOwner,
     ) -> DispatchResult {
+        ensure!(
+            public_key.is_curve_supported(),
+            Error::<T>::UnsupportedCurveType
+        );
         if let Some(AccumParametersStorageKey(acc_owner, params_id)) = public_key.params_ref {
             ensure!(
                 AccumulatorParams::<T>::contains_key(acc_owner, params_id),
                 Error::<T>::ParamsDontExist
             );
+
+            AccumulatorParamsReferences::<T>::mutate(acc_owner, params_id, |refs| {
+                *refs = refs.saturating_add(1)
+            });
         }
 
         AccumulatorKeys::insert(owner, key_counter.inc(), public_key);
@@ -32,16 +46,34 @@ impl<T: Config> Pallet<T> {
     }
 
     pub(super) fn remove_params_(
-        RemoveAccumulatorParams {
-            params_ref: AccumParametersStorageKey(did, counter),
-            ..
-        }: RemoveAccumulatorParams<T>,
+        RemoveAccumulatorParams { params_ref, .. }: RemoveAccumulatorParams<T>,
         accumulator_params: &mut Option<AccumulatorParameters<T>>,
         _: AccumulatorOwner,
     ) -> DispatchResult {
-        accumulator_params
-            .take()
-            .ok_or(Error::<T>::ParamsDontExist)?;
+        Self::remove_existing_params(params_ref, accumulator_params, false)
+    }
+
+    pub(super) fn force_remove_params_(
+        ForceRemoveAccumulatorParams { params_ref, .. }: ForceRemoveAccumulatorParams<T>,
+        accumulator_params: &mut Option<AccumulatorParameters<T>>,
+        _: AccumulatorOwner,
+    ) -> DispatchResult {
+        Self::remove_existing_params(params_ref, accumulator_params, true)
+    }
+
+    /// Removes the params ensuring they aren't referenced by public keys unless `force` is set.
+    fn remove_existing_params(
+        AccumParametersStorageKey(did, counter): AccumParametersStorageKey,
+        accumulator_params: &mut Option<AccumulatorParameters<T>>,
+        force: bool,
+    ) -> DispatchResult {
+        ensure!(accumulator_params.is_some(), Error::<T>::ParamsDontExist);
+        // References of the force removed params are kept, so they're released along with the keys.
+        ensure!(
+            force || AccumulatorParamsReferences::<T>::get(did, counter) == 0,
+            Error::<T>::ParamsInUse
+        );
+        accumulator_params.take();
 
         Self::deposit_event(Event::ParamsRemoved(did, counter));
         Ok(())
@@ -55,10 +87,18 @@ impl<T: Config> Pallet<T> {
         accumulator_pk: &mut Option<AccumulatorPublicKey<T>>,
         _: AccumulatorOwner,
     ) -> DispatchResult {
-        accumulator_pk
+        let public_key = accumulator_pk
             .take()
             .ok_or(Error::<T>::PublicKeyDoesntExist)?;
 
+        if let Some(AccumParametersStorageKey(acc_owner, params_id)) = public_key.params_ref {
+            AccumulatorParamsReferences::<T>::mutate_exists(acc_owner, params_id, |refs| {
+                *refs = refs
+                    .map(|refs| refs.saturating_sub(1))
+                    .filter(|&refs| refs > 0)
+            });
+        }
+
         Self::deposit_event(Event::KeyRemoved(did, counter));
         Ok(())
     }
@@ -87,10 +127,10 @@ impl<T: Config> Pallet<T> {
 
         let current_block = <frame_system::Pallet<T>>::block_number();
         let acc = AccumulatorWithUpdateInfo::new(accumulator, current_block);
-        ensure!(
-            acc_opt.replace(acc).is_none(),
-            Error::<T>::AccumulatorAlreadyExists
-        );
+        ensure!(acc_opt.is_none(), Error::<T>::AccumulatorAlreadyExists);
+        Self::take_snapshot(id, &acc);
+        *acc_opt = Some(acc);
+        OwnerAccumulators::<T>::insert(owner, id, ());
 
         deposit_indexed_event!(AccumulatorAdded(id, accumulated) over id);
         Ok(())
@@ -100,6 +140,8 @@ impl<T: Config> Pallet<T> {
         UpdateAccumulator {
             id,
             new_accumulated,
+            additions,
+            removals,
             ..
         }: UpdateAccumulator<T>,
         accumulator: &mut AccumulatorWithUpdateInfo<T>,
@@ -111,11 +153,27 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotAccumulatorOwner
         );
 
+        accumulator.update_member_count(
+            additions.as_ref().map(Vec::len),
+            removals.as_ref().map(Vec::len),
+        )?;
+
+        if T::VerifyUpdates::get() {
+            Self::verify_update(
+                &accumulator.accumulator,
+                &new_accumulated,
+                additions.as_deref().unwrap_or_default(),
+                removals.as_deref().unwrap_or_default(),
+            )?;
+        }
+
         accumulator
             .accumulator
             .set_new_accumulated(new_accumulated.clone().0)
             .map_err(|_| Error::<T>::AccumulatedTooBig)?;
         accumulator.last_updated_at = <frame_system::Pallet<T>>::block_number();
+        Self::take_snapshot(id, accumulator);
+        Self::record_update_block(id, accumulator.last_updated_at);
 
         // The event stores only the accumulated value which can be used by the verifier.
         // For witness update, that information is retrieved by looking at the block and parsing the extrinsic.
@@ -123,6 +181,175 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    pub(super) fn update_accumulator_public_key_(
+        UpdateAccumulatorPublicKey { id, key_id, .. }: UpdateAccumulatorPublicKey<T>,
+        accumulator: &mut AccumulatorWithUpdateInfo<T>,
+        owner: AccumulatorOwner,
+    ) -> DispatchResult {
+        let key_ref = AccumPublicKeyStorageKey(owner, key_id);
+        Self::replace_accumulator_public_key(id, accumulator, owner, key_ref)?;
+
+        deposit_indexed_event!(AccumulatorPublicKeyUpdated(id, key_ref) over id);
+        Ok(())
+    }
+
+    pub(super) fn transfer_accumulator_(
+        TransferAccumulator {
+            id, new_key_ref, ..
+        }: TransferAccumulator<T>,
+        accumulator: &mut AccumulatorWithUpdateInfo<T>,
+        owner: AccumulatorOwner,
+    ) -> DispatchResult {
+        // Only the DID that owns the accumulator can transfer it
+        ensure!(
+            *accumulator.accumulator.owner_did() == owner,
+            Error::<T>::NotAccumulatorOwner
+        );
+        let AccumPublicKeyStorageKey(new_owner, new_key_id) = new_key_ref;
+        ensure!(new_owner != owner, Error::<T>::SameAccumulatorOwner);
+        // key_id being zero indicates that no public key exists for the accumulator
+        if !new_key_id.is_zero() {
+            ensure!(
+                AccumulatorKeys::<T>::contains_key(new_owner, new_key_id),
+                Error::<T>::PublicKeyDoesntExist
+            );
+        }
+
+        PendingAccumulatorTransfers::<T>::insert(id, new_key_ref);
+
+        deposit_indexed_event!(AccumulatorTransferProposed(id, new_key_ref) over id);
+        Ok(())
+    }
+
+    pub(super) fn accept_accumulator_transfer_(
+        AcceptAccumulatorTransfer { id, .. }: AcceptAccumulatorTransfer<T>,
+        accumulator: &mut AccumulatorWithUpdateInfo<T>,
+        new_owner: AccumulatorOwner,
+    ) -> DispatchResult {
+        // Only the DID owning the public key of the pending transfer can accept it
+        let new_key_ref = PendingAccumulatorTransfers::<T>::get(id)
+            .filter(|AccumPublicKeyStorageKey(key_owner, _)| *key_owner == new_owner)
+            .ok_or(Error::<T>::NoPendingAccumulatorTransfer)?;
+        let owner = *accumulator.accumulator.owner_did();
+
+        Self::replace_accumulator_public_key(id, accumulator, owner, new_key_ref)?;
+        PendingAccumulatorTransfers::<T>::remove(id);
+        OwnerAccumulators::<T>::remove(owner, id);
+        OwnerAccumulators::<T>::insert(new_owner, id, ());
+
+        deposit_indexed_event!(AccumulatorTransferred(id, new_key_ref) over id);
+        Ok(())
+    }
+
+    /// Makes the accumulator use the supplied public key, recording the replaced key in the `ReplacedAccumulatorKeys`.
+    fn replace_accumulator_public_key(
+        id: AccumulatorId,
+        accumulator: &mut AccumulatorWithUpdateInfo<T>,
+        owner: AccumulatorOwner,
+        AccumPublicKeyStorageKey(new_owner, new_key_id): AccumPublicKeyStorageKey,
+    ) -> DispatchResult {
+        // Only the DID that owns the accumulator can replace its public key
+        ensure!(
+            *accumulator.accumulator.owner_did() == owner,
+            Error::<T>::NotAccumulatorOwner
+        );
+        let key_ref = accumulator.accumulator.key_ref();
+        ensure!(
+            key_ref != AccumPublicKeyStorageKey(new_owner, new_key_id),
+            Error::<T>::SameAccumulatorPublicKey
+        );
+        // key_id being zero indicates that no public key exists for the accumulator
+        if !new_key_id.is_zero() {
+            ensure!(
+                AccumulatorKeys::<T>::contains_key(new_owner, new_key_id),
+                Error::<T>::PublicKeyDoesntExist
+            );
+        }
+
+        let mut counter = ReplacedAccumulatorKeysCounter::<T>::get(id);
+        ensure!(
+            counter < IncId::from(T::MaxAccumulatorKeyReplacements::get()),
+            Error::<T>::TooManyAccumulatorKeyReplacements
+        );
+
+        let replaced_at = <frame_system::Pallet<T>>::block_number();
+        ReplacedAccumulatorKeys::<T>::insert(
+            id,
+            *counter.inc(),
+            ReplacedAccumulatorPublicKey {
+                key_ref,
+                replaced_at,
+            },
+        );
+        ReplacedAccumulatorKeysCounter::<T>::insert(id, counter);
+        accumulator
+            .accumulator
+            .set_key_ref(AccumPublicKeyStorageKey(new_owner, new_key_id));
+        Self::take_snapshot(id, accumulator);
+
+        Ok(())
+    }
+
+    /// Records the current state of the accumulator in the `AccumulatorSnapshots`, replacing the oldest snapshot
+    /// if there are already `Config::MaxAccumulatorSnapshots` of them.
+    fn take_snapshot(id: AccumulatorId, accumulator: &AccumulatorWithUpdateInfo<T>) {
+        let max_snapshots = T::MaxAccumulatorSnapshots::get();
+        if max_snapshots == 0 {
+            return;
+        }
+
+        let index = AccumulatorSnapshotsCounter::<T>::mutate(id, |counter| {
+            let index = *counter;
+            *counter += 1;
+
+            index
+        });
+        let AccumulatorCommon {
+            accumulated,
+            key_ref,
+        } = accumulator.accumulator.common().clone();
+        let snapshot = AccumulatorSnapshot {
+            index,
+            block: <frame_system::Pallet<T>>::block_number(),
+            accumulated,
+            key_ref,
+            member_count: accumulator.member_count,
+        };
+
+        AccumulatorSnapshots::<T>::insert(id, (index % max_snapshots as u64) as u32, snapshot);
+    }
+
+    /// Records the supplied block in the `AccumulatorUpdateBlocks` unless it's already the latest recorded one,
+    /// replacing the oldest block if there are already `Config::MaxAccumulatorUpdateBlocks` of them.
+    fn record_update_block(id: AccumulatorId, block: T::BlockNumber) {
+        let max_blocks = T::MaxAccumulatorUpdateBlocks::get();
+        if max_blocks == 0 {
+            return;
+        }
+
+        AccumulatorUpdateBlocksCounter::<T>::mutate(id, |counter| {
+            let latest = counter
+                .checked_sub(1)
+                .and_then(|index| Self::accumulator_update_block(id, index));
+
+            if latest != Some(block) {
+                let slot = (*counter % max_blocks as u64) as u32;
+                AccumulatorUpdateBlocks::<T>::insert(id, slot, (*counter, block));
+                *counter += 1;
+            }
+        });
+    }
+
+    /// Get the number of the block recorded in the `AccumulatorUpdateBlocks` with the supplied sequential number
+    /// if it's still kept.
+    fn accumulator_update_block(id: AccumulatorId, index: u64) -> Option<T::BlockNumber> {
+        let max_blocks = T::MaxAccumulatorUpdateBlocks::get() as u64;
+
+        AccumulatorUpdateBlocks::<T>::get(id, index.checked_rem(max_blocks)? as u32)
+            .filter(|(recorded, _)| *recorded == index)
+            .map(|(_, block)| block)
+    }
+
     pub(super) fn remove_accumulator_(
         RemoveAccumulator { id, .. }: RemoveAccumulator<T>,
         accumulator: &mut Option<AccumulatorWithUpdateInfo<T>>,
@@ -137,6 +364,22 @@ impl<T: Config> Pallet<T> {
             *accumulator.accumulator.owner_did() == signer,
             Error::<T>::NotAccumulatorOwner
         );
+        OwnerAccumulators::<T>::remove(signer, id);
+        let _ = AccumulatorSnapshots::<T>::clear_prefix(id, u32::MAX, None);
+        AccumulatorSnapshotsCounter::<T>::remove(id);
+        let _ = AccumulatorUpdateBlocks::<T>::clear_prefix(
+            id,
+            T::MaxAccumulatorUpdateBlocks::get(),
+            None,
+        );
+        AccumulatorUpdateBlocksCounter::<T>::remove(id);
+        let _ = ReplacedAccumulatorKeys::<T>::clear_prefix(
+            id,
+            T::MaxAccumulatorKeyReplacements::get(),
+            None,
+        );
+        ReplacedAccumulatorKeysCounter::<T>::remove(id);
+        PendingAccumulatorTransfers::<T>::remove(id);
 
         deposit_indexed_event!(AccumulatorRemoved(id));
         Ok(())
@@ -156,13 +399,179 @@ impl<T: Config> Pallet<T> {
         Some((pk, params))
     }
 
-    /// Get accumulated value with public key and params.
+    /// Returns the public keys of the supplied owner referencing params that don't exist anymore
+    /// along with these references.
+    pub fn dangling_params_references(
+        owner: &AccumulatorOwner,
+    ) -> impl Iterator<Item = (IncId, AccumParametersStorageKey)> {
+        AccumulatorKeys::<T>::iter_prefix(owner).filter_map(|(key_id, key)| {
+            key.params_ref
+                .filter(|AccumParametersStorageKey(params_owner, params_id)| {
+                    !AccumulatorParams::<T>::contains_key(params_owner, params_id)
+                })
+                .map(|params_ref| (key_id, params_ref))
+        })
+    }
+
+    /// Get accumulated value with public key and params along with the amount of the members (if it's counted).
+    /// If the version is supplied, the accumulated value and the public key reference are taken from the
+    /// corresponding snapshot, so the key used by the accumulator at that version is returned even if it was
+    /// replaced later. `None` is returned if the snapshot isn't kept anymore.
     pub fn get_accumulator_with_public_key_and_params(
         id: &AccumulatorId,
-    ) -> Option<(Vec<u8>, Option<AccumPublicKeyWithParams<T>>)> {
-        let stored_acc = Accumulators::<T>::get(id)?;
-        let pk_p = Self::public_key_with_params(&stored_acc.accumulator.key_ref());
+        version: Option<AccumulatorVersion<T>>,
+    ) -> Option<(Vec<u8>, Option<AccumPublicKeyWithParams<T>>, Option<u64>)> {
+        let (accumulated, key_ref, member_count) = match version {
+            Some(version) => {
+                let AccumulatorSnapshot {
+                    accumulated,
+                    key_ref,
+                    member_count,
+                    ..
+                } = Self::accumulator_snapshot(id, version)?;
+
+                (accumulated.to_vec(), key_ref, member_count)
+            }
+            None => {
+                let stored_acc = Accumulators::<T>::get(id)?;
+
+                (
+                    stored_acc.accumulator.accumulated().to_vec(),
+                    stored_acc.accumulator.key_ref(),
+                    stored_acc.member_count,
+                )
+            }
+        };
+        let pk_p = Self::public_key_with_params(&key_ref);
+
+        Some((accumulated, pk_p, member_count))
+    }
+
+    /// Get the kept snapshot of the accumulator corresponding to the supplied version.
+    /// For the block version, it's the latest snapshot taken at or before the given block.
+    pub fn accumulator_snapshot(
+        id: &AccumulatorId,
+        version: AccumulatorVersion<T>,
+    ) -> Option<AccumulatorSnapshot<T>> {
+        let max_snapshots = T::MaxAccumulatorSnapshots::get() as u64;
+        let taken = AccumulatorSnapshotsCounter::<T>::get(id);
+        let kept = taken.saturating_sub(max_snapshots)..taken;
+        let snapshot = |index: u64| {
+            AccumulatorSnapshots::<T>::get(id, (index % max_snapshots) as u32)
+                .filter(|snapshot| snapshot.index == index)
+        };
+
+        match version {
+            AccumulatorVersion::Index(index) => {
+                kept.contains(&index).then_some(index).and_then(snapshot)
+            }
+            AccumulatorVersion::Block(block) => kept
+                .rev()
+                .filter_map(snapshot)
+                .find(|snapshot| snapshot.block <= block),
+        }
+    }
+
+    /// Get the accumulator along with the block numbers of its creation and last update.
+    pub fn get_accumulator_with_update_info(
+        id: &AccumulatorId,
+    ) -> Option<AccumulatorWithUpdateInfo<T>> {
+        Accumulators::<T>::get(id)
+    }
+
+    /// Get up to `limit` (capped by `Config::MaxQueryPageSize`) accumulators owned by the supplied DID along with their update info.
+    /// Accumulators are returned in the storage order starting after the one with the `after` id,
+    /// so the id of the last returned accumulator can be used to request the next page.
+    pub fn accumulators_by_owner(
+        owner: AccumulatorOwner,
+        after: Option<AccumulatorId>,
+        limit: u32,
+    ) -> Vec<(AccumulatorId, AccumulatorWithUpdateInfo<T>)> {
+        let ids = match after {
+            Some(after) => OwnerAccumulators::<T>::iter_key_prefix_from(
+                owner,
+                OwnerAccumulators::<T>::hashed_key_for(owner, after),
+            ),
+            None => OwnerAccumulators::<T>::iter_key_prefix(owner),
+        };
+
+        ids.filter_map(|id| Some((id, Accumulators::<T>::get(id)?)))
+            .take(limit.min(T::MaxQueryPageSize::get()) as usize)
+            .collect()
+    }
+
+    /// Get up to `limit` (capped by `Config::MaxQueryPageSize`) numbers of the recent blocks having updates of the
+    /// accumulator along with their sequential numbers. Blocks are returned in ascending order starting after the one
+    /// with the `after` sequential number, so the sequential number of the last returned block can be used to request
+    /// the next page. Only the last `Config::MaxAccumulatorUpdateBlocks` blocks are kept.
+    pub fn accumulator_update_blocks(
+        id: AccumulatorId,
+        after: Option<u64>,
+        limit: u32,
+    ) -> Vec<(u64, T::BlockNumber)> {
+        let recorded = AccumulatorUpdateBlocksCounter::<T>::get(id);
+        let kept_from = recorded.saturating_sub(T::MaxAccumulatorUpdateBlocks::get() as u64);
+        let from = after
+            .map_or(0, |after| after.saturating_add(1))
+            .max(kept_from);
+        let limit = limit.min(T::MaxQueryPageSize::get());
+
+        (from..recorded)
+            .take(limit as usize)
+            .filter_map(|index| Some((index, Self::accumulator_update_block(id, index)?)))
+            .collect()
+    }
+
+    /// Get details of all successful updates of the accumulator with the supplied id made in the current block.
+    /// Accepts calls of the block's extrinsics paired with the indices of these extrinsics, calls nested in
+    /// a single extrinsic (e.g. in a batch) must be supplied in their dispatch order. Calls are matched against
+    /// the `AccumulatorUpdated` events emitted by the extrinsics, so the updates are available only while the events
+    /// are present in the state, i.e. when querying the state of the block which included the updates.
+    pub fn updates_in_current_block<'a, C>(
+        id: &AccumulatorId,
+        calls: impl IntoIterator<Item = (u32, &'a C)>,
+    ) -> Vec<AccumulatorUpdateDetails>
+    where
+        C: IsSubType<Call<T>> + 'a,
+        <T as Config>::Event: TryInto<Event>,
+    {
+        let mut updates =
+            calls
+                .into_iter()
+                .filter_map(|(extrinsic_idx, call)| match call.is_sub_type()? {
+                    Call::update_accumulator { update, .. } if update.id == *id => {
+                        Some((extrinsic_idx, update))
+                    }
+                    _ => None,
+                });
+
+        <frame_system::Pallet<T>>::events()
+            .into_iter()
+            .filter_map(|record| match record.phase {
+                Phase::ApplyExtrinsic(extrinsic_idx) => {
+                    let event = <T as Config>::Event::from(record.event).try_into().ok()?;
 
-        Some((stored_acc.accumulator.accumulated().to_vec(), pk_p))
+                    Some((extrinsic_idx, event))
+                }
+                _ => None,
+            })
+            .filter_map(|(extrinsic_idx, event)| match event {
+                Event::AccumulatorUpdated(acc_id, accumulated) if acc_id == *id => {
+                    // Unsuccessful updates don't emit events, so they're skipped while looking
+                    // for the call which has emitted the event.
+                    updates.find(|(call_extrinsic_idx, update)| {
+                        *call_extrinsic_idx == extrinsic_idx
+                            && update.new_accumulated == accumulated
+                    })
+                }
+                _ => None,
+            })
+            .map(|(_, update)| AccumulatorUpdateDetails {
+                new_accumulated: update.new_accumulated.clone(),
+                additions: update.additions.clone(),
+                removals: update.removals.clone(),
+                witness_update_info: update.witness_update_info.clone(),
+            })
+            .collect()
     }
 }
@@ -0,0 +1,135 @@
+use super::*;
+use crate::common::TypesAndLimits;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use sp_std::marker::PhantomData;
+
+/// `AccumulatorWithUpdateInfo` stored before the members started being counted.
+#[derive(Encode, Decode)]
+struct AccumulatorWithUpdateInfoV0<T: TypesAndLimits> {
+    created_at: T::BlockNumber,
+    last_updated_at: T::BlockNumber,
+    accumulator: Accumulator<T>,
+}
+
+/// Adds `member_count` to the stored accumulators. Since the amount of the members of the existing
+/// accumulators can't be derived from the state, it's set to `None` meaning that it isn't counted.
+pub struct AddMemberCount<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for AddMemberCount<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let db_weight = T::DbWeight::get();
+        if Pallet::<T>::on_chain_storage_version() >= 1 {
+            return db_weight.reads(1);
+        }
+
+        let mut translated = 0u64;
+        Accumulators::<T>::translate::<AccumulatorWithUpdateInfoV0<T>, _>(|_, old| {
+            translated += 1;
+
+            Some(AccumulatorWithUpdateInfo {
+                created_at: old.created_at,
+                last_updated_at: old.last_updated_at,
+                accumulator: old.accumulator,
+                member_count: None,
+            })
+        });
+        StorageVersion::new(1).put::<Pallet<T>>();
+
+        db_weight.reads_writes(translated + 1, translated + 1)
+    }
+}
+
+/// Fills `AccumulatorParamsReferences` using the public keys stored before the references started being counted.
+pub struct CountAccumulatorParamsReferences<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for CountAccumulatorParamsReferences<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let db_weight = T::DbWeight::get();
+        if Pallet::<T>::on_chain_storage_version() >= 3 {
+            return db_weight.reads(1);
+        }
+
+        let (mut keys, mut counted) = (0u64, 0u64);
+        for key in AccumulatorKeys::<T>::iter_values() {
+            keys += 1;
+
+            if let Some(AccumParametersStorageKey(owner, params_id)) = key.params_ref {
+                AccumulatorParamsReferences::<T>::mutate(owner, params_id, |refs| {
+                    *refs = refs.saturating_add(1)
+                });
+                counted += 1;
+            }
+        }
+        StorageVersion::new(3).put::<Pallet<T>>();
+
+        db_weight.reads_writes(keys + counted + 1, counted + 1)
+    }
+}
+
+/// Maximum amount of the accumulators indexed by the `IndexAccumulatorsByOwner` during the runtime upgrade.
+const MAX_ACCUMULATORS_INDEXED_ON_UPGRADE: u32 = 1_000;
+
+/// Fills the `OwnerAccumulators` index using the accumulators stored before it was introduced.
+/// Since there can be too many accumulators to index them in a single block, at most
+/// `MAX_ACCUMULATORS_INDEXED_ON_UPGRADE` of them are indexed during the upgrade. The rest are indexed in the
+/// following blocks using their remaining weight, continuing after the `OwnerIndexMigrationCursor`.
+pub struct IndexAccumulatorsByOwner<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for IndexAccumulatorsByOwner<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let db_weight = T::DbWeight::get();
+        if Pallet::<T>::on_chain_storage_version() >= 2 {
+            return db_weight.reads(1);
+        }
+
+        let weight =
+            Pallet::<T>::index_accumulators_by_owner(None, MAX_ACCUMULATORS_INDEXED_ON_UPGRADE);
+        StorageVersion::new(2).put::<Pallet<T>>();
+
+        weight.saturating_add(db_weight.reads_writes(1, 1))
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Continues the `IndexAccumulatorsByOwner` migration indexing as many accumulators as fit
+    /// into the supplied weight.
+    pub(super) fn continue_owner_index_migration(remaining_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let after = match OwnerIndexMigrationCursor::<T>::get() {
+            Some(after) => after,
+            None => return db_weight.reads(1),
+        };
+        // Reading the cursor and updating it once indexed.
+        let cursor_weight = db_weight.reads_writes(1, 1);
+        // Each accumulator is read and then indexed.
+        let limit = remaining_weight.saturating_sub(cursor_weight).ref_time()
+            / db_weight.reads_writes(1, 1).ref_time().max(1);
+        if limit == 0 {
+            return db_weight.reads(1);
+        }
+
+        Self::index_accumulators_by_owner(Some(after), limit.min(u32::MAX as u64) as u32)
+            .saturating_add(db_weight.reads(1))
+    }
+
+    /// Indexes up to `limit` accumulators stored after the one with the `after` id in the `OwnerAccumulators`.
+    /// The id of the last indexed accumulator is kept in the `OwnerIndexMigrationCursor` if there can be more
+    /// accumulators left, otherwise the cursor is removed.
+    pub(super) fn index_accumulators_by_owner(after: Option<AccumulatorId>, limit: u32) -> Weight {
+        let accumulators = match after {
+            Some(after) => Accumulators::<T>::iter_from(Accumulators::<T>::hashed_key_for(after)),
+            None => Accumulators::<T>::iter(),
+        };
+
+        let (mut indexed, mut last) = (0u32, None);
+        for (id, acc) in accumulators.take(limit as usize) {
+            OwnerAccumulators::<T>::insert(acc.accumulator.owner_did(), id, ());
+            indexed += 1;
+            last = Some(id);
+        }
+        // Fewer accumulators than the limit means that all of them are indexed.
+        OwnerIndexMigrationCursor::<T>::set(last.filter(|_| indexed == limit));
+
+        T::DbWeight::get().reads_writes(indexed as u64, indexed as u64 + 1)
+    }
+}
@@ -6,7 +6,8 @@ use crate::{
 };
 pub use actions::*;
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{dispatch::DispatchResult, ensure, weights::Weight};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, weights::Weight};
+pub use migration::*;
 use sp_std::{fmt::Debug, prelude::*};
 use utils::CheckedDivCeil;
 
@@ -18,9 +19,11 @@ mod actions;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
 mod r#impl;
+mod migration;
 #[cfg(test)]
 mod tests;
 mod types;
+mod verification;
 mod weights;
 
 #[frame_support::pallet]
@@ -36,6 +39,27 @@ pub mod pallet {
         type Event: From<Event>
             + IsType<<Self as frame_system::Config>::Event>
             + Into<<Self as frame_system::Config>::Event>;
+
+        /// Whether the accumulator updates should be verified using the stored public key and params.
+        /// When enabled, updates of the positive and KB universal accumulators having a public key must
+        /// change at most one member, and the new accumulated value must be consistent with that change.
+        /// Batches of such accumulators are rejected. Updates of the universal accumulators and of the accumulators
+        /// without a public key (`key_id` 0, e.g. keyed-verification ones) can't be verified and are accepted as is.
+        /// When disabled, verification is never performed and any update is accepted.
+        type VerifyUpdates: Get<bool>;
+
+        /// Maximum amount of the snapshots kept for each accumulator in the `AccumulatorSnapshots`.
+        type MaxAccumulatorSnapshots: Get<u32>;
+
+        /// Maximum amount of the blocks having updates kept for each accumulator in the `AccumulatorUpdateBlocks`.
+        type MaxAccumulatorUpdateBlocks: Get<u32>;
+
+        /// Maximum amount of the public keys each accumulator can replace via the key rotations and ownership transfers.
+        type MaxAccumulatorKeyReplacements: Get<u32>;
+
+        /// Max amount of entries returned by a single page of the paginated queries.
+        #[pallet::constant]
+        type MaxQueryPageSize: Get<u32>;
     }
 
     #[pallet::event]
@@ -48,6 +72,12 @@ pub mod pallet {
         AccumulatorAdded(AccumulatorId, Bytes),
         AccumulatorUpdated(AccumulatorId, Bytes),
         AccumulatorRemoved(AccumulatorId),
+        /// Accumulator with the given id started using the supplied public key of the same owner.
+        AccumulatorPublicKeyUpdated(AccumulatorId, AccumPublicKeyStorageKey),
+        /// Accumulator with the given id was transferred to the owner of the supplied public key.
+        AccumulatorTransferred(AccumulatorId, AccumPublicKeyStorageKey),
+        /// Transfer of the accumulator with the given id to the owner of the supplied public key was proposed.
+        AccumulatorTransferProposed(AccumulatorId, AccumPublicKeyStorageKey),
     }
 
     #[pallet::error]
@@ -70,10 +100,40 @@ pub mod pallet {
         NotAccumulatorOwner,
         /// The nonce provided is incorrect.
         IncorrectNonce,
+        /// The new accumulated value is inconsistent with the current one and the supplied additions/removals.
+        InvalidAccumulatorUpdate,
+        /// The update can't be verified: it either changes more than one member or the public key has no params.
+        UnverifiableAccumulatorUpdate,
+        /// The accumulated value or one of the members can't be deserialized.
+        MalformedAccumulatorUpdate,
+        /// The stored public key or params can't be deserialized.
+        MalformedAccumulatorPublicKeyOrParams,
+        /// The update removes more members than the universal accumulator has.
+        AccumulatorMemberCountUnderflow,
+        /// The update makes the amount of the members exceed `max_size` of the universal accumulator.
+        AccumulatorMaxSizeExceeded,
+        /// The accumulator already uses the supplied public key.
+        SameAccumulatorPublicKey,
+        /// The accumulator can't be transferred to its current owner.
+        SameAccumulatorOwner,
+        /// The params are referenced by public keys, so they can only be removed using `force_remove_params`.
+        ParamsInUse,
+        /// Curve type isn't supported by the accumulators, only `BLS12-381` is.
+        UnsupportedCurveType,
+        /// The accumulator has no pending transfer to the signer.
+        NoPendingAccumulatorTransfer,
+        /// The accumulator has already replaced `Config::MaxAccumulatorKeyReplacements` public keys.
+        TooManyAccumulatorKeyReplacements,
+        /// The update of the universal accumulator specifies neither additions nor removals.
+        MissingAccumulatorMemberChanges,
     }
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::storage]
@@ -97,6 +157,12 @@ pub mod pallet {
         AccumulatorParameters<T>,
     >;
 
+    /// Amount of the public keys referencing the params stored as key value (owner, counter) -> amount.
+    #[pallet::storage]
+    #[pallet::getter(fn accumulator_params_references)]
+    pub type AccumulatorParamsReferences<T> =
+        StorageDoubleMap<_, Blake2_128Concat, AccumulatorOwner, Identity, IncId, u32, ValueQuery>;
+
     /// Public key storage is kept separate from accumulator storage and a single key can be used to manage
     /// several accumulators. It is assumed that whoever (DID) owns the public key, owns the accumulator as
     /// well and only that DID can update accumulator.
@@ -116,16 +182,95 @@ pub mod pallet {
     /// point for anyone looking for all updates to the accumulator. `last_updated_at` is the block number when
     /// the last update was sent. `created_at` and `last_updated_at` together indicate which blocks should be
     /// considered for finding accumulator updates.
-    /// Historical values and updates are persisted as events indexed with the accumulator id. The reason for
-    /// not storing past values is to save storage in chain state. Another option could have been to store
-    /// block numbers for the updates so that each block from `created_at` doesn't need to be scanned but
-    /// even that requires large storage as we expect millions of updates.
+    /// Historical values are persisted as events indexed with the accumulator id, the updates themselves can be
+    /// found in the extrinsics of the block and queried using `Pallet::updates_in_current_block` at that block.
+    /// The reason for not storing past values is to save storage in chain state. Instead, the numbers of the recent
+    /// blocks having updates are kept in the `AccumulatorUpdateBlocks`, so each block from `created_at` doesn't
+    /// need to be scanned.
     /// Just keeping the latest accumulated value allows for any potential on chain verification as well.
     #[pallet::storage]
     #[pallet::getter(fn accumulator)]
     pub type Accumulators<T> =
         StorageMap<_, Blake2_128Concat, AccumulatorId, AccumulatorWithUpdateInfo<T>, OptionQuery>;
 
+    /// Stores recent states of each accumulator as key value: (accumulator id, snapshot index % max snapshots) -> snapshot
+    /// A snapshot is taken after each change of the accumulator, overwriting the oldest one once there are
+    /// `Config::MaxAccumulatorSnapshots` of them, so the verifiers can check proofs made using the recent versions.
+    #[pallet::storage]
+    #[pallet::getter(fn accumulator_snapshot_by_slot)]
+    pub type AccumulatorSnapshots<T> =
+        StorageDoubleMap<_, Blake2_128Concat, AccumulatorId, Identity, u32, AccumulatorSnapshot<T>>;
+
+    /// Stores the amount of the snapshots taken for each accumulator.
+    #[pallet::storage]
+    #[pallet::getter(fn accumulator_snapshots_counter)]
+    pub type AccumulatorSnapshotsCounter<T> =
+        StorageMap<_, Blake2_128Concat, AccumulatorId, u64, ValueQuery>;
+
+    /// Stores ids of the accumulators owned by each DID as key value: (owner, accumulator id) -> ()
+    /// Allows enumerating the accumulators of an owner, e.g. to recover them after the off-chain data loss.
+    #[pallet::storage]
+    pub type OwnerAccumulators<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccumulatorOwner,
+        Blake2_128Concat,
+        AccumulatorId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Stores public keys previously used by the accumulators as key value:
+    /// (accumulator id, replacement number) -> (public key reference, replaced_at)
+    /// Keeps an audit trail of the key rotations and ownership transfers of each accumulator, which is limited
+    /// to `Config::MaxAccumulatorKeyReplacements` entries and removed along with the accumulator.
+    #[pallet::storage]
+    #[pallet::getter(fn replaced_accumulator_key)]
+    pub type ReplacedAccumulatorKeys<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccumulatorId,
+        Identity,
+        IncId,
+        ReplacedAccumulatorPublicKey<T>,
+    >;
+
+    /// Stores the amount of the public keys replaced by each accumulator.
+    #[pallet::storage]
+    #[pallet::getter(fn replaced_accumulator_keys_counter)]
+    pub type ReplacedAccumulatorKeysCounter<T> =
+        StorageMap<_, Blake2_128Concat, AccumulatorId, IncId, ValueQuery>;
+
+    /// Stores transfers of the accumulators proposed by their owners as key value:
+    /// accumulator id -> public key of the new owner
+    /// A transfer takes effect only once the new owner accepts it.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_accumulator_transfer)]
+    pub type PendingAccumulatorTransfers<T> =
+        StorageMap<_, Blake2_128Concat, AccumulatorId, AccumPublicKeyStorageKey>;
+
+    /// Stores numbers of the blocks having updates of each accumulator as key value:
+    /// (accumulator id, sequential number % max blocks) -> (sequential number, block number)
+    /// A block is recorded once no matter how many updates of the accumulator it has, overwriting the oldest one
+    /// once there are `Config::MaxAccumulatorUpdateBlocks` of them. Blocks of the updates made before this storage
+    /// was introduced aren't recorded.
+    #[pallet::storage]
+    #[pallet::getter(fn accumulator_update_block_by_slot)]
+    pub type AccumulatorUpdateBlocks<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, AccumulatorId, Identity, u32, (u64, T::BlockNumber)>;
+
+    /// Stores the amount of the blocks recorded in the `AccumulatorUpdateBlocks` for each accumulator.
+    #[pallet::storage]
+    #[pallet::getter(fn accumulator_update_blocks_counter)]
+    pub type AccumulatorUpdateBlocksCounter<T> =
+        StorageMap<_, Blake2_128Concat, AccumulatorId, u64, ValueQuery>;
+
+    /// Stores the id of the last accumulator indexed by the `IndexAccumulatorsByOwner` migration while there are
+    /// accumulators left to be indexed in the `OwnerAccumulators` using the remaining weight of the next blocks.
+    #[pallet::storage]
+    #[pallet::getter(fn owner_index_migration_cursor)]
+    pub type OwnerIndexMigrationCursor<T> = StorageValue<_, AccumulatorId>;
+
     #[pallet::storage]
     #[pallet::getter(fn version)]
     pub type Version<T> = StorageValue<_, common::StorageVersion, ValueQuery>;
@@ -151,6 +296,13 @@ pub mod pallet {
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        fn on_idle(_: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            Self::continue_owner_index_migration(remaining_weight)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::weight(SubstrateWeight::<T>::add_params(params, signature))]
@@ -231,8 +383,14 @@ pub mod pallet {
         /// Update an existing accumulator. The update contains the new accumulated value, the updates themselves
         /// and the witness updated info. The updates and witness update info are optional as the owner might be
         /// privately communicating the updated witnesses. It logs an event with the accumulator id and the new
-        /// accumulated value which is sufficient for a verifier. But the prover (who has a witness to update) needs
-        /// the updates and the witness update info and is expected to look into the corresponding extrinsic arguments.
+        /// accumulated value which is sufficient for a verifier. The prover (who has a witness to update) needs
+        /// the updates and the witness update info which are retrieved from the extrinsic itself.
+        /// The new accumulated value is verified against the supplied additions/removals only if `Config::VerifyUpdates` is enabled.
+        /// In that case, updates of the verifiable accumulators changing more than one member are rejected since a batch
+        /// can't be verified using the public key alone, so the batches have to be submitted as single-member updates.
+        /// Updates of the universal accumulators and of the accumulators without a public key are never verified.
+        /// Additions/removals are used to count the members, so the updates of the universal accumulator can't exceed its `max_size`
+        /// and must specify the additions and/or removals.
         #[pallet::weight(SubstrateWeight::<T>::update_accumulator(update, signature))]
         pub fn update_accumulator(
             origin: OriginFor<T>,
@@ -247,6 +405,56 @@ pub mod pallet {
                 .map_err(Into::into)
         }
 
+        /// Make an existing accumulator use another public key of its owner. The public key being replaced
+        /// is kept in the `ReplacedAccumulatorKeys` along with the block number of the replacement.
+        /// The accumulated value isn't changed, so it should be updated after the rotation if needed.
+        #[pallet::weight(SubstrateWeight::<T>::update_accumulator_public_key(update, signature))]
+        pub fn update_accumulator_public_key(
+            origin: OriginFor<T>,
+            update: UpdateAccumulatorPublicKey<T>,
+            signature: DidOrDidMethodKeySignature<AccumulatorOwner>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            update
+                .signed(signature)
+                .execute(Self::update_accumulator_public_key_)
+                .map_err(Into::into)
+        }
+
+        /// Propose to transfer an existing accumulator to another owner by making it use the supplied public key of
+        /// the new owner. Must be signed by the current owner. The transfer takes effect once the new owner accepts it
+        /// using `accept_accumulator_transfer`, a subsequent proposal replaces the pending one.
+        #[pallet::weight(SubstrateWeight::<T>::transfer_accumulator(transfer, signature))]
+        pub fn transfer_accumulator(
+            origin: OriginFor<T>,
+            transfer: TransferAccumulator<T>,
+            signature: DidOrDidMethodKeySignature<AccumulatorOwner>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            transfer
+                .signed(signature)
+                .execute(Self::transfer_accumulator_)
+                .map_err(Into::into)
+        }
+
+        /// Accept the pending transfer of the accumulator. Must be signed by the new owner. The public key being
+        /// replaced is kept in the `ReplacedAccumulatorKeys` along with the block number of the transfer.
+        #[pallet::weight(SubstrateWeight::<T>::accept_accumulator_transfer(accept, signature))]
+        pub fn accept_accumulator_transfer(
+            origin: OriginFor<T>,
+            accept: AcceptAccumulatorTransfer<T>,
+            signature: DidOrDidMethodKeySignature<AccumulatorOwner>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            accept
+                .signed(signature)
+                .execute(Self::accept_accumulator_transfer_)
+                .map_err(Into::into)
+        }
+
         #[pallet::weight(SubstrateWeight::<T>::remove_accumulator(remove, signature))]
         pub fn remove_accumulator(
             origin: OriginFor<T>,
@@ -260,6 +468,22 @@ pub mod pallet {
                 .execute_removable(Self::remove_accumulator_)
                 .map_err(Into::into)
         }
+
+        /// Remove the params even if they're referenced by public keys leaving these references dangling.
+        /// References of the removed params are released along with the public keys.
+        #[pallet::weight(SubstrateWeight::<T>::force_remove_params(remove, signature))]
+        pub fn force_remove_params(
+            origin: OriginFor<T>,
+            remove: ForceRemoveAccumulatorParams<T>,
+            signature: DidOrDidMethodKeySignature<AccumulatorOwner>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            remove
+                .signed(signature)
+                .execute_removable(Self::force_remove_params_)
+                .map_err(Into::into)
+        }
     }
 }
 
@@ -284,16 +508,38 @@ impl<T: Config> SubstrateWeight<T> {
     ) -> Weight {
         let bytes_len = public_key.public_key.bytes.len() as u32;
 
+        // Increasing the amount of the references to the params.
+        let params_reference = public_key
+            .public_key
+            .params_ref
+            .map_or(Weight::from_ref_time(0), |_| {
+                T::DbWeight::get().reads_writes(1, 1)
+            });
+
         sig.weight_for_sig_type::<T>(
             || Self::add_public_sr25519(bytes_len),
             || Self::add_public_ed25519(bytes_len),
             || Self::add_public_secp256k1(bytes_len),
         )
+        .saturating_add(params_reference)
     }
 
     fn remove_params(
         _: &RemoveAccumulatorParams<T>,
         sig: &DidOrDidMethodKeySignature<AccumulatorOwner>,
+    ) -> Weight {
+        sig.weight_for_sig_type::<T>(
+            Self::remove_params_sr25519,
+            Self::remove_params_ed25519,
+            Self::remove_params_secp256k1,
+        )
+        // Checking whether the params are referenced.
+        .saturating_add(T::DbWeight::get().reads(1))
+    }
+
+    fn force_remove_params(
+        _: &ForceRemoveAccumulatorParams<T>,
+        sig: &DidOrDidMethodKeySignature<AccumulatorOwner>,
     ) -> Weight {
         sig.weight_for_sig_type::<T>(
             Self::remove_params_sr25519,
@@ -311,6 +557,8 @@ impl<T: Config> SubstrateWeight<T> {
             Self::remove_public_ed25519,
             Self::remove_public_secp256k1,
         )
+        // Decreasing the amount of the references to the params of the removed key.
+        .saturating_add(T::DbWeight::get().reads_writes(1, 1))
     }
 
     fn add_accumulator(
@@ -324,6 +572,7 @@ impl<T: Config> SubstrateWeight<T> {
             || Self::add_accumulator_ed25519(len),
             || Self::add_accumulator_secp256k1(len),
         )
+        .saturating_add(Self::take_snapshot())
     }
 
     fn remove_accumulator(
@@ -335,6 +584,49 @@ impl<T: Config> SubstrateWeight<T> {
             Self::remove_accumulator_ed25519,
             Self::remove_accumulator_secp256k1,
         )
+        // Removal of the snapshots, the update blocks and the replaced keys along with their counters
+        // and removal of the pending transfer.
+        .saturating_add(T::DbWeight::get().writes(
+            T::MaxAccumulatorSnapshots::get() as u64
+                + T::MaxAccumulatorUpdateBlocks::get() as u64
+                + T::MaxAccumulatorKeyReplacements::get() as u64
+                + 4,
+        ))
+    }
+
+    fn update_accumulator_public_key(
+        _: &UpdateAccumulatorPublicKey<T>,
+        sig: &DidOrDidMethodKeySignature<AccumulatorOwner>,
+    ) -> Weight {
+        sig.weight_for_sig_type::<T>(
+            Self::update_accumulator_public_key_sr25519,
+            Self::update_accumulator_public_key_ed25519,
+            Self::update_accumulator_public_key_secp256k1,
+        )
+        .saturating_add(Self::take_snapshot())
+    }
+
+    fn transfer_accumulator(
+        _: &TransferAccumulator<T>,
+        sig: &DidOrDidMethodKeySignature<AccumulatorOwner>,
+    ) -> Weight {
+        sig.weight_for_sig_type::<T>(
+            Self::transfer_accumulator_sr25519,
+            Self::transfer_accumulator_ed25519,
+            Self::transfer_accumulator_secp256k1,
+        )
+    }
+
+    fn accept_accumulator_transfer(
+        _: &AcceptAccumulatorTransfer<T>,
+        sig: &DidOrDidMethodKeySignature<AccumulatorOwner>,
+    ) -> Weight {
+        sig.weight_for_sig_type::<T>(
+            Self::accept_accumulator_transfer_sr25519,
+            Self::accept_accumulator_transfer_ed25519,
+            Self::accept_accumulator_transfer_secp256k1,
+        )
+        .saturating_add(Self::take_snapshot())
     }
 
     fn update_accumulator(
@@ -362,6 +654,11 @@ impl<T: Config> SubstrateWeight<T> {
             .checked_div_ceil(acc.removals.as_ref().map_or(0, |v| v.len()) as u32)
             .unwrap_or(0);
         let wit_len = acc.witness_update_info.as_ref().map_or(0, |v| v.len()) as u32;
+        let verification = if T::VerifyUpdates::get() {
+            Self::verify_update()
+        } else {
+            Weight::from_ref_time(0)
+        };
 
         sig.weight_for_sig_type::<T>(
             || {
@@ -395,5 +692,21 @@ impl<T: Config> SubstrateWeight<T> {
                 )
             },
         )
+        .saturating_add(verification)
+        .saturating_add(Self::take_snapshot())
+        // Recording the block in the `AccumulatorUpdateBlocks`.
+        .saturating_add(T::DbWeight::get().reads_writes(2, 2))
+    }
+
+    /// Weight of recording the state of the accumulator in the `AccumulatorSnapshots`.
+    /// The storage used by the snapshots is bounded since each of them is limited by
+    /// `Limits::MaxAccumulatorAccumulatedSize` and only the last `Config::MaxAccumulatorSnapshots` are kept.
+    fn take_snapshot() -> Weight {
+        if T::MaxAccumulatorSnapshots::get() == 0 {
+            Weight::from_ref_time(0)
+        } else {
+            // Incrementing the counter and overwriting the oldest snapshot.
+            T::DbWeight::get().reads_writes(1, 2)
+        }
     }
 }
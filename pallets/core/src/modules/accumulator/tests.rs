@@ -1,7 +1,10 @@
 use super::*;
-use crate::tests::common::*;
+use crate::{tests::common::*, util::compressed};
+use ark_bls12_381::{Fr, G1Affine, G2Affine};
+use ark_ec::{AffineRepr, CurveGroup};
 use frame_support::assert_err;
 use sp_core::{Hasher, H256};
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 
 fn accumulator_events() -> Vec<(super::Event, Vec<H256>)> {
     System::events()
@@ -304,6 +307,37 @@ crate::did_or_did_method_key! {
 
             run_to_block(20);
 
+            // Only `BLS12-381` params and keys are supported.
+            for curve_type in [CurveType::P256, CurveType::P384, CurveType::Ed25519] {
+                let ap = AddAccumulatorParams {
+                    params: AccumulatorParameters {
+                        label: None,
+                        curve_type,
+                        bytes: vec![1; 100].try_into().unwrap(),
+                    },
+                    nonce: next_nonce,
+                };
+                let sig = did_sig::<Test, _, _, _>(&ap, &author_kp, author, 1);
+                assert_err!(
+                    AccumMod::add_params(Origin::signed(1), ap, sig),
+                    Error::<Test>::UnsupportedCurveType
+                );
+
+                let ak = AddAccumulatorPublicKey {
+                    public_key: AccumulatorPublicKey {
+                        params_ref: None,
+                        curve_type,
+                        bytes: vec![2; 100].try_into().unwrap(),
+                    },
+                    nonce: next_nonce,
+                };
+                let sig = did_sig::<Test, _, _, _>(&ak, &author_kp, author, 1);
+                assert_err!(
+                    AccumMod::add_public_key(Origin::signed(1), ak, sig),
+                    Error::<Test>::UnsupportedCurveType
+                );
+            }
+
             let params = AccumulatorParameters {
                 label: Some(vec![0, 1, 2, 3].try_into().unwrap()),
                 curve_type: CurveType::Bls12381,
@@ -388,7 +422,8 @@ crate::did_or_did_method_key! {
                         vec![<Test as frame_system::Config>::Hashing::hash(&$id[..])]
                     )));
 
-                    let resp = AccumMod::get_accumulator_with_public_key_and_params(&$id).unwrap();
+                    let resp = AccumMod::get_accumulator_with_public_key_and_params(&$id, None).unwrap();
+                    assert_eq!(resp.2, Some(0));
                     if ($key_id == 0) {
                         assert!(resp.1.is_none())
                     } else {
@@ -436,7 +471,8 @@ crate::did_or_did_method_key! {
                         Some(AccumulatorWithUpdateInfo {
                             created_at: $created_at,
                             last_updated_at: current_block,
-                            accumulator: accumulator.clone()
+                            accumulator: accumulator.clone(),
+                            member_count: None
                         })
                     );
                     assert!(accumulator_events().contains(&(
@@ -469,7 +505,8 @@ crate::did_or_did_method_key! {
                         Some(AccumulatorWithUpdateInfo {
                             created_at: $created_at,
                             last_updated_at: current_block,
-                            accumulator: accumulator.clone()
+                            accumulator: accumulator.clone(),
+                            member_count: None
                         })
                     );
                     assert!(accumulator_events().contains(&(
@@ -508,7 +545,7 @@ crate::did_or_did_method_key! {
                         super::super::Event::AccumulatorRemoved($id),
                         vec![<Test as frame_system::Config>::Hashing::hash(&$id[..])]
                     )));
-                    assert!(AccumMod::get_accumulator_with_public_key_and_params(&$id).is_none());
+                    assert!(AccumMod::get_accumulator_with_public_key_and_params(&$id, None).is_none());
                     next_nonce += 1;
 
                     current_block += 10;
@@ -547,4 +584,1133 @@ crate::did_or_did_method_key! {
             );
         });
     }
+
+    #[test]
+    fn accumulator_update_details() {
+        ext().execute_with(|| {
+            use crate::tests::common::Call as TestCall;
+            use sp_runtime::traits::Dispatchable;
+
+            run_to_block(10);
+
+            let (author, author_kp) = newdid();
+            let author = AccumulatorOwner(author.into());
+            let mut next_nonce = 1;
+
+            let id = AccumulatorId(rand::random());
+            let other_id = AccumulatorId(rand::random());
+            for id in [id, other_id] {
+                let add_accum = AddAccumulator {
+                    id,
+                    accumulator: Accumulator::Positive(AccumulatorCommon {
+                        accumulated: vec![3; 32].try_into().unwrap(),
+                        key_ref: AccumPublicKeyStorageKey(author, 0u8.into()),
+                    }),
+                    nonce: next_nonce,
+                };
+                let sig = did_sig(&add_accum, &author_kp, author, 1);
+                AccumMod::add_accumulator(Origin::signed(1), add_accum, sig).unwrap();
+                next_nonce += 1;
+            }
+            let no_calls: Vec<(u32, &TestCall)> = vec![];
+            assert!(AccumMod::updates_in_current_block(&id, no_calls).is_empty());
+
+            run_to_block(20);
+            System::reset_events();
+            System::note_finished_initialize();
+
+            let update_call = |id, additions, removals, witness_update_info, nonce: u64| {
+                let update = UpdateAccumulator {
+                    id,
+                    new_accumulated: vec![nonce as u8; 32].into(),
+                    additions,
+                    removals,
+                    witness_update_info,
+                    nonce,
+                };
+                let signature = did_sig(&update, &author_kp, author, 1);
+
+                TestCall::AccumMod(super::Call::update_accumulator { update, signature })
+            };
+            // The second extrinsic imitates a batch having an unsuccessful update.
+            let extrinsics = vec![
+                vec![(
+                    update_call(
+                        id,
+                        Some(vec![vec![0, 1, 2].into()]),
+                        None,
+                        Some(vec![1, 2].into()),
+                        next_nonce,
+                    ),
+                    true,
+                )],
+                vec![
+                    (
+                        update_call(
+                            other_id,
+                            Some(vec![vec![9, 4].into()]),
+                            None,
+                            None,
+                            next_nonce + 1,
+                        ),
+                        true,
+                    ),
+                    (update_call(id, None, None, None, next_nonce + 5), false),
+                    (
+                        update_call(
+                            id,
+                            None,
+                            Some(vec![vec![0, 1, 2].into()]),
+                            None,
+                            next_nonce + 2,
+                        ),
+                        true,
+                    ),
+                ],
+            ];
+            for calls in &extrinsics {
+                for (call, succeeds) in calls {
+                    let res = call.clone().dispatch(Origin::signed(1));
+                    assert_eq!(res.is_ok(), *succeeds);
+                }
+                System::note_applied_extrinsic(&Ok(().into()), Default::default());
+            }
+
+            // Only the accumulated value is logged in the events.
+            let updated = [(id, next_nonce), (other_id, next_nonce + 1), (id, next_nonce + 2)];
+            for (acc_id, nonce) in updated {
+                assert!(accumulator_events().contains(&(
+                    super::super::Event::AccumulatorUpdated(acc_id, vec![nonce as u8; 32].into()),
+                    vec![<Test as frame_system::Config>::Hashing::hash(&acc_id[..])]
+                )));
+            }
+
+            let calls: Vec<_> = extrinsics
+                .iter()
+                .enumerate()
+                .flat_map(|(idx, calls)| calls.iter().map(move |(call, _)| (idx as u32, call)))
+                .collect();
+            assert_eq!(
+                AccumMod::updates_in_current_block(&id, calls.clone()),
+                vec![
+                    AccumulatorUpdateDetails {
+                        new_accumulated: vec![next_nonce as u8; 32].into(),
+                        additions: Some(vec![vec![0, 1, 2].into()]),
+                        removals: None,
+                        witness_update_info: Some(vec![1, 2].into()),
+                    },
+                    AccumulatorUpdateDetails {
+                        new_accumulated: vec![next_nonce as u8 + 2; 32].into(),
+                        additions: None,
+                        removals: Some(vec![vec![0, 1, 2].into()]),
+                        witness_update_info: None,
+                    }
+                ]
+            );
+            assert_eq!(
+                AccumMod::updates_in_current_block(&other_id, calls.clone()),
+                vec![AccumulatorUpdateDetails {
+                    new_accumulated: vec![next_nonce as u8 + 1; 32].into(),
+                    additions: Some(vec![vec![9, 4].into()]),
+                    removals: None,
+                    witness_update_info: None,
+                }]
+            );
+            // Calls of the other extrinsics aren't matched against the events.
+            let shifted = calls.iter().map(|&(idx, call)| (idx + 1, call));
+            assert!(AccumMod::updates_in_current_block(&other_id, shifted).is_empty());
+            next_nonce += 3;
+
+            assert_eq!(
+                AccumMod::get_accumulator_with_update_info(&id).map(|acc| (acc.created_at, acc.last_updated_at)),
+                Some((10, 20))
+            );
+
+            System::reset_events();
+            assert!(AccumMod::updates_in_current_block(&id, calls).is_empty());
+
+            // Each block having updates is recorded once.
+            assert_eq!(AccumMod::accumulator_update_blocks(id, None, 10), vec![(0, 20)]);
+            assert_eq!(AccumMod::accumulator_update_blocks(other_id, None, 10), vec![(0, 20)]);
+
+            let update = |next_nonce: &mut u64| {
+                let update_accum = UpdateAccumulator {
+                    id,
+                    new_accumulated: vec![*next_nonce as u8; 32].into(),
+                    additions: None,
+                    removals: None,
+                    witness_update_info: None,
+                    nonce: *next_nonce,
+                };
+                let sig = did_sig(&update_accum, &author_kp, author, 1);
+                AccumMod::update_accumulator(Origin::signed(1), update_accum, sig).unwrap();
+                *next_nonce += 1;
+            };
+            for block in 21..=26 {
+                run_to_block(block);
+                update(&mut next_nonce);
+            }
+
+            // Only the last `MaxAccumulatorUpdateBlocks` blocks are kept,
+            // pages are capped by `MaxQueryPageSize`.
+            let first_page = AccumMod::accumulator_update_blocks(id, None, 10);
+            assert_eq!(first_page, (1..=5).map(|idx| (idx, 20 + idx)).collect::<Vec<_>>());
+            assert_eq!(
+                AccumMod::accumulator_update_blocks(id, Some(first_page[4].0), 10),
+                vec![(6, 26)]
+            );
+            assert_eq!(
+                AccumMod::accumulator_update_blocks(id, Some(0), 2),
+                vec![(1, 21), (2, 22)]
+            );
+            assert_eq!(
+                AccumMod::accumulator_update_blocks(id, Some(1), 2),
+                vec![(2, 22), (3, 23)]
+            );
+            assert!(AccumMod::accumulator_update_blocks(id, Some(6), 10).is_empty());
+            assert_eq!(AccumMod::accumulator_update_blocks(other_id, None, 10), vec![(0, 20)]);
+
+            // The removal clears the recorded blocks.
+            let rem_accum = RemoveAccumulator {
+                id,
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&rem_accum, &author_kp, author, 1);
+            AccumMod::remove_accumulator(Origin::signed(1), rem_accum, sig).unwrap();
+            assert!(AccumMod::accumulator_update_blocks(id, None, 10).is_empty());
+            assert_eq!(AccumulatorUpdateBlocks::<Test>::iter_prefix(id).count(), 0);
+        });
+    }
+
+    #[test]
+    fn verify_accumulator_updates() {
+        ext().execute_with(|| {
+            VerifyAccumulatorUpdates::set(&true);
+            run_to_block(10);
+
+            let (author, author_kp) = newdid();
+            let author = AccumulatorOwner(author.into());
+            let mut next_nonce = 1;
+
+            let secret_key = Fr::from(11u64);
+            let p = G1Affine::generator();
+            let p_tilde = G2Affine::generator();
+
+            let ap = AddAccumulatorParams {
+                params: AccumulatorParameters {
+                    label: None,
+                    curve_type: CurveType::Bls12381,
+                    bytes: compressed((p, p_tilde)).try_into().unwrap(),
+                },
+                nonce: next_nonce,
+            };
+            let sig = did_sig::<Test, _, _, _>(&ap, &author_kp, author, 1);
+            AccumMod::add_params(Origin::signed(1), ap, sig).unwrap();
+            next_nonce += 1;
+
+            let ak = AddAccumulatorPublicKey {
+                public_key: AccumulatorPublicKey {
+                    params_ref: Some(AccumParametersStorageKey(author, 1u8.into())),
+                    curve_type: CurveType::Bls12381,
+                    bytes: compressed((p_tilde * secret_key).into_affine()).try_into().unwrap(),
+                },
+                nonce: next_nonce,
+            };
+            let sig = did_sig::<Test, _, _, _>(&ak, &author_kp, author, 1);
+            AccumMod::add_public_key(Origin::signed(1), ak, sig).unwrap();
+            next_nonce += 1;
+
+            let y = Fr::from(5u64);
+            let base = (p * Fr::from(3u64)).into_affine();
+            let product = (base * (y + secret_key)).into_affine();
+            let other_base = (p * Fr::from(7u64)).into_affine();
+            let other_product = (other_base * (y + secret_key)).into_affine();
+
+            let id = AccumulatorId(rand::random());
+            let kb_id = AccumulatorId(rand::random());
+            for (id, accumulator) in [
+                (id, Accumulator::Positive(AccumulatorCommon {
+                    accumulated: compressed(base).try_into().unwrap(),
+                    key_ref: AccumPublicKeyStorageKey(author, 1u8.into()),
+                })),
+                (kb_id, Accumulator::KBUniversal(AccumulatorCommon {
+                    accumulated: compressed((base, other_product)).try_into().unwrap(),
+                    key_ref: AccumPublicKeyStorageKey(author, 1u8.into()),
+                })),
+            ] {
+                let add_accum = AddAccumulator { id, accumulator, nonce: next_nonce };
+                let sig = did_sig(&add_accum, &author_kp, author, 1);
+                AccumMod::add_accumulator(Origin::signed(1), add_accum, sig).unwrap();
+                next_nonce += 1;
+            }
+
+            macro_rules! update {
+                ($id: expr, $new_accumulated: expr, $additions: expr, $removals: expr) => {{
+                    let update_accum = UpdateAccumulator {
+                        id: $id,
+                        new_accumulated: $new_accumulated.into(),
+                        additions: $additions,
+                        removals: $removals,
+                        witness_update_info: None,
+                        nonce: next_nonce,
+                    };
+                    let sig = did_sig(&update_accum, &author_kp, author, 1);
+                    let res = AccumMod::update_accumulator(Origin::signed(1), update_accum, sig);
+                    if res.is_ok() {
+                        next_nonce += 1;
+                    }
+
+                    res
+                }};
+            }
+
+            let member = compressed(y);
+            assert_err!(
+                update!(id, compressed(other_base), Some(vec![member.clone().into()]), None),
+                Error::<Test>::InvalidAccumulatorUpdate
+            );
+            // Batches can't be verified, so only single-member updates are accepted.
+            for (additions, removals) in [
+                (Some(vec![member.clone().into(); 2]), None),
+                (None, Some(vec![member.clone().into(); 2])),
+                (Some(vec![member.clone().into()]), Some(vec![member.clone().into()])),
+            ] {
+                assert_err!(
+                    update!(id, compressed(product), additions, removals),
+                    Error::<Test>::UnverifiableAccumulatorUpdate
+                );
+            }
+            assert_err!(
+                update!(id, compressed(product), Some(vec![vec![1; 3].into()]), None),
+                Error::<Test>::MalformedAccumulatorUpdate
+            );
+            assert_err!(
+                update!(id, vec![1; 48], Some(vec![member.clone().into()]), None),
+                Error::<Test>::MalformedAccumulatorUpdate
+            );
+            assert_err!(
+                update!(id, compressed(product), None, Some(vec![member.clone().into()])),
+                Error::<Test>::InvalidAccumulatorUpdate
+            );
+            update!(id, compressed(product), Some(vec![member.clone().into()]), None).unwrap();
+            assert_err!(
+                update!(id, compressed(base), None, None),
+                Error::<Test>::InvalidAccumulatorUpdate
+            );
+            update!(id, compressed(product), None, None).unwrap();
+            update!(id, compressed(base), None, Some(vec![member.clone().into()])).unwrap();
+
+            assert_err!(
+                update!(kb_id, compressed((product, other_product)), Some(vec![member.clone().into()]), None),
+                Error::<Test>::InvalidAccumulatorUpdate
+            );
+            assert_err!(
+                update!(kb_id, compressed(product), Some(vec![member.clone().into()]), None),
+                Error::<Test>::MalformedAccumulatorUpdate
+            );
+            update!(kb_id, compressed((product, other_base)), Some(vec![member.clone().into()]), None).unwrap();
+            update!(kb_id, compressed((base, other_product)), None, Some(vec![member.clone().into()])).unwrap();
+            check_nonce(&author, next_nonce - 1);
+
+            // Keys and params of the curves other than `BLS12-381` stored before these were rejected can't be used.
+            AccumulatorKeys::<Test>::insert(
+                author,
+                IncId::from(2u8),
+                AccumulatorPublicKey {
+                    params_ref: Some(AccumParametersStorageKey(author, 1u8.into())),
+                    curve_type: CurveType::P256,
+                    bytes: compressed((p_tilde * secret_key).into_affine()).try_into().unwrap(),
+                },
+            );
+            let p256_id = AccumulatorId(rand::random());
+            let add_accum = AddAccumulator {
+                id: p256_id,
+                accumulator: Accumulator::Positive(AccumulatorCommon {
+                    accumulated: compressed(base).try_into().unwrap(),
+                    key_ref: AccumPublicKeyStorageKey(author, 2u8.into()),
+                }),
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&add_accum, &author_kp, author, 1);
+            AccumMod::add_accumulator(Origin::signed(1), add_accum, sig).unwrap();
+            next_nonce += 1;
+            assert_err!(
+                update!(p256_id, compressed(product), Some(vec![member.into()]), None),
+                Error::<Test>::UnsupportedCurveType
+            );
+
+            VerifyAccumulatorUpdates::set(&false);
+            update!(id, vec![1; 48], Some(vec![vec![1; 3].into(); 2]), None).unwrap();
+        });
+    }
+
+    #[test]
+    fn accumulators_by_owner() {
+        ext().execute_with(|| {
+            run_to_block(10);
+
+            let (author, author_kp) = newdid();
+            let author = AccumulatorOwner(author.into());
+            let (other, other_kp) = newdid();
+            let other = AccumulatorOwner(other.into());
+            let mut next_nonce = 1;
+            let mut other_next_nonce = 1;
+
+            for (owner, kp, nonce) in [
+                (author, &author_kp, &mut next_nonce),
+                (other, &other_kp, &mut other_next_nonce),
+            ] {
+                let ak = AddAccumulatorPublicKey {
+                    public_key: AccumulatorPublicKey {
+                        params_ref: None,
+                        curve_type: CurveType::Bls12381,
+                        bytes: vec![2; 100].try_into().unwrap(),
+                    },
+                    nonce: *nonce,
+                };
+                let sig = did_sig::<Test, _, _, _>(&ak, kp, owner, 1);
+                AccumMod::add_public_key(Origin::signed(1), ak, sig).unwrap();
+                *nonce += 1;
+            }
+
+            let mut ids: Vec<_> = (0..5).map(|_| AccumulatorId(rand::random())).collect();
+            for (idx, &id) in ids.iter().enumerate() {
+                let (owner, kp, nonce) = if idx < 4 {
+                    (author, &author_kp, &mut next_nonce)
+                } else {
+                    (other, &other_kp, &mut other_next_nonce)
+                };
+                let add_accum = AddAccumulator {
+                    id,
+                    accumulator: Accumulator::Positive(AccumulatorCommon {
+                        accumulated: vec![3; 32].try_into().unwrap(),
+                        key_ref: AccumPublicKeyStorageKey(owner, 1u8.into()),
+                    }),
+                    nonce: *nonce,
+                };
+                let sig = did_sig(&add_accum, kp, owner, 1);
+                AccumMod::add_accumulator(Origin::signed(1), add_accum, sig).unwrap();
+                *nonce += 1;
+            }
+            let other_id = ids.pop().unwrap();
+            let owned = |owner| {
+                AccumMod::accumulators_by_owner(owner, None, u32::MAX)
+                    .into_iter()
+                    .map(|(id, _)| id)
+                    .collect::<BTreeSet<_>>()
+            };
+
+            assert_eq!(owned(author), ids.iter().copied().collect());
+            assert_eq!(owned(other), [other_id].into_iter().collect());
+
+            let mut paginated = vec![];
+            let mut after = None;
+            loop {
+                let page = AccumMod::accumulators_by_owner(author, after, 3);
+                assert!(page.len() <= 3);
+                match page.last() {
+                    Some((id, _)) => after = Some(*id),
+                    None => break,
+                }
+                paginated.extend(page);
+            }
+            assert_eq!(paginated.len(), ids.len());
+            for (id, acc) in paginated {
+                assert!(ids.contains(&id));
+                assert_eq!(Accumulators::<Test>::get(id).unwrap(), acc);
+            }
+            assert!(AccumMod::accumulators_by_owner(author, None, 0).is_empty());
+
+            // Removal of the accumulator drops its pending transfer.
+            let transfer = TransferAccumulator {
+                id: ids[0],
+                new_key_ref: AccumPublicKeyStorageKey(other, 1u8.into()),
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&transfer, &author_kp, author, 1);
+            AccumMod::transfer_accumulator(Origin::signed(1), transfer, sig).unwrap();
+            next_nonce += 1;
+
+            let rem_accum = RemoveAccumulator {
+                id: ids[0],
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&rem_accum, &author_kp, author, 1);
+            AccumMod::remove_accumulator(Origin::signed(1), rem_accum, sig).unwrap();
+            next_nonce += 1;
+            assert!(!PendingAccumulatorTransfers::<Test>::contains_key(ids[0]));
+
+            let transfer = TransferAccumulator {
+                id: ids[1],
+                new_key_ref: AccumPublicKeyStorageKey(other, 1u8.into()),
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&transfer, &author_kp, author, 1);
+            AccumMod::transfer_accumulator(Origin::signed(1), transfer, sig).unwrap();
+
+            let accept = AcceptAccumulatorTransfer {
+                id: ids[1],
+                nonce: other_next_nonce,
+            };
+            let sig = did_sig(&accept, &other_kp, other, 1);
+            AccumMod::accept_accumulator_transfer(Origin::signed(1), accept, sig).unwrap();
+
+            assert_eq!(owned(author), ids[2..].iter().copied().collect());
+            assert_eq!(owned(other), [ids[1], other_id].into_iter().collect());
+            assert!(!OwnerAccumulators::<Test>::contains_key(author, ids[0]));
+        });
+    }
+
+    #[test]
+    fn accumulator_snapshots() {
+        ext().execute_with(|| {
+            run_to_block(10);
+
+            let (author, author_kp) = newdid();
+            let author = AccumulatorOwner(author.into());
+            let mut next_nonce = 1;
+
+            let ak = AddAccumulatorPublicKey {
+                public_key: AccumulatorPublicKey {
+                    params_ref: None,
+                    curve_type: CurveType::Bls12381,
+                    bytes: vec![2; 100].try_into().unwrap(),
+                },
+                nonce: next_nonce,
+            };
+            let sig = did_sig::<Test, _, _, _>(&ak, &author_kp, author, 1);
+            AccumMod::add_public_key(Origin::signed(1), ak, sig).unwrap();
+            next_nonce += 1;
+
+            let id = AccumulatorId(rand::random());
+            let add_accum = AddAccumulator {
+                id,
+                accumulator: Accumulator::Positive(AccumulatorCommon {
+                    accumulated: vec![0; 32].try_into().unwrap(),
+                    key_ref: AccumPublicKeyStorageKey(author, 1u8.into()),
+                }),
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&add_accum, &author_kp, author, 1);
+            AccumMod::add_accumulator(Origin::signed(1), add_accum, sig).unwrap();
+            next_nonce += 1;
+
+            let accumulated_at = |version| {
+                AccumMod::get_accumulator_with_public_key_and_params(&id, Some(version))
+                    .map(|(accumulated, _, _)| accumulated)
+            };
+            assert_eq!(accumulated_at(AccumulatorVersion::Index(0)), Some(vec![0; 32]));
+            assert_eq!(accumulated_at(AccumulatorVersion::Block(10)), Some(vec![0; 32]));
+            assert_eq!(accumulated_at(AccumulatorVersion::Block(9)), None);
+
+            for i in 1..=3u8 {
+                run_to_block(10 + i as u64 * 10);
+
+                let update_accum = UpdateAccumulator {
+                    id,
+                    new_accumulated: vec![i; 32].into(),
+                    additions: None,
+                    removals: None,
+                    witness_update_info: None,
+                    nonce: next_nonce,
+                };
+                let sig = did_sig(&update_accum, &author_kp, author, 1);
+                AccumMod::update_accumulator(Origin::signed(1), update_accum, sig).unwrap();
+                next_nonce += 1;
+            }
+            assert_eq!(AccumulatorSnapshotsCounter::<Test>::get(id), 4);
+
+            // Only the last `MaxAccumulatorSnapshots` snapshots are kept.
+            assert_eq!(accumulated_at(AccumulatorVersion::Index(0)), None);
+            assert_eq!(accumulated_at(AccumulatorVersion::Block(15)), None);
+            for i in 1..=3u8 {
+                assert_eq!(accumulated_at(AccumulatorVersion::Index(i as u64)), Some(vec![i; 32]));
+                assert_eq!(
+                    accumulated_at(AccumulatorVersion::Block(15 + i as u64 * 10)),
+                    Some(vec![i; 32])
+                );
+            }
+            assert_eq!(accumulated_at(AccumulatorVersion::Index(4)), None);
+            assert_eq!(accumulated_at(AccumulatorVersion::Block(100)), Some(vec![3; 32]));
+            assert_eq!(
+                AccumMod::accumulator_snapshot(&id, AccumulatorVersion::Index(2)),
+                Some(AccumulatorSnapshot {
+                    index: 2,
+                    block: 30,
+                    accumulated: vec![2; 32].try_into().unwrap(),
+                    key_ref: AccumPublicKeyStorageKey(author, 1u8.into()),
+                    member_count: None,
+                })
+            );
+
+            // Snapshots taken before the key rotation keep referencing the replaced key.
+            let ak = AddAccumulatorPublicKey {
+                public_key: AccumulatorPublicKey {
+                    params_ref: None,
+                    curve_type: CurveType::Bls12381,
+                    bytes: vec![3; 100].try_into().unwrap(),
+                },
+                nonce: next_nonce,
+            };
+            let sig = did_sig::<Test, _, _, _>(&ak, &author_kp, author, 1);
+            AccumMod::add_public_key(Origin::signed(1), ak, sig).unwrap();
+            next_nonce += 1;
+
+            run_to_block(50);
+            let update_key = UpdateAccumulatorPublicKey {
+                id,
+                key_id: 2u8.into(),
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&update_key, &author_kp, author, 1);
+            AccumMod::update_accumulator_public_key(Origin::signed(1), update_key, sig).unwrap();
+            next_nonce += 1;
+
+            let key_bytes_at = |version| {
+                AccumMod::get_accumulator_with_public_key_and_params(&id, version)
+                    .and_then(|(_, key, _)| key)
+                    .map(|(key, _)| key.bytes.to_vec())
+            };
+            assert_eq!(key_bytes_at(Some(AccumulatorVersion::Index(3))), Some(vec![2; 100]));
+            assert_eq!(key_bytes_at(Some(AccumulatorVersion::Block(45))), Some(vec![2; 100]));
+            assert_eq!(key_bytes_at(Some(AccumulatorVersion::Index(4))), Some(vec![3; 100]));
+            assert_eq!(key_bytes_at(None), Some(vec![3; 100]));
+
+            let rem_accum = RemoveAccumulator {
+                id,
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&rem_accum, &author_kp, author, 1);
+            AccumMod::remove_accumulator(Origin::signed(1), rem_accum, sig).unwrap();
+
+            assert_eq!(accumulated_at(AccumulatorVersion::Index(4)), None);
+            assert_eq!(AccumulatorSnapshots::<Test>::iter_prefix(id).count(), 0);
+            assert_eq!(AccumulatorSnapshotsCounter::<Test>::get(id), 0);
+        });
+    }
+
+    #[test]
+    fn rotate_and_transfer_accumulator() {
+        ext().execute_with(|| {
+            run_to_block(10);
+
+            let (author, author_kp) = newdid();
+            let author = AccumulatorOwner(author.into());
+            let (new_owner, new_owner_kp) = newdid();
+            let new_owner = AccumulatorOwner(new_owner.into());
+            let mut next_nonce = 1;
+            let mut new_owner_next_nonce = 1;
+
+            for _ in 0..2 {
+                let ak = AddAccumulatorPublicKey {
+                    public_key: AccumulatorPublicKey {
+                        params_ref: None,
+                        curve_type: CurveType::Bls12381,
+                        bytes: vec![2; 100].try_into().unwrap(),
+                    },
+                    nonce: next_nonce,
+                };
+                let sig = did_sig::<Test, _, _, _>(&ak, &author_kp, author, 1);
+                AccumMod::add_public_key(Origin::signed(1), ak, sig).unwrap();
+                next_nonce += 1;
+            }
+            let ak = AddAccumulatorPublicKey {
+                public_key: AccumulatorPublicKey {
+                    params_ref: None,
+                    curve_type: CurveType::Bls12381,
+                    bytes: vec![3; 100].try_into().unwrap(),
+                },
+                nonce: new_owner_next_nonce,
+            };
+            let sig = did_sig::<Test, _, _, _>(&ak, &new_owner_kp, new_owner, 1);
+            AccumMod::add_public_key(Origin::signed(1), ak, sig).unwrap();
+            new_owner_next_nonce += 1;
+
+            let id = AccumulatorId(rand::random());
+            let add_accum = AddAccumulator {
+                id,
+                accumulator: Accumulator::Positive(AccumulatorCommon {
+                    accumulated: vec![3; 32].try_into().unwrap(),
+                    key_ref: AccumPublicKeyStorageKey(author, 1u8.into()),
+                }),
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&add_accum, &author_kp, author, 1);
+            AccumMod::add_accumulator(Origin::signed(1), add_accum, sig).unwrap();
+            next_nonce += 1;
+
+            run_to_block(20);
+
+            for (key_id, err) in [
+                (1u8, Error::<Test>::SameAccumulatorPublicKey),
+                (3u8, Error::<Test>::PublicKeyDoesntExist),
+            ] {
+                let update_key = UpdateAccumulatorPublicKey {
+                    id,
+                    key_id: key_id.into(),
+                    nonce: next_nonce,
+                };
+                let sig = did_sig(&update_key, &author_kp, author, 1);
+                assert_err!(
+                    AccumMod::update_accumulator_public_key(Origin::signed(1), update_key, sig),
+                    err
+                );
+            }
+
+            let update_key = UpdateAccumulatorPublicKey {
+                id,
+                key_id: 2u8.into(),
+                nonce: new_owner_next_nonce,
+            };
+            let sig = did_sig(&update_key, &new_owner_kp, new_owner, 1);
+            assert_err!(
+                AccumMod::update_accumulator_public_key(Origin::signed(1), update_key, sig),
+                Error::<Test>::NotAccumulatorOwner
+            );
+
+            let update_key = UpdateAccumulatorPublicKey {
+                id,
+                key_id: 2u8.into(),
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&update_key, &author_kp, author, 1);
+            AccumMod::update_accumulator_public_key(Origin::signed(1), update_key, sig).unwrap();
+            check_nonce(&author, next_nonce);
+            next_nonce += 1;
+
+            let key_ref = AccumPublicKeyStorageKey(author, 2u8.into());
+            assert_eq!(
+                Accumulators::<Test>::get(id).unwrap().accumulator.key_ref(),
+                key_ref
+            );
+            assert!(accumulator_events().contains(&(
+                super::super::Event::AccumulatorPublicKeyUpdated(id, key_ref),
+                vec![<Test as frame_system::Config>::Hashing::hash(&id[..])]
+            )));
+            assert_eq!(
+                ReplacedAccumulatorKeys::<Test>::get(id, IncId::from(1u8)),
+                Some(ReplacedAccumulatorPublicKey {
+                    key_ref: AccumPublicKeyStorageKey(author, 1u8.into()),
+                    replaced_at: 20
+                })
+            );
+
+            run_to_block(30);
+
+            let transfer = TransferAccumulator {
+                id,
+                new_key_ref: AccumPublicKeyStorageKey(author, 1u8.into()),
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&transfer, &author_kp, author, 1);
+            assert_err!(
+                AccumMod::transfer_accumulator(Origin::signed(1), transfer, sig),
+                Error::<Test>::SameAccumulatorOwner
+            );
+
+            let new_key_ref = AccumPublicKeyStorageKey(new_owner, 1u8.into());
+            let transfer = TransferAccumulator {
+                id,
+                new_key_ref,
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&transfer, &author_kp, author, 1);
+            AccumMod::transfer_accumulator(Origin::signed(1), transfer, sig).unwrap();
+            check_nonce(&author, next_nonce);
+            next_nonce += 1;
+
+            // The transfer takes effect only once accepted by the new owner.
+            assert_eq!(
+                *Accumulators::<Test>::get(id).unwrap().accumulator.owner_did(),
+                author
+            );
+            assert_eq!(PendingAccumulatorTransfers::<Test>::get(id), Some(new_key_ref));
+            assert!(accumulator_events().contains(&(
+                super::super::Event::AccumulatorTransferProposed(id, new_key_ref),
+                vec![<Test as frame_system::Config>::Hashing::hash(&id[..])]
+            )));
+
+            let (stranger, stranger_kp) = newdid();
+            let stranger = AccumulatorOwner(stranger.into());
+            for (owner, kp, nonce) in [(stranger, &stranger_kp, 1), (author, &author_kp, next_nonce)] {
+                let accept = AcceptAccumulatorTransfer { id, nonce };
+                let sig = did_sig(&accept, kp, owner, 1);
+                assert_err!(
+                    AccumMod::accept_accumulator_transfer(Origin::signed(1), accept, sig),
+                    Error::<Test>::NoPendingAccumulatorTransfer
+                );
+            }
+
+            let accept = AcceptAccumulatorTransfer {
+                id,
+                nonce: new_owner_next_nonce,
+            };
+            let sig = did_sig(&accept, &new_owner_kp, new_owner, 1);
+            AccumMod::accept_accumulator_transfer(Origin::signed(1), accept, sig).unwrap();
+            check_nonce(&new_owner, new_owner_next_nonce);
+            new_owner_next_nonce += 1;
+
+            assert_eq!(
+                *Accumulators::<Test>::get(id).unwrap().accumulator.owner_did(),
+                new_owner
+            );
+            assert!(!PendingAccumulatorTransfers::<Test>::contains_key(id));
+            assert!(accumulator_events().contains(&(
+                super::super::Event::AccumulatorTransferred(id, new_key_ref),
+                vec![<Test as frame_system::Config>::Hashing::hash(&id[..])]
+            )));
+            assert_eq!(ReplacedAccumulatorKeysCounter::<Test>::get(id), IncId::from(2u8));
+            assert_eq!(
+                ReplacedAccumulatorKeys::<Test>::get(id, IncId::from(2u8)),
+                Some(ReplacedAccumulatorPublicKey {
+                    key_ref,
+                    replaced_at: 30
+                })
+            );
+
+            // The previous owner can't update the accumulator anymore.
+            let update_accum = UpdateAccumulator {
+                id,
+                new_accumulated: vec![4; 32].into(),
+                additions: None,
+                removals: None,
+                witness_update_info: None,
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&update_accum, &author_kp, author, 1);
+            assert_err!(
+                AccumMod::update_accumulator(Origin::signed(1), update_accum, sig),
+                Error::<Test>::NotAccumulatorOwner
+            );
+
+            let update_accum = UpdateAccumulator {
+                id,
+                new_accumulated: vec![4; 32].into(),
+                additions: None,
+                removals: None,
+                witness_update_info: None,
+                nonce: new_owner_next_nonce,
+            };
+            let sig = did_sig(&update_accum, &new_owner_kp, new_owner, 1);
+            AccumMod::update_accumulator(Origin::signed(1), update_accum, sig).unwrap();
+            check_nonce(&new_owner, new_owner_next_nonce);
+            new_owner_next_nonce += 1;
+
+            // Only `MaxAccumulatorKeyReplacements` keys can be replaced.
+            let update_key = UpdateAccumulatorPublicKey {
+                id,
+                key_id: 0u8.into(),
+                nonce: new_owner_next_nonce,
+            };
+            let sig = did_sig(&update_key, &new_owner_kp, new_owner, 1);
+            assert_err!(
+                AccumMod::update_accumulator_public_key(Origin::signed(1), update_key, sig),
+                Error::<Test>::TooManyAccumulatorKeyReplacements
+            );
+
+            // The replaced keys are removed along with the accumulator.
+            let rem_accum = RemoveAccumulator {
+                id,
+                nonce: new_owner_next_nonce,
+            };
+            let sig = did_sig(&rem_accum, &new_owner_kp, new_owner, 1);
+            AccumMod::remove_accumulator(Origin::signed(1), rem_accum, sig).unwrap();
+            assert_eq!(ReplacedAccumulatorKeys::<Test>::iter_prefix(id).count(), 0);
+            assert!(!ReplacedAccumulatorKeysCounter::<Test>::contains_key(id));
+        });
+    }
+
+    #[test]
+    fn universal_accumulator_max_size() {
+        ext().execute_with(|| {
+            run_to_block(10);
+
+            let (author, author_kp) = newdid();
+            let author = AccumulatorOwner(author.into());
+            let mut next_nonce = 1;
+
+            let id = AccumulatorId(rand::random());
+            let add_accum = AddAccumulator {
+                id,
+                accumulator: Accumulator::Universal(UniversalAccumulator {
+                    common: AccumulatorCommon {
+                        accumulated: vec![3; 32].try_into().unwrap(),
+                        key_ref: AccumPublicKeyStorageKey(author, 0u8.into()),
+                    },
+                    max_size: 3,
+                }),
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&add_accum, &author_kp, author, 1);
+            AccumMod::add_accumulator(Origin::signed(1), add_accum, sig).unwrap();
+            next_nonce += 1;
+
+            for (additions, removals, res, member_count) in [
+                (None, None, Err(Error::<Test>::MissingAccumulatorMemberChanges), 0),
+                (Some(0), Some(1), Err(Error::<Test>::AccumulatorMemberCountUnderflow), 0),
+                (Some(4), None, Err(Error::<Test>::AccumulatorMaxSizeExceeded), 0),
+                (Some(2), None, Ok(()), 2),
+                (Some(3), Some(1), Err(Error::<Test>::AccumulatorMaxSizeExceeded), 2),
+                (Some(2), Some(1), Ok(()), 3),
+                (Some(0), Some(4), Err(Error::<Test>::AccumulatorMemberCountUnderflow), 3),
+                (Some(1), Some(4), Ok(()), 0),
+                (None, Some(0), Ok(()), 0),
+            ] {
+                let update_accum = UpdateAccumulator {
+                    id,
+                    new_accumulated: vec![4; 32].into(),
+                    additions: additions.map(|additions| vec![vec![1, 2].into(); additions]),
+                    removals: removals.map(|removals| vec![vec![3, 4].into(); removals]),
+                    witness_update_info: None,
+                    nonce: next_nonce,
+                };
+                let sig = did_sig(&update_accum, &author_kp, author, 1);
+                let update_res = AccumMod::update_accumulator(Origin::signed(1), update_accum, sig);
+
+                if let Err(err) = res {
+                    assert_err!(update_res, err);
+                } else {
+                    update_res.unwrap();
+                    next_nonce += 1;
+                }
+                assert_eq!(
+                    AccumMod::get_accumulator_with_public_key_and_params(&id, None).unwrap().2,
+                    Some(member_count)
+                );
+            }
+
+            // Members of other accumulators aren't counted.
+            let id = AccumulatorId(rand::random());
+            let add_accum = AddAccumulator {
+                id,
+                accumulator: Accumulator::Positive(AccumulatorCommon {
+                    accumulated: vec![3; 32].try_into().unwrap(),
+                    key_ref: AccumPublicKeyStorageKey(author, 0u8.into()),
+                }),
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&add_accum, &author_kp, author, 1);
+            AccumMod::add_accumulator(Origin::signed(1), add_accum, sig).unwrap();
+            next_nonce += 1;
+
+            for removals in [None, Some(vec![vec![3, 4].into()])] {
+                let update_accum = UpdateAccumulator {
+                    id,
+                    new_accumulated: vec![4; 32].into(),
+                    additions: None,
+                    removals,
+                    witness_update_info: None,
+                    nonce: next_nonce,
+                };
+                let sig = did_sig(&update_accum, &author_kp, author, 1);
+                AccumMod::update_accumulator(Origin::signed(1), update_accum, sig).unwrap();
+                next_nonce += 1;
+            }
+            assert_eq!(
+                AccumMod::get_accumulator_with_public_key_and_params(&id, None).unwrap().2,
+                None
+            );
+        });
+    }
+    #[test]
+    fn params_references() {
+        ext().execute_with(|| {
+            run_to_block(10);
+
+            let (author, author_kp) = newdid();
+            let author = AccumulatorOwner(author.into());
+            let mut next_nonce = 1;
+            let (author_1, author_1_kp) = newdid();
+            let author_1 = AccumulatorOwner(author_1.into());
+            let mut next_nonce_1 = 1;
+
+            let ap = AddAccumulatorParams {
+                params: AccumulatorParameters {
+                    label: None,
+                    curve_type: CurveType::Bls12381,
+                    bytes: vec![1; 100].try_into().unwrap(),
+                },
+                nonce: next_nonce,
+            };
+            let sig = did_sig::<Test, _, _, _>(&ap, &author_kp, author, 1);
+            AccumMod::add_params(Origin::signed(1), ap, sig).unwrap();
+            next_nonce += 1;
+
+            // Keys of any owner referencing the params are counted.
+            for (owner, kp, nonce) in [
+                (author, &author_kp, &mut next_nonce),
+                (author_1, &author_1_kp, &mut next_nonce_1),
+            ] {
+                let ak = AddAccumulatorPublicKey {
+                    public_key: AccumulatorPublicKey {
+                        params_ref: Some(AccumParametersStorageKey(author, 1u8.into())),
+                        curve_type: CurveType::Bls12381,
+                        bytes: vec![2; 96].try_into().unwrap(),
+                    },
+                    nonce: *nonce,
+                };
+                let sig = did_sig::<Test, _, _, _>(&ak, kp, owner, 1);
+                AccumMod::add_public_key(Origin::signed(1), ak, sig).unwrap();
+                *nonce += 1;
+            }
+            assert_eq!(AccumMod::accumulator_params_references(author, IncId::from(1u8)), 2);
+
+            let rem = RemoveAccumulatorPublicKey {
+                key_ref: AccumPublicKeyStorageKey(author, 1u8.into()),
+                nonce: next_nonce,
+            };
+            let sig = did_sig::<Test, _, _, _>(&rem, &author_kp, author, 1);
+            AccumMod::remove_public_key(Origin::signed(1), rem, sig).unwrap();
+            next_nonce += 1;
+            assert_eq!(AccumMod::accumulator_params_references(author, IncId::from(1u8)), 1);
+
+            let rem = RemoveAccumulatorParams {
+                params_ref: AccumParametersStorageKey(author, 1u8.into()),
+                nonce: next_nonce,
+            };
+            let sig = did_sig::<Test, _, _, _>(&rem, &author_kp, author, 1);
+            assert_err!(
+                AccumMod::remove_params(Origin::signed(1), rem, sig),
+                Error::<Test>::ParamsInUse
+            );
+            check_nonce(&author, next_nonce - 1);
+
+            // Force removal leaves the reference of the remaining key dangling.
+            let rem = ForceRemoveAccumulatorParams {
+                params_ref: AccumParametersStorageKey(author, 1u8.into()),
+                nonce: next_nonce,
+            };
+            let sig = did_sig::<Test, _, _, _>(&rem, &author_kp, author, 1);
+            AccumMod::force_remove_params(Origin::signed(1), rem, sig).unwrap();
+            assert_eq!(AccumMod::accumulator_params(author, IncId::from(1u8)), None);
+            assert_eq!(
+                AccumMod::dangling_params_references(&author_1).collect::<Vec<_>>(),
+                vec![(IncId::from(1u8), AccumParametersStorageKey(author, 1u8.into()))]
+            );
+
+            let rem = RemoveAccumulatorPublicKey {
+                key_ref: AccumPublicKeyStorageKey(author_1, 1u8.into()),
+                nonce: next_nonce_1,
+            };
+            let sig = did_sig::<Test, _, _, _>(&rem, &author_1_kp, author_1, 1);
+            AccumMod::remove_public_key(Origin::signed(1), rem, sig).unwrap();
+            assert_eq!(AccumMod::accumulator_params_references(author, IncId::from(1u8)), 0);
+            assert!(AccumMod::dangling_params_references(&author_1).next().is_none());
+        });
+    }
+}
+
+#[test]
+fn add_member_count_migration() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    ext().execute_with(|| {
+        let id = AccumulatorId(rand::random());
+        let accumulator = Accumulator::<Test>::Positive(AccumulatorCommon {
+            accumulated: vec![3; 32].try_into().unwrap(),
+            key_ref: AccumPublicKeyStorageKey(
+                AccumulatorOwner(crate::did::Did(rand::random()).into()),
+                0u8.into(),
+            ),
+        });
+        frame_support::storage::unhashed::put(
+            &Accumulators::<Test>::hashed_key_for(id),
+            &(5u64, 7u64, accumulator.clone()),
+        );
+        StorageVersion::new(0).put::<AccumMod>();
+        assert_eq!(Accumulators::<Test>::get(id), None);
+
+        AddMemberCount::<Test>::on_runtime_upgrade();
+        assert_eq!(AccumMod::on_chain_storage_version(), 1);
+        assert_eq!(
+            Accumulators::<Test>::get(id),
+            Some(AccumulatorWithUpdateInfo {
+                created_at: 5,
+                last_updated_at: 7,
+                accumulator,
+                member_count: None
+            })
+        );
+    });
+}
+
+#[test]
+fn count_accumulator_params_references_migration() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    ext().execute_with(|| {
+        let owner = AccumulatorOwner(crate::did::Did(rand::random()).into());
+
+        for (key_id, params_ref) in [
+            (1u8, Some(AccumParametersStorageKey(owner, 1u8.into()))),
+            (2, Some(AccumParametersStorageKey(owner, 1u8.into()))),
+            (3, None),
+        ] {
+            AccumulatorKeys::<Test>::insert(
+                owner,
+                IncId::from(key_id),
+                AccumulatorPublicKey {
+                    params_ref,
+                    curve_type: CurveType::Bls12381,
+                    bytes: vec![2; 96].try_into().unwrap(),
+                },
+            );
+        }
+        StorageVersion::new(2).put::<AccumMod>();
+
+        CountAccumulatorParamsReferences::<Test>::on_runtime_upgrade();
+        assert_eq!(AccumMod::on_chain_storage_version(), 3);
+        assert_eq!(
+            AccumMod::accumulator_params_references(owner, IncId::from(1u8)),
+            2
+        );
+    });
+}
+
+#[test]
+fn index_accumulators_by_owner_migration() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    ext().execute_with(|| {
+        let owners = [
+            AccumulatorOwner(crate::did::Did(rand::random()).into()),
+            AccumulatorOwner(crate::did::Did(rand::random()).into()),
+        ];
+        let ids: Vec<_> = (0..5u8)
+            .map(|i| {
+                let id = AccumulatorId(rand::random());
+                let accumulator = Accumulator::<Test>::Positive(AccumulatorCommon {
+                    accumulated: vec![i; 32].try_into().unwrap(),
+                    key_ref: AccumPublicKeyStorageKey(owners[i as usize % 2], 0u8.into()),
+                });
+                Accumulators::<Test>::insert(id, AccumulatorWithUpdateInfo::new(accumulator, 1));
+
+                id
+            })
+            .collect();
+        let indexed = || OwnerAccumulators::<Test>::iter_keys().count();
+
+        // Only the limited amount of the accumulators is indexed at once.
+        AccumMod::index_accumulators_by_owner(None, 2);
+        assert_eq!(indexed(), 2);
+        assert!(AccumMod::owner_index_migration_cursor().is_some());
+
+        // The rest is indexed using the remaining weight of the next blocks.
+        AccumMod::continue_owner_index_migration(Weight::from_ref_time(0));
+        assert_eq!(indexed(), 2);
+        AccumMod::continue_owner_index_migration(Weight::from_ref_time(u64::MAX));
+        assert_eq!(indexed(), 5);
+        assert_eq!(AccumMod::owner_index_migration_cursor(), None);
+        for (i, id) in ids.iter().enumerate() {
+            assert!(OwnerAccumulators::<Test>::contains_key(owners[i % 2], id));
+        }
+
+        // The upgrade indexes all of the accumulators if there are few of them.
+        let _ = OwnerAccumulators::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(1).put::<AccumMod>();
+        IndexAccumulatorsByOwner::<Test>::on_runtime_upgrade();
+        assert_eq!(AccumMod::on_chain_storage_version(), 2);
+        assert_eq!(indexed(), 5);
+        assert_eq!(AccumMod::owner_index_migration_cursor(), None);
+    });
 }
@@ -188,6 +188,20 @@ pub struct AccumulatorPublicKey<T: Limits> {
     pub params_ref: Option<AccumParametersStorageKey>,
 }
 
+impl<T: Limits> AccumulatorParameters<T> {
+    /// Returns `true` if the params' curve type is supported by the accumulators, i.e. it's `BLS12-381`.
+    pub fn is_curve_supported(&self) -> bool {
+        self.curve_type == CurveType::Bls12381
+    }
+}
+
+impl<T: Limits> AccumulatorPublicKey<T> {
+    /// Returns `true` if the key's curve type is supported by the accumulators, i.e. it's `BLS12-381`.
+    pub fn is_curve_supported(&self) -> bool {
+        self.curve_type == CurveType::Bls12381
+    }
+}
+
 #[derive(
     Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound, MaxEncodedLen,
 )]
@@ -248,7 +262,7 @@ pub struct AccumulatorCommon<T: Limits> {
 #[scale_info(omit_prefix)]
 pub struct UniversalAccumulator<T: Limits> {
     pub common: AccumulatorCommon<T>,
-    /// This is not enforced on chain and serves as metadata only
+    /// Max amount of the members, enforced on chain against the `member_count` of the accumulator.
     pub max_size: u64,
 }
 
@@ -279,6 +293,26 @@ impl<T: Limits> Accumulator<T> {
         }
     }
 
+    /// Get the accumulated value along with the reference to the public key of the accumulator
+    pub fn common(&self) -> &AccumulatorCommon<T> {
+        match self {
+            Accumulator::Positive(a) => a,
+            Accumulator::Universal(a) => &a.common,
+            Accumulator::KBUniversal(a) => a,
+        }
+    }
+
+    /// Set reference to the public key of the accumulator
+    pub fn set_key_ref(&mut self, key_ref: AccumPublicKeyStorageKey) -> &mut Self {
+        match self {
+            Accumulator::Positive(a) => a.key_ref = key_ref,
+            Accumulator::Universal(a) => a.common.key_ref = key_ref,
+            Accumulator::KBUniversal(a) => a.key_ref = key_ref,
+        }
+
+        self
+    }
+
     pub fn set_new_accumulated<A>(&mut self, new_accumulated: A) -> Result<&mut Self, A::Error>
     where
         A: TryInto<BoundedBytes<T::MaxAccumulatorAccumulatedSize>>,
@@ -322,14 +356,134 @@ where
     pub created_at: T::BlockNumber,
     pub last_updated_at: T::BlockNumber,
     pub accumulator: Accumulator<T>,
+    /// Amount of the members of the universal accumulator calculated from the `additions`/`removals` of the updates.
+    /// `None` for other accumulator types and for the universal accumulators created before the members started
+    /// being counted.
+    pub member_count: Option<u64>,
 }
 
 impl<T: TypesAndLimits> AccumulatorWithUpdateInfo<T> {
     pub fn new(accumulator: Accumulator<T>, created_at: T::BlockNumber) -> Self {
+        // Only the universal accumulators have their size enforced, so only their members are counted.
+        let member_count = matches!(accumulator, Accumulator::Universal(_)).then_some(0);
+
         Self {
             accumulator,
             created_at,
             last_updated_at: created_at,
+            member_count,
         }
     }
 }
+
+impl<T: Config> AccumulatorWithUpdateInfo<T> {
+    /// Updates the amount of the members of the universal accumulator (if it's counted) ensuring that it doesn't
+    /// underflow and doesn't exceed `max_size`. The update must specify its additions and/or removals,
+    /// a missing one is considered empty.
+    pub fn update_member_count(
+        &mut self,
+        added: Option<usize>,
+        removed: Option<usize>,
+    ) -> Result<(), Error<T>> {
+        let (Accumulator::Universal(UniversalAccumulator { max_size, .. }), Some(member_count)) =
+            (&self.accumulator, self.member_count.as_mut())
+        else {
+            return Ok(());
+        };
+        ensure!(
+            added.is_some() || removed.is_some(),
+            Error::<T>::MissingAccumulatorMemberChanges
+        );
+
+        let new_count = member_count
+            .saturating_add(added.unwrap_or_default() as u64)
+            .checked_sub(removed.unwrap_or_default() as u64)
+            .ok_or(Error::<T>::AccumulatorMemberCountUnderflow)?;
+        ensure!(
+            new_count <= *max_size,
+            Error::<T>::AccumulatorMaxSizeExceeded
+        );
+        *member_count = new_count;
+
+        Ok(())
+    }
+}
+
+/// Details of a single accumulator update required by the holders to update their witnesses.
+#[derive(scale_info_derive::TypeInfo, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(omit_prefix)]
+pub struct AccumulatorUpdateDetails {
+    pub new_accumulated: Bytes,
+    pub additions: Option<Vec<Bytes>>,
+    pub removals: Option<Vec<Bytes>>,
+    pub witness_update_info: Option<Bytes>,
+}
+
+/// Public key previously used by the accumulator.
+#[derive(
+    scale_info_derive::TypeInfo, Encode, Decode, Clone, PartialEq, Eq, Debug, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct ReplacedAccumulatorPublicKey<T>
+where
+    T: TypesAndLimits,
+{
+    pub key_ref: AccumPublicKeyStorageKey,
+    /// Block number when the key was replaced.
+    pub replaced_at: T::BlockNumber,
+}
+
+/// State of the accumulator recorded after one of its changes.
+#[derive(
+    scale_info_derive::TypeInfo, Encode, Decode, Clone, PartialEq, Eq, Debug, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct AccumulatorSnapshot<T>
+where
+    T: TypesAndLimits,
+{
+    /// Sequential number of the snapshot starting from `0` for the accumulator's initial state.
+    pub index: u64,
+    /// Block number when the snapshot was taken.
+    pub block: T::BlockNumber,
+    pub accumulated: BoundedBytes<T::MaxAccumulatorAccumulatedSize>,
+    /// Public key used by the accumulator when the snapshot was taken.
+    pub key_ref: AccumPublicKeyStorageKey,
+    pub member_count: Option<u64>,
+}
+
+/// Version of the accumulator to be retrieved from the stored snapshots.
+#[derive(scale_info_derive::TypeInfo, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub enum AccumulatorVersion<T>
+where
+    T: TypesAndLimits,
+{
+    /// The latest state of the accumulator as of the given block.
+    Block(T::BlockNumber),
+    /// State of the accumulator recorded in the snapshot with the given index.
+    Index(u64),
+}
@@ -0,0 +1,144 @@
+//! On-chain verification of the accumulator updates using the stored public key and params.
+//! An accumulator `V` with the secret key `α` gets updated to `V * (y + α)` when `y` is added
+//! and to `V * 1/(y + α)` when `y` is removed, so having the public key `Q̃ = P̃ * α`
+//! the addition can be verified using the pairing equation `e(V', P̃) = e(V, P̃ * y + Q̃)`.
+//! Since only `P̃` and `P̃ * α` are known, only updates changing a single member can be verified:
+//! verifying a batch would require `P̃ * α^k` for each power `k` up to the batch size.
+//! Only `BLS12-381` public keys and params can be used for the verification.
+//! Universal accumulators and accumulators without a public key (`key_id` 0) can't be verified,
+//! so their updates are accepted as is. Verification runs only if `Config::VerifyUpdates` is enabled.
+use super::*;
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_serialize::CanonicalDeserialize;
+use ark_std::Zero;
+
+/// Change of the accumulator members made by a single update.
+enum MemberChange {
+    Addition(Fr),
+    Removal(Fr),
+}
+
+impl<T: Config> Pallet<T> {
+    /// Ensures that the new accumulated value is consistent with the current one, stored public key/params
+    /// and the supplied additions/removals.
+    /// Positive and KB universal accumulators having a public key are verified, others are accepted as is.
+    pub(super) fn verify_update(
+        accumulator: &Accumulator<T>,
+        new_accumulated: &[u8],
+        additions: &[Bytes],
+        removals: &[Bytes],
+    ) -> DispatchResult {
+        let AccumPublicKeyStorageKey(owner, key_id) = accumulator.key_ref();
+        let accumulated_count = match accumulator {
+            Accumulator::Positive(_) => 1,
+            Accumulator::KBUniversal(_) => 2,
+            Accumulator::Universal(_) => return Ok(()),
+        };
+        // Zero key id is used by the keyed-verification accumulators which can't be verified using a public key.
+        if key_id.is_zero() {
+            return Ok(());
+        }
+
+        let change = match (additions, removals) {
+            ([], []) => {
+                ensure!(
+                    accumulator.accumulated() == new_accumulated,
+                    Error::<T>::InvalidAccumulatorUpdate
+                );
+
+                return Ok(());
+            }
+            ([addition], []) => MemberChange::Addition(parse(addition)?),
+            ([], [removal]) => MemberChange::Removal(parse(removal)?),
+            _ => return Err(Error::<T>::UnverifiableAccumulatorUpdate.into()),
+        };
+
+        let (p_tilde, q_tilde) = Self::p_tilde_with_public_key(owner, key_id)?;
+        let old = parse_accumulated::<T>(accumulator.accumulated(), accumulated_count)?;
+        let new = parse_accumulated::<T>(new_accumulated, accumulated_count)?;
+
+        let valid = match (change, old.as_slice(), new.as_slice()) {
+            (MemberChange::Addition(y), [base], [product])
+            | (MemberChange::Removal(y), [product], [base]) => {
+                is_multiplied_by(base, product, &y, &p_tilde, &q_tilde)
+            }
+            // KB universal accumulator consists of membership and non-membership accumulators,
+            // so adding a member implies removing it from the non-members and vice versa.
+            (
+                MemberChange::Addition(y),
+                [mem_base, non_mem_product],
+                [mem_product, non_mem_base],
+            )
+            | (
+                MemberChange::Removal(y),
+                [mem_product, non_mem_base],
+                [mem_base, non_mem_product],
+            ) => {
+                is_multiplied_by(mem_base, mem_product, &y, &p_tilde, &q_tilde)
+                    && is_multiplied_by(non_mem_base, non_mem_product, &y, &p_tilde, &q_tilde)
+            }
+            _ => false,
+        };
+        ensure!(valid, Error::<T>::InvalidAccumulatorUpdate);
+
+        Ok(())
+    }
+
+    /// Retrieves `P̃` from the params along with the public key `Q̃`.
+    fn p_tilde_with_public_key(
+        owner: AccumulatorOwner,
+        key_id: IncId,
+    ) -> Result<(G2Affine, G2Affine), Error<T>> {
+        let public_key =
+            AccumulatorKeys::<T>::get(owner, key_id).ok_or(Error::<T>::PublicKeyDoesntExist)?;
+        let AccumParametersStorageKey(params_owner, params_id) = public_key
+            .params_ref
+            .ok_or(Error::<T>::UnverifiableAccumulatorUpdate)?;
+        let params = AccumulatorParams::<T>::get(params_owner, params_id)
+            .ok_or(Error::<T>::ParamsDontExist)?;
+        // Keys and params added before the curve type started being checked may use other curves.
+        ensure!(
+            public_key.is_curve_supported() && params.is_curve_supported(),
+            Error::<T>::UnsupportedCurveType
+        );
+
+        // Params consist of `P` from `G1` followed by `P̃` from `G2`.
+        let (_, p_tilde) = <(G1Affine, G2Affine)>::deserialize_compressed(&params.bytes[..])
+            .map_err(|_| Error::<T>::MalformedAccumulatorPublicKeyOrParams)?;
+        let q_tilde = G2Affine::deserialize_compressed(&public_key.bytes[..])
+            .map_err(|_| Error::<T>::MalformedAccumulatorPublicKeyOrParams)?;
+
+        Ok((p_tilde, q_tilde))
+    }
+}
+
+/// Checks that `product = base * (y + α)` using the pairing equation `e(product, P̃) = e(base, P̃ * y + Q̃)`.
+fn is_multiplied_by(
+    base: &G1Affine,
+    product: &G1Affine,
+    y: &Fr,
+    p_tilde: &G2Affine,
+    q_tilde: &G2Affine,
+) -> bool {
+    let rhs = (*p_tilde * y + q_tilde).into_affine();
+
+    Bls12_381::multi_pairing([*product, -*base], [*p_tilde, rhs]).is_zero()
+}
+
+/// Parses a compressed member.
+fn parse<T: Config>(member: &Bytes) -> Result<Fr, Error<T>> {
+    Fr::deserialize_compressed(&member[..]).map_err(|_| Error::<T>::MalformedAccumulatorUpdate)
+}
+
+/// Parses the supplied amount of the compressed accumulated values.
+fn parse_accumulated<T: Config>(bytes: &[u8], count: usize) -> Result<Vec<G1Affine>, Error<T>> {
+    let mut reader = bytes;
+    let accumulated = (0..count)
+        .map(|_| G1Affine::deserialize_compressed(&mut reader))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| Error::<T>::MalformedAccumulatorUpdate)?;
+    ensure!(reader.is_empty(), Error::<T>::MalformedAccumulatorUpdate);
+
+    Ok(accumulated)
+}
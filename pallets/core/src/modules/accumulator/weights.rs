@@ -49,6 +49,16 @@ pub trait WeightInfo {
     fn remove_accumulator_sr25519() -> Weight;
     fn remove_accumulator_ed25519() -> Weight;
     fn remove_accumulator_secp256k1() -> Weight;
+    fn update_accumulator_public_key_sr25519() -> Weight;
+    fn update_accumulator_public_key_ed25519() -> Weight;
+    fn update_accumulator_public_key_secp256k1() -> Weight;
+    fn transfer_accumulator_sr25519() -> Weight;
+    fn transfer_accumulator_ed25519() -> Weight;
+    fn transfer_accumulator_secp256k1() -> Weight;
+    fn accept_accumulator_transfer_sr25519() -> Weight;
+    fn accept_accumulator_transfer_ed25519() -> Weight;
+    fn accept_accumulator_transfer_secp256k1() -> Weight;
+    fn verify_update() -> Weight;
 }
 
 /// Weights for accumulator using the Substrate node and recommended hardware.
@@ -133,19 +143,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             // Standard Error: 0
             .saturating_add(Weight::from_ref_time(9_000_u64).saturating_mul(b as u64))
             .saturating_add(T::DbWeight::get().reads(5_u64))
-            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
     }
     fn add_accumulator_ed25519(b: u32) -> Weight {
         Weight::from_ref_time(61_650_000_u64)
             // Standard Error: 0
             .saturating_add(Weight::from_ref_time(3_000_u64).saturating_mul(b as u64))
             .saturating_add(T::DbWeight::get().reads(5_u64))
-            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
     }
     fn add_accumulator_secp256k1(_b: u32) -> Weight {
         Weight::from_ref_time(167_711_000_u64)
             .saturating_add(T::DbWeight::get().reads(5_u64))
-            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
     }
     fn update_accumulator_sr25519(a: u32, b: u32, c: u32, d: u32, e: u32, _f: u32) -> Weight {
         Weight::from_ref_time(46_170_000_u64)
@@ -197,17 +207,65 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn remove_accumulator_sr25519() -> Weight {
         Weight::from_ref_time(60_493_000_u64)
             .saturating_add(T::DbWeight::get().reads(4_u64))
-            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
     }
     fn remove_accumulator_ed25519() -> Weight {
         Weight::from_ref_time(57_062_000_u64)
             .saturating_add(T::DbWeight::get().reads(4_u64))
-            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
     }
     fn remove_accumulator_secp256k1() -> Weight {
         Weight::from_ref_time(167_644_000_u64)
             .saturating_add(T::DbWeight::get().reads(4_u64))
-            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    fn update_accumulator_public_key_sr25519() -> Weight {
+        Weight::from_ref_time(63_118_000_u64)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    fn update_accumulator_public_key_ed25519() -> Weight {
+        Weight::from_ref_time(59_874_000_u64)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    fn update_accumulator_public_key_secp256k1() -> Weight {
+        Weight::from_ref_time(170_302_000_u64)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    fn transfer_accumulator_sr25519() -> Weight {
+        Weight::from_ref_time(63_472_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    fn transfer_accumulator_ed25519() -> Weight {
+        Weight::from_ref_time(60_215_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    fn transfer_accumulator_secp256k1() -> Weight {
+        Weight::from_ref_time(170_941_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    fn accept_accumulator_transfer_sr25519() -> Weight {
+        Weight::from_ref_time(63_472_000_u64)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
+    }
+    fn accept_accumulator_transfer_ed25519() -> Weight {
+        Weight::from_ref_time(60_215_000_u64)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
+    }
+    fn accept_accumulator_transfer_secp256k1() -> Weight {
+        Weight::from_ref_time(170_941_000_u64)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
+    }
+    fn verify_update() -> Weight {
+        Weight::from_ref_time(3_862_917_000_u64).saturating_add(T::DbWeight::get().reads(2_u64))
     }
 }
 
@@ -292,19 +350,19 @@ impl WeightInfo for () {
             // Standard Error: 0
             .saturating_add(Weight::from_ref_time(9_000_u64).saturating_mul(b as u64))
             .saturating_add(RocksDbWeight::get().reads(5_u64))
-            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
     fn add_accumulator_ed25519(b: u32) -> Weight {
         Weight::from_ref_time(61_650_000_u64)
             // Standard Error: 0
             .saturating_add(Weight::from_ref_time(3_000_u64).saturating_mul(b as u64))
             .saturating_add(RocksDbWeight::get().reads(5_u64))
-            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
     fn add_accumulator_secp256k1(_b: u32) -> Weight {
         Weight::from_ref_time(167_711_000_u64)
             .saturating_add(RocksDbWeight::get().reads(5_u64))
-            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
     fn update_accumulator_sr25519(a: u32, b: u32, c: u32, d: u32, e: u32, _f: u32) -> Weight {
         Weight::from_ref_time(46_170_000_u64)
@@ -356,16 +414,64 @@ impl WeightInfo for () {
     fn remove_accumulator_sr25519() -> Weight {
         Weight::from_ref_time(60_493_000_u64)
             .saturating_add(RocksDbWeight::get().reads(4_u64))
-            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
     fn remove_accumulator_ed25519() -> Weight {
         Weight::from_ref_time(57_062_000_u64)
             .saturating_add(RocksDbWeight::get().reads(4_u64))
-            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
     fn remove_accumulator_secp256k1() -> Weight {
         Weight::from_ref_time(167_644_000_u64)
             .saturating_add(RocksDbWeight::get().reads(4_u64))
-            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn update_accumulator_public_key_sr25519() -> Weight {
+        Weight::from_ref_time(63_118_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn update_accumulator_public_key_ed25519() -> Weight {
+        Weight::from_ref_time(59_874_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn update_accumulator_public_key_secp256k1() -> Weight {
+        Weight::from_ref_time(170_302_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn transfer_accumulator_sr25519() -> Weight {
+        Weight::from_ref_time(63_472_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn transfer_accumulator_ed25519() -> Weight {
+        Weight::from_ref_time(60_215_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn transfer_accumulator_secp256k1() -> Weight {
+        Weight::from_ref_time(170_941_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn accept_accumulator_transfer_sr25519() -> Weight {
+        Weight::from_ref_time(63_472_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(9_u64))
+    }
+    fn accept_accumulator_transfer_ed25519() -> Weight {
+        Weight::from_ref_time(60_215_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(9_u64))
+    }
+    fn accept_accumulator_transfer_secp256k1() -> Weight {
+        Weight::from_ref_time(170_941_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(9_u64))
+    }
+    fn verify_update() -> Weight {
+        Weight::from_ref_time(3_862_917_000_u64).saturating_add(RocksDbWeight::get().reads(2_u64))
     }
 }
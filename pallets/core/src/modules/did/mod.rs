@@ -479,6 +479,9 @@ pub mod pallet {
             _d: AggregatedDidDetailsResponse<T>,
             _qi: crate::trust_registry::QueryTrustRegistryBy,
             _qy: crate::trust_registry::QueryTrustRegistriesBy,
+            _sf: crate::trust_registry::SchemaMetadataFilter,
+            _tq: crate::trust_registry::TrqpAuthorizationQuery,
+            _ta: crate::trust_registry::TrqpAuthorization<T>,
             _a: crate::trust_registry::AggregatedTrustRegistrySchemaMetadata<T>,
             _u: crate::accumulator::UpdateAccumulator<T>,
         ) -> DispatchResult {
@@ -46,6 +46,35 @@ pub struct RemoveOffchainSignatureParams<T: Types> {
     pub nonce: T::BlockNumber,
 }
 
+/// Removes the params even if they're referenced by public keys leaving these references dangling.
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct ForceRemoveOffchainSignatureParams<T: Types> {
+    pub params_ref: SignatureParamsStorageKey,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct ShareOffchainSignatureParams<T: Types> {
+    pub params_ref: SignatureParamsStorageKey,
+    pub nonce: T::BlockNumber,
+}
+
 #[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
@@ -61,14 +90,81 @@ pub struct RemoveOffchainSignaturePublicKey<T: Types> {
     pub nonce: T::BlockNumber,
 }
 
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct AddThresholdIssuerGroup<T: TypesAndLimits> {
+    pub group: ThresholdIssuerGroup<T>,
+    pub did: Did,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct UpdateThresholdIssuerGroup<T: TypesAndLimits> {
+    pub group_ref: ThresholdIssuerGroupStorageKey,
+    pub group: ThresholdIssuerGroup<T>,
+    pub did: Did,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct RemoveThresholdIssuerGroup<T: Types> {
+    pub group_ref: ThresholdIssuerGroupStorageKey,
+    pub did: Did,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct RevokeOffchainSignaturePublicKey<T: Types> {
+    pub key_ref: SignaturePublicKeyStorageKey,
+    pub did: Did,
+    pub nonce: T::BlockNumber,
+}
+
 crate::impl_action_with_nonce! {
     for Did:
         AddOffchainSignaturePublicKey with 1 as len, did as target,
-        RemoveOffchainSignaturePublicKey with 1 as len, did as target
+        RemoveOffchainSignaturePublicKey with 1 as len, did as target,
+        RevokeOffchainSignaturePublicKey with 1 as len, did as target,
+        AddThresholdIssuerGroup with group.participants.len() as len, did as target,
+        UpdateThresholdIssuerGroup with group.participants.len() as len, did as target,
+        RemoveThresholdIssuerGroup with 1 as len, did as target
 }
 
 crate::impl_action_with_nonce! {
     for ():
         AddOffchainSignatureParams with 1 as len, () as target,
-        RemoveOffchainSignatureParams with 1 as len, () as target
+        RemoveOffchainSignatureParams with 1 as len, () as target,
+        ShareOffchainSignatureParams with 1 as len, () as target,
+        ForceRemoveOffchainSignatureParams with 1 as len, () as target
 }
@@ -2,16 +2,20 @@ use super::*;
 use crate::{
     common::{CurveType, IntermediateError, ToStateChange},
     did::{Did, DidSignature, UncheckedDidKey},
-    util::{Action, BoundedBytes, IncId},
+    util::{compressed, Action, BoundedBytes, IncId},
 };
+use ark_bls12_381::{G1Affine, G2Affine};
+use ark_ec::AffineRepr;
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
+use sp_std::collections::btree_map::BTreeMap;
 #[cfg(not(feature = "std"))]
 use sp_std::prelude::*;
 
 const MAX_PARAMS: u32 = 512;
 const MAX_LABEL: u32 = 128;
 const MAX_KEY: u32 = 256;
+const MAX_PARTICIPANTS: u32 = 64;
 
 crate::bench_with_all_pairs! {
     with_pairs:
@@ -181,4 +185,361 @@ crate::bench_with_all_pairs! {
     verify {
         assert!(PublicKeys::<T>::get(did, IncId::from(2u8)).is_none());
     }
+
+    revoke_public_sr25519 for sr25519, revoke_public_ed25519 for ed25519, revoke_public_secp256k1 for secp256k1 {
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([1; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        Pallet::<T>::add_public_key_(
+            AddOffchainSignaturePublicKey {
+                did: did,
+                key: BBSPlusPublicKey::new(
+                    BoundedBytes::try_from(vec![0; MAX_KEY as usize]).unwrap(),
+                    None,
+                    CurveType::Bls12381,
+                ).into(),
+                nonce: 2u8.into()
+            },
+            &mut Default::default()
+        ).unwrap();
+
+        let revoke_key = RevokeOffchainSignaturePublicKey {
+            did: did,
+            key_ref: (did, 1u8.into()),
+            nonce: 1u8.into()
+        };
+
+        let sig = pair.sign(&revoke_key.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: revoke_public_key(RawOrigin::Signed(caller), revoke_key, signature)
+    verify {
+        assert!(RevokedPublicKeys::<T>::get(did, IncId::from(1u8)).is_some());
+    }
+
+    add_threshold_issuer_group_sr25519 for sr25519, add_threshold_issuer_group_ed25519 for ed25519, add_threshold_issuer_group_secp256k1 for secp256k1 {
+        {
+            let p in 1 .. MAX_PARTICIPANTS;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([1; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let mut details = Default::default();
+        for participant_id in 1..=p {
+            Pallet::<T>::add_public_key_(
+                AddOffchainSignaturePublicKey {
+                    did,
+                    key: BBSPlusPublicKey::new_with_participant_id(
+                        BoundedBytes::try_from(vec![0; MAX_KEY as usize]).unwrap(),
+                        None,
+                        CurveType::Bls12381,
+                        participant_id as ParticipantId,
+                    ).into(),
+                    nonce: 1u8.into()
+                },
+                &mut details
+            ).unwrap();
+        }
+        Pallet::<T>::add_public_key_(
+            AddOffchainSignaturePublicKey {
+                did,
+                key: BBSPlusPublicKey::new(
+                    BoundedBytes::try_from(vec![0; MAX_KEY as usize]).unwrap(),
+                    None,
+                    CurveType::Bls12381,
+                ).into(),
+                nonce: 1u8.into()
+            },
+            &mut details
+        ).unwrap();
+
+        let group = ThresholdIssuerGroup::<T> {
+            participants: (1..=p)
+                .map(|participant_id| (participant_id as ParticipantId, (did, IncId::from(participant_id))))
+                .collect::<BTreeMap<_, _>>()
+                .try_into()
+                .unwrap(),
+            threshold: 1,
+            aggregate_key: IncId::from(p + 1),
+            params_ref: None,
+        };
+
+        let add_group = AddThresholdIssuerGroup {
+            did,
+            group: group.clone(),
+            nonce: 1u8.into()
+        };
+
+        let sig = pair.sign(&add_group.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: add_threshold_issuer_group(RawOrigin::Signed(caller), add_group, signature)
+    verify {
+        assert_eq!(ThresholdIssuerGroups::<T>::get(did, IncId::from(1u8)).unwrap(), group);
+    }
+
+    update_threshold_issuer_group_sr25519 for sr25519, update_threshold_issuer_group_ed25519 for ed25519, update_threshold_issuer_group_secp256k1 for secp256k1 {
+        {
+            let p in 1 .. MAX_PARTICIPANTS;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([1; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let mut details = Default::default();
+        for participant_id in 1..=p {
+            Pallet::<T>::add_public_key_(
+                AddOffchainSignaturePublicKey {
+                    did,
+                    key: BBSPlusPublicKey::new_with_participant_id(
+                        BoundedBytes::try_from(vec![0; MAX_KEY as usize]).unwrap(),
+                        None,
+                        CurveType::Bls12381,
+                        participant_id as ParticipantId,
+                    ).into(),
+                    nonce: 1u8.into()
+                },
+                &mut details
+            ).unwrap();
+        }
+        Pallet::<T>::add_public_key_(
+            AddOffchainSignaturePublicKey {
+                did,
+                key: BBSPlusPublicKey::new(
+                    BoundedBytes::try_from(vec![0; MAX_KEY as usize]).unwrap(),
+                    None,
+                    CurveType::Bls12381,
+                ).into(),
+                nonce: 1u8.into()
+            },
+            &mut details
+        ).unwrap();
+
+        let mut group = ThresholdIssuerGroup::<T> {
+            participants: (1..=p)
+                .map(|participant_id| (participant_id as ParticipantId, (did, IncId::from(participant_id))))
+                .collect::<BTreeMap<_, _>>()
+                .try_into()
+                .unwrap(),
+            threshold: 1,
+            aggregate_key: IncId::from(p + 1),
+            params_ref: None,
+        };
+
+        Pallet::<T>::add_threshold_issuer_group_(
+            AddThresholdIssuerGroup {
+                did,
+                group: group.clone(),
+                nonce: 1u8.into()
+            },
+            &mut details
+        ).unwrap();
+        group.threshold = p as ParticipantId;
+
+        let update_group = UpdateThresholdIssuerGroup {
+            did,
+            group_ref: (did, IncId::from(1u8)),
+            group: group.clone(),
+            nonce: 1u8.into()
+        };
+
+        let sig = pair.sign(&update_group.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: update_threshold_issuer_group(RawOrigin::Signed(caller), update_group, signature)
+    verify {
+        assert_eq!(ThresholdIssuerGroups::<T>::get(did, IncId::from(1u8)).unwrap(), group);
+    }
+
+    remove_threshold_issuer_group_sr25519 for sr25519, remove_threshold_issuer_group_ed25519 for ed25519, remove_threshold_issuer_group_secp256k1 for secp256k1 {
+        {
+            let p in 1 .. MAX_PARTICIPANTS;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([1; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let mut details = Default::default();
+        for participant_id in 1..=p {
+            Pallet::<T>::add_public_key_(
+                AddOffchainSignaturePublicKey {
+                    did,
+                    key: BBSPlusPublicKey::new_with_participant_id(
+                        BoundedBytes::try_from(vec![0; MAX_KEY as usize]).unwrap(),
+                        None,
+                        CurveType::Bls12381,
+                        participant_id as ParticipantId,
+                    ).into(),
+                    nonce: 1u8.into()
+                },
+                &mut details
+            ).unwrap();
+        }
+        Pallet::<T>::add_public_key_(
+            AddOffchainSignaturePublicKey {
+                did,
+                key: BBSPlusPublicKey::new(
+                    BoundedBytes::try_from(vec![0; MAX_KEY as usize]).unwrap(),
+                    None,
+                    CurveType::Bls12381,
+                ).into(),
+                nonce: 1u8.into()
+            },
+            &mut details
+        ).unwrap();
+
+        let group = ThresholdIssuerGroup::<T> {
+            participants: (1..=p)
+                .map(|participant_id| (participant_id as ParticipantId, (did, IncId::from(participant_id))))
+                .collect::<BTreeMap<_, _>>()
+                .try_into()
+                .unwrap(),
+            threshold: 1,
+            aggregate_key: IncId::from(p + 1),
+            params_ref: None,
+        };
+
+        Pallet::<T>::add_threshold_issuer_group_(
+            AddThresholdIssuerGroup {
+                did,
+                group: group.clone(),
+                nonce: 1u8.into()
+            },
+            &mut details
+        ).unwrap();
+
+        let remove_group = RemoveThresholdIssuerGroup {
+            did,
+            group_ref: (did, IncId::from(1u8)),
+            nonce: 1u8.into()
+        };
+
+        let sig = pair.sign(&remove_group.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: remove_threshold_issuer_group(RawOrigin::Signed(caller), remove_group, signature)
+    verify {
+        assert!(ThresholdIssuerGroups::<T>::get(did, IncId::from(1u8)).is_none());
+        assert_eq!(ThresholdIssuerGroupKeyReferences::<T>::iter_prefix(did).count(), 0);
+    }
+
+    share_params_sr25519 for sr25519, share_params_ed25519 for ed25519, share_params_secp256k1 for secp256k1 {
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([1; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        ActionWithNonceWrapper::<T, _, _>::new(
+            1u8.into(),
+            SignatureParamsOwner(did.into()),
+            AddOffchainSignatureParams {
+                params: BBSPlusParameters::new(
+                    BoundedBytes::try_from(vec![1; MAX_LABEL as usize]).unwrap(),
+                    BoundedBytes::try_from(vec![0; MAX_PARAMS as usize]).unwrap(),
+                    CurveType::Bls12381,
+                ).into(),
+                nonce: 1u8.into()
+            },
+        ).modify::<T, _, _, _, _>(|action, entity| super::Pallet::<T>::add_params_(action.action, entity, SignatureParamsOwner(did.into())).map_err(IntermediateError::<T>::from)).unwrap();
+
+        let share = ShareOffchainSignatureParams {
+            params_ref: (SignatureParamsOwner(did.into()), 1u8.into()),
+            nonce: 1u8.into()
+        };
+
+        let sig = pair.sign(&share.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: share_params(RawOrigin::Signed(caller), share, signature)
+    verify {
+        assert!(SharedParams::<T>::contains_key(SignatureParamsOwner(did.into()), IncId::from(1u8)));
+    };
+
+    standard:
+    add_shared_params {
+        let owner = SignatureParamsOwner(Did([1; Did::BYTE_SIZE]).into());
+        let params: OffchainSignatureParams<T> = BBSPlusParameters::new(
+            BoundedBytes::try_from(vec![1; MAX_LABEL as usize]).unwrap(),
+            BoundedBytes::try_from(vec![0; MAX_PARAMS as usize]).unwrap(),
+            CurveType::Bls12381,
+        ).into();
+        SignatureParams::<T>::insert(owner, IncId::from(1u8), params);
+    }: add_shared_params(RawOrigin::Root, (owner, 1u8.into()))
+    verify {
+        assert!(SharedParams::<T>::contains_key(owner, IncId::from(1u8)));
+    }
+
+    remove_shared_params {
+        let owner = SignatureParamsOwner(Did([1; Did::BYTE_SIZE]).into());
+        let params: OffchainSignatureParams<T> = BBSPlusParameters::new(
+            BoundedBytes::try_from(vec![1; MAX_LABEL as usize]).unwrap(),
+            BoundedBytes::try_from(vec![0; MAX_PARAMS as usize]).unwrap(),
+            CurveType::Bls12381,
+        ).into();
+        SignatureParams::<T>::insert(owner, IncId::from(1u8), params);
+        SharedParams::<T>::insert(owner, IncId::from(1u8), ());
+    }: remove_shared_params(RawOrigin::Root, (owner, 1u8.into()))
+    verify {
+        assert!(!SharedParams::<T>::contains_key(owner, IncId::from(1u8)));
+    }
+
+    validate_params {
+        let b in 200 .. MAX_PARAMS;
+
+        // `Pointcheval-Sanders` params with as much message generators as fit into `b` bytes.
+        let (g, g_tilde) = (G1Affine::generator(), G2Affine::generator());
+        let params: OffchainSignatureParams<T> = PSParameters::new(
+            None,
+            BoundedBytes::try_from(compressed((g, g_tilde, vec![g; (b as usize - 152) / 48]))).unwrap(),
+            CurveType::Bls12381,
+        ).into();
+    }: {
+        params.ensure_valid_points().unwrap();
+    }
+
+    validate_public_key {
+        let b in 0 .. MAX_KEY;
+
+        // `Pointcheval-Sanders` public key with as much message elements as fit into `b` bytes.
+        let (g, g_tilde) = (G1Affine::generator(), G2Affine::generator());
+        let message_count = (b as usize).saturating_sub(112) / 144;
+        let key: OffchainPublicKey<T> = PSPublicKey::new(
+            BoundedBytes::try_from(compressed((g_tilde, vec![g; message_count.max(1)], vec![g_tilde; message_count.max(1)]))).unwrap(),
+            None,
+            CurveType::Bls12381,
+        ).into();
+    }: {
+        key.ensure_valid_points().unwrap();
+    }
 }
@@ -0,0 +1,30 @@
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use sp_std::marker::PhantomData;
+
+/// Fills `ParamsReferences` using the public keys stored before the references started being counted.
+pub struct CountParamsReferences<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for CountParamsReferences<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let db_weight = T::DbWeight::get();
+        if Pallet::<T>::on_chain_storage_version() >= 1 {
+            return db_weight.reads(1);
+        }
+
+        let (mut keys, mut counted) = (0u64, 0u64);
+        for key in PublicKeys::<T>::iter_values() {
+            keys += 1;
+
+            if let Some((owner, params_id)) = key.params_ref() {
+                ParamsReferences::<T>::mutate(owner, params_id, |refs| {
+                    *refs = refs.saturating_add(1)
+                });
+                counted += 1;
+            }
+        }
+        StorageVersion::new(1).put::<Pallet<T>>();
+
+        db_weight.reads_writes(keys + counted + 1, counted + 1)
+    }
+}
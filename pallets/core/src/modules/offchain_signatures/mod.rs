@@ -2,6 +2,13 @@
 //! Currently, can be either `BBS`, `BBS+`, `Pointcheval-Sanders` or `BBDT16`.
 //! For `BBS`, `BBS+` and `Pointcheval-Sanders`, the public key is in group G2 but for `BBDT16`, it's
 //! in group G1 and used to verify the proof of validity of MAC (and related proofs) but not the MAC itself.
+//! Public keys for the IETF draft `BBS` scheme and the `ecdsa-sd`/selective disclosure `Ed25519` Data Integrity
+//! cryptosuites can be added as well, these don't have parameters on chain.
+//! Participants' keys used in threshold issuance can be grouped into a threshold issuer group having
+//! the threshold and the aggregated public key used to verify the credentials issued by the group.
+//! Params can be shared to be discoverable and referenced by public keys of any DID, while unshared params
+//! can only be referenced by the keys of their owner. Params referenced by public keys can only be removed using
+//! `force_remove_params` leaving dangling references. Besides the owner, the root origin can share and unshare any params.
 
 use crate::{
     common::{self, signatures::ForSigType},
@@ -16,23 +23,28 @@ use frame_support::{
     dispatch::{DispatchResult, Weight},
     traits::Get,
 };
-use frame_system::ensure_signed;
+use frame_system::{ensure_root, ensure_signed};
 use weights::*;
 
 pub use actions::*;
+pub use migration::*;
 pub use pallet::*;
 pub use params::*;
 pub use public_key::*;
 pub use schemes::*;
+pub use threshold_issuer_group::*;
 
 mod actions;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
+mod migration;
 mod params;
 mod public_key;
 mod schemes;
 #[cfg(test)]
 mod tests;
+mod threshold_issuer_group;
+mod validation;
 mod weights;
 
 #[frame_support::pallet]
@@ -49,6 +61,14 @@ pub mod pallet {
         type Event: From<Event>
             + IsType<<Self as frame_system::Config>::Event>
             + Into<<Self as frame_system::Config>::Event>;
+
+        /// Whether the bytes of the added public keys and params should be decoded and validated
+        /// according to their signature scheme and curve type.
+        type ValidateKeysAndParams: Get<bool>;
+
+        /// Max amount of entries returned by a single page of the paginated queries.
+        #[pallet::constant]
+        type MaxQueryPageSize: Get<u32>;
     }
 
     #[pallet::event]
@@ -56,8 +76,14 @@ pub mod pallet {
     pub enum Event {
         ParamsAdded(SignatureParamsOwner, IncId),
         ParamsRemoved(SignatureParamsOwner, IncId),
+        ParamsShared(SignatureParamsOwner, IncId),
         KeyAdded(Did, IncId),
         KeyRemoved(Did, IncId),
+        KeyRevoked(Did, IncId),
+        ThresholdIssuerGroupAdded(Did, IncId),
+        ThresholdIssuerGroupUpdated(Did, IncId),
+        ParamsUnshared(SignatureParamsOwner, IncId),
+        ThresholdIssuerGroupRemoved(Did, IncId),
     }
 
     #[pallet::error]
@@ -67,10 +93,50 @@ pub mod pallet {
         PublicKeyDoesntExist,
         NotOwner,
         IncorrectNonce,
+        /// Public key and the referenced params have different curve types.
+        IncorrectParamsCurve,
+        /// Params bytes don't represent valid points of the params of the signature scheme.
+        MalformedParams,
+        /// Public key bytes don't represent valid points of the public key of the signature scheme.
+        MalformedPublicKey,
+        /// Public key supports an amount of the messages different from the referenced params.
+        IncorrectPublicKeyMessageCount,
+        /// Curve type isn't supported by the signature scheme.
+        UnsupportedCurveType,
+        /// Threshold issuer group with the given id doesn't exist for the DID.
+        ThresholdIssuerGroupDoesntExist,
+        /// Threshold must be greater than zero and not exceed the amount of the group participants.
+        InvalidThreshold,
+        /// Signature scheme of the aggregated public key doesn't support threshold issuance.
+        ThresholdIssuanceNotSupported,
+        /// Participant's public key has a participant id different from the group's one.
+        IncorrectParticipantId,
+        /// Participant's public key has a signature scheme or curve type different from the aggregated public key.
+        IncorrectParticipantKeyScheme,
+        /// Public key is revoked.
+        PublicKeyRevoked,
+        /// Params are already shared.
+        ParamsAlreadyShared,
+        /// Params are referenced by public keys, so they can only be removed using `force_remove_params`.
+        ParamsInUse,
+        /// Participant's public key references params different from the group's ones.
+        IncorrectParticipantParams,
+        /// Params aren't shared, so they can only be referenced by the keys of their owner.
+        ParamsNotShared,
+        /// The aggregated public key references params different from the group's ones.
+        IncorrectAggregateKeyParams,
+        /// The aggregated public key has a participant id or is a participant's public key.
+        InvalidAggregateKey,
+        /// Public key is referenced by threshold issuer groups, so it can't be removed.
+        PublicKeyInUse,
     }
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// On adding new params, corresponding counter is increased by 1 but
@@ -92,12 +158,59 @@ pub mod pallet {
         OffchainSignatureParams<T>,
     >;
 
+    /// Shared params are stored as key value (did, counter) -> (). Shared params are discoverable
+    /// and can be referenced by public keys of any DID.
+    #[pallet::storage]
+    #[pallet::getter(fn is_shared_params)]
+    pub type SharedParams<T> =
+        StorageDoubleMap<_, Blake2_128Concat, SignatureParamsOwner, Identity, IncId, ()>;
+
+    /// Amount of the public keys referencing the params stored as key value (did, counter) -> amount.
+    #[pallet::storage]
+    #[pallet::getter(fn did_params_references)]
+    pub type ParamsReferences<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SignatureParamsOwner,
+        Identity,
+        IncId,
+        u32,
+        ValueQuery,
+    >;
+
     /// Public keys are stored as key value (did, counter) -> public key
     #[pallet::storage]
     #[pallet::getter(fn did_public_key)]
     pub type PublicKeys<T> =
         StorageDoubleMap<_, Blake2_128Concat, Did, Identity, IncId, OffchainPublicKey<T>>;
 
+    /// Revoked public keys are stored as key value (did, counter) -> block number of the revocation.
+    /// Revoked keys remain in `PublicKeys`, so signatures made before the revocation can still be verified.
+    #[pallet::storage]
+    #[pallet::getter(fn did_public_key_revoked_at)]
+    pub type RevokedPublicKeys<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, Did, Identity, IncId, T::BlockNumber>;
+
+    /// On adding new threshold issuer group, corresponding counter is increased by 1 but
+    /// the counters don't decrease on removal.
+    #[pallet::storage]
+    #[pallet::getter(fn did_threshold_issuer_groups_counter)]
+    pub type ThresholdIssuerGroupsCounter<T> =
+        StorageMap<_, Blake2_128Concat, Did, IncId, ValueQuery>;
+
+    /// Threshold issuer groups are stored as key value (did, counter) -> threshold issuer group
+    #[pallet::storage]
+    #[pallet::getter(fn threshold_issuer_group)]
+    pub type ThresholdIssuerGroups<T> =
+        StorageDoubleMap<_, Blake2_128Concat, Did, Identity, IncId, ThresholdIssuerGroup<T>>;
+
+    /// Amount of the threshold issuer groups referencing the public key either as the aggregated or
+    /// a participant's key stored as key value (did, counter) -> amount.
+    #[pallet::storage]
+    #[pallet::getter(fn did_public_key_group_references)]
+    pub type ThresholdIssuerGroupKeyReferences<T> =
+        StorageDoubleMap<_, Blake2_128Concat, Did, Identity, IncId, u32, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn version)]
     pub type Version<T> = StorageValue<_, common::StorageVersion, ValueQuery>;
@@ -139,6 +252,46 @@ pub mod pallet {
                 .map_err(Into::into)
         }
 
+        /// Share existing params of any DID making them discoverable and referenceable by the public keys of any DID.
+        /// Params remain owned by their DID. Can only be called by the root origin.
+        #[pallet::weight(SubstrateWeight::<T>::add_shared_params())]
+        pub fn add_shared_params(
+            origin: OriginFor<T>,
+            params_ref: SignatureParamsStorageKey,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            Self::add_shared_params_(params_ref)
+        }
+
+        /// Unshare params, so they can no longer be referenced by the new public keys of DIDs other than the owner.
+        /// Public keys already referencing the params aren't affected. Can only be called by the root origin.
+        #[pallet::weight(SubstrateWeight::<T>::remove_shared_params())]
+        pub fn remove_shared_params(
+            origin: OriginFor<T>,
+            params_ref: SignatureParamsStorageKey,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            Self::remove_shared_params_(params_ref)
+        }
+
+        /// Share existing params making them discoverable and referenceable by the public keys of any DID.
+        /// Only the params owner can share them.
+        #[pallet::weight(SubstrateWeight::<T>::share_params(share, signature))]
+        pub fn share_params(
+            origin: OriginFor<T>,
+            share: ShareOffchainSignatureParams<T>,
+            signature: DidOrDidMethodKeySignature<SignatureParamsOwner>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            share
+                .signed(signature)
+                .execute_view(Self::share_params_)
+                .map_err(Into::into)
+        }
+
         /// Add new offchain signature public key. Only the DID controller can add key and it should use the nonce from the DID module.
         /// This kind of key cannot be removed by calling `remove_keys` from the DID module but only by calling `remove_public_key` of this module.
         #[pallet::weight(SubstrateWeight::<T>::add_public(public_key, signature))]
@@ -184,6 +337,85 @@ pub mod pallet {
                 .execute_from_controller(Self::remove_public_key_)
                 .map_err(Into::into)
         }
+
+        /// Revoke existing offchain signature public key. Revoked key remains resolvable along with the block number
+        /// of its revocation. Only the DID controller can revoke key and it should use the nonce from the DID module.
+        #[pallet::weight(SubstrateWeight::<T>::revoke_public(revoke, signature))]
+        pub fn revoke_public_key(
+            origin: OriginFor<T>,
+            revoke: RevokeOffchainSignaturePublicKey<T>,
+            signature: DidOrDidMethodKeySignature<Controller>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            revoke
+                .signed(signature)
+                .execute_from_controller(Self::revoke_public_key_)
+                .map_err(Into::into)
+        }
+
+        /// Add new threshold issuer group grouping the participants' public keys used in threshold issuance.
+        /// Only the DID controller can add group and it should use the nonce from the DID module.
+        #[pallet::weight(SubstrateWeight::<T>::add_threshold_issuer_group(add_group, signature))]
+        pub fn add_threshold_issuer_group(
+            origin: OriginFor<T>,
+            add_group: AddThresholdIssuerGroup<T>,
+            signature: DidOrDidMethodKeySignature<Controller>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            add_group
+                .signed(signature)
+                .execute_from_controller(Self::add_threshold_issuer_group_)
+                .map_err(Into::into)
+        }
+
+        /// Update existing threshold issuer group. Only the DID controller can update group and it should use the nonce from the DID module.
+        #[pallet::weight(SubstrateWeight::<T>::update_threshold_issuer_group(update_group, signature))]
+        pub fn update_threshold_issuer_group(
+            origin: OriginFor<T>,
+            update_group: UpdateThresholdIssuerGroup<T>,
+            signature: DidOrDidMethodKeySignature<Controller>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            update_group
+                .signed(signature)
+                .execute_from_controller(Self::update_threshold_issuer_group_)
+                .map_err(Into::into)
+        }
+
+        /// Remove the params even if they're referenced by public keys leaving these references dangling.
+        /// References of the removed params are released along with the public keys.
+        #[pallet::weight(SubstrateWeight::<T>::force_remove_params(remove, signature))]
+        pub fn force_remove_params(
+            origin: OriginFor<T>,
+            remove: ForceRemoveOffchainSignatureParams<T>,
+            signature: DidOrDidMethodKeySignature<SignatureParamsOwner>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            remove
+                .signed(signature)
+                .execute_view(Self::force_remove_params_)
+                .map_err(Into::into)
+        }
+
+        /// Remove existing threshold issuer group releasing its public keys. Only the DID controller can remove group
+        /// and it should use the nonce from the DID module.
+        #[pallet::weight(SubstrateWeight::<T>::remove_threshold_issuer_group(remove_group, signature))]
+        pub fn remove_threshold_issuer_group(
+            origin: OriginFor<T>,
+            remove_group: RemoveThresholdIssuerGroup<T>,
+            signature: DidOrDidMethodKeySignature<Controller>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            remove_group
+                .signed(signature)
+                .execute_from_controller(Self::remove_threshold_issuer_group_)
+                .map_err(Into::into)
+        }
     }
 }
 
@@ -191,10 +423,32 @@ impl<T: Config> HandleDidRemoval for Pallet<T> {
     fn on_did_removal(did: Did) -> Weight {
         use sp_io::MultiRemovalResults;
         // TODO: limit and cursor
-        let MultiRemovalResults { backend, .. } =
-            PublicKeys::<T>::clear_prefix(did, u32::MAX, None);
-
-        T::DbWeight::get().writes(backend as u64)
+        // Keys are drained one by one to release the references to their params.
+        let mut keys = 0u64;
+        for (_, key) in PublicKeys::<T>::drain_prefix(did) {
+            Self::release_params_reference(&key);
+            keys += 1 + key.params_ref().is_some() as u64;
+        }
+        let MultiRemovalResults {
+            backend: revoked, ..
+        } = RevokedPublicKeys::<T>::clear_prefix(did, u32::MAX, None);
+        // Groups are drained one by one to release the references to their keys.
+        let mut groups = 0u64;
+        for (_, group) in ThresholdIssuerGroups::<T>::drain_prefix(did) {
+            Self::release_group_key_references(did, &group);
+            groups += 1 + group.participants.len() as u64 + 1;
+        }
+        // Keys of the removed DID can still be referenced by the groups of other DIDs.
+        let MultiRemovalResults {
+            backend: group_references,
+            ..
+        } = ThresholdIssuerGroupKeyReferences::<T>::clear_prefix(did, u32::MAX, None);
+
+        T::DbWeight::get()
+            .reads_writes(keys + groups, keys + groups)
+            .saturating_add(
+                T::DbWeight::get().writes(revoked.saturating_add(group_references) as u64),
+            )
     }
 }
 
@@ -205,12 +459,29 @@ impl<T: Config> SubstrateWeight<T> {
     ) -> Weight {
         let bytes_len = add_params.params.bytes().len() as u32;
         let label_len = add_params.params.label().map_or(0, |v| v.len()) as u32;
+        let validation = if T::ValidateKeysAndParams::get() {
+            Self::validate_params(bytes_len)
+        } else {
+            Weight::from_ref_time(0)
+        };
 
         sig.weight_for_sig_type::<T>(
             || Self::add_params_sr25519(bytes_len, label_len),
             || Self::add_params_ed25519(bytes_len, label_len),
             || Self::add_params_secp256k1(bytes_len, label_len),
         )
+        .saturating_add(validation)
+    }
+
+    fn share_params(
+        _: &ShareOffchainSignatureParams<T>,
+        sig: &DidOrDidMethodKeySignature<SignatureParamsOwner>,
+    ) -> Weight {
+        sig.weight_for_sig_type::<T>(
+            Self::share_params_sr25519,
+            Self::share_params_ed25519,
+            Self::share_params_secp256k1,
+        )
     }
 
     fn add_public(
@@ -218,12 +489,34 @@ impl<T: Config> SubstrateWeight<T> {
         sig: &DidOrDidMethodKeySignature<Controller>,
     ) -> Weight {
         let len = public_key.key.bytes().len() as u32;
+        let validation = if T::ValidateKeysAndParams::get() {
+            // Referenced params are validated as well, so assume they have the maximum size.
+            let params_validation = public_key
+                .key
+                .params_ref()
+                .map_or(Weight::from_ref_time(0), |_| {
+                    Self::validate_params(T::MaxOffchainParamsBytesSize::get())
+                });
+
+            Self::validate_public_key(len).saturating_add(params_validation)
+        } else {
+            Weight::from_ref_time(0)
+        };
+        // Increasing the amount of the references to the params.
+        let params_reference = public_key
+            .key
+            .params_ref()
+            .map_or(Weight::from_ref_time(0), |_| {
+                T::DbWeight::get().reads_writes(1, 1)
+            });
 
         sig.weight_for_sig_type::<T>(
             || Self::add_public_sr25519(len),
             || Self::add_public_ed25519(len),
             || Self::add_public_secp256k1(len),
         )
+        .saturating_add(validation)
+        .saturating_add(params_reference)
     }
 
     fn remove_params(
@@ -235,6 +528,21 @@ impl<T: Config> SubstrateWeight<T> {
             Self::remove_params_ed25519,
             Self::remove_params_secp256k1,
         )
+        // Checking whether params are referenced and shared, and removing the latter.
+        .saturating_add(T::DbWeight::get().reads_writes(2, 1))
+    }
+
+    fn force_remove_params(
+        _: &ForceRemoveOffchainSignatureParams<T>,
+        sig: &DidOrDidMethodKeySignature<SignatureParamsOwner>,
+    ) -> Weight {
+        sig.weight_for_sig_type::<T>(
+            Self::remove_params_sr25519,
+            Self::remove_params_ed25519,
+            Self::remove_params_secp256k1,
+        )
+        // Checking whether params are referenced and shared, and removing the latter.
+        .saturating_add(T::DbWeight::get().reads_writes(2, 1))
     }
 
     fn remove_public(
@@ -246,5 +554,68 @@ impl<T: Config> SubstrateWeight<T> {
             Self::remove_public_ed25519,
             Self::remove_public_secp256k1,
         )
+        // Decreasing the amount of the references to the params of the removed key
+        // and checking whether the key is referenced by threshold issuer groups.
+        .saturating_add(T::DbWeight::get().reads_writes(2, 1))
+    }
+
+    fn revoke_public(
+        _: &RevokeOffchainSignaturePublicKey<T>,
+        sig: &DidOrDidMethodKeySignature<Controller>,
+    ) -> Weight {
+        sig.weight_for_sig_type::<T>(
+            Self::revoke_public_sr25519,
+            Self::revoke_public_ed25519,
+            Self::revoke_public_secp256k1,
+        )
+    }
+
+    fn add_threshold_issuer_group(
+        add_group: &AddThresholdIssuerGroup<T>,
+        sig: &DidOrDidMethodKeySignature<Controller>,
+    ) -> Weight {
+        let participants = add_group.group.participants.len() as u32;
+
+        sig.weight_for_sig_type::<T>(
+            || Self::add_threshold_issuer_group_sr25519(participants),
+            || Self::add_threshold_issuer_group_ed25519(participants),
+            || Self::add_threshold_issuer_group_secp256k1(participants),
+        )
+    }
+
+    fn update_threshold_issuer_group(
+        update_group: &UpdateThresholdIssuerGroup<T>,
+        sig: &DidOrDidMethodKeySignature<Controller>,
+    ) -> Weight {
+        let participants = update_group.group.participants.len() as u32;
+
+        sig.weight_for_sig_type::<T>(
+            || Self::update_threshold_issuer_group_sr25519(participants),
+            || Self::update_threshold_issuer_group_ed25519(participants),
+            || Self::update_threshold_issuer_group_secp256k1(participants),
+        )
+        // The replaced group may have more participants than the new one.
+        .saturating_add(Self::group_key_references(
+            T::MaxThresholdIssuerParticipants::get().saturating_sub(participants),
+        ))
+    }
+
+    fn remove_threshold_issuer_group(
+        _: &RemoveThresholdIssuerGroup<T>,
+        sig: &DidOrDidMethodKeySignature<Controller>,
+    ) -> Weight {
+        // Participants of the removed group aren't known in advance.
+        let participants = T::MaxThresholdIssuerParticipants::get();
+
+        sig.weight_for_sig_type::<T>(
+            || Self::remove_threshold_issuer_group_sr25519(participants),
+            || Self::remove_threshold_issuer_group_ed25519(participants),
+            || Self::remove_threshold_issuer_group_secp256k1(participants),
+        )
+    }
+
+    /// Weight of releasing the references to the supplied amount of the participants' keys.
+    fn group_key_references(participants: u32) -> Weight {
+        T::DbWeight::get().reads_writes(participants as u64, participants as u64)
     }
 }
@@ -1,17 +1,18 @@
 use crate::{
-    common::{AuthorizeTarget, Limits, TypesAndLimits},
+    common::{AuthorizeTarget, CurveType, Limits, TypesAndLimits},
     did::{DidKey, DidMethodKey, DidOrDidMethodKey},
     offchain_signatures::schemes::*,
     util::{Associated, IncId, OptionExt, StorageRef},
 };
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{ensure, DebugNoBound};
+use frame_support::{ensure, traits::Get, DebugNoBound};
 use sp_runtime::DispatchResult;
-use sp_std::fmt::Debug;
+use sp_std::{fmt::Debug, prelude::*};
 
 use super::{
-    AddOffchainSignatureParams, BBSPlusPublicKey, Config, Error, Event, PSPublicKey, Pallet,
-    ParamsCounter, RemoveOffchainSignatureParams, SignatureParams,
+    AddOffchainSignatureParams, BBSPlusPublicKey, Config, Error, Event,
+    ForceRemoveOffchainSignatureParams, PSPublicKey, Pallet, ParamsCounter, ParamsReferences,
+    RemoveOffchainSignatureParams, ShareOffchainSignatureParams, SharedParams, SignatureParams,
 };
 
 /// DID owner of the signature parameters.
@@ -98,6 +99,26 @@ impl<T: Limits> OffchainSignatureParams<T> {
         self.try_into().ok()
     }
 
+    /// Returns signature scheme of the parameters.
+    pub fn scheme(&self) -> OffchainSignatureScheme {
+        match self {
+            Self::BBS(_) => OffchainSignatureScheme::BBS,
+            Self::BBSPlus(_) => OffchainSignatureScheme::BBSPlus,
+            Self::PS(_) => OffchainSignatureScheme::PS,
+            Self::BBDT16(_) => OffchainSignatureScheme::BBDT16,
+        }
+    }
+
+    /// Returns `true` if the parameters have supplied signature scheme and curve type, `None` matches any.
+    pub fn matches(
+        &self,
+        scheme: Option<OffchainSignatureScheme>,
+        curve_type: Option<CurveType>,
+    ) -> bool {
+        scheme.map_or(true, |scheme| self.scheme() == scheme)
+            && curve_type.map_or(true, |curve_type| self.curve_type() == curve_type)
+    }
+
     /// Returns underlying **unchecked** bytes representation for parameters corresponding to either signature scheme.
     pub fn bytes(&self) -> &[u8] {
         match self {
@@ -108,6 +129,21 @@ impl<T: Limits> OffchainSignatureParams<T> {
         }
     }
 
+    /// Returns curve type of the parameters corresponding to either signature scheme.
+    pub fn curve_type(&self) -> CurveType {
+        match self {
+            Self::BBS(params) => params.curve_type,
+            Self::BBSPlus(params) => params.curve_type,
+            Self::PS(params) => params.curve_type,
+            Self::BBDT16(params) => params.curve_type,
+        }
+    }
+
+    /// Returns `true` if the parameters' curve type is supported by their signature scheme.
+    pub fn is_curve_supported(&self) -> bool {
+        self.curve_type() == CurveType::Bls12381
+    }
+
     /// Returns underlying label for a key corresponding to either signature scheme.
     pub fn label(&self) -> Option<&[u8]> {
         match self {
@@ -125,19 +161,106 @@ impl<T: Config> Pallet<T> {
         params_counter: &mut IncId,
         signer: SignatureParamsOwner,
     ) -> DispatchResult {
-        SignatureParams::<T>::insert(signer, params_counter.inc(), params);
+        Self::insert_params(params, params_counter, signer)
+    }
+
+    /// Shares existing params on behalf of the root origin, the params remain owned by their DID.
+    pub(super) fn add_shared_params_((did, counter): SignatureParamsStorageKey) -> DispatchResult {
+        ensure!(
+            SignatureParams::<T>::contains_key(did, counter),
+            Error::<T>::ParamsDontExist
+        );
+        ensure!(
+            !SharedParams::<T>::contains_key(did, counter),
+            Error::<T>::ParamsAlreadyShared
+        );
+
+        SharedParams::<T>::insert(did, counter, ());
+
+        Self::deposit_event(Event::ParamsShared(did, counter));
+        Ok(())
+    }
+
+    /// Unshares params on behalf of the root origin. Public keys already referencing the params keep referencing them.
+    pub(super) fn remove_shared_params_(
+        (did, counter): SignatureParamsStorageKey,
+    ) -> DispatchResult {
+        ensure!(
+            SharedParams::<T>::take(did, counter).is_some(),
+            Error::<T>::ParamsNotShared
+        );
 
-        Self::deposit_event(Event::ParamsAdded(signer, *params_counter));
+        Self::deposit_event(Event::ParamsUnshared(did, counter));
         Ok(())
     }
 
-    pub(super) fn remove_params_(
-        RemoveOffchainSignatureParams {
+    pub(super) fn share_params_(
+        ShareOffchainSignatureParams {
             params_ref: (did, counter),
             ..
-        }: RemoveOffchainSignatureParams<T>,
+        }: ShareOffchainSignatureParams<T>,
+        (): (),
+        owner: SignatureParamsOwner,
+    ) -> DispatchResult {
+        ensure!(did == owner, Error::<T>::NotOwner);
+        ensure!(
+            SignatureParams::<T>::contains_key(did, counter),
+            Error::<T>::ParamsDontExist
+        );
+        ensure!(
+            !SharedParams::<T>::contains_key(did, counter),
+            Error::<T>::ParamsAlreadyShared
+        );
+
+        SharedParams::<T>::insert(did, counter, ());
+
+        Self::deposit_event(Event::ParamsShared(did, counter));
+        Ok(())
+    }
+
+    fn insert_params(
+        params: OffchainSignatureParams<T>,
+        params_counter: &mut IncId,
+        owner: SignatureParamsOwner,
+    ) -> DispatchResult {
+        ensure!(
+            params.is_curve_supported(),
+            Error::<T>::UnsupportedCurveType
+        );
+        if T::ValidateKeysAndParams::get() {
+            params.ensure_valid_points()?;
+        }
+
+        let params_id = *params_counter.inc();
+        SignatureParams::<T>::insert(owner, params_id, params);
+
+        Self::deposit_event(Event::ParamsAdded(owner, params_id));
+        Ok(())
+    }
+
+    pub(super) fn remove_params_(
+        RemoveOffchainSignatureParams { params_ref, .. }: RemoveOffchainSignatureParams<T>,
+        (): (),
+        owner: SignatureParamsOwner,
+    ) -> DispatchResult {
+        Self::remove_existing_params(params_ref, owner, false)
+    }
+
+    pub(super) fn force_remove_params_(
+        ForceRemoveOffchainSignatureParams { params_ref, .. }: ForceRemoveOffchainSignatureParams<
+            T,
+        >,
         (): (),
         owner: SignatureParamsOwner,
+    ) -> DispatchResult {
+        Self::remove_existing_params(params_ref, owner, true)
+    }
+
+    /// Removes the params ensuring they aren't referenced by public keys unless `force` is set.
+    fn remove_existing_params(
+        (did, counter): SignatureParamsStorageKey,
+        owner: SignatureParamsOwner,
+        force: bool,
     ) -> DispatchResult {
         // Only the DID that added the param can it
         ensure!(did == owner, Error::<T>::NotOwner);
@@ -146,8 +269,15 @@ impl<T: Config> Pallet<T> {
             SignatureParams::<T>::contains_key(did, counter),
             Error::<T>::ParamsDontExist
         );
+        // Referenced params (shared or not) can only be removed by force.
+        // References of the force removed params are kept, so they're released along with the keys.
+        ensure!(
+            force || ParamsReferences::<T>::get(did, counter) == 0,
+            Error::<T>::ParamsInUse
+        );
 
         SignatureParams::<T>::remove(did, counter);
+        SharedParams::<T>::remove(did, counter);
 
         Self::deposit_event(Event::ParamsRemoved(did, counter));
         Ok(())
@@ -158,4 +288,42 @@ impl<T: Config> Pallet<T> {
     ) -> impl Iterator<Item = (IncId, OffchainSignatureParams<T>)> {
         SignatureParams::<T>::iter_prefix(did)
     }
+
+    /// Returns up to `limit` (capped by `Config::MaxQueryPageSize`) shared params having supplied signature scheme
+    /// and curve type. Params are returned in the storage order starting after the ones referenced by `after`,
+    /// so the reference of the last returned params can be used to request the next page.
+    pub fn shared_params(
+        scheme: Option<OffchainSignatureScheme>,
+        curve_type: Option<CurveType>,
+        after: Option<SignatureParamsStorageKey>,
+        limit: u32,
+    ) -> Vec<(SignatureParamsStorageKey, OffchainSignatureParams<T>)> {
+        let keys = match after {
+            Some((owner, params_id)) => SharedParams::<T>::iter_keys_from(
+                SharedParams::<T>::hashed_key_for(owner, params_id),
+            ),
+            None => SharedParams::<T>::iter_keys(),
+        };
+
+        keys.filter_map(|(owner, params_id)| {
+            SignatureParams::<T>::get(owner, params_id).map(|params| ((owner, params_id), params))
+        })
+        .filter(|(_, params)| params.matches(scheme, curve_type))
+        .take(limit.min(T::MaxQueryPageSize::get()) as usize)
+        .collect()
+    }
+
+    /// Returns amount of the public keys referencing supplied params.
+    pub fn params_references((owner, params_id): SignatureParamsStorageKey) -> u32 {
+        ParamsReferences::<T>::get(owner, params_id)
+    }
+
+    /// Returns params of the supplied owner having supplied signature scheme and curve type.
+    pub fn did_params_filtered(
+        did: &SignatureParamsOwner,
+        scheme: Option<OffchainSignatureScheme>,
+        curve_type: Option<CurveType>,
+    ) -> impl Iterator<Item = (IncId, OffchainSignatureParams<T>)> {
+        Self::did_params(did).filter(move |(_, params)| params.matches(scheme, curve_type))
+    }
 }
@@ -1,21 +1,26 @@
 use crate::{
-    common::Limits,
+    common::{CurveType, Limits},
     did::{Did, OnChainDidDetails},
     offchain_signatures::{schemes::*, SignatureParams},
     util::IncId,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{ensure, DebugNoBound};
+use frame_support::{ensure, traits::Get, DebugNoBound};
 use sp_runtime::DispatchResult;
 
 use super::{
     AddOffchainSignaturePublicKey, Config, Error, Event, OffchainSignatureParams, Pallet,
-    PublicKeys, RemoveOffchainSignaturePublicKey, SignatureParamsStorageKey,
+    ParamsReferences, PublicKeys, RemoveOffchainSignaturePublicKey,
+    RevokeOffchainSignaturePublicKey, RevokedPublicKeys, SharedParams, SignatureParamsOwner,
+    SignatureParamsStorageKey,
 };
 
 pub type SignaturePublicKeyStorageKey = (Did, IncId);
+pub type OffchainPublicKeyWithParams<T> =
+    (OffchainPublicKey<T>, Option<OffchainSignatureParams<T>>);
 
-/// Public key for different signature schemes. Currently, can be either `BBS`, `BBS+`, `Pointcheval-Sanders` or `BBDT16`.
+/// Public key for different signature schemes. Currently, can be either `BBS`, `BBS+`, `Pointcheval-Sanders`, `BBDT16`,
+/// IETF draft `BBS`, `ECDSA-SD` or selective disclosure `Ed25519`.
 #[derive(
     scale_info_derive::TypeInfo, Encode, Decode, Clone, PartialEq, Eq, DebugNoBound, MaxEncodedLen,
 )]
@@ -36,6 +41,12 @@ pub enum OffchainPublicKey<T: Limits> {
     /// Public key for the BBDT16 signature scheme. This will be in group G1 and will be used to verify
     /// the validity proof of the MAC and not the MAC itself.
     BBDT16(BBDT16PublicKey<T>),
+    /// Public key for the IETF draft BBS signature scheme. Doesn't have parameters on chain.
+    BBSIetf(BBSIetfPublicKey<T>),
+    /// Public key for the `ecdsa-sd` Data Integrity cryptosuite. Doesn't have parameters on chain.
+    EcdsaSd(EcdsaSdPublicKey<T>),
+    /// Public key for the selective disclosure `Ed25519` Data Integrity cryptosuite. Doesn't have parameters on chain.
+    Ed25519Sd(Ed25519SdPublicKey<T>),
 }
 
 impl<T: Limits> OffchainPublicKey<T> {
@@ -59,6 +70,44 @@ impl<T: Limits> OffchainPublicKey<T> {
         self.try_into().ok()
     }
 
+    /// Returns underlying public key if it corresponds to the IETF draft BBS scheme.
+    pub fn into_bbs_ietf(self) -> Option<BBSIetfPublicKey<T>> {
+        self.try_into().ok()
+    }
+
+    /// Returns underlying public key if it corresponds to the `ecdsa-sd` cryptosuite.
+    pub fn into_ecdsa_sd(self) -> Option<EcdsaSdPublicKey<T>> {
+        self.try_into().ok()
+    }
+
+    /// Returns underlying public key if it corresponds to the selective disclosure `Ed25519` cryptosuite.
+    pub fn into_ed25519_sd(self) -> Option<Ed25519SdPublicKey<T>> {
+        self.try_into().ok()
+    }
+
+    /// Returns signature scheme of the key.
+    pub fn scheme(&self) -> OffchainSignatureScheme {
+        match self {
+            Self::BBS(_) => OffchainSignatureScheme::BBS,
+            Self::BBSPlus(_) => OffchainSignatureScheme::BBSPlus,
+            Self::PS(_) => OffchainSignatureScheme::PS,
+            Self::BBDT16(_) => OffchainSignatureScheme::BBDT16,
+            Self::BBSIetf(_) => OffchainSignatureScheme::BBSIetf,
+            Self::EcdsaSd(_) => OffchainSignatureScheme::EcdsaSd,
+            Self::Ed25519Sd(_) => OffchainSignatureScheme::Ed25519Sd,
+        }
+    }
+
+    /// Returns `true` if the key has supplied signature scheme and curve type, `None` matches any.
+    pub fn matches(
+        &self,
+        scheme: Option<OffchainSignatureScheme>,
+        curve_type: Option<CurveType>,
+    ) -> bool {
+        scheme.map_or(true, |scheme| self.scheme() == scheme)
+            && curve_type.map_or(true, |curve_type| self.curve_type() == curve_type)
+    }
+
     /// Returns underlying **unchecked** bytes representation for a key corresponding to either signature scheme.
     pub fn bytes(&self) -> &[u8] {
         match self {
@@ -66,6 +115,33 @@ impl<T: Limits> OffchainPublicKey<T> {
             Self::BBSPlus(key) => &key.bytes[..],
             Self::PS(key) => &key.bytes[..],
             Self::BBDT16(key) => &key.bytes[..],
+            Self::BBSIetf(key) => &key.bytes[..],
+            Self::EcdsaSd(key) => &key.bytes[..],
+            Self::Ed25519Sd(key) => &key.bytes[..],
+        }
+    }
+
+    /// Returns curve type of the key corresponding to either signature scheme.
+    pub fn curve_type(&self) -> CurveType {
+        match self {
+            Self::BBS(key) => key.curve_type,
+            Self::BBSPlus(key) => key.curve_type,
+            Self::PS(key) => key.curve_type,
+            Self::BBDT16(key) => key.curve_type,
+            Self::BBSIetf(_) => CurveType::Bls12381,
+            Self::EcdsaSd(key) => key.curve_type,
+            Self::Ed25519Sd(_) => CurveType::Ed25519,
+        }
+    }
+
+    /// Returns `true` if the key's curve type is supported by its signature scheme.
+    pub fn is_curve_supported(&self) -> bool {
+        match self {
+            Self::BBS(_) | Self::BBSPlus(_) | Self::PS(_) | Self::BBDT16(_) | Self::BBSIetf(_) => {
+                self.curve_type() == CurveType::Bls12381
+            }
+            Self::EcdsaSd(_) => matches!(self.curve_type(), CurveType::P256 | CurveType::P384),
+            Self::Ed25519Sd(_) => self.curve_type() == CurveType::Ed25519,
         }
     }
 
@@ -76,11 +152,31 @@ impl<T: Limits> OffchainPublicKey<T> {
             Self::BBSPlus(bbs_plus_key) => &bbs_plus_key.params_ref,
             Self::PS(ps_key) => &ps_key.params_ref,
             Self::BBDT16(key) => &key.params_ref,
+            Self::BBSIetf(_) | Self::EcdsaSd(_) | Self::Ed25519Sd(_) => return None,
         };
 
         opt.as_ref()
     }
 
+    /// Returns participant id of the key used in threshold issuance if it's set.
+    pub fn participant_id(&self) -> Option<ParticipantId> {
+        match self {
+            Self::BBS(key) => key.participant_id,
+            Self::BBSPlus(key) => key.participant_id,
+            Self::PS(key) => key.participant_id,
+            Self::BBDT16(key) => key.participant_id,
+            Self::BBSIetf(_) | Self::EcdsaSd(_) | Self::Ed25519Sd(_) => None,
+        }
+    }
+
+    /// Returns `true` if the key's signature scheme can be used in threshold issuance.
+    pub fn supports_threshold_issuance(&self) -> bool {
+        matches!(
+            self,
+            Self::BBS(_) | Self::BBSPlus(_) | Self::PS(_) | Self::BBDT16(_)
+        )
+    }
+
     /// Returns `true` if supplied params have same scheme as the given key.
     pub fn params_match_scheme(&self, params: &OffchainSignatureParams<T>) -> bool {
         match self {
@@ -88,25 +184,65 @@ impl<T: Limits> OffchainPublicKey<T> {
             Self::BBSPlus(_) => matches!(params, OffchainSignatureParams::BBSPlus(_)),
             Self::PS(_) => matches!(params, OffchainSignatureParams::PS(_)),
             Self::BBDT16(_) => matches!(params, OffchainSignatureParams::BBDT16(_)),
+            Self::BBSIetf(_) | Self::EcdsaSd(_) | Self::Ed25519Sd(_) => false,
         }
     }
 
-    /// Ensures that supplied key has a valid size and has constrained parameters.
-    pub fn ensure_valid(&self) -> Result<(), Error<T>>
+    /// Combines key with signature params (if exist and have same scheme).
+    pub fn with_params(self) -> OffchainPublicKeyWithParams<T>
     where
         T: Config,
     {
-        if let Some((did, params_id)) = self.params_ref() {
-            let params =
-                SignatureParams::<T>::get(did, params_id).ok_or(Error::<T>::ParamsDontExist)?;
+        let params = self
+            .params_ref()
+            .and_then(|(did, params_id)| SignatureParams::<T>::get(did, params_id))
+            .filter(|params| self.params_match_scheme(params));
 
+        (self, params)
+    }
+
+    /// Ensures that supplied key has a valid size and has constrained parameters which are either owned by
+    /// the key owner or shared.
+    /// If `Config::ValidateKeysAndParams` is enabled, also ensures that the key bytes represent valid points
+    /// supporting the same amount of the messages as the referenced parameters.
+    pub fn ensure_valid(&self, owner: Did) -> Result<(), Error<T>>
+    where
+        T: Config,
+    {
+        ensure!(self.is_curve_supported(), Error::<T>::UnsupportedCurveType);
+
+        let params = self
+            .params_ref()
+            .map(|(did, params_id)| {
+                let params =
+                    SignatureParams::<T>::get(did, params_id).ok_or(Error::<T>::ParamsDontExist)?;
+                ensure!(
+                    did == SignatureParamsOwner(owner.into())
+                        || SharedParams::<T>::contains_key(did, params_id),
+                    Error::<T>::ParamsNotShared
+                );
+
+                Ok(params)
+            })
+            .transpose()?;
+
+        if let Some(params) = &params {
             ensure!(
-                self.params_match_scheme(&params),
+                self.params_match_scheme(params),
                 Error::<T>::IncorrectParamsScheme
             );
-            // Note: Once we have more than 1 curve type, it should check that params and key
-            // both have same curve type
-        };
+            ensure!(
+                self.curve_type() == params.curve_type(),
+                Error::<T>::IncorrectParamsCurve
+            );
+        }
+
+        if T::ValidateKeysAndParams::get() {
+            match &params {
+                Some(params) => self.ensure_valid_points_with_params(params)?,
+                None => self.ensure_valid_points().map(drop)?,
+            }
+        }
 
         Ok(())
     }
@@ -119,8 +255,13 @@ impl<T: Config> Pallet<T> {
         }: AddOffchainSignaturePublicKey<T>,
         OnChainDidDetails { last_key_id, .. }: &mut OnChainDidDetails,
     ) -> DispatchResult {
-        key.ensure_valid()?;
+        key.ensure_valid(owner)?;
 
+        if let Some((params_owner, params_id)) = key.params_ref() {
+            ParamsReferences::<T>::mutate(params_owner, params_id, |refs| {
+                *refs = refs.saturating_add(1)
+            });
+        }
         PublicKeys::<T>::insert(owner, last_key_id.inc(), key);
 
         Self::deposit_event(Event::KeyAdded(owner, *last_key_id));
@@ -141,14 +282,96 @@ impl<T: Config> Pallet<T> {
         );
 
         ensure!(did == owner, Error::<T>::NotOwner);
+        ensure!(
+            ThresholdIssuerGroupKeyReferences::<T>::get(did, counter) == 0,
+            Error::<T>::PublicKeyInUse
+        );
 
-        PublicKeys::<T>::remove(did, counter);
+        if let Some(key) = PublicKeys::<T>::take(did, counter) {
+            Self::release_params_reference(&key);
+        }
+        RevokedPublicKeys::<T>::remove(did, counter);
 
         Self::deposit_event(Event::KeyRemoved(owner, counter));
         Ok(())
     }
 
+    pub(super) fn revoke_public_key_(
+        RevokeOffchainSignaturePublicKey {
+            key_ref: (did, counter),
+            did: owner,
+            ..
+        }: RevokeOffchainSignaturePublicKey<T>,
+        _: &mut OnChainDidDetails,
+    ) -> DispatchResult {
+        ensure!(
+            PublicKeys::<T>::contains_key(did, counter),
+            Error::<T>::PublicKeyDoesntExist
+        );
+
+        ensure!(did == owner, Error::<T>::NotOwner);
+        ensure!(
+            !RevokedPublicKeys::<T>::contains_key(did, counter),
+            Error::<T>::PublicKeyRevoked
+        );
+
+        RevokedPublicKeys::<T>::insert(did, counter, <frame_system::Pallet<T>>::block_number());
+
+        Self::deposit_event(Event::KeyRevoked(owner, counter));
+        Ok(())
+    }
+
+    /// Decreases the amount of the references to the params used by the supplied key.
+    pub(super) fn release_params_reference(key: &OffchainPublicKey<T>) {
+        if let Some((params_owner, params_id)) = key.params_ref() {
+            ParamsReferences::<T>::mutate_exists(params_owner, params_id, |refs| {
+                *refs = refs
+                    .map(|refs| refs.saturating_sub(1))
+                    .filter(|&refs| refs > 0)
+            });
+        }
+    }
+
+    /// Returns the public keys of the supplied DID referencing params that don't exist anymore
+    /// along with these references.
+    pub fn dangling_params_references(
+        did: &Did,
+    ) -> impl Iterator<Item = (IncId, SignatureParamsStorageKey)> {
+        PublicKeys::<T>::iter_prefix(did).filter_map(|(key_id, key)| {
+            key.params_ref()
+                .copied()
+                .filter(|(owner, params_id)| !SignatureParams::<T>::contains_key(owner, params_id))
+                .map(|params_ref| (key_id, params_ref))
+        })
+    }
+
+    /// Returns number of the block in which the public key was revoked if it was revoked.
+    /// Signatures made before this block can still be considered valid by the verifiers.
+    pub fn public_key_revoked_at(
+        (did, key_id): SignaturePublicKeyStorageKey,
+    ) -> Option<T::BlockNumber> {
+        RevokedPublicKeys::<T>::get(did, key_id)
+    }
+
     pub fn did_public_keys(did: &Did) -> impl Iterator<Item = (IncId, OffchainPublicKey<T>)> {
         PublicKeys::<T>::iter_prefix(did)
     }
+
+    /// Returns public key along with its params (if exist and have same scheme).
+    pub fn public_key_with_params(
+        (did, key_id): SignaturePublicKeyStorageKey,
+    ) -> Option<OffchainPublicKeyWithParams<T>> {
+        PublicKeys::<T>::get(did, key_id).map(OffchainPublicKey::with_params)
+    }
+
+    /// Returns public keys of the supplied DID along with their params having supplied signature scheme and curve type.
+    pub fn did_public_keys_with_params(
+        did: &Did,
+        scheme: Option<OffchainSignatureScheme>,
+        curve_type: Option<CurveType>,
+    ) -> impl Iterator<Item = (IncId, OffchainPublicKeyWithParams<T>)> {
+        Self::did_public_keys(did)
+            .filter(move |(_, key)| key.matches(scheme, curve_type))
+            .map(|(key_id, key)| (key_id, key.with_params()))
+    }
 }
@@ -13,6 +13,22 @@ use crate::offchain_signatures::OffchainPublicKey;
 /// Identifier of the participant used in the threshold issuance.
 pub type ParticipantId = u16;
 
+/// Tag of the signature scheme of the offchain public key or parameters.
+#[derive(
+    scale_info_derive::TypeInfo, Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[scale_info(omit_prefix)]
+pub enum OffchainSignatureScheme {
+    BBS,
+    BBSPlus,
+    PS,
+    BBDT16,
+    BBSIetf,
+    EcdsaSd,
+    Ed25519Sd,
+}
+
 /// Defines public key and signature params for the given signature scheme.
 macro_rules! def_signature_scheme_key_and_params {
     (for $scheme: ident: $(#[$key_meta:meta])* $key: ident<$key_byte_size: ident>, $(#[$params_meta:meta])* $params: ident<$params_byte_size: ident>) => {
@@ -39,7 +55,7 @@ macro_rules! def_signature_scheme_key_and_params {
 
         impl<T: Limits> $key<T> {
             /// Instantiates new public key for the signature scheme.
-            /// This function doesn't validate supplied bytes.
+            /// This function doesn't validate supplied bytes, they're validated on addition if `Config::ValidateKeysAndParams` is enabled.
             pub fn new(
                 bytes: BoundedBytes<T::$key_byte_size>,
                 params_ref: impl Into<Option<SignatureParamsStorageKey>>,
@@ -133,7 +149,7 @@ macro_rules! def_signature_scheme_key_and_params {
 
         impl<T: Limits> $params<T> {
             /// Instantiates new parameters for the signature scheme.
-            /// This function doesn't validate supplied bytes.
+            /// This function doesn't validate supplied bytes, they're validated on addition if `Config::ValidateKeysAndParams` is enabled.
             pub fn new(
                 label: impl Into<Option<BoundedBytes<T::MaxOffchainParamsLabelSize>>>,
                 bytes: BoundedBytes<T::$params_byte_size>,
@@ -197,3 +213,148 @@ def_signature_scheme_key_and_params! {
         /// Signature parameters for the BBDT16 signature scheme.
         BBDT16Parameters<MaxOffchainParamsBytesSize>
 }
+
+/// Implements conversions between the public key of the signature scheme not having parameters and `OffchainPublicKey`.
+macro_rules! impl_offchain_public_key_conversions {
+    ($scheme: ident: $key: ident) => {
+        impl<T: Limits> From<$key<T>> for OffchainPublicKey<T> {
+            fn from(key: $key<T>) -> Self {
+                Self::$scheme(key)
+            }
+        }
+
+        impl<T: Limits> TryFrom<OffchainPublicKey<T>> for $key<T> {
+            type Error = OffchainPublicKey<T>;
+
+            fn try_from(key: OffchainPublicKey<T>) -> Result<$key<T>, OffchainPublicKey<T>> {
+                match key {
+                    OffchainPublicKey::$scheme(key) => Ok(key),
+                    other => Err(other),
+                }
+            }
+        }
+    };
+}
+
+/// Ciphersuite of the IETF draft BBS signature scheme.
+#[derive(
+    scale_info_derive::TypeInfo, Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[scale_info(omit_prefix)]
+pub enum BBSIetfCiphersuite {
+    /// `BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_`
+    Bls12381Sha256,
+    /// `BBS_BLS12381G1_XOF:SHAKE-256_SSWU_RO_`
+    Bls12381Shake256,
+}
+
+/// Public key for the IETF draft BBS signature scheme. Generators are derived from the ciphersuite,
+/// so the key doesn't reference any parameters.
+#[derive(
+    scale_info_derive::TypeInfo,
+    Encode,
+    Decode,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    DebugNoBound,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct BBSIetfPublicKey<T: Limits> {
+    pub(crate) ciphersuite: BBSIetfCiphersuite,
+    pub(crate) bytes: BoundedBytes<T::MaxBBSIetfPublicKeySize>,
+}
+
+impl<T: Limits> BBSIetfPublicKey<T> {
+    /// Instantiates new public key for the IETF draft BBS signature scheme.
+    /// This function doesn't validate supplied bytes, they're validated on addition if `Config::ValidateKeysAndParams` is enabled.
+    pub fn new(
+        bytes: BoundedBytes<T::MaxBBSIetfPublicKeySize>,
+        ciphersuite: BBSIetfCiphersuite,
+    ) -> Self {
+        Self { ciphersuite, bytes }
+    }
+
+    /// Returns ciphersuite of the key.
+    pub fn ciphersuite(&self) -> BBSIetfCiphersuite {
+        self.ciphersuite
+    }
+}
+
+impl_offchain_public_key_conversions!(BBSIetf: BBSIetfPublicKey);
+
+/// Public key for the `ecdsa-sd` Data Integrity cryptosuite. Must be either for `P-256` or `P-384` curve.
+#[derive(
+    scale_info_derive::TypeInfo,
+    Encode,
+    Decode,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    DebugNoBound,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct EcdsaSdPublicKey<T: Limits> {
+    pub(crate) curve_type: CurveType,
+    pub(crate) bytes: BoundedBytes<T::MaxEcdsaSdPublicKeySize>,
+}
+
+impl<T: Limits> EcdsaSdPublicKey<T> {
+    /// Instantiates new public key for the `ecdsa-sd` cryptosuite.
+    /// This function doesn't validate supplied bytes, they're validated on addition if `Config::ValidateKeysAndParams` is enabled.
+    pub fn new(bytes: BoundedBytes<T::MaxEcdsaSdPublicKeySize>, curve_type: CurveType) -> Self {
+        Self { curve_type, bytes }
+    }
+}
+
+impl_offchain_public_key_conversions!(EcdsaSd: EcdsaSdPublicKey);
+
+/// Public key for the selective disclosure `Ed25519` Data Integrity cryptosuite.
+#[derive(
+    scale_info_derive::TypeInfo,
+    Encode,
+    Decode,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    DebugNoBound,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct Ed25519SdPublicKey<T: Limits> {
+    pub(crate) bytes: BoundedBytes<T::MaxEd25519SdPublicKeySize>,
+}
+
+impl<T: Limits> Ed25519SdPublicKey<T> {
+    /// Instantiates new public key for the selective disclosure `Ed25519` cryptosuite.
+    /// This function doesn't validate supplied bytes, they're validated on addition if `Config::ValidateKeysAndParams` is enabled.
+    pub fn new(bytes: BoundedBytes<T::MaxEd25519SdPublicKeySize>) -> Self {
+        Self { bytes }
+    }
+}
+
+impl_offchain_public_key_conversions!(Ed25519Sd: Ed25519SdPublicKey);
@@ -4,9 +4,9 @@ use crate::{
     did::{base::*, tests::check_did_detail, AddControllers},
     offchain_signatures,
     tests::common::*,
-    util::{Action, ActionWithNonce, BoundedBytes},
+    util::{compressed, Action, ActionWithNonce, BoundedBytes},
 };
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
 use frame_support::assert_err;
 use sp_core::H256;
 use sp_runtime::traits::CheckedConversion;
@@ -905,7 +905,7 @@ with_each_scheme! {
                 IncId::from(0u8)
             );
 
-            // Add key with reference to existent params
+            // Add key with reference to existent params of another DID which aren't shared
             let key_4 = SchemeKey::new(
                 vec![92u8; 100].try_into().unwrap(),
                 Some((SignatureParamsOwner(author.into()), 1u8.into())),
@@ -917,6 +917,14 @@ with_each_scheme! {
                 nonce: next_nonce_1,
             };
             let sig = did_sig(&ak, &author_kp_1, Controller(author_1.into()), 1);
+            assert_err!(
+                SignatureMod::add_public_key(Origin::signed(1), ak.clone(), sig.clone()),
+                Error::<Test>::ParamsNotShared
+            );
+            check_nonce(&author_1, next_nonce_1 - 1);
+
+            // Add key with reference to existent shared params of another DID
+            SignatureMod::add_shared_params(Origin::root(), (SignatureParamsOwner(author.into()), 1u8.into())).unwrap();
             SignatureMod::add_public_key(Origin::signed(1), ak, sig).unwrap();
             check_nonce(&author_1, next_nonce_1);
             assert_eq!(
@@ -1273,6 +1281,10 @@ with_each_scheme! {
                 }
             );
 
+            // Params are referenced by the keys of another DID.
+            SignatureMod::add_shared_params(Origin::root(), (SignatureParamsOwner(author.into()), 1u8.into())).unwrap();
+            SignatureMod::add_shared_params(Origin::root(), (SignatureParamsOwner(author_1.into()), 1u8.into())).unwrap();
+
             let did_detail = DIDModule::onchain_did_details(&author).unwrap();
             let ak = AddOffchainSignaturePublicKey {
                 key: key.clone().into(),
@@ -1370,3 +1382,1149 @@ with_each_scheme! {
         });
     }
 }
+
+#[test]
+fn validate_keys_and_params() {
+    use ark_bls12_381::{G1Affine, G2Affine};
+    use ark_ec::AffineRepr;
+
+    ext().execute_with(|| {
+        run_to_block(10);
+
+        let (author, author_kp) = newdid();
+        let owner = SignatureParamsOwner(author.into());
+        let mut next_nonce = 1;
+        let (g, g_tilde) = (G1Affine::generator(), G2Affine::generator());
+
+        let mut add_params = |params: OffchainSignatureParams<Test>| {
+            let ap = AddOffchainSignatureParams {
+                params,
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&ap, &author_kp, owner, 1);
+            let res = SignatureMod::add_params(Origin::signed(1), ap, sig);
+            if res.is_ok() {
+                next_nonce += 1;
+            }
+
+            res
+        };
+
+        // Bytes aren't validated unless it's enabled.
+        add_params(
+            BBSPlusParameters::new(None, vec![1; 100].try_into().unwrap(), CurveType::Bls12381)
+                .into(),
+        )
+        .unwrap();
+        ValidateOffchainKeysAndParams::set(&true);
+
+        let bbs_plus_params: OffchainSignatureParams<Test> = BBSPlusParameters::new(
+            None,
+            compressed((g, g_tilde, g, vec![g; 2])).try_into().unwrap(),
+            CurveType::Bls12381,
+        )
+        .into();
+        for params in [
+            BBSPlusParameters::new(None, vec![1; 100].try_into().unwrap(), CurveType::Bls12381)
+                .into(),
+            // Trailing bytes
+            BBSPlusParameters::new(
+                None,
+                [compressed((g, g_tilde, g, vec![g; 2])), vec![0]]
+                    .concat()
+                    .try_into()
+                    .unwrap(),
+                CurveType::Bls12381,
+            )
+            .into(),
+            // No message generators
+            BBSPlusParameters::new(
+                None,
+                compressed((g, g_tilde, g, Vec::<G1Affine>::new()))
+                    .try_into()
+                    .unwrap(),
+                CurveType::Bls12381,
+            )
+            .into(),
+            // Params of the other scheme
+            BBSParameters::new(
+                None,
+                compressed((g, g_tilde, g, vec![g; 2])).try_into().unwrap(),
+                CurveType::Bls12381,
+            )
+            .into(),
+        ] {
+            assert_err!(add_params(params), Error::<Test>::MalformedParams);
+        }
+
+        for params in [
+            bbs_plus_params.clone(),
+            BBSParameters::new(
+                None,
+                compressed((g, g_tilde, vec![g; 3])).try_into().unwrap(),
+                CurveType::Bls12381,
+            )
+            .into(),
+            PSParameters::new(
+                None,
+                compressed((g, g_tilde, vec![g; 3])).try_into().unwrap(),
+                CurveType::Bls12381,
+            )
+            .into(),
+            BBDT16Parameters::new(
+                None,
+                compressed((g, g, g, vec![g; 3])).try_into().unwrap(),
+                CurveType::Bls12381,
+            )
+            .into(),
+        ] {
+            add_params(params).unwrap();
+        }
+        assert_eq!(
+            SignatureParams::<Test>::get(owner, IncId::from(2u8)),
+            Some(bbs_plus_params)
+        );
+
+        let mut add_key = |key: OffchainPublicKey<Test>| {
+            let ak = AddOffchainSignaturePublicKey {
+                key,
+                did: author,
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&ak, &author_kp, Controller(author.into()), 1);
+            let res = SignatureMod::add_public_key(Origin::signed(1), ak, sig);
+            if res.is_ok() {
+                next_nonce += 1;
+            }
+
+            res
+        };
+
+        for key in [
+            BBSPlusPublicKey::new(vec![1; 96].try_into().unwrap(), None, CurveType::Bls12381)
+                .into(),
+            // `G1` point instead of `G2`
+            BBSPlusPublicKey::new(
+                compressed(g).try_into().unwrap(),
+                (owner, 2u8.into()),
+                CurveType::Bls12381,
+            )
+            .into(),
+            BBDT16PublicKey::new(
+                compressed(g_tilde).try_into().unwrap(),
+                (owner, 5u8.into()),
+                CurveType::Bls12381,
+            )
+            .into(),
+        ] {
+            assert_err!(add_key(key), Error::<Test>::MalformedPublicKey);
+        }
+        // Referenced params are validated as well.
+        assert_err!(
+            add_key(
+                BBSPlusPublicKey::new(
+                    compressed(g_tilde).try_into().unwrap(),
+                    (owner, 1u8.into()),
+                    CurveType::Bls12381
+                )
+                .into()
+            ),
+            Error::<Test>::MalformedParams
+        );
+
+        for key in [
+            BBSPlusPublicKey::new(
+                compressed(g_tilde).try_into().unwrap(),
+                (owner, 2u8.into()),
+                CurveType::Bls12381,
+            )
+            .into(),
+            BBSPublicKey::new(
+                compressed(g_tilde).try_into().unwrap(),
+                (owner, 3u8.into()),
+                CurveType::Bls12381,
+            )
+            .into(),
+            BBDT16PublicKey::new(
+                compressed(g).try_into().unwrap(),
+                (owner, 5u8.into()),
+                CurveType::Bls12381,
+            )
+            .into(),
+            BBDT16PublicKey::new(compressed(g).try_into().unwrap(), None, CurveType::Bls12381)
+                .into(),
+        ] {
+            add_key(key).unwrap();
+        }
+    });
+}
+
+#[test]
+fn keys_without_params() {
+    use ark_bls12_381::{G1Affine, G2Affine};
+    use ark_ec::AffineRepr;
+
+    ext().execute_with(|| {
+        run_to_block(10);
+
+        let (author, author_kp) = newdid();
+        let owner = SignatureParamsOwner(author.into());
+        let mut next_nonce = 1;
+
+        let ap = AddOffchainSignatureParams {
+            params: BBSParameters::new(None, vec![1; 100].try_into().unwrap(), CurveType::P256)
+                .into(),
+            nonce: next_nonce,
+        };
+        let sig = did_sig(&ap, &author_kp, owner, 1);
+        assert_err!(
+            SignatureMod::add_params(Origin::signed(1), ap, sig),
+            Error::<Test>::UnsupportedCurveType
+        );
+
+        let mut add_key = |key: OffchainPublicKey<Test>| {
+            let ak = AddOffchainSignaturePublicKey {
+                key,
+                did: author,
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&ak, &author_kp, Controller(author.into()), 1);
+            let res = SignatureMod::add_public_key(Origin::signed(1), ak, sig);
+            if res.is_ok() {
+                next_nonce += 1;
+            }
+
+            res
+        };
+
+        for key in [
+            BBSPublicKey::new(vec![1; 96].try_into().unwrap(), None, CurveType::Ed25519).into(),
+            EcdsaSdPublicKey::new(vec![2; 33].try_into().unwrap(), CurveType::Bls12381).into(),
+            EcdsaSdPublicKey::new(vec![2; 32].try_into().unwrap(), CurveType::Ed25519).into(),
+        ] {
+            assert_err!(add_key(key), Error::<Test>::UnsupportedCurveType);
+        }
+
+        let bbs_ietf_key = BBSIetfPublicKey::<Test>::new(
+            compressed(G2Affine::generator()).try_into().unwrap(),
+            BBSIetfCiphersuite::Bls12381Shake256,
+        );
+        // SEC1 compressed generator of `P-384`.
+        let ecdsa_sd_key = EcdsaSdPublicKey::<Test>::new(
+            hex::decode(
+                "03aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a38\
+                 5502f25dbf55296c3a545e3872760ab7",
+            )
+            .unwrap()
+            .try_into()
+            .unwrap(),
+            CurveType::P384,
+        );
+        let ed25519_sd_key = Ed25519SdPublicKey::<Test>::new(vec![4; 32].try_into().unwrap());
+
+        // Bytes aren't validated unless it's enabled.
+        add_key(
+            BBSIetfPublicKey::new(
+                vec![1; 10].try_into().unwrap(),
+                BBSIetfCiphersuite::Bls12381Sha256,
+            )
+            .into(),
+        )
+        .unwrap();
+        ValidateOffchainKeysAndParams::set(&true);
+
+        for key in [
+            // `G1` point instead of `G2`
+            BBSIetfPublicKey::new(
+                compressed(G1Affine::generator()).try_into().unwrap(),
+                BBSIetfCiphersuite::Bls12381Sha256,
+            )
+            .into(),
+            // Uncompressed point
+            EcdsaSdPublicKey::new(vec![4; 33].try_into().unwrap(), CurveType::P256).into(),
+            // Size of the `P-256` point
+            EcdsaSdPublicKey::new(vec![2; 33].try_into().unwrap(), CurveType::P384).into(),
+            // Not on the curve
+            EcdsaSdPublicKey::new(
+                [&[2][..], &[0; 31], &[1]].concat().try_into().unwrap(),
+                CurveType::P256,
+            )
+            .into(),
+            EcdsaSdPublicKey::new(vec![3; 49].try_into().unwrap(), CurveType::P384).into(),
+            // Coordinate exceeding the field modulus
+            EcdsaSdPublicKey::new(
+                [&[2][..], &[0xff; 32]].concat().try_into().unwrap(),
+                CurveType::P256,
+            )
+            .into(),
+            Ed25519SdPublicKey::new(vec![4; 31].try_into().unwrap()).into(),
+            Ed25519SdPublicKey::new(vec![4; 33].try_into().unwrap()).into(),
+        ] {
+            assert_err!(add_key(key), Error::<Test>::MalformedPublicKey);
+        }
+
+        for key in [
+            bbs_ietf_key.clone().into(),
+            ecdsa_sd_key.clone().into(),
+            ed25519_sd_key.clone().into(),
+        ] {
+            add_key(key).unwrap();
+        }
+
+        let keys: BTreeMap<_, _> = SignatureMod::did_public_keys(&author).collect();
+        assert_eq!(keys.len(), 4);
+        assert!(keys.values().all(|key| key.params_ref().is_none()));
+        assert_eq!(
+            SignatureMod::did_public_key(author, IncId::from(3u8))
+                .and_then(OffchainPublicKey::into_bbs_ietf),
+            Some(bbs_ietf_key)
+        );
+        assert_eq!(
+            SignatureMod::did_public_key(author, IncId::from(4u8))
+                .and_then(OffchainPublicKey::into_ecdsa_sd),
+            Some(ecdsa_sd_key)
+        );
+        assert_eq!(
+            SignatureMod::did_public_key(author, IncId::from(5u8))
+                .and_then(OffchainPublicKey::into_ed25519_sd),
+            Some(ed25519_sd_key)
+        );
+        assert_eq!(
+            SignatureMod::did_public_key(author, IncId::from(5u8))
+                .and_then(OffchainPublicKey::into_bbs_ietf),
+            None
+        );
+    });
+}
+
+#[test]
+fn scheme_agnostic_getters() {
+    ext().execute_with(|| {
+        run_to_block(10);
+
+        let (author, author_kp) = newdid();
+        let owner = SignatureParamsOwner(author.into());
+        let mut next_nonce = 1;
+
+        let bbs_params: OffchainSignatureParams<Test> =
+            BBSParameters::new(None, vec![1; 100].try_into().unwrap(), CurveType::Bls12381).into();
+        let ps_params: OffchainSignatureParams<Test> =
+            PSParameters::new(None, vec![2; 100].try_into().unwrap(), CurveType::Bls12381).into();
+        for params in [bbs_params.clone(), ps_params.clone()] {
+            let ap = AddOffchainSignatureParams {
+                params,
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&ap, &author_kp, owner, 1);
+            SignatureMod::add_params(Origin::signed(1), ap, sig).unwrap();
+            next_nonce += 1;
+        }
+
+        let bbs_key: OffchainPublicKey<Test> = BBSPublicKey::new(
+            vec![3; 96].try_into().unwrap(),
+            (owner, 1u8.into()),
+            CurveType::Bls12381,
+        )
+        .into();
+        let ecdsa_sd_key: OffchainPublicKey<Test> =
+            EcdsaSdPublicKey::new(vec![2; 33].try_into().unwrap(), CurveType::P256).into();
+        for key in [bbs_key.clone(), ecdsa_sd_key.clone()] {
+            let ak = AddOffchainSignaturePublicKey {
+                key,
+                did: author,
+                nonce: next_nonce,
+            };
+            let sig = did_sig(&ak, &author_kp, Controller(author.into()), 1);
+            SignatureMod::add_public_key(Origin::signed(1), ak, sig).unwrap();
+            next_nonce += 1;
+        }
+
+        assert_eq!(
+            SignatureMod::public_key_with_params((author, 2u8.into())),
+            Some((bbs_key.clone(), Some(bbs_params.clone())))
+        );
+        assert_eq!(
+            SignatureMod::public_key_with_params((author, 3u8.into())),
+            Some((ecdsa_sd_key.clone(), None))
+        );
+        assert_eq!(
+            SignatureMod::public_key_with_params((author, 4u8.into())),
+            None
+        );
+
+        assert_eq!(
+            SignatureMod::did_public_keys_with_params(&author, None, None)
+                .collect::<BTreeMap<_, _>>(),
+            BTreeMap::from_iter([
+                (
+                    IncId::from(2u8),
+                    (bbs_key.clone(), Some(bbs_params.clone()))
+                ),
+                (IncId::from(3u8), (ecdsa_sd_key.clone(), None))
+            ])
+        );
+        assert_eq!(
+            SignatureMod::did_public_keys_with_params(
+                &author,
+                Some(OffchainSignatureScheme::EcdsaSd),
+                None
+            )
+            .collect::<Vec<_>>(),
+            vec![(IncId::from(3u8), (ecdsa_sd_key.clone(), None))]
+        );
+        assert_eq!(
+            SignatureMod::did_public_keys_with_params(&author, None, Some(CurveType::Bls12381))
+                .collect::<Vec<_>>(),
+            vec![(IncId::from(2u8), (bbs_key, Some(bbs_params.clone())))]
+        );
+        assert_eq!(
+            SignatureMod::did_public_keys_with_params(
+                &author,
+                Some(OffchainSignatureScheme::EcdsaSd),
+                Some(CurveType::P384)
+            )
+            .count(),
+            0
+        );
+
+        assert_eq!(
+            SignatureMod::did_params_filtered(&owner, None, Some(CurveType::Bls12381))
+                .collect::<Vec<_>>(),
+            vec![
+                (IncId::from(1u8), bbs_params),
+                (IncId::from(2u8), ps_params.clone())
+            ]
+        );
+        assert_eq!(
+            SignatureMod::did_params_filtered(&owner, Some(OffchainSignatureScheme::PS), None)
+                .collect::<Vec<_>>(),
+            vec![(IncId::from(2u8), ps_params)]
+        );
+        assert_eq!(
+            SignatureMod::did_params_filtered(&owner, Some(OffchainSignatureScheme::BBDT16), None)
+                .count(),
+            0
+        );
+    });
+}
+
+#[test]
+fn threshold_issuer_groups() {
+    ext().execute_with(|| {
+        run_to_block(10);
+
+        let (author, author_kp) = newdid();
+        let (participant, participant_kp) = newdid();
+
+        let add_key = |did: Did, kp: &sp_core::sr25519::Pair, key: OffchainPublicKey<Test>| {
+            let ak = AddOffchainSignaturePublicKey {
+                key,
+                did,
+                nonce: did_nonce::<Test, _>(did).unwrap(),
+            };
+            let sig = did_sig(&ak, kp, Controller(did.into()), 1);
+            SignatureMod::add_public_key(Origin::signed(1), ak, sig)
+        };
+        let participant_key = |participant_id| -> OffchainPublicKey<Test> {
+            BBSPlusPublicKey::new_with_participant_id(
+                vec![participant_id as u8; 96].try_into().unwrap(),
+                None,
+                CurveType::Bls12381,
+                participant_id,
+            )
+            .into()
+        };
+
+        // Participants' keys with ids 1 and 2 are owned by the author, 3 - by the other participant.
+        add_key(author, &author_kp, participant_key(1)).unwrap();
+        add_key(author, &author_kp, participant_key(2)).unwrap();
+        add_key(participant, &participant_kp, participant_key(3)).unwrap();
+        // Aggregated key.
+        add_key(
+            author,
+            &author_kp,
+            BBSPlusPublicKey::new(vec![9; 96].try_into().unwrap(), None, CurveType::Bls12381)
+                .into(),
+        )
+        .unwrap();
+        add_key(
+            author,
+            &author_kp,
+            EcdsaSdPublicKey::new(vec![2; 33].try_into().unwrap(), CurveType::P256).into(),
+        )
+        .unwrap();
+        add_key(
+            author,
+            &author_kp,
+            BBSPublicKey::new_with_participant_id(
+                vec![4; 96].try_into().unwrap(),
+                None,
+                CurveType::Bls12381,
+                4,
+            )
+            .into(),
+        )
+        .unwrap();
+
+        let group = |participants: &[(ParticipantId, (Did, u8))], threshold, aggregate_key: u8| {
+            ThresholdIssuerGroup::<Test> {
+                participants: participants
+                    .iter()
+                    .map(|&(participant_id, (did, key_id))| {
+                        (participant_id, (did, IncId::from(key_id)))
+                    })
+                    .collect::<BTreeMap<_, _>>()
+                    .try_into()
+                    .unwrap(),
+                threshold,
+                aggregate_key: aggregate_key.into(),
+                params_ref: None,
+            }
+        };
+        let add_group = |group: ThresholdIssuerGroup<Test>| {
+            let ag = AddThresholdIssuerGroup {
+                group,
+                did: author,
+                nonce: did_nonce::<Test, _>(author).unwrap(),
+            };
+            let sig = did_sig(&ag, &author_kp, Controller(author.into()), 1);
+            SignatureMod::add_threshold_issuer_group(Origin::signed(1), ag, sig)
+        };
+        let update_group = |group_ref, group: ThresholdIssuerGroup<Test>| {
+            let ug = UpdateThresholdIssuerGroup {
+                group_ref,
+                group,
+                did: author,
+                nonce: did_nonce::<Test, _>(author).unwrap(),
+            };
+            let sig = did_sig(&ug, &author_kp, Controller(author.into()), 1);
+            SignatureMod::update_threshold_issuer_group(Origin::signed(1), ug, sig)
+        };
+
+        let participants = [(1, (author, 2)), (2, (author, 3)), (3, (participant, 2))];
+        for (group, err) in [
+            (group(&participants, 0, 4), Error::<Test>::InvalidThreshold),
+            (group(&participants, 4, 4), Error::<Test>::InvalidThreshold),
+            (
+                group(&participants, 2, 10),
+                Error::<Test>::PublicKeyDoesntExist,
+            ),
+            (
+                group(&participants, 2, 5),
+                Error::<Test>::ThresholdIssuanceNotSupported,
+            ),
+            (
+                group(&participants, 2, 2),
+                Error::<Test>::InvalidAggregateKey,
+            ),
+            (
+                group(&[(1, (author, 2)), (2, (participant, 2))], 2, 4),
+                Error::<Test>::IncorrectParticipantId,
+            ),
+            (
+                group(&[(1, (author, 2)), (4, (author, 6))], 2, 4),
+                Error::<Test>::IncorrectParticipantKeyScheme,
+            ),
+            (
+                group(&[(1, (author, 2)), (4, (participant, 4))], 2, 4),
+                Error::<Test>::PublicKeyDoesntExist,
+            ),
+        ] {
+            assert_err!(add_group(group), err);
+        }
+        assert_err!(
+            add_group(group(&[], 0, 4)),
+            crate::did::Error::<Test>::EmptyPayload
+        );
+
+        let mut group_with_params = group(&participants, 2, 4);
+        group_with_params.params_ref = Some((SignatureParamsOwner(author.into()), 1u8.into()));
+        assert_err!(add_group(group_with_params), Error::<Test>::ParamsDontExist);
+
+        // Participants' keys must reference the group's params.
+        let params_ref = (SignatureParamsOwner(author.into()), IncId::from(1u8));
+        let ap = AddOffchainSignatureParams {
+            params: BBSPlusParameters::new(
+                None,
+                vec![1; 100].try_into().unwrap(),
+                CurveType::Bls12381,
+            )
+            .into(),
+            nonce: did_nonce::<Test, _>(author).unwrap(),
+        };
+        let sig = did_sig(&ap, &author_kp, params_ref.0, 1);
+        SignatureMod::add_params(Origin::signed(1), ap, sig).unwrap();
+        add_key(
+            author,
+            &author_kp,
+            BBSPlusPublicKey::new_with_participant_id(
+                vec![7; 96].try_into().unwrap(),
+                Some(params_ref),
+                CurveType::Bls12381,
+                1,
+            )
+            .into(),
+        )
+        .unwrap();
+        // Aggregated key referencing the params.
+        add_key(
+            author,
+            &author_kp,
+            BBSPlusPublicKey::new(
+                vec![8; 96].try_into().unwrap(),
+                Some(params_ref),
+                CurveType::Bls12381,
+            )
+            .into(),
+        )
+        .unwrap();
+        let with_params_key = [(1, (author, 7)), (2, (author, 3))];
+        assert_err!(
+            add_group(group(&with_params_key, 2, 4)),
+            Error::<Test>::IncorrectParticipantParams
+        );
+        assert_err!(
+            add_group(group(&with_params_key, 2, 8)),
+            Error::<Test>::IncorrectAggregateKeyParams
+        );
+        let mut group_with_params = group(&with_params_key, 2, 4);
+        group_with_params.params_ref = Some(params_ref);
+        assert_err!(
+            add_group(group_with_params.clone()),
+            Error::<Test>::IncorrectAggregateKeyParams
+        );
+        group_with_params.aggregate_key = 8u8.into();
+        assert_err!(
+            add_group(group_with_params),
+            Error::<Test>::IncorrectParticipantParams
+        );
+
+        let first = group(&participants, 2, 4);
+        add_group(first.clone()).unwrap();
+        assert!(
+            sig_events().contains(&offchain_signatures::Event::ThresholdIssuerGroupAdded(
+                author,
+                1u8.into()
+            ))
+        );
+        assert_eq!(
+            SignatureMod::threshold_issuer_group(author, IncId::from(1u8)),
+            Some(first.clone())
+        );
+
+        let second = group(&participants[..2], 1, 4);
+        add_group(second.clone()).unwrap();
+        assert_eq!(
+            SignatureMod::did_threshold_issuer_groups(&author).collect::<BTreeMap<_, _>>(),
+            BTreeMap::from_iter([
+                (IncId::from(1u8), first),
+                (IncId::from(2u8), second.clone())
+            ])
+        );
+
+        assert_err!(
+            update_group((author, 3u8.into()), second.clone()),
+            Error::<Test>::ThresholdIssuerGroupDoesntExist
+        );
+        assert_err!(
+            update_group((author, 1u8.into()), group(&participants, 5, 4)),
+            Error::<Test>::InvalidThreshold
+        );
+        let updated = group(&participants, 3, 4);
+        update_group((author, 1u8.into()), updated.clone()).unwrap();
+        assert!(
+            sig_events().contains(&offchain_signatures::Event::ThresholdIssuerGroupUpdated(
+                author,
+                1u8.into()
+            ))
+        );
+        assert_eq!(
+            SignatureMod::threshold_issuer_group(author, IncId::from(1u8)),
+            Some(updated)
+        );
+
+        // Only groups of the signer can be updated.
+        let ug = UpdateThresholdIssuerGroup {
+            group_ref: (author, 2u8.into()),
+            group: second,
+            did: participant,
+            nonce: did_nonce::<Test, _>(participant).unwrap(),
+        };
+        let sig = did_sig(&ug, &participant_kp, Controller(participant.into()), 1);
+        assert_err!(
+            SignatureMod::update_threshold_issuer_group(Origin::signed(1), ug, sig),
+            Error::<Test>::NotOwner
+        );
+
+        let remove_key = |key_ref: SignaturePublicKeyStorageKey, kp: &sp_core::sr25519::Pair| {
+            let rk = RemoveOffchainSignaturePublicKey {
+                key_ref,
+                did: key_ref.0,
+                nonce: did_nonce::<Test, _>(key_ref.0).unwrap(),
+            };
+            let sig = did_sig(&rk, kp, Controller(key_ref.0.into()), 1);
+            SignatureMod::remove_public_key(Origin::signed(1), rk, sig)
+        };
+        let remove_group = |group_ref, did: Did, kp: &sp_core::sr25519::Pair| {
+            let rg = RemoveThresholdIssuerGroup {
+                group_ref,
+                did,
+                nonce: did_nonce::<Test, _>(did).unwrap(),
+            };
+            let sig = did_sig(&rg, kp, Controller(did.into()), 1);
+            SignatureMod::remove_threshold_issuer_group(Origin::signed(1), rg, sig)
+        };
+
+        // Keys used by the groups can't be removed.
+        assert_eq!(
+            SignatureMod::did_public_key_group_references(author, IncId::from(4u8)),
+            2
+        );
+        assert_err!(
+            remove_key((author, 4u8.into()), &author_kp),
+            Error::<Test>::PublicKeyInUse
+        );
+        assert_err!(
+            remove_key((participant, 2u8.into()), &participant_kp),
+            Error::<Test>::PublicKeyInUse
+        );
+
+        assert_err!(
+            remove_group((author, 3u8.into()), author, &author_kp),
+            Error::<Test>::ThresholdIssuerGroupDoesntExist
+        );
+        assert_err!(
+            remove_group((author, 2u8.into()), participant, &participant_kp),
+            Error::<Test>::NotOwner
+        );
+        remove_group((author, 2u8.into()), author, &author_kp).unwrap();
+        assert!(
+            sig_events().contains(&offchain_signatures::Event::ThresholdIssuerGroupRemoved(
+                author,
+                2u8.into()
+            ))
+        );
+        assert_eq!(
+            SignatureMod::threshold_issuer_group(author, IncId::from(2u8)),
+            None
+        );
+        // The aggregated key is still used by the first group.
+        assert_eq!(
+            SignatureMod::did_public_key_group_references(author, IncId::from(4u8)),
+            1
+        );
+        assert_err!(
+            remove_key((author, 4u8.into()), &author_kp),
+            Error::<Test>::PublicKeyInUse
+        );
+
+        remove_group((author, 1u8.into()), author, &author_kp).unwrap();
+        assert_eq!(ThresholdIssuerGroupKeyReferences::<Test>::iter().count(), 0);
+        remove_key((participant, 2u8.into()), &participant_kp).unwrap();
+        remove_key((author, 4u8.into()), &author_kp).unwrap();
+
+        // Removal of the DID releases the references of its groups.
+        add_key(
+            author,
+            &author_kp,
+            BBSPlusPublicKey::new(vec![10; 96].try_into().unwrap(), None, CurveType::Bls12381)
+                .into(),
+        )
+        .unwrap();
+        add_group(group(&[(1, (author, 2)), (2, (author, 3))], 2, 9)).unwrap();
+        assert_eq!(ThresholdIssuerGroupKeyReferences::<Test>::iter().count(), 3);
+        let _ = <SignatureMod as HandleDidRemoval>::on_did_removal(author);
+        assert_eq!(ThresholdIssuerGroupKeyReferences::<Test>::iter().count(), 0);
+        assert_eq!(
+            SignatureMod::did_threshold_issuer_groups(&author).count(),
+            0
+        );
+    });
+}
+
+#[test]
+fn revoke_public_key() {
+    ext().execute_with(|| {
+        run_to_block(10);
+
+        let (author, author_kp) = newdid();
+        let (other, other_kp) = newdid();
+
+        let key: OffchainPublicKey<Test> = BBSPlusPublicKey::new_with_participant_id(
+            vec![1; 96].try_into().unwrap(),
+            None,
+            CurveType::Bls12381,
+            1,
+        )
+        .into();
+        let ak = AddOffchainSignaturePublicKey {
+            key: key.clone(),
+            did: author,
+            nonce: did_nonce::<Test, _>(author).unwrap(),
+        };
+        let sig = did_sig(&ak, &author_kp, Controller(author.into()), 1);
+        SignatureMod::add_public_key(Origin::signed(1), ak, sig).unwrap();
+
+        let revoke =
+            |key_ref: SignaturePublicKeyStorageKey, did: Did, kp: &sp_core::sr25519::Pair| {
+                let rk = RevokeOffchainSignaturePublicKey {
+                    key_ref,
+                    did,
+                    nonce: did_nonce::<Test, _>(did).unwrap(),
+                };
+                let sig = did_sig(&rk, kp, Controller(did.into()), 1);
+                SignatureMod::revoke_public_key(Origin::signed(1), rk, sig)
+            };
+
+        assert_err!(
+            revoke((author, 3u8.into()), author, &author_kp),
+            Error::<Test>::PublicKeyDoesntExist
+        );
+        assert_err!(
+            revoke((author, 2u8.into()), other, &other_kp),
+            Error::<Test>::NotOwner
+        );
+        assert_eq!(
+            SignatureMod::public_key_revoked_at((author, 2u8.into())),
+            None
+        );
+
+        run_to_block(15);
+        revoke((author, 2u8.into()), author, &author_kp).unwrap();
+        assert!(sig_events().contains(&offchain_signatures::Event::KeyRevoked(author, 2u8.into())));
+        assert_err!(
+            revoke((author, 2u8.into()), author, &author_kp),
+            Error::<Test>::PublicKeyRevoked
+        );
+
+        // Revoked key remains resolvable along with the block of the revocation.
+        assert_eq!(
+            SignatureMod::did_public_key(author, IncId::from(2u8)),
+            Some(key)
+        );
+        assert_eq!(
+            SignatureMod::public_key_revoked_at((author, 2u8.into())),
+            Some(15)
+        );
+
+        // Revoked keys can't be used in threshold issuer groups.
+        let ag = AddThresholdIssuerGroup {
+            group: ThresholdIssuerGroup {
+                participants: BTreeMap::from_iter([(1, (author, IncId::from(2u8)))])
+                    .try_into()
+                    .unwrap(),
+                threshold: 1,
+                aggregate_key: 2u8.into(),
+                params_ref: None,
+            },
+            did: author,
+            nonce: did_nonce::<Test, _>(author).unwrap(),
+        };
+        let sig = did_sig(&ag, &author_kp, Controller(author.into()), 1);
+        assert_err!(
+            SignatureMod::add_threshold_issuer_group(Origin::signed(1), ag, sig),
+            Error::<Test>::PublicKeyRevoked
+        );
+
+        let rk = RemoveOffchainSignaturePublicKey {
+            key_ref: (author, 2u8.into()),
+            did: author,
+            nonce: did_nonce::<Test, _>(author).unwrap(),
+        };
+        let sig = did_sig(&rk, &author_kp, Controller(author.into()), 1);
+        SignatureMod::remove_public_key(Origin::signed(1), rk, sig).unwrap();
+        assert_eq!(
+            SignatureMod::public_key_revoked_at((author, 2u8.into())),
+            None
+        );
+    });
+}
+
+#[test]
+fn shared_params() {
+    ext().execute_with(|| {
+        run_to_block(10);
+
+        let (author, author_kp) = newdid();
+        let (other, other_kp) = newdid();
+        let owner = SignatureParamsOwner(author.into());
+
+        let params: OffchainSignatureParams<Test> =
+            BBSPlusParameters::new(None, vec![1; 100].try_into().unwrap(), CurveType::Bls12381)
+                .into();
+        let add_params = |params: OffchainSignatureParams<Test>| {
+            let ap = AddOffchainSignatureParams {
+                params,
+                nonce: did_nonce::<Test, _>(author).unwrap(),
+            };
+            let sig = did_sig(&ap, &author_kp, owner, 1);
+            SignatureMod::add_params(Origin::signed(1), ap, sig).unwrap();
+        };
+        let add_key = |did: Did,
+                       kp: &sp_core::sr25519::Pair,
+                       params_ref: Option<SignatureParamsStorageKey>| {
+            let ak = AddOffchainSignaturePublicKey {
+                key: BBSPlusPublicKey::new(
+                    vec![1; 96].try_into().unwrap(),
+                    params_ref,
+                    CurveType::Bls12381,
+                )
+                .into(),
+                did,
+                nonce: did_nonce::<Test, _>(did).unwrap(),
+            };
+            let sig = did_sig(&ak, kp, Controller(did.into()), 1);
+            SignatureMod::add_public_key(Origin::signed(1), ak, sig)
+        };
+        let remove_key = |key_ref: SignaturePublicKeyStorageKey, kp: &sp_core::sr25519::Pair| {
+            let rk = RemoveOffchainSignaturePublicKey {
+                key_ref,
+                did: key_ref.0,
+                nonce: did_nonce::<Test, _>(key_ref.0).unwrap(),
+            };
+            let sig = did_sig(&rk, kp, Controller(key_ref.0.into()), 1);
+            SignatureMod::remove_public_key(Origin::signed(1), rk, sig).unwrap();
+        };
+        let remove_params = |params_ref: SignatureParamsStorageKey| {
+            let rp = RemoveOffchainSignatureParams {
+                params_ref,
+                nonce: did_nonce::<Test, _>(author).unwrap(),
+            };
+            let sig = did_sig(&rp, &author_kp, owner, 1);
+            SignatureMod::remove_params(Origin::signed(1), rp, sig)
+        };
+        let share = |params_ref: SignatureParamsStorageKey,
+                     signer: Did,
+                     kp: &sp_core::sr25519::Pair| {
+            let sp = ShareOffchainSignatureParams {
+                params_ref,
+                nonce: did_nonce::<Test, _>(signer).unwrap(),
+            };
+            let sig = did_sig(&sp, kp, SignatureParamsOwner(signer.into()), 1);
+            SignatureMod::share_params(Origin::signed(1), sp, sig)
+        };
+
+        add_params(params.clone());
+
+        // Unshared params can only be referenced by the keys of their owner.
+        add_key(author, &author_kp, Some((owner, 1u8.into()))).unwrap();
+        assert_err!(
+            add_key(other, &other_kp, Some((owner, 1u8.into()))),
+            Error::<Test>::ParamsNotShared
+        );
+
+        // The root can share params of any DID, these remain owned by the DID.
+        assert_err!(
+            SignatureMod::add_shared_params(Origin::signed(1), (owner, 1u8.into())),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_err!(
+            SignatureMod::add_shared_params(Origin::root(), (owner, 2u8.into())),
+            Error::<Test>::ParamsDontExist
+        );
+        SignatureMod::add_shared_params(Origin::root(), (owner, 1u8.into())).unwrap();
+        assert!(sig_events().contains(&offchain_signatures::Event::ParamsShared(
+            owner,
+            1u8.into()
+        )));
+        assert_err!(
+            SignatureMod::add_shared_params(Origin::root(), (owner, 1u8.into())),
+            Error::<Test>::ParamsAlreadyShared
+        );
+        assert_eq!(
+            SignatureMod::shared_params(Some(OffchainSignatureScheme::BBSPlus), None, None, 10),
+            vec![((owner, IncId::from(1u8)), params.clone())]
+        );
+        assert!(
+            SignatureMod::shared_params(Some(OffchainSignatureScheme::PS), None, None, 10)
+                .is_empty()
+        );
+
+        // Keys of any DID can reference shared params.
+        add_key(other, &other_kp, Some((owner, 1u8.into()))).unwrap();
+        assert_eq!(SignatureMod::params_references((owner, 1u8.into())), 2);
+
+        // The root can unshare params, keys already referencing them aren't affected.
+        assert_err!(
+            SignatureMod::remove_shared_params(Origin::signed(1), (owner, 1u8.into())),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        SignatureMod::remove_shared_params(Origin::root(), (owner, 1u8.into())).unwrap();
+        assert!(sig_events().contains(&offchain_signatures::Event::ParamsUnshared(
+            owner,
+            1u8.into()
+        )));
+        assert_err!(
+            SignatureMod::remove_shared_params(Origin::root(), (owner, 1u8.into())),
+            Error::<Test>::ParamsNotShared
+        );
+        assert!(SignatureMod::shared_params(None, None, None, 10).is_empty());
+        assert_eq!(SignatureMod::params_references((owner, 1u8.into())), 2);
+        assert_err!(
+            add_key(other, &other_kp, Some((owner, 1u8.into()))),
+            Error::<Test>::ParamsNotShared
+        );
+
+        // The owner can share params as well.
+        assert_err!(
+            share((owner, 2u8.into()), author, &author_kp),
+            Error::<Test>::ParamsDontExist
+        );
+        assert_err!(
+            share((owner, 1u8.into()), other, &other_kp),
+            Error::<Test>::NotOwner
+        );
+        share((owner, 1u8.into()), author, &author_kp).unwrap();
+        assert_err!(
+            share((owner, 1u8.into()), author, &author_kp),
+            Error::<Test>::ParamsAlreadyShared
+        );
+
+        // Shared params can be paginated.
+        for _ in 0..3 {
+            add_params(params.clone());
+        }
+        for params_id in 2u8..=4 {
+            SignatureMod::add_shared_params(Origin::root(), (owner, params_id.into())).unwrap();
+        }
+        // Pages are capped by `MaxQueryPageSize`.
+        assert_eq!(SignatureMod::shared_params(None, None, None, u32::MAX).len(), 3);
+        let mut shared = SignatureMod::shared_params(None, None, None, 2);
+        assert_eq!(shared.len(), 2);
+        shared.extend(SignatureMod::shared_params(None, None, Some(shared[1].0), 2));
+        assert_eq!(
+            shared
+                .into_iter()
+                .map(|(params_ref, _)| params_ref)
+                .collect::<BTreeSet<_>>(),
+            (1u8..=4).map(|params_id| (owner, params_id.into())).collect()
+        );
+
+        // Referenced shared params can't be removed without force.
+        assert_err!(remove_params((owner, 1u8.into())), Error::<Test>::ParamsInUse);
+
+        // Removing the DID releases the references of its keys.
+        let _ = <SignatureMod as HandleDidRemoval>::on_did_removal(other);
+        assert_eq!(SignatureMod::did_public_key(other, IncId::from(2u8)), None);
+        assert_eq!(SignatureMod::params_references((owner, 1u8.into())), 1);
+        remove_key((author, 2u8.into()), &author_kp);
+        assert_eq!(SignatureMod::params_references((owner, 1u8.into())), 0);
+        remove_params((owner, 1u8.into())).unwrap();
+        assert!(!SharedParams::<Test>::contains_key(owner, IncId::from(1u8)));
+    });
+}
+
+#[test]
+fn count_params_references_migration() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    ext().execute_with(|| {
+        let (author, _) = newdid();
+        let owner = SignatureParamsOwner(author.into());
+
+        for (key_id, params_ref) in [
+            (1u8, Some((owner, IncId::from(1u8)))),
+            (2, Some((owner, IncId::from(1u8)))),
+            (3, Some((owner, IncId::from(2u8)))),
+            (4, None),
+        ] {
+            let key: OffchainPublicKey<Test> = BBSPublicKey::new(
+                vec![1; 96].try_into().unwrap(),
+                params_ref,
+                CurveType::Bls12381,
+            )
+            .into();
+            PublicKeys::<Test>::insert(author, IncId::from(key_id), key);
+        }
+        StorageVersion::new(0).put::<SignatureMod>();
+
+        CountParamsReferences::<Test>::on_runtime_upgrade();
+        assert_eq!(SignatureMod::on_chain_storage_version(), 1);
+        assert_eq!(SignatureMod::params_references((owner, 1u8.into())), 2);
+        assert_eq!(SignatureMod::params_references((owner, 2u8.into())), 1);
+
+        // Migration isn't applied twice.
+        CountParamsReferences::<Test>::on_runtime_upgrade();
+        assert_eq!(SignatureMod::params_references((owner, 1u8.into())), 2);
+    });
+}
+
+#[test]
+fn force_remove_referenced_params() {
+    ext().execute_with(|| {
+        run_to_block(10);
+
+        let (author, author_kp) = newdid();
+        let (other, other_kp) = newdid();
+        let owner = SignatureParamsOwner(author.into());
+
+        let ap = AddOffchainSignatureParams {
+            params: BBSPlusParameters::new(
+                None,
+                vec![1; 100].try_into().unwrap(),
+                CurveType::Bls12381,
+            )
+            .into(),
+            nonce: did_nonce::<Test, _>(author).unwrap(),
+        };
+        let sig = did_sig(&ap, &author_kp, owner, 1);
+        SignatureMod::add_params(Origin::signed(1), ap, sig).unwrap();
+        SignatureMod::add_shared_params(Origin::root(), (owner, 1u8.into())).unwrap();
+
+        let ak = AddOffchainSignaturePublicKey {
+            key: BBSPlusPublicKey::new(
+                vec![1; 96].try_into().unwrap(),
+                (owner, 1u8.into()),
+                CurveType::Bls12381,
+            )
+            .into(),
+            did: other,
+            nonce: did_nonce::<Test, _>(other).unwrap(),
+        };
+        let sig = did_sig(&ak, &other_kp, Controller(other.into()), 1);
+        SignatureMod::add_public_key(Origin::signed(1), ak, sig).unwrap();
+        assert_eq!(SignatureMod::params_references((owner, 1u8.into())), 1);
+        assert!(SignatureMod::dangling_params_references(&other)
+            .next()
+            .is_none());
+
+        let rp = RemoveOffchainSignatureParams {
+            params_ref: (owner, 1u8.into()),
+            nonce: did_nonce::<Test, _>(author).unwrap(),
+        };
+        let sig = did_sig(&rp, &author_kp, owner, 1);
+        assert_err!(
+            SignatureMod::remove_params(Origin::signed(1), rp, sig),
+            Error::<Test>::ParamsInUse
+        );
+
+        // Shared params can be removed by force as well.
+        let rp = ForceRemoveOffchainSignatureParams {
+            params_ref: (owner, 1u8.into()),
+            nonce: did_nonce::<Test, _>(author).unwrap(),
+        };
+        let sig = did_sig(&rp, &author_kp, owner, 1);
+        SignatureMod::force_remove_params(Origin::signed(1), rp, sig).unwrap();
+        assert!(!SharedParams::<Test>::contains_key(owner, IncId::from(1u8)));
+        assert_eq!(
+            SignatureMod::did_public_key_params(owner, IncId::from(1u8)),
+            None
+        );
+        assert_eq!(
+            SignatureMod::dangling_params_references(&other).collect::<Vec<_>>(),
+            vec![(IncId::from(2u8), (owner, IncId::from(1u8)))]
+        );
+
+        let rk = RemoveOffchainSignaturePublicKey {
+            key_ref: (other, 2u8.into()),
+            did: other,
+            nonce: did_nonce::<Test, _>(other).unwrap(),
+        };
+        let sig = did_sig(&rk, &other_kp, Controller(other.into()), 1);
+        SignatureMod::remove_public_key(Origin::signed(1), rk, sig).unwrap();
+        assert_eq!(SignatureMod::params_references((owner, 1u8.into())), 0);
+        assert!(SignatureMod::dangling_params_references(&other)
+            .next()
+            .is_none());
+    });
+}
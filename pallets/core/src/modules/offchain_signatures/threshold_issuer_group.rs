@@ -0,0 +1,216 @@
+use crate::{
+    common::Limits,
+    did::{Did, OnChainDidDetails},
+    offchain_signatures::{schemes::*, SignatureParams},
+    util::IncId,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{ensure, CloneNoBound, DebugNoBound, EqNoBound, PartialEqNoBound};
+use sp_runtime::{BoundedBTreeMap, DispatchResult};
+
+#[cfg(feature = "serde")]
+use crate::util::btree_map;
+
+use super::{
+    AddThresholdIssuerGroup, Config, Error, Event, Pallet, PublicKeys, RemoveThresholdIssuerGroup,
+    RevokedPublicKeys, SignatureParamsStorageKey, SignaturePublicKeyStorageKey,
+    ThresholdIssuerGroupKeyReferences, ThresholdIssuerGroups, ThresholdIssuerGroupsCounter,
+    UpdateThresholdIssuerGroup,
+};
+
+pub type ThresholdIssuerGroupStorageKey = (Did, IncId);
+
+/// Group of the participants issuing credentials using threshold issuance.
+/// Credentials issued by the group are verified using its aggregated public key.
+#[derive(
+    scale_info_derive::TypeInfo,
+    Encode,
+    Decode,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    DebugNoBound,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct ThresholdIssuerGroup<T: Limits> {
+    /// Public keys of the participants by their participant ids. Each key must have the same participant id set.
+    #[cfg_attr(feature = "serde", serde(with = "btree_map"))]
+    pub participants: BoundedBTreeMap<
+        ParticipantId,
+        SignaturePublicKeyStorageKey,
+        T::MaxThresholdIssuerParticipants,
+    >,
+    /// Minimum amount of the participants required to issue a credential.
+    pub threshold: ParticipantId,
+    /// Identifier of the aggregated public key of the group owned by the group's DID.
+    pub aggregate_key: IncId,
+    /// The params used by the participants' keys and the aggregated public key.
+    pub params_ref: Option<SignatureParamsStorageKey>,
+}
+
+impl<T: Limits> ThresholdIssuerGroup<T> {
+    /// Ensures that the threshold can be reached by the group's participants and that all referenced keys
+    /// exist, belong to the same signature scheme supporting threshold issuance and reference the group's params.
+    /// The aggregated public key can't be a participant's key.
+    pub fn ensure_valid(&self, owner: Did) -> Result<(), Error<T>>
+    where
+        T: Config,
+    {
+        ensure!(
+            self.threshold > 0 && self.threshold as usize <= self.participants.len(),
+            Error::<T>::InvalidThreshold
+        );
+
+        let aggregate_key = PublicKeys::<T>::get(owner, self.aggregate_key)
+            .ok_or(Error::<T>::PublicKeyDoesntExist)?;
+        ensure!(
+            !RevokedPublicKeys::<T>::contains_key(owner, self.aggregate_key),
+            Error::<T>::PublicKeyRevoked
+        );
+        ensure!(
+            aggregate_key.supports_threshold_issuance(),
+            Error::<T>::ThresholdIssuanceNotSupported
+        );
+        ensure!(
+            aggregate_key.participant_id().is_none()
+                && !self
+                    .participants
+                    .values()
+                    .any(|key_ref| *key_ref == (owner, self.aggregate_key)),
+            Error::<T>::InvalidAggregateKey
+        );
+
+        if let Some((did, params_id)) = &self.params_ref {
+            let params =
+                SignatureParams::<T>::get(did, params_id).ok_or(Error::<T>::ParamsDontExist)?;
+            ensure!(
+                aggregate_key.params_match_scheme(&params),
+                Error::<T>::IncorrectParamsScheme
+            );
+        }
+
+        ensure!(
+            aggregate_key.params_ref() == self.params_ref.as_ref(),
+            Error::<T>::IncorrectAggregateKeyParams
+        );
+
+        for (&participant_id, (did, key_id)) in self.participants.iter() {
+            let key = PublicKeys::<T>::get(did, key_id).ok_or(Error::<T>::PublicKeyDoesntExist)?;
+            ensure!(
+                !RevokedPublicKeys::<T>::contains_key(did, key_id),
+                Error::<T>::PublicKeyRevoked
+            );
+
+            ensure!(
+                key.participant_id() == Some(participant_id),
+                Error::<T>::IncorrectParticipantId
+            );
+            ensure!(
+                key.scheme() == aggregate_key.scheme()
+                    && key.curve_type() == aggregate_key.curve_type(),
+                Error::<T>::IncorrectParticipantKeyScheme
+            );
+            ensure!(
+                key.params_ref() == self.params_ref.as_ref(),
+                Error::<T>::IncorrectParticipantParams
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns references to the aggregated public key owned by the supplied DID and the participants' keys.
+    pub fn key_refs(&self, owner: Did) -> impl Iterator<Item = SignaturePublicKeyStorageKey> + '_ {
+        core::iter::once((owner, self.aggregate_key)).chain(self.participants.values().copied())
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub(super) fn add_threshold_issuer_group_(
+        AddThresholdIssuerGroup { group, did, .. }: AddThresholdIssuerGroup<T>,
+        _: &mut OnChainDidDetails,
+    ) -> DispatchResult {
+        group.ensure_valid(did)?;
+
+        Self::add_group_key_references(did, &group);
+        let group_id = ThresholdIssuerGroupsCounter::<T>::mutate(did, |counter| *counter.inc());
+        ThresholdIssuerGroups::<T>::insert(did, group_id, group);
+
+        Self::deposit_event(Event::ThresholdIssuerGroupAdded(did, group_id));
+        Ok(())
+    }
+
+    pub(super) fn update_threshold_issuer_group_(
+        UpdateThresholdIssuerGroup {
+            group_ref: (owner, group_id),
+            group,
+            did,
+            ..
+        }: UpdateThresholdIssuerGroup<T>,
+        _: &mut OnChainDidDetails,
+    ) -> DispatchResult {
+        let current_group = ThresholdIssuerGroups::<T>::get(owner, group_id)
+            .ok_or(Error::<T>::ThresholdIssuerGroupDoesntExist)?;
+        ensure!(owner == did, Error::<T>::NotOwner);
+
+        group.ensure_valid(did)?;
+
+        Self::release_group_key_references(did, &current_group);
+        Self::add_group_key_references(did, &group);
+        ThresholdIssuerGroups::<T>::insert(did, group_id, group);
+
+        Self::deposit_event(Event::ThresholdIssuerGroupUpdated(did, group_id));
+        Ok(())
+    }
+
+    pub(super) fn remove_threshold_issuer_group_(
+        RemoveThresholdIssuerGroup {
+            group_ref: (owner, group_id),
+            did,
+            ..
+        }: RemoveThresholdIssuerGroup<T>,
+        _: &mut OnChainDidDetails,
+    ) -> DispatchResult {
+        let group = ThresholdIssuerGroups::<T>::get(owner, group_id)
+            .ok_or(Error::<T>::ThresholdIssuerGroupDoesntExist)?;
+        ensure!(owner == did, Error::<T>::NotOwner);
+
+        Self::release_group_key_references(did, &group);
+        ThresholdIssuerGroups::<T>::remove(did, group_id);
+
+        Self::deposit_event(Event::ThresholdIssuerGroupRemoved(did, group_id));
+        Ok(())
+    }
+
+    fn add_group_key_references(owner: Did, group: &ThresholdIssuerGroup<T>) {
+        for (did, key_id) in group.key_refs(owner) {
+            ThresholdIssuerGroupKeyReferences::<T>::mutate(did, key_id, |refs| {
+                *refs = refs.saturating_add(1)
+            });
+        }
+    }
+
+    pub(super) fn release_group_key_references(owner: Did, group: &ThresholdIssuerGroup<T>) {
+        for (did, key_id) in group.key_refs(owner) {
+            ThresholdIssuerGroupKeyReferences::<T>::mutate_exists(did, key_id, |refs| {
+                *refs = refs
+                    .map(|refs| refs.saturating_sub(1))
+                    .filter(|&refs| refs > 0)
+            });
+        }
+    }
+
+    pub fn did_threshold_issuer_groups(
+        did: &Did,
+    ) -> impl Iterator<Item = (IncId, ThresholdIssuerGroup<T>)> {
+        ThresholdIssuerGroups::<T>::iter_prefix(did)
+    }
+}
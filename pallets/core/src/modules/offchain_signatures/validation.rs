@@ -0,0 +1,190 @@
+//! Optional validation of the offchain signature public keys and params bytes.
+//! Keys and params are expected to be serialized in the compressed form used by the `arkworks` libraries,
+//! so each point gets decoded and checked to be on the curve and in the correct subgroup.
+//! - `BBS` params consist of `g1: G1`, `g2: G2` and message generators `h: Vec<G1>`, public key is a `G2` point.
+//! - `BBS+` params consist of `g1: G1`, `g2: G2`, `h_0: G1` and message generators `h: Vec<G1>`, public key is a `G2` point.
+//! - `Pointcheval-Sanders` params consist of `g: G1`, `g_tilde: G2` and message generators `h: Vec<G1>`,
+//! public key consists of `alpha_tilde: G2`, `beta: Vec<G1>` and `beta_tilde: Vec<G2>` having an element per message.
+//! - `BBDT16` params consist of `g_0: G1`, `g: G1`, `g_tilde: G1` and message generators `h: Vec<G1>`,
+//! public key is a `G1` point.
+//! - IETF draft `BBS` public key is a `G2` point, there are no params.
+//! - `ECDSA-SD` public key is a SEC1 compressed point of either `P-256` or `P-384` curve, checked to be on the curve.
+//! - Selective disclosure `Ed25519` public key consists of 32 bytes, only its length is checked.
+use super::*;
+use crate::common::{CurveType, Limits};
+use ark_bls12_381::{G1Affine, G2Affine};
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_serialize::CanonicalDeserialize;
+use frame_support::ensure;
+
+/// Byte size of the SEC1 compressed `P-256` point.
+const P256_COMPRESSED_POINT_SIZE: usize = 33;
+/// Byte size of the SEC1 compressed `P-384` point.
+const P384_COMPRESSED_POINT_SIZE: usize = 49;
+/// Byte size of the `Ed25519` public key.
+const ED25519_PUBLIC_KEY_SIZE: usize = 32;
+
+impl<T: Config> OffchainSignatureParams<T> {
+    /// Ensures that the params bytes represent valid points of the params of the corresponding signature scheme
+    /// returning the amount of the messages supported by them.
+    pub fn ensure_valid_points(&self) -> Result<usize, Error<T>> {
+        let message_count = decode_params(self).ok_or(Error::<T>::MalformedParams)?;
+        ensure!(message_count > 0, Error::<T>::MalformedParams);
+
+        Ok(message_count)
+    }
+}
+
+impl<T: Config> OffchainPublicKey<T> {
+    /// Ensures that the public key bytes represent valid points of the key of the corresponding signature scheme
+    /// returning the amount of the messages supported by the key if it depends on it.
+    pub fn ensure_valid_points(&self) -> Result<Option<usize>, Error<T>> {
+        decode_public_key(self).ok_or(Error::<T>::MalformedPublicKey)
+    }
+
+    /// Ensures that the public key and the supplied params it references are valid and support
+    /// the same amount of the messages.
+    pub(super) fn ensure_valid_points_with_params(
+        &self,
+        params: &OffchainSignatureParams<T>,
+    ) -> Result<(), Error<T>> {
+        let key_message_count = self.ensure_valid_points()?;
+        let params_message_count = params.ensure_valid_points()?;
+
+        if let Some(key_message_count) = key_message_count {
+            ensure!(
+                key_message_count == params_message_count,
+                Error::<T>::IncorrectPublicKeyMessageCount
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes the params returning the amount of the message generators.
+fn decode_params<T: Limits>(params: &OffchainSignatureParams<T>) -> Option<usize> {
+    let mut decoder = Decoder(params.bytes());
+    let message_count = match params.curve_type() {
+        CurveType::Bls12381 => match params {
+            OffchainSignatureParams::BBS(_) | OffchainSignatureParams::PS(_) => {
+                decoder.point::<G1Affine>()?;
+                decoder.point::<G2Affine>()?;
+                decoder.points::<G1Affine>()?
+            }
+            OffchainSignatureParams::BBSPlus(_) => {
+                decoder.point::<G1Affine>()?;
+                decoder.point::<G2Affine>()?;
+                decoder.point::<G1Affine>()?;
+                decoder.points::<G1Affine>()?
+            }
+            OffchainSignatureParams::BBDT16(_) => {
+                decoder.point::<G1Affine>()?;
+                decoder.point::<G1Affine>()?;
+                decoder.point::<G1Affine>()?;
+                decoder.points::<G1Affine>()?
+            }
+        },
+        CurveType::P256 | CurveType::P384 | CurveType::Ed25519 => return None,
+    };
+
+    decoder.is_empty().then_some(message_count)
+}
+
+/// Decodes the public key returning the amount of the messages supported by it if it depends on it.
+fn decode_public_key<T: Limits>(key: &OffchainPublicKey<T>) -> Option<Option<usize>> {
+    let mut decoder = Decoder(key.bytes());
+    let message_count = match key.curve_type() {
+        CurveType::Bls12381 => match key {
+            OffchainPublicKey::BBS(_) | OffchainPublicKey::BBSPlus(_) => {
+                decoder.point::<G2Affine>()?;
+                None
+            }
+            OffchainPublicKey::PS(_) => {
+                decoder.point::<G2Affine>()?;
+                let beta = decoder.points::<G1Affine>()?;
+                let beta_tilde = decoder.points::<G2Affine>()?;
+                if beta == 0 || beta != beta_tilde {
+                    return None;
+                }
+
+                Some(beta)
+            }
+            OffchainPublicKey::BBDT16(_) => {
+                decoder.point::<G1Affine>()?;
+                None
+            }
+            OffchainPublicKey::BBSIetf(_) => {
+                decoder.point::<G2Affine>()?;
+                None
+            }
+            OffchainPublicKey::EcdsaSd(_) | OffchainPublicKey::Ed25519Sd(_) => return None,
+        },
+        CurveType::P256 => {
+            decoder.sec1_compressed_point::<ark_secp256r1::Config>(P256_COMPRESSED_POINT_SIZE)?;
+            None
+        }
+        CurveType::P384 => {
+            decoder.sec1_compressed_point::<ark_secp384r1::Config>(P384_COMPRESSED_POINT_SIZE)?;
+            None
+        }
+        CurveType::Ed25519 => {
+            decoder.bytes(ED25519_PUBLIC_KEY_SIZE)?;
+            None
+        }
+    };
+
+    decoder.is_empty().then_some(message_count)
+}
+
+/// Decodes compressed points performing the curve and subgroup checks.
+struct Decoder<'a>(&'a [u8]);
+
+impl Decoder<'_> {
+    /// Decodes a single point.
+    fn point<P: CanonicalDeserialize>(&mut self) -> Option<P> {
+        P::deserialize_compressed(&mut self.0).ok()
+    }
+
+    /// Decodes a sequence of points prefixed with its length returning the amount of the decoded points.
+    fn points<P: CanonicalDeserialize>(&mut self) -> Option<usize> {
+        let len = u64::deserialize_compressed(&mut self.0).ok()?;
+        // Don't trust the supplied length to not go through the huge amount of the iterations.
+        if len > self.0.len() as u64 {
+            return None;
+        }
+
+        (0..len).try_for_each(|_| self.point::<P>().map(drop))?;
+
+        Some(len as usize)
+    }
+
+    /// Takes a SEC1 compressed point of the given size checking its prefix and that the curve has a point
+    /// with its `x` coordinate. Both curves have a cofactor of one, so there is no subgroup check.
+    fn sec1_compressed_point<C: SWCurveConfig>(&mut self, size: usize) -> Option<()> {
+        let (prefix, x) = self.bytes(size)?.split_first()?;
+        if !matches!(prefix, 0x02 | 0x03) {
+            return None;
+        }
+        // SEC1 encodes the coordinate in the big-endian order while the arkworks expects the little-endian one.
+        let x: Vec<u8> = x.iter().rev().copied().collect();
+        let x = C::BaseField::deserialize_uncompressed(&x[..]).ok()?;
+
+        Affine::<C>::get_ys_from_x_unchecked(x).map(|_| ())
+    }
+
+    /// Takes the given amount of the raw bytes.
+    fn bytes(&mut self, size: usize) -> Option<&[u8]> {
+        if self.0.len() < size {
+            return None;
+        }
+        let (bytes, rest) = self.0.split_at(size);
+        self.0 = rest;
+
+        Some(bytes)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
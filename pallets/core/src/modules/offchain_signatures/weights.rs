@@ -40,6 +40,25 @@ pub trait WeightInfo {
     fn remove_public_sr25519() -> Weight;
     fn remove_public_ed25519() -> Weight;
     fn remove_public_secp256k1() -> Weight;
+    fn revoke_public_sr25519() -> Weight;
+    fn revoke_public_ed25519() -> Weight;
+    fn revoke_public_secp256k1() -> Weight;
+    fn validate_params(b: u32) -> Weight;
+    fn validate_public_key(b: u32) -> Weight;
+    fn add_threshold_issuer_group_sr25519(p: u32) -> Weight;
+    fn add_threshold_issuer_group_ed25519(p: u32) -> Weight;
+    fn add_threshold_issuer_group_secp256k1(p: u32) -> Weight;
+    fn update_threshold_issuer_group_sr25519(p: u32) -> Weight;
+    fn update_threshold_issuer_group_ed25519(p: u32) -> Weight;
+    fn update_threshold_issuer_group_secp256k1(p: u32) -> Weight;
+    fn add_shared_params() -> Weight;
+    fn remove_shared_params() -> Weight;
+    fn share_params_sr25519() -> Weight;
+    fn share_params_ed25519() -> Weight;
+    fn share_params_secp256k1() -> Weight;
+    fn remove_threshold_issuer_group_sr25519(p: u32) -> Weight;
+    fn remove_threshold_issuer_group_ed25519(p: u32) -> Weight;
+    fn remove_threshold_issuer_group_secp256k1(p: u32) -> Weight;
 }
 
 /// Weights for bbs_plus using the Substrate node and recommended hardware.
@@ -121,6 +140,131 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(4_u64))
             .saturating_add(T::DbWeight::get().writes(2_u64))
     }
+    fn revoke_public_sr25519() -> Weight {
+        Weight::from_ref_time(60_118_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn revoke_public_ed25519() -> Weight {
+        Weight::from_ref_time(58_402_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn revoke_public_secp256k1() -> Weight {
+        Weight::from_ref_time(162_533_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn validate_params(b: u32) -> Weight {
+        Weight::from_ref_time(1_204_000_u64)
+            // Standard Error: 0
+            .saturating_add(Weight::from_ref_time(1_578_000_u64).saturating_mul(b as u64))
+    }
+    fn validate_public_key(b: u32) -> Weight {
+        Weight::from_ref_time(1_517_000_u64)
+            // Standard Error: 0
+            .saturating_add(Weight::from_ref_time(2_913_000_u64).saturating_mul(b as u64))
+    }
+    fn add_threshold_issuer_group_sr25519(p: u32) -> Weight {
+        Weight::from_ref_time(61_903_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().reads(3_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
+    fn add_threshold_issuer_group_ed25519(p: u32) -> Weight {
+        Weight::from_ref_time(60_874_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().reads(3_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
+    fn add_threshold_issuer_group_secp256k1(p: u32) -> Weight {
+        Weight::from_ref_time(164_215_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().reads(3_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
+    fn update_threshold_issuer_group_sr25519(p: u32) -> Weight {
+        Weight::from_ref_time(60_412_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64).saturating_mul(p as u64))
+    }
+    fn update_threshold_issuer_group_ed25519(p: u32) -> Weight {
+        Weight::from_ref_time(59_536_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64).saturating_mul(p as u64))
+    }
+    fn update_threshold_issuer_group_secp256k1(p: u32) -> Weight {
+        Weight::from_ref_time(162_957_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64).saturating_mul(p as u64))
+    }
+    fn add_shared_params() -> Weight {
+        Weight::from_ref_time(15_972_000_u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn remove_shared_params() -> Weight {
+        Weight::from_ref_time(13_476_000_u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn share_params_sr25519() -> Weight {
+        Weight::from_ref_time(54_218_000_u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn share_params_ed25519() -> Weight {
+        Weight::from_ref_time(51_907_000_u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn share_params_secp256k1() -> Weight {
+        Weight::from_ref_time(154_632_000_u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn remove_threshold_issuer_group_sr25519(p: u32) -> Weight {
+        Weight::from_ref_time(59_284_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
+    fn remove_threshold_issuer_group_ed25519(p: u32) -> Weight {
+        Weight::from_ref_time(57_625_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
+    fn remove_threshold_issuer_group_secp256k1(p: u32) -> Weight {
+        Weight::from_ref_time(161_804_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
 }
 
 // For backwards compatibility and tests
@@ -201,4 +345,129 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(4_u64))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
+    fn revoke_public_sr25519() -> Weight {
+        Weight::from_ref_time(60_118_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn revoke_public_ed25519() -> Weight {
+        Weight::from_ref_time(58_402_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn revoke_public_secp256k1() -> Weight {
+        Weight::from_ref_time(162_533_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn validate_params(b: u32) -> Weight {
+        Weight::from_ref_time(1_204_000_u64)
+            // Standard Error: 0
+            .saturating_add(Weight::from_ref_time(1_578_000_u64).saturating_mul(b as u64))
+    }
+    fn validate_public_key(b: u32) -> Weight {
+        Weight::from_ref_time(1_517_000_u64)
+            // Standard Error: 0
+            .saturating_add(Weight::from_ref_time(2_913_000_u64).saturating_mul(b as u64))
+    }
+    fn add_threshold_issuer_group_sr25519(p: u32) -> Weight {
+        Weight::from_ref_time(61_903_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().reads(3_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
+    fn add_threshold_issuer_group_ed25519(p: u32) -> Weight {
+        Weight::from_ref_time(60_874_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().reads(3_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
+    fn add_threshold_issuer_group_secp256k1(p: u32) -> Weight {
+        Weight::from_ref_time(164_215_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().reads(3_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
+    fn update_threshold_issuer_group_sr25519(p: u32) -> Weight {
+        Weight::from_ref_time(60_412_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64).saturating_mul(p as u64))
+    }
+    fn update_threshold_issuer_group_ed25519(p: u32) -> Weight {
+        Weight::from_ref_time(59_536_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64).saturating_mul(p as u64))
+    }
+    fn update_threshold_issuer_group_secp256k1(p: u32) -> Weight {
+        Weight::from_ref_time(162_957_000_u64)
+            // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(4_318_000_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64).saturating_mul(p as u64))
+    }
+    fn add_shared_params() -> Weight {
+        Weight::from_ref_time(15_972_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn remove_shared_params() -> Weight {
+        Weight::from_ref_time(13_476_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn share_params_sr25519() -> Weight {
+        Weight::from_ref_time(54_218_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn share_params_ed25519() -> Weight {
+        Weight::from_ref_time(51_907_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn share_params_secp256k1() -> Weight {
+        Weight::from_ref_time(154_632_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn remove_threshold_issuer_group_sr25519(p: u32) -> Weight {
+        Weight::from_ref_time(59_284_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
+    fn remove_threshold_issuer_group_ed25519(p: u32) -> Weight {
+        Weight::from_ref_time(57_625_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
+    fn remove_threshold_issuer_group_secp256k1(p: u32) -> Weight {
+        Weight::from_ref_time(161_804_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(p as u64))
+    }
 }
@@ -4,17 +4,67 @@ use crate::{
     did::{Did, DidSignature, UncheckedDidKey},
     util::{BoundedBytes, WithNonce},
 };
+use alloc::{format, string::String};
+use base64::Engine;
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 use sp_core::U256;
-use sp_runtime::traits::TryCollect;
 #[cfg(not(feature = "std"))]
 use sp_std::prelude::*;
 
-const MIN_CREDENTIAL_SIZE: u32 = 1000;
+const MIN_CREDENTIAL_SIZE: u32 = 2000;
 const MAX_CREDENTIAL_SIZE: u32 = 10_000;
 const MAX_POLICY_CONTROLLERS: u32 = 15;
 
+/// `base64url`-encoded `GZIP`-compressed bitstring inflating to `MAX_DECODED_LIST_SIZE` bytes,
+/// i.e. the most expensive `encodedList` to decode which fits into the smallest credential.
+fn worst_case_encoded_list() -> String {
+    let list = vec![0; validation::MAX_DECODED_LIST_SIZE];
+    let deflated = miniz_oxide::deflate::compress_to_vec(&list, 10);
+    let gzip = [
+        &[0x1f, 0x8b, 0x08, 0, 0, 0, 0, 0, 0x02, 0xff][..],
+        &deflated[..],
+        &validation::crc32(&list).to_le_bytes()[..],
+        &(list.len() as u32).to_le_bytes()[..],
+    ]
+    .concat();
+
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(gzip)
+}
+
+/// Encodes supplied `Did` as `did:dock:<SS58>`.
+fn did_uri(Did(did): Did) -> String {
+    let payload = [&[42u8][..], &did[..]].concat();
+    let checksum = sp_io::hashing::blake2_512(&[&b"SS58PRE"[..], &payload[..]].concat());
+
+    format!(
+        "did:dock:{}",
+        bs58::encode([&payload[..], &checksum[..2]].concat()).into_string()
+    )
+}
+
+/// Builds a `JSON-LD` status list credential having the worst-case `encodedList`, issued by the supplied `Did`
+/// and padded to have `size` bytes.
+fn credential_bytes<T: Config>(
+    credential_type: &str,
+    subject_type: &str,
+    issuer: Did,
+    size: u32,
+) -> BoundedBytes<T::MaxStatusListCredentialSize> {
+    let mut credential = format!(
+        r#"{{"@context":["https://www.w3.org/2018/credentials/v1"],"id":"https://example.com/status/1","type":["VerifiableCredential","{}"],"issuer":"{}","issuanceDate":"2021-04-05T14:27:40Z","credentialSubject":{{"id":"https://example.com/status/1#list","type":"{}","encodedList":"{}"}},"padding":""#,
+        credential_type,
+        did_uri(issuer),
+        subject_type,
+        worst_case_encoded_list()
+    );
+    let padding = (size as usize).saturating_sub(credential.len() + 2);
+    credential.extend(core::iter::repeat('a').take(padding));
+    credential.push_str(r#""}"#);
+
+    BoundedBytes(credential.into_bytes().try_into().unwrap())
+}
+
 crate::bench_with_all_pairs! {
     with_pairs:
     update_sr25519 for sr25519, update_ed25519 for ed25519, update_secp256k1 for secp256k1 {
@@ -34,17 +84,17 @@ crate::bench_with_all_pairs! {
 
         let id = [1u8; 32].into();
         let credential = StatusListCredentialWithPolicy {
-            status_list_credential: StatusListCredential::<T>::RevocationList2020Credential(BoundedBytes((0..r).map(|v| v as u8).try_collect().unwrap())),
+            status_list_credential: StatusListCredential::<T>::RevocationList2020Credential(credential_bytes::<T>("RevocationList2020Credential", "RevocationList2020", did, r)),
             policy: Policy::one_of((0..MAX_POLICY_CONTROLLERS).map(|i| U256::from(i).into()).map(Did)).unwrap()
         };
         AddStatusListCredential { id, credential }.modify_removable(super::Pallet::<T>::create_).unwrap();
 
-        let credential = StatusListCredential::<T>::StatusList2021Credential(BoundedBytes((0..r).map(|v| v as u8).try_collect().unwrap()));
+        let credential = StatusListCredential::<T>::StatusList2021Credential(credential_bytes::<T>("StatusList2021Credential", "StatusList2021", did, r));
         let update_credential_raw = UpdateStatusListCredentialRaw {
              /// Unique identifier of the underlying `StatusListCredential`
             id,
             /// The `StatusListCredential` itself
-            credential,
+            credential: credential.clone(),
             _marker: PhantomData
         };
         let update = WithNonce::new_with_nonce(update_credential_raw, 1u32.into());
@@ -54,7 +104,7 @@ crate::bench_with_all_pairs! {
     }: update(RawOrigin::Signed(caller), update.into_data(), vec![SignatureWithNonce::new(signature, 1u32.into())])
     verify {
         assert_eq!(StatusListCredentials::get(id).unwrap(), StatusListCredentialWithPolicy {
-            status_list_credential: StatusListCredential::<T>::StatusList2021Credential(BoundedBytes((0..r).map(|v| v as u8).try_collect().unwrap())),
+            status_list_credential: credential,
             policy: Policy::one_of((0..MAX_POLICY_CONTROLLERS).map(|i| U256::from(i).into()).map(Did)).unwrap()
         });
     }
@@ -73,7 +123,7 @@ crate::bench_with_all_pairs! {
 
         let id = [1u8; 32].into();
         let credential = StatusListCredentialWithPolicy {
-            status_list_credential: StatusListCredential::<T>::RevocationList2020Credential(BoundedBytes((0..MAX_CREDENTIAL_SIZE).map(|v| v as u8).try_collect().unwrap())),
+            status_list_credential: StatusListCredential::<T>::RevocationList2020Credential(credential_bytes::<T>("RevocationList2020Credential", "RevocationList2020", did, MAX_CREDENTIAL_SIZE)),
             policy: Policy::one_of((0..MAX_POLICY_CONTROLLERS).map(|i| U256::from(i).into()).map(Did)).unwrap()
         };
         AddStatusListCredential { id, credential }.modify_removable(super::Pallet::<T>::create_).unwrap();
@@ -101,15 +151,22 @@ crate::bench_with_all_pairs! {
 
         let id = [1u8; 32].into();
         let credential = StatusListCredentialWithPolicy {
-            status_list_credential: StatusListCredential::<T>::RevocationList2020Credential(BoundedBytes((0..r).map(|v| v as u8).try_collect().unwrap())),
+            status_list_credential: StatusListCredential::<T>::RevocationList2020Credential(credential_bytes::<T>("RevocationList2020Credential", "RevocationList2020", Did([0; Did::BYTE_SIZE]), r)),
             policy: Policy::one_of((0..c).map(|i| U256::from(i).into()).map(Did)).unwrap()
         };
 
-    }: create(RawOrigin::Signed(caller), id, credential)
+    }: create(RawOrigin::Signed(caller), id, credential.clone())
     verify {
-        assert_eq!(StatusListCredentials::<T>::get(id).unwrap(), StatusListCredentialWithPolicy {
-            status_list_credential: StatusListCredential::<T>::RevocationList2020Credential(BoundedBytes((0..r).map(|v| v as u8).try_collect().unwrap())),
-            policy: Policy::one_of((0..c).map(|i| U256::from(i).into()).map(Did)).unwrap()
-        });
+        assert_eq!(StatusListCredentials::<T>::get(id).unwrap(), credential);
+    }
+
+    validate_content {
+        let r in MIN_CREDENTIAL_SIZE .. MAX_CREDENTIAL_SIZE as u32;
+
+        // The issuer is the last controller, so all of them have to be checked.
+        let policy = Policy::<T>::one_of((0..MAX_POLICY_CONTROLLERS).map(|i| U256::from(i).into()).map(Did)).unwrap();
+        let credential = StatusListCredential::<T>::StatusList2021Credential(credential_bytes::<T>("StatusList2021Credential", "StatusList2021", Did(U256::from(MAX_POLICY_CONTROLLERS - 1).into()), r));
+    }: {
+        credential.ensure_valid_content(&policy).unwrap();
     }
 }
@@ -25,7 +25,7 @@ impl<T: Config> Pallet<T> {
         status_list_credential: &mut StatusListCredentialWithPolicy<T>,
         _: BTreeSet<PolicyExecutor>,
     ) -> DispatchResult {
-        credential.ensure_valid()?;
+        credential.ensure_valid_for(&status_list_credential.policy)?;
 
         status_list_credential.status_list_credential = credential;
 
@@ -1,6 +1,7 @@
 //! Storage for status list-related verifiable credentials:
 //! - [`RevocationList2020Credential`](https://w3c-ccg.github.io/vc-status-rl-2020/#revocationlist2020credential)
-//! - [`StatusList2021Credential`](https://www.w3.org/TR/vc-status-list/#statuslist2021credential).
+//! - [`StatusList2021Credential`](https://www.w3.org/TR/vc-status-list/#statuslist2021credential)
+//! - [`BitstringStatusListCredential`](https://www.w3.org/TR/vc-bitstring-status-list/#bitstringstatuslistcredential).
 use crate::{
     common::{signatures::ForSigType, PolicyExecutor, SignatureWithNonce},
     deposit_indexed_event,
@@ -21,6 +22,7 @@ mod weights;
 
 pub mod actions;
 pub mod types;
+pub mod validation;
 
 pub use actions::*;
 pub use pallet::*;
@@ -47,6 +49,24 @@ pub mod pallet {
         StatusListCredentialTooSmall,
         /// Action can't have an empty payload.
         EmptyPayload,
+        /// The `BitstringStatusListCredential`'s `statusSize` is either zero or exceeds the max allowed size.
+        InvalidStatusSize,
+        /// The `BitstringStatusListCredential`'s `statusSize` can't be used along with its `statusPurpose`.
+        StatusSizeDoesntMatchPurpose,
+        /// The `StatusListCredential` isn't a valid `JSON` object.
+        InvalidStatusListCredentialJson,
+        /// The `StatusListCredential`'s `type` doesn't contain the expected credential type.
+        InvalidStatusListCredentialType,
+        /// The `StatusListCredential`'s `credentialSubject.type` doesn't contain the expected type.
+        InvalidStatusListCredentialSubjectType,
+        /// The `StatusListCredential`'s `credentialSubject.encodedList` can't be decoded.
+        InvalidStatusListEncodedList,
+        /// The `StatusListCredential`'s `issuer` isn't a valid `did:dock` or `did:key` DID.
+        InvalidStatusListCredentialIssuer,
+        /// The `StatusListCredential`'s `issuer` isn't one of the `Policy` controllers.
+        StatusListCredentialIssuerIsNotController,
+        /// The `BitstringStatusListCredential`'s `credentialSubject.statusPurpose` differs from the supplied one.
+        StatusPurposeMismatch,
     }
 
     #[pallet::event]
@@ -68,12 +88,18 @@ pub mod pallet {
         type Event: From<Event>
             + IsType<<Self as frame_system::Config>::Event>
             + Into<<Self as frame_system::Config>::Event>;
+
+        /// Whether the content of the `StatusListCredential`s should be validated on create/update.
+        /// When enabled, the underlying credential must be a `JSON-LD` object of the expected type having
+        /// a decodable `encodedList` and issued by one of the `Policy` controllers.
+        type ValidateContent: Get<bool>;
     }
 
     /// Stores `StatusListCredential`s along with their modification policies.
     /// The credential itself is represented as a raw byte sequence and can be either
     /// - [`RevocationList2020Credential`](https://w3c-ccg.github.io/vc-status-rl-2020/#revocationlist2020credential)
     /// - [`StatusList2021Credential`](https://www.w3.org/TR/vc-status-list/#statuslist2021credential)
+    /// - [`BitstringStatusListCredential`](https://www.w3.org/TR/vc-bitstring-status-list/#bitstringstatuslistcredential)
     #[pallet::storage]
     #[pallet::getter(fn status_list_credential)]
     pub type StatusListCredentials<T> =
@@ -82,7 +108,7 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Associates a new `StatusListCredentialWithPolicy` with the supplied identifier.
-        /// This method doesn't ensure `StatusListCredential` is a valid `JSON-LD` object.
+        /// This method ensures `StatusListCredential` is a valid `JSON-LD` object only if `Config::ValidateContent` is enabled.
         #[pallet::weight(SubstrateWeight::<T>::create(credential))]
         pub fn create(
             origin: OriginFor<T>,
@@ -97,7 +123,7 @@ pub mod pallet {
         }
 
         /// Updates `StatusListCredential` associated with the supplied identifier.
-        /// This method doesn't ensure `StatusListCredential` is a valid `JSON-LD` object.
+        /// This method ensures `StatusListCredential` is a valid `JSON-LD` object only if `Config::ValidateContent` is enabled.
         #[pallet::weight(SubstrateWeight::<T>::update(&proof[0], update_credential))]
         pub fn update(
             origin: OriginFor<T>,
@@ -143,6 +169,7 @@ impl<T: Config> SubstrateWeight<T> {
         }: &StatusListCredentialWithPolicy<T>,
     ) -> Weight {
         <Self as WeightInfo>::create(status_list_credential.len(), policy.len())
+            .saturating_add(Self::content_validation(status_list_credential))
     }
 
     fn update(
@@ -154,6 +181,15 @@ impl<T: Config> SubstrateWeight<T> {
             || Self::update_ed25519(credential.len()),
             || Self::update_secp256k1(credential.len()),
         )
+        .saturating_add(Self::content_validation(credential))
+    }
+
+    fn content_validation(credential: &StatusListCredential<T>) -> Weight {
+        if T::ValidateContent::get() {
+            Self::validate_content(credential.len())
+        } else {
+            Weight::from_ref_time(0)
+        }
     }
 
     fn remove(
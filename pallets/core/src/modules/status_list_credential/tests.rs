@@ -2,8 +2,8 @@
 
 use super::*;
 use crate::{
-    common::{MultiSignedAction, Policy, ToStateChange},
-    did::Did,
+    common::{Limits, MultiSignedAction, Policy, ToStateChange},
+    did::{Did, DidMethodKey, DidOrDidMethodKey},
     tests::common::*,
     util::{Action, BoundedBytes, Types, WithNonce},
 };
@@ -224,6 +224,324 @@ fn create_status_list_credential() {
     });
 }
 
+/// Checks `statusPurpose`/`statusSize` validation of the `BitstringStatusListCredential`.
+#[test]
+fn create_bitstring_status_list_credential() {
+    ext().execute_with(|| {
+        let did = Did(random());
+        let policy = Policy::one_of([did]).unwrap();
+        let bitstring_credential = |status_purpose, status_size| {
+            StatusListCredential::<Test>::BitstringStatusListCredential(
+                BitstringStatusListCredential {
+                    status_purpose,
+                    status_size,
+                    credential: BoundedBytes((0..10).map(|v| v as u8).try_collect().unwrap()),
+                },
+            )
+        };
+
+        let cases = [
+            (
+                StatusPurpose::Revocation,
+                0,
+                Err(Error::<Test>::InvalidStatusSize),
+            ),
+            (
+                StatusPurpose::Message,
+                9,
+                Err(Error::<Test>::InvalidStatusSize),
+            ),
+            (
+                StatusPurpose::Revocation,
+                2,
+                Err(Error::<Test>::StatusSizeDoesntMatchPurpose),
+            ),
+            (
+                StatusPurpose::Suspension,
+                8,
+                Err(Error::<Test>::StatusSizeDoesntMatchPurpose),
+            ),
+            (
+                StatusPurpose::Message,
+                1,
+                Err(Error::<Test>::StatusSizeDoesntMatchPurpose),
+            ),
+            (
+                StatusPurpose::Refresh,
+                2,
+                Err(Error::<Test>::StatusSizeDoesntMatchPurpose),
+            ),
+            (StatusPurpose::Revocation, 1, Ok(())),
+            (StatusPurpose::Suspension, 1, Ok(())),
+            (StatusPurpose::Refresh, 1, Ok(())),
+            (StatusPurpose::Message, 2, Ok(())),
+            (StatusPurpose::Message, 8, Ok(())),
+        ];
+
+        for (status_purpose, status_size, expected) in cases {
+            let id = StatusListCredentialId(rand::random());
+            let credential = StatusListCredentialWithPolicy {
+                status_list_credential: bitstring_credential(status_purpose, status_size),
+                policy: policy.clone(),
+            };
+
+            match expected {
+                Ok(()) => {
+                    assert_ok!(Mod::create(Origin::signed(ABBA), id, credential.clone()));
+                    assert_eq!(Mod::status_list_credential(id).unwrap(), credential);
+                }
+                Err(err) => assert_noop!(Mod::create(Origin::signed(ABBA), id, credential), err),
+            }
+        }
+
+        assert_noop!(
+            Mod::create(
+                Origin::signed(ABBA),
+                StatusListCredentialId(rand::random()),
+                StatusListCredentialWithPolicy {
+                    status_list_credential: StatusListCredential::BitstringStatusListCredential(
+                        BitstringStatusListCredential {
+                            status_purpose: StatusPurpose::Revocation,
+                            status_size: 1,
+                            credential: BoundedBytes(
+                                (0..5).map(|v| v as u8).try_collect().unwrap()
+                            ),
+                        }
+                    ),
+                    policy,
+                }
+            ),
+            Error::<Test>::StatusListCredentialTooSmall
+        );
+    });
+}
+
+/// `base64url`-encoded `GZIP`-compressed 16KB bitstring.
+const ENCODED_LIST: &str = "H4sIAAAAAAACA-3BMQEAAADCoPVPbQwfoAAAAAAAAAAAAAAAAAAAAIC3AYbSVKsAQAAA";
+
+fn json_credential(
+    credential_type: &str,
+    subject_type: &str,
+    issuer: &str,
+    status_purpose: &str,
+    encoded_list: &str,
+) -> BoundedBytes<<Test as Limits>::MaxStatusListCredentialSize> {
+    let credential = format!(
+        r#"{{"@context":["https://www.w3.org/ns/credentials/v2"],"id":"https://example.com/status/1","type":["VerifiableCredential","{}"],"issuer":{},"credentialSubject":{{"id":"https://example.com/status/1#list","type":"{}","statusPurpose":"{}","encodedList":"{}"}}}}"#,
+        credential_type, issuer, subject_type, status_purpose, encoded_list
+    );
+
+    BoundedBytes(credential.into_bytes().try_into().unwrap())
+}
+
+/// Checks content validation of the `StatusListCredential`.
+#[test]
+fn validate_status_list_credential_content() {
+    ext().execute_with(|| {
+        use sp_core::{crypto::Ss58Codec, Pair};
+
+        ValidateStatusListCredentialContent::set(&true);
+
+        let did = Did(random());
+        let keypair = create_did(did);
+        let did_uri = format!(
+            r#""did:dock:{}""#,
+            sp_core::crypto::AccountId32::from(did.0).to_ss58check()
+        );
+        let did_key_pair = sp_core::ed25519::Pair::generate().0;
+        let did_method_key: DidMethodKey = did_key_pair.public().into();
+        let did_key_uri = format!(
+            r#"{{"id":"did:key:z{}"}}"#,
+            bs58::encode([&[0xed, 0x01][..], &did_key_pair.public().0[..]].concat()).into_string()
+        );
+        let policy = Policy::one_of([DidOrDidMethodKey::from(did), did_method_key.into()]).unwrap();
+
+        let bitstring = |status_purpose, credential| {
+            StatusListCredential::<Test>::BitstringStatusListCredential(
+                BitstringStatusListCredential {
+                    status_purpose,
+                    status_size: 1,
+                    credential,
+                },
+            )
+        };
+        let other_did_uri = format!(
+            r#""did:dock:{}""#,
+            sp_core::crypto::AccountId32::from(random::<[u8; 32]>()).to_ss58check()
+        );
+
+        let cases = [
+            (
+                StatusListCredential::StatusList2021Credential(BoundedBytes(
+                    (0..10).map(|v| v as u8).try_collect().unwrap(),
+                )),
+                Err(Error::<Test>::InvalidStatusListCredentialJson),
+            ),
+            (
+                StatusListCredential::StatusList2021Credential(json_credential(
+                    "RevocationList2020Credential",
+                    "StatusList2021",
+                    &did_uri,
+                    "revocation",
+                    ENCODED_LIST,
+                )),
+                Err(Error::<Test>::InvalidStatusListCredentialType),
+            ),
+            (
+                StatusListCredential::RevocationList2020Credential(json_credential(
+                    "RevocationList2020Credential",
+                    "StatusList2021",
+                    &did_uri,
+                    "revocation",
+                    ENCODED_LIST,
+                )),
+                Err(Error::<Test>::InvalidStatusListCredentialSubjectType),
+            ),
+            (
+                bitstring(
+                    StatusPurpose::Suspension,
+                    json_credential(
+                        "BitstringStatusListCredential",
+                        "BitstringStatusList",
+                        &did_uri,
+                        "revocation",
+                        ENCODED_LIST,
+                    ),
+                ),
+                Err(Error::<Test>::StatusPurposeMismatch),
+            ),
+            (
+                StatusListCredential::StatusList2021Credential(json_credential(
+                    "StatusList2021Credential",
+                    "StatusList2021",
+                    &did_uri,
+                    "revocation",
+                    "H4sIAAAAAAAAA",
+                )),
+                Err(Error::<Test>::InvalidStatusListEncodedList),
+            ),
+            (
+                // `CRC32` of the trailer doesn't match the decompressed bitstring.
+                StatusListCredential::StatusList2021Credential(json_credential(
+                    "StatusList2021Credential",
+                    "StatusList2021",
+                    &did_uri,
+                    "revocation",
+                    "H4sIAAAAAAACA-3BMQEAAADCoPVPbQwfoAAAAAAAAAAAAAAAAAAAAIC3AQAAAAAAQAAA",
+                )),
+                Err(Error::<Test>::InvalidStatusListEncodedList),
+            ),
+            (
+                // `ISIZE` of the trailer doesn't match the decompressed bitstring.
+                StatusListCredential::StatusList2021Credential(json_credential(
+                    "StatusList2021Credential",
+                    "StatusList2021",
+                    &did_uri,
+                    "revocation",
+                    "H4sIAAAAAAACA-3BMQEAAADCoPVPbQwfoAAAAAAAAAAAAAAAAAAAAIC3AYbSVKv_PwAA",
+                )),
+                Err(Error::<Test>::InvalidStatusListEncodedList),
+            ),
+            (
+                StatusListCredential::StatusList2021Credential(json_credential(
+                    "StatusList2021Credential",
+                    "StatusList2021",
+                    r#""did:example:123""#,
+                    "revocation",
+                    ENCODED_LIST,
+                )),
+                Err(Error::<Test>::InvalidStatusListCredentialIssuer),
+            ),
+            (
+                StatusListCredential::StatusList2021Credential(json_credential(
+                    "StatusList2021Credential",
+                    "StatusList2021",
+                    &other_did_uri,
+                    "revocation",
+                    ENCODED_LIST,
+                )),
+                Err(Error::<Test>::StatusListCredentialIssuerIsNotController),
+            ),
+            (
+                StatusListCredential::RevocationList2020Credential(json_credential(
+                    "RevocationList2020Credential",
+                    "RevocationList2020",
+                    &did_uri,
+                    "revocation",
+                    ENCODED_LIST,
+                )),
+                Ok(()),
+            ),
+            (
+                StatusListCredential::StatusList2021Credential(json_credential(
+                    "StatusList2021Credential",
+                    "StatusList2021",
+                    &did_key_uri,
+                    "revocation",
+                    &format!("u{}", ENCODED_LIST),
+                )),
+                Ok(()),
+            ),
+            (
+                bitstring(
+                    StatusPurpose::Suspension,
+                    json_credential(
+                        "BitstringStatusListCredential",
+                        "BitstringStatusList",
+                        &did_uri,
+                        "suspension",
+                        &format!("u{}", ENCODED_LIST),
+                    ),
+                ),
+                Ok(()),
+            ),
+        ];
+
+        for (status_list_credential, expected) in cases {
+            let id = StatusListCredentialId(rand::random());
+            let credential = StatusListCredentialWithPolicy {
+                status_list_credential: status_list_credential.clone(),
+                policy: policy.clone(),
+            };
+
+            match expected {
+                Ok(()) => {
+                    assert_ok!(Mod::create(Origin::signed(ABBA), id, credential));
+                }
+                Err(err) => {
+                    assert_noop!(Mod::create(Origin::signed(ABBA), id, credential), err);
+                    continue;
+                }
+            }
+
+            let update = UpdateStatusListCredentialRaw {
+                id,
+                credential: StatusListCredential::StatusList2021Credential(json_credential(
+                    "StatusList2021Credential",
+                    "StatusList2021",
+                    &did_uri,
+                    "revocation",
+                    "H4sIAAAAAAAAA",
+                )),
+                _marker: PhantomData,
+            };
+            let auth = get_pauth(&update, &[(did, &keypair)][..]);
+            assert_noop!(
+                Mod::update(Origin::signed(ABBA), update, auth),
+                Error::<Test>::InvalidStatusListEncodedList
+            );
+
+            let update = UpdateStatusListCredentialRaw {
+                id,
+                credential: status_list_credential,
+                _marker: PhantomData,
+            };
+            let auth = get_pauth(&update, &[(did, &keypair)][..]);
+            assert_ok!(Mod::update(Origin::signed(ABBA), update, auth));
+        }
+    });
+}
+
 /// Checks update mechanism of the `StatusListCredential`.
 #[test]
 fn update_status_list_credential() {
@@ -10,8 +10,9 @@ use sp_runtime::DispatchResult;
 
 use super::{Config, Error, StatusListCredentials};
 
-/// Either [`RevocationList2020Credential`](https://w3c-ccg.github.io/vc-status-rl-2020/#revocationlist2020credential)
-/// or [`StatusList2021Credential`](https://www.w3.org/TR/vc-status-list/#statuslist2021credential).
+/// Either [`RevocationList2020Credential`](https://w3c-ccg.github.io/vc-status-rl-2020/#revocationlist2020credential),
+/// [`StatusList2021Credential`](https://www.w3.org/TR/vc-status-list/#statuslist2021credential)
+/// or [`BitstringStatusListCredential`](https://www.w3.org/TR/vc-bitstring-status-list/#bitstringstatuslistcredential).
 /// The underlying verifiable credential is represented as a raw byte sequence.
 #[derive(
     Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, DebugNoBound, MaxEncodedLen,
@@ -29,6 +30,91 @@ pub enum StatusListCredential<T: Limits> {
     RevocationList2020Credential(BoundedBytes<T::MaxStatusListCredentialSize>),
     /// A verifiable credential that contains a status list as per https://www.w3.org/TR/vc-status-list/#statuslist2021credential.
     StatusList2021Credential(BoundedBytes<T::MaxStatusListCredentialSize>),
+    /// A verifiable credential that contains a bitstring status list as per https://www.w3.org/TR/vc-bitstring-status-list/#bitstringstatuslistcredential.
+    BitstringStatusListCredential(BitstringStatusListCredential<T>),
+}
+
+/// Purpose of the status list as per https://www.w3.org/TR/vc-bitstring-status-list/#bitstringstatuslistcredential.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(omit_prefix)]
+pub enum StatusPurpose {
+    /// Used to cancel the validity of a verifiable credential. This status is not reversible.
+    Revocation,
+    /// Used to temporarily prevent the acceptance of a verifiable credential. This status is reversible.
+    Suspension,
+    /// Used to indicate a status message associated with a verifiable credential.
+    Message,
+    /// Used to signal that an updated verifiable credential is available via its `refreshService`.
+    Refresh,
+}
+
+impl StatusPurpose {
+    /// Returns the `statusPurpose` value used in the credential.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Revocation => "revocation",
+            Self::Suspension => "suspension",
+            Self::Message => "message",
+            Self::Refresh => "refresh",
+        }
+    }
+}
+
+/// A [`BitstringStatusListCredential`](https://www.w3.org/TR/vc-bitstring-status-list/#bitstringstatuslistcredential)
+/// along with the `statusPurpose` and `statusSize` of its entries.
+/// The underlying verifiable credential is represented as a raw byte sequence.
+#[derive(
+    Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, DebugNoBound, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct BitstringStatusListCredential<T: Limits> {
+    /// Purpose of the status entries.
+    pub status_purpose: StatusPurpose,
+    /// Size of each status entry in bits.
+    pub status_size: u8,
+    /// The verifiable credential itself.
+    pub credential: BoundedBytes<T::MaxStatusListCredentialSize>,
+}
+
+impl<T: Limits> BitstringStatusListCredential<T> {
+    /// Max size of the status entry in bits. The specification doesn't bound `statusSize`, but a `message`
+    /// entry must come with a `statusMessage` listing each of its `2^statusSize` values, so entries are
+    /// limited to a single byte, i.e. up to 256 status messages, which keeps such a list within a credential.
+    pub const MAX_STATUS_SIZE: u8 = 8;
+
+    /// Ensures that `statusSize` is valid for the given `statusPurpose`.
+    /// `revocation`, `suspension` and `refresh` entries are single-bit flags, while `message`
+    /// entries must be able to hold more than one status.
+    pub fn ensure_valid(&self) -> Result<(), Error<T>>
+    where
+        T: Config,
+    {
+        ensure!(
+            (1..=Self::MAX_STATUS_SIZE).contains(&self.status_size),
+            Error::InvalidStatusSize
+        );
+
+        let size_matches_purpose = match self.status_purpose {
+            StatusPurpose::Revocation | StatusPurpose::Suspension | StatusPurpose::Refresh => {
+                self.status_size == 1
+            }
+            StatusPurpose::Message => self.status_size > 1,
+        };
+        ensure!(size_matches_purpose, Error::StatusSizeDoesntMatchPurpose);
+
+        Ok(())
+    }
 }
 
 impl<T: Limits> StatusListCredential<T> {
@@ -37,6 +123,10 @@ impl<T: Limits> StatusListCredential<T> {
         match self {
             Self::RevocationList2020Credential(bytes) => bytes,
             Self::StatusList2021Credential(bytes) => bytes,
+            Self::BitstringStatusListCredential(BitstringStatusListCredential {
+                credential,
+                ..
+            }) => credential,
         }
     }
 
@@ -50,7 +140,8 @@ impl<T: Limits> StatusListCredential<T> {
         self.len() == 0
     }
 
-    /// Ensures that byte length is valid.
+    /// Ensures that byte length is valid along with the `statusPurpose`/`statusSize`
+    /// of the `BitstringStatusListCredential`.
     pub fn ensure_valid(&self) -> Result<(), Error<T>>
     where
         T: Config,
@@ -60,6 +151,25 @@ impl<T: Limits> StatusListCredential<T> {
             Error::StatusListCredentialTooSmall
         );
 
+        if let Self::BitstringStatusListCredential(credential) = self {
+            credential.ensure_valid()?;
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that the credential is valid and, if `Config::ValidateContent` is enabled,
+    /// that its content is a valid status list credential issued by one of the `Policy` controllers.
+    pub fn ensure_valid_for(&self, policy: &Policy<T>) -> Result<(), Error<T>>
+    where
+        T: Config,
+    {
+        self.ensure_valid()?;
+
+        if T::ValidateContent::get() {
+            self.ensure_valid_content(policy)?;
+        }
+
         Ok(())
     }
 }
@@ -99,7 +209,7 @@ impl<T: Limits> StatusListCredentialWithPolicy<T> {
         self.policy
             .ensure_valid()
             .map_err(IntermediateError::<T>::from)?;
-        self.status_list_credential.ensure_valid()?;
+        self.status_list_credential.ensure_valid_for(&self.policy)?;
 
         Ok(())
     }
@@ -0,0 +1,228 @@
+//! Content validation of the `StatusListCredential`s.
+//! Works in both `std` and `no_std` environments, so it can be performed by the runtime
+//! before the credential gets stored.
+use super::{BitstringStatusListCredential, Config, Error, StatusListCredential};
+use crate::{
+    common::{Limits, Policy},
+    did::{Did, DidMethodKey, DidOrDidMethodKey},
+    util::{Bytes32, Bytes33},
+};
+use alloc::vec::Vec;
+use base64::{
+    alphabet,
+    engine::{
+        general_purpose::{GeneralPurpose, GeneralPurposeConfig},
+        DecodePaddingMode,
+    },
+    Engine,
+};
+use frame_support::ensure;
+use serde_json::Value;
+
+/// Max byte size of the decompressed `encodedList`.
+pub const MAX_DECODED_LIST_SIZE: usize = 1 << 20;
+
+/// `base64url` engine accepting both padded and unpadded input.
+const BASE64_URL: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+impl<T: Limits> StatusListCredential<T> {
+    /// Returns expected `type` of the credential and `type` of its `credentialSubject`.
+    pub fn expected_types(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::RevocationList2020Credential(_) => {
+                ("RevocationList2020Credential", "RevocationList2020")
+            }
+            Self::StatusList2021Credential(_) => ("StatusList2021Credential", "StatusList2021"),
+            Self::BitstringStatusListCredential(_) => {
+                ("BitstringStatusListCredential", "BitstringStatusList")
+            }
+        }
+    }
+
+    /// Ensures that the underlying raw bytes represent a `JSON-LD` status list credential
+    /// of the expected type having a decodable `encodedList` and issued by one of the `Policy` controllers.
+    pub fn ensure_valid_content(&self, policy: &Policy<T>) -> Result<(), Error<T>>
+    where
+        T: Config,
+    {
+        let credential: Value = serde_json::from_slice(self.bytes())
+            .map_err(|_| Error::<T>::InvalidStatusListCredentialJson)?;
+        let (credential_type, subject_type) = self.expected_types();
+        let subject = &credential["credentialSubject"];
+
+        ensure!(
+            contains_str(&credential["type"], credential_type),
+            Error::<T>::InvalidStatusListCredentialType
+        );
+        ensure!(
+            contains_str(&subject["type"], subject_type),
+            Error::<T>::InvalidStatusListCredentialSubjectType
+        );
+        if let Self::BitstringStatusListCredential(BitstringStatusListCredential {
+            status_purpose,
+            ..
+        }) = self
+        {
+            ensure!(
+                contains_str(&subject["statusPurpose"], status_purpose.as_str()),
+                Error::<T>::StatusPurposeMismatch
+            );
+        }
+
+        subject["encodedList"]
+            .as_str()
+            .and_then(decode_encoded_list)
+            .ok_or(Error::<T>::InvalidStatusListEncodedList)?;
+
+        let issuer = &credential["issuer"];
+        let issuer = issuer
+            .as_str()
+            .or_else(|| issuer["id"].as_str())
+            .and_then(parse_did)
+            .ok_or(Error::<T>::InvalidStatusListCredentialIssuer)?;
+        let Policy::OneOf(controllers) = policy;
+        ensure!(
+            controllers.contains(&issuer),
+            Error::<T>::StatusListCredentialIssuerIsNotController
+        );
+
+        Ok(())
+    }
+}
+
+/// Returns `true` if the supplied value is either the expected string or an array containing it.
+fn contains_str(value: &Value, expected: &str) -> bool {
+    match value {
+        Value::String(value) => value == expected,
+        Value::Array(values) => values.iter().any(|value| value.as_str() == Some(expected)),
+        _ => false,
+    }
+}
+
+/// Decodes `base64url`-encoded (optionally with the `u` multibase prefix) `GZIP`-compressed bitstring.
+pub fn decode_encoded_list(encoded_list: &str) -> Option<Vec<u8>> {
+    // `GZIP` magic bytes are always encoded as `H4sI`, so `u` can only be a multibase prefix.
+    let encoded_list = encoded_list.strip_prefix('u').unwrap_or(encoded_list);
+    let compressed = BASE64_URL.decode(encoded_list).ok()?;
+
+    gunzip(&compressed)
+}
+
+/// Decompresses a single `GZIP` member as per https://www.rfc-editor.org/rfc/rfc1952.
+fn gunzip(bytes: &[u8]) -> Option<Vec<u8>> {
+    const FHCRC: u8 = 1 << 1;
+    const FEXTRA: u8 = 1 << 2;
+    const FNAME: u8 = 1 << 3;
+    const FCOMMENT: u8 = 1 << 4;
+
+    let (header, mut rest) = (bytes.get(..10)?, bytes.get(10..)?);
+    if header[..3] != [0x1f, 0x8b, 0x08] {
+        return None;
+    }
+    let flags = header[3];
+
+    if flags & FEXTRA != 0 {
+        let len = u16::from_le_bytes([*rest.first()?, *rest.get(1)?]) as usize;
+        rest = rest.get(2 + len..)?;
+    }
+    for flag in [FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            let end = rest.iter().position(|&byte| byte == 0)?;
+            rest = &rest[end + 1..];
+        }
+    }
+    if flags & FHCRC != 0 {
+        rest = rest.get(2..)?;
+    }
+
+    // Trailer consists of `CRC32` and `ISIZE`, 4 bytes each.
+    let (deflated, trailer) = rest.split_at(rest.len().checked_sub(8)?);
+    let crc = u32::from_le_bytes(trailer[..4].try_into().ok()?);
+    let size = u32::from_le_bytes(trailer[4..].try_into().ok()?);
+    let inflated =
+        miniz_oxide::inflate::decompress_to_vec_with_limit(deflated, MAX_DECODED_LIST_SIZE).ok()?;
+
+    // `ISIZE` is the size of the original input modulo `2^32` which can't be exceeded due to the limit.
+    if inflated.is_empty() || inflated.len() as u32 != size || crc32(&inflated) != crc {
+        None
+    } else {
+        Some(inflated)
+    }
+}
+
+/// `CRC32` lookup table for the reversed polynomial `0xEDB88320` used by `GZIP`.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut idx = 0;
+    while idx < 256 {
+        let mut crc = idx as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[idx] = crc;
+        idx += 1;
+    }
+
+    table
+};
+
+/// Computes `CRC32` of the supplied bytes as per https://www.rfc-editor.org/rfc/rfc1952#section-8.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| {
+        CRC32_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Parses either `did:dock:<SS58>` or `did:key:z<base58btc>`.
+pub fn parse_did(did: &str) -> Option<DidOrDidMethodKey> {
+    if let Some(ss58) = did.strip_prefix("did:dock:") {
+        parse_ss58(ss58).map(Did).map(Into::into)
+    } else if let Some(key) = did.strip_prefix("did:key:z") {
+        let bytes = bs58::decode(key).into_vec().ok()?;
+
+        let did_method_key = match bytes.as_slice() {
+            // `ed25519-pub` multicodec.
+            [0xed, 0x01, key @ ..] => DidMethodKey::Ed25519(Bytes32(key.try_into().ok()?)),
+            // `secp256k1-pub` multicodec.
+            [0xe7, 0x01, key @ ..] => DidMethodKey::Secp256k1(Bytes33(key.try_into().ok()?)),
+            _ => return None,
+        };
+
+        Some(did_method_key.into())
+    } else {
+        None
+    }
+}
+
+/// Decodes `SS58`-encoded 32-byte public key ignoring the network prefix.
+fn parse_ss58(ss58: &str) -> Option<[u8; 32]> {
+    const CHECKSUM_PREFIX: &[u8] = b"SS58PRE";
+    const CHECKSUM_LEN: usize = 2;
+
+    let data = bs58::decode(ss58).into_vec().ok()?;
+    let prefix_len = match data.first()? {
+        0..=63 => 1,
+        64..=127 => 2,
+        _ => return None,
+    };
+    if data.len() != prefix_len + 32 + CHECKSUM_LEN {
+        return None;
+    }
+
+    let (payload, checksum) = data.split_at(data.len() - CHECKSUM_LEN);
+    let hash = sp_io::hashing::blake2_512(&[CHECKSUM_PREFIX, payload].concat());
+    if hash[..CHECKSUM_LEN] != *checksum {
+        return None;
+    }
+
+    payload[prefix_len..].try_into().ok()
+}
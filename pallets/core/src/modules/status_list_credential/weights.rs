@@ -35,6 +35,7 @@ pub trait WeightInfo {
     fn remove_ed25519() -> Weight;
     fn remove_secp256k1() -> Weight;
     fn create(r: u32, c: u32) -> Weight;
+    fn validate_content(r: u32) -> Weight;
 }
 
 /// Weights for status_list_credential using the Substrate node and recommended hardware.
@@ -79,6 +80,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(2))
     }
+    fn validate_content(r: u32) -> Weight {
+        Weight::from_ref_time(3_794_344_000) // Standard Error: 0
+            .saturating_add(Weight::from_ref_time(1_000).saturating_mul(r as u64))
+    }
 }
 
 // For backwards compatibility and tests
@@ -122,4 +127,8 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(2))
             .saturating_add(RocksDbWeight::get().writes(2))
     }
+    fn validate_content(r: u32) -> Weight {
+        Weight::from_ref_time(3_794_344_000) // Standard Error: 0
+            .saturating_add(Weight::from_ref_time(1_000).saturating_mul(r as u64))
+    }
 }
@@ -1,9 +1,13 @@
 use super::*;
 use crate::{
+    blob::BlobId,
     impl_action, impl_action_with_nonce,
     util::{Bytes, Types, WithNonce},
 };
-use alloc::{collections::BTreeSet, string::String};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+};
 use frame_support::{CloneNoBound, DebugNoBound, EqNoBound, PartialEqNoBound};
 
 #[derive(
@@ -125,6 +129,240 @@ pub struct SetParticipantInformationRaw<T: Types> {
     pub _marker: PhantomData<T>,
 }
 
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct RemoveTrustRegistry<T: Types> {
+    pub registry_id: TrustRegistryId,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct TransferTrustRegistry<T: Types> {
+    pub registry_id: TrustRegistryId,
+    /// `Convener` that will own the registry after accepting the transfer.
+    pub new_convener: Convener,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct AcceptTrustRegistryTransfer<T: Types> {
+    pub registry_id: TrustRegistryId,
+    /// `Convener` the registry is being transferred to.
+    pub new_convener: Convener,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct SetFeePayoutAccount<T: Types> {
+    pub did: ConvenerOrIssuerOrVerifier,
+    /// Account to receive verification fees, `None` removes the existing one.
+    pub account: Option<T::AccountId>,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct SettleVerificationFee<T: Types> {
+    pub registry_id: TrustRegistryId,
+    pub schema_id: TrustRegistrySchemaId,
+    pub issuer: Issuer,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct SetIssuersAccreditation<T: Types> {
+    pub registry_id: TrustRegistryId,
+    pub schema_id: TrustRegistrySchemaId,
+    /// Accreditation periods of the `Issuer`s, `None` makes the `Issuer` accredited indefinitely.
+    pub accreditations: UnboundedIssuersWith<Option<IssuerAccreditation<T>>>,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct UpdateRecognizedRegistries<T: Types> {
+    pub registry_id: TrustRegistryId,
+    /// Registries to be recognized (or no longer recognized) by the registry.
+    pub registries: RegistryIdUpdate,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct UpdateRecognitionAcceptances<T: Types> {
+    pub registry_id: TrustRegistryId,
+    /// Registries whose recognition of the registry is accepted (or no longer accepted).
+    pub recognizers: RegistryIdUpdate,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct RequestParticipation<T: Types> {
+    pub registry_id: TrustRegistryId,
+    pub participant: IssuerOrVerifier,
+    /// Information to be set for the participant once the request is approved.
+    pub information: UnboundedTrustRegistryParticipantInformation,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct ResolveParticipationRequests<T: Types> {
+    pub registry_id: TrustRegistryId,
+    /// Decisions on the pending participation requests keyed by the requesting participants.
+    pub resolutions: BTreeMap<IssuerOrVerifier, ParticipationRequestResolution>,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct AddSchemaDefinition<T: Types> {
+    pub schema_id: TrustRegistrySchemaId,
+    /// URI of the JSON Schema document.
+    pub json_schema: String,
+    /// URI of the credential type issued under the schema.
+    pub credential_type: String,
+    /// Hash of the JSON Schema document.
+    pub content_hash: SchemaContentHash,
+    /// `Blob` holding the JSON Schema document on-chain.
+    pub blob_id: Option<BlobId>,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct AddGovFrameworkVersion<T: Types> {
+    pub registry_id: TrustRegistryId,
+    /// Hash of the governance framework document.
+    pub hash: GovFrameworkHash,
+    /// URI the governance framework document can be retrieved from.
+    pub uri: String,
+    /// Block starting from which the version is in force.
+    pub effective_at: T::BlockNumber,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct UpdateDelegations<T: Types> {
+    pub registry_id: TrustRegistryId,
+    /// Scopes of the delegations to be set for the delegated `Issuer`s. `None` revokes the delegation.
+    pub delegations: BTreeMap<Issuer, Option<UnboundedDelegationScope>>,
+    pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, DebugNoBound, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct RevokeDelegations<T: Types> {
+    pub registry_id: TrustRegistryId,
+    /// Delegated `Issuer`s to be revoked for each of the delegating `Issuer`s.
+    pub delegations: BTreeMap<Issuer, BTreeSet<Issuer>>,
+    pub nonce: T::BlockNumber,
+}
+
 pub type ChangeParticipants<T> = WithNonce<T, ChangeParticipantsRaw<T>>;
 pub type SetParticipantInformation<T> = WithNonce<T, SetParticipantInformationRaw<T>>;
 
@@ -144,7 +382,37 @@ impl_action_with_nonce!(
         InitOrUpdateTrustRegistry with 1 as len, registry_id as target,
         SetSchemasMetadata with { |this: &Self| match &this.schemas { SetOrModify::Set(_) => 1, SetOrModify::Modify(update) => update.len() } } as len, registry_id as target,
         SuspendIssuers with issuers.len() as len, registry_id as target,
-        UnsuspendIssuers with issuers.len() as len, registry_id as target
+        UnsuspendIssuers with issuers.len() as len, registry_id as target,
+        RemoveTrustRegistry with 1 as len, registry_id as target,
+        TransferTrustRegistry with 1 as len, registry_id as target,
+        SettleVerificationFee with 1 as len, registry_id as target,
+        SetIssuersAccreditation with accreditations.len() as len, registry_id as target,
+        UpdateRecognizedRegistries with registries.len() as len, registry_id as target,
+        UpdateRecognitionAcceptances with recognizers.len() as len, registry_id as target,
+        ResolveParticipationRequests with resolutions.len() as len, registry_id as target,
+        AddGovFrameworkVersion with 1 as len, registry_id as target,
+        UpdateDelegations with delegations.len() as len, registry_id as target,
+        RevokeDelegations with delegations.len() as len, registry_id as target
+);
+
+impl_action_with_nonce!(
+    for (TrustRegistryId, IssuerOrVerifier):
+        RequestParticipation with 1 as len, { |this: &Self| (this.registry_id, this.participant) } as target
+);
+
+impl_action_with_nonce!(
+    for TrustRegistrySchemaId:
+        AddSchemaDefinition with 1 as len, schema_id as target
+);
+
+impl_action_with_nonce!(
+    for ConvenerOrIssuerOrVerifier:
+        SetFeePayoutAccount with 1 as len, did as target
+);
+
+impl_action_with_nonce!(
+    for Convener:
+        AcceptTrustRegistryTransfer with 1 as len, new_convener as target
 );
 
 impl_action_with_nonce!(
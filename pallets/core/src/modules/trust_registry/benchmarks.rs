@@ -8,9 +8,13 @@ use crate::{
 };
 use alloc::collections::{BTreeMap, BTreeSet};
 use core::iter::{empty, once};
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 use scale_info::prelude::string::String;
+use sp_runtime::{
+    traits::{Bounded, Zero},
+    SaturatedConversion,
+};
 #[cfg(not(feature = "std"))]
 use sp_std::prelude::*;
 
@@ -26,6 +30,128 @@ const TRUST_REGISTRY_PARTICIPANTS: u32 = 100;
 const TRUST_REGISTRY_PARTICIPANT_ORG_NAME: u32 = 100;
 const TRUST_REGISTRY_PARTICIPANT_LOGO: u32 = 100;
 const TRUST_REGISTRY_PARTICIPANT_DESCRIPTION: u32 = 100;
+const RECOGNIZED_REGISTRIES: u32 = 50;
+const PARTICIPATION_REQUESTS: u32 = 50;
+const SCHEMA_DEFINITION_URIS: u32 = 200;
+const GOV_FRAMEWORK_VERSIONS: u32 = 100;
+const CHAINED_DELEGATIONS: u32 = 110;
+
+/// Stores definitions of the schemas with the supplied ids.
+fn define_schemas<T: Config>(
+    author: Convener,
+    ids: impl IntoIterator<Item = TrustRegistrySchemaId>,
+) {
+    for schema_id in ids {
+        TrustRegistrySchemaDefinitions::<T>::insert(
+            schema_id,
+            TrustRegistrySchemaDefinition::<T> {
+                author,
+                json_schema: String::from("https://schema.dock.io/Credential.json")
+                    .try_into()
+                    .unwrap(),
+                credential_type: String::from(
+                    "https://www.w3.org/2018/credentials#VerifiableCredential",
+                )
+                .try_into()
+                .unwrap(),
+                content_hash: SchemaContentHash([1; 32]),
+                blob_id: None,
+            },
+        );
+    }
+}
+
+/// Builds a participation request of the max size with the deposit reserved from a funded account.
+fn participation_request<T: Config>(
+    idx: u32,
+    expires_at: T::BlockNumber,
+) -> ParticipationRequest<T> {
+    let depositor: T::AccountId = account("depositor", idx, 0);
+    let deposit = T::ParticipationRequestDeposit::get();
+    T::Currency::make_free_balance_be(&depositor, BalanceOf::<T>::max_value() / 2u32.into());
+    T::Currency::reserve(&depositor, deposit).unwrap();
+
+    ParticipationRequest {
+        information: TrustRegistryStoredParticipantInformation {
+            org_name: (0..TRUST_REGISTRY_PARTICIPANT_ORG_NAME)
+                .map(|c| c as u8 as char)
+                .collect::<String>()
+                .try_into()
+                .unwrap(),
+            logo: (0..TRUST_REGISTRY_PARTICIPANT_LOGO)
+                .map(|c| c as u8 as char)
+                .collect::<String>()
+                .try_into()
+                .unwrap(),
+            description: (0..TRUST_REGISTRY_PARTICIPANT_DESCRIPTION)
+                .map(|c| c as u8 as char)
+                .collect::<String>()
+                .try_into()
+                .unwrap(),
+        },
+        expires_at,
+        depositor,
+        deposit: deposit.saturated_into(),
+    }
+}
+
+/// Adds up to `delegations` scoped delegations of all schemas forming the chains above the `Issuer`.
+/// Each `Issuer` is delegated by `T::MaxDelegatedIssuers` `Issuer`s of the level above which are followed
+/// while looking for the `Issuer`'s delegatable schemas, i.e. up to `T::MaxDelegationDepth - 1` levels.
+fn add_delegation_chains<T: Config>(
+    registry_id: TrustRegistryId,
+    issuer: Issuer,
+    delegations: u32,
+    schemas: &BTreeSet<TrustRegistrySchemaId>,
+) {
+    let mut added = 0u32;
+    let mut level = vec![issuer];
+
+    for _ in 1..T::MaxDelegationDepth::get() {
+        let mut next_level = Vec::new();
+
+        for delegated_issuer in level {
+            let count = T::MaxDelegatedIssuers::get().min(delegations - added);
+            let delegators: BTreeSet<_> = (added..added + count)
+                .map(|idx| {
+                    let mut raw = [0xde; Did::BYTE_SIZE];
+                    raw[..4].copy_from_slice(&idx.to_be_bytes());
+
+                    Issuer(Did(raw).into())
+                })
+                .collect();
+            added += count;
+
+            for delegator in &delegators {
+                TrustRegistryDelegationScopes::<T>::insert(
+                    registry_id,
+                    (*delegator, delegated_issuer),
+                    DelegationScope::<T> {
+                        schemas: None,
+                        max_depth: None,
+                    },
+                );
+                TrustRegistryIssuerSchemas::<T>::insert(
+                    registry_id,
+                    *delegator,
+                    IssuerSchemas(schemas.clone().try_into().unwrap()),
+                );
+            }
+            TrustRegistryIssuerDelegators::<T>::insert(
+                registry_id,
+                delegated_issuer,
+                IssuerDelegators(delegators.clone().try_into().unwrap()),
+            );
+            next_level.extend(delegators);
+
+            if added == delegations {
+                return;
+            }
+        }
+
+        level = next_level;
+    }
+}
 
 crate::bench_with_all_pairs! {
     with_pairs:
@@ -114,6 +240,7 @@ crate::bench_with_all_pairs! {
             .execute(|action, set, _: BTreeSet<ConvenerOrIssuerOrVerifier>| Pallet::<T>::change_participants_(action, set, participants.keys().copied().map(|did| ConvenerOrIssuerOrVerifier(*did)).collect()), |_| None)
             .unwrap();
 
+        define_schemas::<T>(Convener(did.into()), (0..s).map(|idx| TrustRegistrySchemaId([idx as u8; 32])));
         let mut schemas: BTreeMap<_, _> = (0..s)
             .map(|idx|
                 (
@@ -386,9 +513,941 @@ crate::bench_with_all_pairs! {
                 .filter(|(_, issuer, _)| unsuspend_issuers.issuers.contains(issuer))
                 .all(|(_, _, config)| !config.suspended)
         );
-    };
+    }
+
+    remove_trust_registry_sr25519 for sr25519, remove_trust_registry_ed25519 for ed25519, remove_trust_registry_secp256k1 for secp256k1 {
+        {
+            let i in 0 .. SCHEMA_ISSUERS as u32;
+            let v in 0 .. SCHEMA_VERIFIERS as u32;
+            let s in 0 .. SCHEMAS_COUNT as u32;
+            let p in 0 .. TRUST_REGISTRY_PARTICIPANTS as u32;
+            let a in 0 .. SCHEMA_ISSUERS as u32;
+            let r in 0 .. RECOGNIZED_REGISTRIES as u32;
+            let q in 0 .. PARTICIPATION_REQUESTS as u32;
+            let g in 0 .. GOV_FRAMEWORK_VERSIONS as u32;
+            let d in 0 .. DELEGATED_ISSUERS as u32;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>().try_into().unwrap()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(
+            1u32.into(),
+            Convener(did.into()),
+            init_or_update_trust_registry.clone()
+        ).modify::<T, _, _, _, _>(
+            |action, set| action.action.modify_removable(|action, info| Pallet::<T>::init_or_update_trust_registry_(
+                action,
+                set,
+                info,
+                Convener(did.into())
+            ).map_err(IntermediateError::<T>::from))
+        ).unwrap();
+
+        let participants: MultiTargetUpdate<_, _> = (0..i.max(v)).map(|idx| idx as u8)
+            .chain((0..p).map(|idx| 100 + idx as u8))
+            .map(|v| IssuerOrVerifier(Did([v; 32]).into()))
+            .map(|participant| (participant, AddOrRemoveOrModify::Add(())))
+            .collect();
+
+        let action = ChangeParticipantsRaw {
+            registry_id: TrustRegistryIdForParticipants(TrustRegistryId(id)),
+            participants: participants.clone(),
+            _marker: PhantomData::<T>,
+        };
+        MultiSignedAction::new(action, empty::<SignatureWithNonce<T::BlockNumber, DidOrDidMethodKeySignature<ConvenerOrIssuerOrVerifier>>>())
+            .execute(|action, set, _: BTreeSet<ConvenerOrIssuerOrVerifier>| Pallet::<T>::change_participants_(action, set, participants.keys().copied().map(|did| ConvenerOrIssuerOrVerifier(*did)).collect()), |_| None)
+            .unwrap();
+
+        define_schemas::<T>(Convener(did.into()), (0..s).map(|idx| TrustRegistrySchemaId([idx as u8; 32])));
+        let schemas: MultiTargetUpdate<_, _> = (0..s)
+            .map(|idx|
+                (
+                    TrustRegistrySchemaId([idx as u8; 32]),
+                    SetOrAddOrRemoveOrModify::Set(UnboundedTrustRegistrySchemaMetadata {
+                        issuers: UnboundedIssuersWith((0..i).map(|idx|
+                            (
+                                Issuer(Did([idx as u8; 32]).into()),
+                                UnboundedVerificationPrices(
+                                    once((String::from("USD"), VerificationPrice(1000))).collect()
+                                )
+                            )
+                        ).collect()),
+                        verifiers: UnboundedTrustRegistrySchemaVerifiers(
+                            (0..v)
+                                .map(|idx| Verifier(Did([idx as u8; 32]).into())
+                        ).collect())
+                    })
+                )
+            ).collect();
+
+        SetSchemasMetadata {
+            registry_id: TrustRegistryId(id),
+            schemas: SetOrModify::Modify(schemas),
+            nonce: 2u32.into()
+        }.view(|action, set| Pallet::<T>::set_schemas_metadata_(action, set, ConvenerOrIssuerOrVerifier(did.into()))).unwrap();
+
+        for idx in 0..a {
+            let key = (TrustRegistrySchemaId([200; 32]), Issuer(Did([idx as u8; 32]).into()));
+            let valid_until = T::BlockNumber::from(1_000u32 + idx);
+
+            TrustRegistryIssuerAccreditations::<T>::insert(
+                TrustRegistryId(id),
+                key,
+                IssuerAccreditation { valid_from: None, valid_until: Some(valid_until) }
+            );
+        }
+
+        for idx in 0..r {
+            let other_id = TrustRegistryId([100 + idx as u8; 32]);
+
+            TrustRegistryRecognitions::<T>::mutate(TrustRegistryId(id), |recognized| recognized.try_insert(other_id).unwrap());
+            TrustRegistryRecognitionAcceptances::<T>::insert(other_id, TrustRegistryId(id), ());
+            TrustRegistryRecognitions::<T>::mutate(other_id, |recognized| recognized.try_insert(TrustRegistryId(id)).unwrap());
+            TrustRegistryRecognitionAcceptances::<T>::insert(TrustRegistryId(id), other_id, ());
+        }
+
+        for idx in 0..q {
+            let participant = IssuerOrVerifier(Did([200 + idx as u8; 32]).into());
+
+            TrustRegistryParticipationRequests::<T>::insert(
+                TrustRegistryId(id),
+                participant,
+                participation_request::<T>(idx, T::BlockNumber::from(1_000u32 + idx))
+            );
+        }
+        TrustRegistryParticipationRequestsCount::<T>::insert(TrustRegistryId(id), q);
+
+        // Initialization already recorded the first governance framework version.
+        let versions = TrustRegistryGovFrameworkVersionsCount::<T>::get(TrustRegistryId(id));
+        for version in versions..g.max(versions) {
+            TrustRegistryGovFrameworkVersions::<T>::insert(TrustRegistryId(id), version, GovFrameworkVersion {
+                hash: Some(GovFrameworkHash([version as u8; 32])),
+                uri: vec![1; TRUST_REGISTRY_GOV_FRAMEWORK as usize].try_into().unwrap(),
+                effective_at: 1u32.into()
+            });
+        }
+        TrustRegistryGovFrameworkVersionsCount::<T>::insert(TrustRegistryId(id), g.max(versions));
+
+        for idx in 0..d {
+            let (delegator, delegate) = (Issuer(Did([idx as u8; 32]).into()), Issuer(Did([150 + idx as u8; 32]).into()));
+
+            TrustRegistryDelegationScopes::<T>::insert(TrustRegistryId(id), (delegator, delegate), DelegationScope {
+                schemas: None,
+                max_depth: None
+            });
+            TrustRegistryIssuerDelegators::<T>::mutate(TrustRegistryId(id), delegate, |delegators| delegators.try_insert(delegator).unwrap());
+        }
+
+        let remove_trust_registry = RemoveTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&remove_trust_registry.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: remove_trust_registry(RawOrigin::Signed(caller), remove_trust_registry, signature)
+    verify {
+        assert!(TrustRegistriesInfo::<T>::get(TrustRegistryId(id)).is_none());
+        assert!(TrustRegistriesParticipants::<T>::get(TrustRegistryIdForParticipants(TrustRegistryId(id))).is_empty());
+        assert_eq!(TrustRegistrySchemasMetadata::<T>::iter().count(), 0);
+        assert!(ConvenerTrustRegistries::<T>::get(Convener(did.into())).is_empty());
+        assert_eq!(TrustRegistryIssuerAccreditations::<T>::iter_prefix(TrustRegistryId(id)).count(), 0);
+        assert_eq!(TrustRegistryRecognitionAcceptances::<T>::iter().count(), 0);
+        assert_eq!(TrustRegistryParticipationRequests::<T>::iter_prefix(TrustRegistryId(id)).count(), 0);
+        assert_eq!(TrustRegistryGovFrameworkVersions::<T>::iter_prefix(TrustRegistryId(id)).count(), 0);
+        assert_eq!(TrustRegistryDelegationScopes::<T>::iter_prefix(TrustRegistryId(id)).count(), 0);
+    }
+
+    transfer_trust_registry_sr25519 for sr25519, transfer_trust_registry_ed25519 for ed25519, transfer_trust_registry_secp256k1 for secp256k1 {
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+        let new_convener = Convener(Did([2; Did::BYTE_SIZE]).into());
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>().try_into().unwrap()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(
+            1u32.into(),
+            Convener(did.into()),
+            init_or_update_trust_registry.clone()
+        ).modify::<T, _, _, _, _>(
+            |action, set| action.action.modify_removable(|action, info| Pallet::<T>::init_or_update_trust_registry_(
+                action,
+                set,
+                info,
+                Convener(did.into())
+            ).map_err(IntermediateError::<T>::from))
+        ).unwrap();
+
+        let transfer_trust_registry = TransferTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            new_convener,
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&transfer_trust_registry.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: transfer_trust_registry(RawOrigin::Signed(caller), transfer_trust_registry, signature)
+    verify {
+        assert_eq!(PendingTrustRegistryTransfers::<T>::get(TrustRegistryId(id)), Some(new_convener));
+    }
+
+    accept_trust_registry_transfer_sr25519 for sr25519, accept_trust_registry_transfer_ed25519 for ed25519, accept_trust_registry_transfer_secp256k1 for secp256k1 {
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let new_did = Did([2; Did::BYTE_SIZE]);
+        let public = pair.public();
+        let new_convener = Convener(new_did.into());
+
+        crate::did::Pallet::<T>::new_onchain_(
+            new_did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>().try_into().unwrap()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(
+            1u32.into(),
+            Convener(did.into()),
+            init_or_update_trust_registry.clone()
+        ).modify::<T, _, _, _, _>(
+            |action, set| action.action.modify_removable(|action, info| Pallet::<T>::init_or_update_trust_registry_(
+                action,
+                set,
+                info,
+                Convener(did.into())
+            ).map_err(IntermediateError::<T>::from))
+        ).unwrap();
+        PendingTrustRegistryTransfers::<T>::insert(TrustRegistryId(id), new_convener);
+
+        let accept_trust_registry_transfer = AcceptTrustRegistryTransfer {
+            registry_id: TrustRegistryId(id),
+            new_convener,
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&accept_trust_registry_transfer.to_state_change().encode());
+        let signature = DidSignature::new(new_did, 1u32, sig).into();
+    }: accept_trust_registry_transfer(RawOrigin::Signed(caller), accept_trust_registry_transfer, signature)
+    verify {
+        assert_eq!(TrustRegistriesInfo::<T>::get(TrustRegistryId(id)).unwrap().convener, new_convener);
+        assert!(ConvenerTrustRegistries::<T>::get(new_convener).contains(&TrustRegistryId(id)));
+        assert!(ConvenerTrustRegistries::<T>::get(Convener(did.into())).is_empty());
+        assert!(PendingTrustRegistryTransfers::<T>::get(TrustRegistryId(id)).is_none());
+    }
+
+    set_fee_payout_account_sr25519 for sr25519, set_fee_payout_account_ed25519 for ed25519, set_fee_payout_account_secp256k1 for secp256k1 {
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+        let account: T::AccountId = account("payout", 0, 0);
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let set_fee_payout_account = SetFeePayoutAccount {
+            did: ConvenerOrIssuerOrVerifier(did.into()),
+            account: Some(account.clone()),
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&set_fee_payout_account.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: set_fee_payout_account(RawOrigin::Signed(caller), set_fee_payout_account, signature)
+    verify {
+        assert_eq!(FeePayoutAccounts::<T>::get(ConvenerOrIssuerOrVerifier(did.into())), Some(account));
+    }
+
+    settle_verification_fee_sr25519 for sr25519, settle_verification_fee_ed25519 for ed25519, settle_verification_fee_secp256k1 for secp256k1 {
+        let pair as Pair;
+        let caller: T::AccountId = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+        let convener = Convener(Did([2; Did::BYTE_SIZE]).into());
+        let issuer = Issuer(Did([3; Did::BYTE_SIZE]).into());
+        let verifier = Verifier(did.into());
+        let issuer_account: T::AccountId = account("issuer", 0, 0);
+        let convener_account: T::AccountId = account("convener", 0, 0);
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>().try_into().unwrap()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(
+            1u32.into(),
+            convener,
+            init_or_update_trust_registry.clone()
+        ).modify::<T, _, _, _, _>(
+            |action, set| action.action.modify_removable(|action, info| Pallet::<T>::init_or_update_trust_registry_(
+                action,
+                set,
+                info,
+                convener
+            ).map_err(IntermediateError::<T>::from))
+        ).unwrap();
+
+        let participants: MultiTargetUpdate<_, _> = [IssuerOrVerifier(*issuer), IssuerOrVerifier(*verifier)]
+            .into_iter()
+            .map(|participant| (participant, AddOrRemoveOrModify::Add(())))
+            .collect();
+
+        let action = ChangeParticipantsRaw {
+            registry_id: TrustRegistryIdForParticipants(TrustRegistryId(id)),
+            participants: participants.clone(),
+            _marker: PhantomData::<T>,
+        };
+        MultiSignedAction::new(action, empty::<SignatureWithNonce<T::BlockNumber, DidOrDidMethodKeySignature<ConvenerOrIssuerOrVerifier>>>())
+            .execute(|action, set, _: BTreeSet<ConvenerOrIssuerOrVerifier>| Pallet::<T>::change_participants_(action, set, participants.keys().copied().map(|did| ConvenerOrIssuerOrVerifier(*did)).collect()), |_| None)
+            .unwrap();
+
+        let schema_id = TrustRegistrySchemaId([4; 32]);
+        define_schemas::<T>(convener, once(schema_id));
+        let schemas: MultiTargetUpdate<_, _> = once((
+            schema_id,
+            SetOrAddOrRemoveOrModify::Set(UnboundedTrustRegistrySchemaMetadata {
+                issuers: UnboundedIssuersWith(once((
+                    issuer,
+                    UnboundedVerificationPrices(
+                        once((String::from(T::NativeCurrencySymbol::get()), VerificationPrice(1000))).collect()
+                    )
+                )).collect()),
+                verifiers: UnboundedTrustRegistrySchemaVerifiers(once(verifier).collect())
+            })
+        )).collect();
+
+        SetSchemasMetadata {
+            registry_id: TrustRegistryId(id),
+            schemas: SetOrModify::Modify(schemas),
+            nonce: 2u32.into()
+        }.view(|action, set| Pallet::<T>::set_schemas_metadata_(action, set, ConvenerOrIssuerOrVerifier(*convener))).unwrap();
+
+        FeePayoutAccounts::<T>::insert(ConvenerOrIssuerOrVerifier(*issuer), issuer_account.clone());
+        FeePayoutAccounts::<T>::insert(ConvenerOrIssuerOrVerifier(*convener), convener_account);
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+        let settle_verification_fee = SettleVerificationFee {
+            registry_id: TrustRegistryId(id),
+            schema_id,
+            issuer,
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&settle_verification_fee.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: settle_verification_fee(RawOrigin::Signed(caller), settle_verification_fee, signature)
+    verify {
+        assert!(!T::Currency::free_balance(&issuer_account).is_zero());
+    }
+
+    set_issuers_accreditation_sr25519 for sr25519, set_issuers_accreditation_ed25519 for ed25519, set_issuers_accreditation_secp256k1 for secp256k1 {
+        {
+            let i in 1 .. SCHEMA_ISSUERS as u32;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>().try_into().unwrap()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(
+            1u32.into(),
+            Convener(did.into()),
+            init_or_update_trust_registry.clone()
+        ).modify::<T, _, _, _, _>(
+            |action, set| action.action.modify_removable(|action, info| Pallet::<T>::init_or_update_trust_registry_(
+                action,
+                set,
+                info,
+                Convener(did.into())
+            ).map_err(IntermediateError::<T>::from))
+        ).unwrap();
+
+        let issuers: Vec<_> = (0..i).map(|idx| Issuer(Did([100 + idx as u8; 32]).into())).collect();
+        let participants: MultiTargetUpdate<_, _> = issuers
+            .iter()
+            .map(|issuer| (IssuerOrVerifier(**issuer), AddOrRemoveOrModify::Add(())))
+            .collect();
+
+        let action = ChangeParticipantsRaw {
+            registry_id: TrustRegistryIdForParticipants(TrustRegistryId(id)),
+            participants: participants.clone(),
+            _marker: PhantomData::<T>,
+        };
+        MultiSignedAction::new(action, empty::<SignatureWithNonce<T::BlockNumber, DidOrDidMethodKeySignature<ConvenerOrIssuerOrVerifier>>>())
+            .execute(|action, set, _: BTreeSet<ConvenerOrIssuerOrVerifier>| Pallet::<T>::change_participants_(action, set, participants.keys().copied().map(|did| ConvenerOrIssuerOrVerifier(*did)).collect()), |_| None)
+            .unwrap();
+
+        let schema_id = TrustRegistrySchemaId([4; 32]);
+        define_schemas::<T>(Convener(did.into()), once(schema_id));
+        let schemas: MultiTargetUpdate<_, _> = once((
+            schema_id,
+            SetOrAddOrRemoveOrModify::Set(UnboundedTrustRegistrySchemaMetadata {
+                issuers: UnboundedIssuersWith(issuers.iter().map(|issuer|
+                    (
+                        *issuer,
+                        UnboundedVerificationPrices(
+                            once((String::from("USD"), VerificationPrice(1000))).collect()
+                        )
+                    )
+                ).collect()),
+                verifiers: UnboundedTrustRegistrySchemaVerifiers(Default::default())
+            })
+        )).collect();
+
+        SetSchemasMetadata {
+            registry_id: TrustRegistryId(id),
+            schemas: SetOrModify::Modify(schemas),
+            nonce: 2u32.into()
+        }.view(|action, set| Pallet::<T>::set_schemas_metadata_(action, set, ConvenerOrIssuerOrVerifier(did.into()))).unwrap();
+
+        let accreditation = IssuerAccreditation {
+            valid_from: Some(1u32.into()),
+            valid_until: Some(1_000u32.into())
+        };
+        let set_issuers_accreditation = SetIssuersAccreditation {
+            registry_id: TrustRegistryId(id),
+            schema_id,
+            accreditations: UnboundedIssuersWith(issuers.iter().map(|issuer| (*issuer, Some(accreditation.clone()))).collect()),
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&set_issuers_accreditation.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: set_issuers_accreditation(RawOrigin::Signed(caller), set_issuers_accreditation, signature)
+    verify {
+        assert_eq!(TrustRegistryIssuerAccreditations::<T>::iter_prefix(TrustRegistryId(id)).count(), i as usize);
+    };
+
+    update_recognized_registries_sr25519 for sr25519, update_recognized_registries_ed25519 for ed25519, update_recognized_registries_secp256k1 for secp256k1 {
+        {
+            let r in 1 .. RECOGNIZED_REGISTRIES as u32;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>().try_into().unwrap()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(
+            1u32.into(),
+            Convener(did.into()),
+            init_or_update_trust_registry.clone()
+        ).modify::<T, _, _, _, _>(
+            |action, set| action.action.modify_removable(|action, info| Pallet::<T>::init_or_update_trust_registry_(
+                action,
+                set,
+                info,
+                Convener(did.into())
+            ).map_err(IntermediateError::<T>::from))
+        ).unwrap();
+
+        let registry_ids: Vec<_> = (0..r).map(|idx| TrustRegistryId([100 + idx as u8; 32])).collect();
+        for registry_id in &registry_ids {
+            TrustRegistriesInfo::<T>::insert(registry_id, TrustRegistryInfo {
+                convener: Convener(Did([2; 32]).into()),
+                name: init_or_update_trust_registry.name.clone().try_into().unwrap(),
+                gov_framework: init_or_update_trust_registry.gov_framework.clone().try_into().unwrap()
+            });
+        }
+
+        let update_recognized_registries = UpdateRecognizedRegistries {
+            registry_id: TrustRegistryId(id),
+            registries: registry_ids.iter().map(|registry_id| (*registry_id, AddOrRemoveOrModify::Add(()))).collect(),
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&update_recognized_registries.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: update_recognized_registries(RawOrigin::Signed(caller), update_recognized_registries, signature)
+    verify {
+        assert_eq!(TrustRegistryRecognitions::<T>::get(TrustRegistryId(id)).len(), r as usize);
+    };
+
+    update_recognition_acceptances_sr25519 for sr25519, update_recognition_acceptances_ed25519 for ed25519, update_recognition_acceptances_secp256k1 for secp256k1 {
+        {
+            let r in 1 .. RECOGNIZED_REGISTRIES as u32;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>().try_into().unwrap()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(
+            1u32.into(),
+            Convener(did.into()),
+            init_or_update_trust_registry.clone()
+        ).modify::<T, _, _, _, _>(
+            |action, set| action.action.modify_removable(|action, info| Pallet::<T>::init_or_update_trust_registry_(
+                action,
+                set,
+                info,
+                Convener(did.into())
+            ).map_err(IntermediateError::<T>::from))
+        ).unwrap();
+
+        let registry_ids: Vec<_> = (0..r).map(|idx| TrustRegistryId([100 + idx as u8; 32])).collect();
+        for registry_id in &registry_ids {
+            TrustRegistriesInfo::<T>::insert(registry_id, TrustRegistryInfo {
+                convener: Convener(Did([2; 32]).into()),
+                name: init_or_update_trust_registry.name.clone().try_into().unwrap(),
+                gov_framework: init_or_update_trust_registry.gov_framework.clone().try_into().unwrap()
+            });
+        }
+
+        let update_recognition_acceptances = UpdateRecognitionAcceptances {
+            registry_id: TrustRegistryId(id),
+            recognizers: registry_ids.iter().map(|registry_id| (*registry_id, AddOrRemoveOrModify::Add(()))).collect(),
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&update_recognition_acceptances.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: update_recognition_acceptances(RawOrigin::Signed(caller), update_recognition_acceptances, signature)
+    verify {
+        assert_eq!(TrustRegistryRecognitionAcceptances::<T>::iter_prefix(TrustRegistryId(id)).count(), r as usize);
+    };
+
+    request_participation_sr25519 for sr25519, request_participation_ed25519 for ed25519, request_participation_secp256k1 for secp256k1 {
+        {
+            let o in 1 .. TRUST_REGISTRY_PARTICIPANT_ORG_NAME as u32;
+            let l in 1 .. TRUST_REGISTRY_PARTICIPANT_LOGO as u32;
+            let d in 1 .. TRUST_REGISTRY_PARTICIPANT_DESCRIPTION as u32;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>().try_into().unwrap()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(
+            1u32.into(),
+            Convener(Did([1; 32]).into()),
+            init_or_update_trust_registry.clone()
+        ).modify::<T, _, _, _, _>(
+            |action, set| action.action.modify_removable(|action, info| Pallet::<T>::init_or_update_trust_registry_(
+                action,
+                set,
+                info,
+                Convener(Did([1; 32]).into())
+            ).map_err(IntermediateError::<T>::from))
+        ).unwrap();
+
+        let request_participation = RequestParticipation {
+            registry_id: TrustRegistryId(id),
+            participant: IssuerOrVerifier(did.into()),
+            information: UnboundedTrustRegistryParticipantInformation {
+                org_name: (0..o).map(|c| c as u8 as char).collect(),
+                logo: (0..l).map(|c| c as u8 as char).collect(),
+                description: (0..d).map(|c| c as u8 as char).collect()
+            },
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&request_participation.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: request_participation(RawOrigin::Signed(caller), request_participation, signature)
+    verify {
+        assert!(TrustRegistryParticipationRequests::<T>::contains_key(TrustRegistryId(id), IssuerOrVerifier(did.into())));
+    };
+
+    resolve_participation_requests_sr25519 for sr25519, resolve_participation_requests_ed25519 for ed25519, resolve_participation_requests_secp256k1 for secp256k1 {
+        {
+            let r in 1 .. PARTICIPATION_REQUESTS as u32;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>().try_into().unwrap()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(
+            1u32.into(),
+            Convener(did.into()),
+            init_or_update_trust_registry.clone()
+        ).modify::<T, _, _, _, _>(
+            |action, set| action.action.modify_removable(|action, info| Pallet::<T>::init_or_update_trust_registry_(
+                action,
+                set,
+                info,
+                Convener(did.into())
+            ).map_err(IntermediateError::<T>::from))
+        ).unwrap();
+
+        let participants: Vec<_> = (0..r).map(|idx| IssuerOrVerifier(Did([100 + idx as u8; 32]).into())).collect();
+        for (idx, participant) in participants.iter().enumerate() {
+            TrustRegistryParticipationRequests::<T>::insert(TrustRegistryId(id), participant, participation_request::<T>(idx as u32, 10u32.into()));
+        }
+        TrustRegistryParticipationRequestsCount::<T>::insert(TrustRegistryId(id), r);
+
+        let resolve_participation_requests = ResolveParticipationRequests {
+            registry_id: TrustRegistryId(id),
+            resolutions: participants.iter().map(|participant| (*participant, ParticipationRequestResolution::Approve)).collect(),
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&resolve_participation_requests.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: resolve_participation_requests(RawOrigin::Signed(caller), resolve_participation_requests, signature)
+    verify {
+        assert_eq!(TrustRegistryParticipationRequests::<T>::iter_prefix(TrustRegistryId(id)).count(), 0);
+        assert_eq!(TrustRegistriesParticipants::<T>::get(TrustRegistryIdForParticipants(TrustRegistryId(id))).len(), r as usize);
+    }
+
+    add_gov_framework_version_sr25519 for sr25519, add_gov_framework_version_ed25519 for ed25519, add_gov_framework_version_secp256k1 for secp256k1 {
+        {
+            let u in 1 .. TRUST_REGISTRY_GOV_FRAMEWORK as u32;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>().try_into().unwrap()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(
+            1u32.into(),
+            Convener(did.into()),
+            init_or_update_trust_registry.clone()
+        ).modify::<T, _, _, _, _>(
+            |action, set| action.action.modify_removable(|action, info| Pallet::<T>::init_or_update_trust_registry_(
+                action,
+                set,
+                info,
+                Convener(did.into())
+            ).map_err(IntermediateError::<T>::from))
+        ).unwrap();
+
+        // Worst case: the history is full, so the oldest version has to be pruned after checking that it's superseded.
+        let max_versions = T::MaxGovFrameworkVersions::get();
+        for version in 0..max_versions {
+            TrustRegistryGovFrameworkVersions::<T>::insert(TrustRegistryId(id), version, GovFrameworkVersion {
+                hash: Some(GovFrameworkHash([1; 32])),
+                uri: vec![1; u as usize].try_into().unwrap(),
+                effective_at: frame_system::Pallet::<T>::block_number(),
+            });
+        }
+        TrustRegistryGovFrameworkVersionsCount::<T>::insert(TrustRegistryId(id), max_versions);
+
+        let add_gov_framework_version = AddGovFrameworkVersion {
+            registry_id: TrustRegistryId(id),
+            hash: GovFrameworkHash([2; 32]),
+            uri: (0..u).map(|idx| (98 + idx % 20) as u8 as char).collect(),
+            effective_at: Bounded::max_value(),
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&add_gov_framework_version.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: add_gov_framework_version(RawOrigin::Signed(caller), add_gov_framework_version, signature)
+    verify {
+        assert_eq!(TrustRegistryGovFrameworkVersionsCount::<T>::get(TrustRegistryId(id)), max_versions + 1);
+        assert!(TrustRegistryGovFrameworkVersions::<T>::get(TrustRegistryId(id), 0).is_none());
+    }
+
+    update_delegations_sr25519 for sr25519, update_delegations_ed25519 for ed25519, update_delegations_secp256k1 for secp256k1 {
+        {
+            let d in 1 .. DELEGATED_ISSUERS as u32;
+            let s in 1 .. SCHEMAS_COUNT as u32;
+            let c in 1 .. CHAINED_DELEGATIONS as u32;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(1u32.into(), Convener(did.into()), init_or_update_trust_registry.clone()).modify::<T, _, _, _, _>(
+            |action, set|
+                action.action.modify_removable(
+                    |action, info| Pallet::<T>::init_or_update_trust_registry_(action, set, info, Convener(did.into())).map_err(IntermediateError::<T>::from)
+                )
+        ).unwrap();
+
+        let delegated: BTreeSet<_> = (0..d).map(|idx| Issuer(Did([idx as u8 + 90; 32]).into())).collect();
+
+        let participants: BTreeMap<_, _> = delegated.iter()
+            .map(|did| IssuerOrVerifier(**did))
+            .map(|participant| (participant, AddOrRemoveOrModify::Add(())))
+            .collect();
+        let action = ChangeParticipantsRaw {
+            registry_id: TrustRegistryIdForParticipants(TrustRegistryId(id)),
+            participants: participants.clone().into(),
+            _marker: PhantomData::<T>,
+        };
+        MultiSignedAction::new(action, empty::<SignatureWithNonce<T::BlockNumber, DidOrDidMethodKeySignature<ConvenerOrIssuerOrVerifier>>>())
+            .execute(|action, set, _: BTreeSet<ConvenerOrIssuerOrVerifier>| Pallet::<T>::change_participants_(action, set, participants.keys().copied().map(|did| ConvenerOrIssuerOrVerifier(*did)).collect()), |_| None)
+            .unwrap();
+
+        let schemas: BTreeSet<_> = (0..s).map(|idx| TrustRegistrySchemaId([idx as u8; 32])).collect();
+        TrustRegistryIssuerSchemas::<T>::insert(TrustRegistryId(id), Issuer(did.into()), IssuerSchemas(schemas.clone().try_into().unwrap()));
+        add_delegation_chains::<T>(TrustRegistryId(id), Issuer(did.into()), c, &schemas);
+
+        let update_delegations = UpdateDelegations {
+            registry_id: TrustRegistryId(id),
+            delegations: delegated.iter().map(|issuer| (*issuer, Some(UnboundedDelegationScope { schemas: Some(schemas.clone()), max_depth: None }))).collect(),
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&update_delegations.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: update_delegations(RawOrigin::Signed(caller), update_delegations, signature)
+    verify {
+        assert_eq!(Pallet::<T>::issuer_delegations(TrustRegistryId(id), Issuer(did.into())).len(), d as usize);
+    }
+
+    revoke_delegations_sr25519 for sr25519, revoke_delegations_ed25519 for ed25519, revoke_delegations_secp256k1 for secp256k1 {
+        {
+            let r in 1 .. DELEGATED_ISSUERS as u32;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        let id = [1u8; 32].into();
+        let init_or_update_trust_registry = InitOrUpdateTrustRegistry {
+            registry_id: TrustRegistryId(id),
+            nonce: 1u32.into(),
+            gov_framework: Bytes(vec![1; 100]),
+            name: (0..10).map(|idx| (98 + idx) as u8 as char).collect::<String>()
+        };
+        ActionWithNonceWrapper::<T, _, _>::new(1u32.into(), Convener(did.into()), init_or_update_trust_registry.clone()).modify::<T, _, _, _, _>(
+            |action, set|
+                action.action.modify_removable(
+                    |action, info| Pallet::<T>::init_or_update_trust_registry_(action, set, info, Convener(did.into())).map_err(IntermediateError::<T>::from)
+                )
+        ).unwrap();
+
+        let delegated: BTreeSet<_> = (0..r).map(|idx| Issuer(Did([idx as u8 + 90; 32]).into())).collect();
+
+        let participants: BTreeMap<_, _> = delegated.iter()
+            .map(|did| IssuerOrVerifier(**did))
+            .map(|participant| (participant, AddOrRemoveOrModify::Add(())))
+            .collect();
+        let action = ChangeParticipantsRaw {
+            registry_id: TrustRegistryIdForParticipants(TrustRegistryId(id)),
+            participants: participants.clone().into(),
+            _marker: PhantomData::<T>,
+        };
+        MultiSignedAction::new(action, empty::<SignatureWithNonce<T::BlockNumber, DidOrDidMethodKeySignature<ConvenerOrIssuerOrVerifier>>>())
+            .execute(|action, set, _: BTreeSet<ConvenerOrIssuerOrVerifier>| Pallet::<T>::change_participants_(action, set, participants.keys().copied().map(|did| ConvenerOrIssuerOrVerifier(*did)).collect()), |_| None)
+            .unwrap();
+
+        let schemas: BTreeSet<_> = (0..SCHEMAS_COUNT).map(|idx| TrustRegistrySchemaId([idx as u8; 32])).collect();
+        TrustRegistryIssuerSchemas::<T>::insert(TrustRegistryId(id), Issuer(did.into()), IssuerSchemas(schemas.clone().try_into().unwrap()));
+
+        let mut config = TrustRegistryIssuerConfigurations::<T>::get(TrustRegistryId(id), Issuer(did.into()));
+        Pallet::<T>::update_delegations_(
+            ActionWithNonceWrapper::<T, _, _>::new(
+                1u32.into(),
+                (TrustRegistryId(id), Issuer(did.into())),
+                UpdateDelegations {
+                    registry_id: TrustRegistryId(id),
+                    delegations: delegated.iter().map(|issuer| (*issuer, Some(UnboundedDelegationScope { schemas: Some(schemas.clone()), max_depth: None }))).collect(),
+                    nonce: 1u32.into()
+                }
+            ),
+            &mut config,
+            Issuer(did.into())
+        ).unwrap();
+        TrustRegistryIssuerConfigurations::<T>::insert(TrustRegistryId(id), Issuer(did.into()), config);
+
+        let revoke_delegations = RevokeDelegations {
+            registry_id: TrustRegistryId(id),
+            delegations: once((Issuer(did.into()), delegated.clone())).collect(),
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&revoke_delegations.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: revoke_delegations(RawOrigin::Signed(caller), revoke_delegations, signature)
+    verify {
+        assert!(Pallet::<T>::issuer_delegations(TrustRegistryId(id), Issuer(did.into())).is_empty());
+    }
+
+    add_schema_definition_sr25519 for sr25519, add_schema_definition_ed25519 for ed25519, add_schema_definition_secp256k1 for secp256k1 {
+        {
+            let u in 2 .. SCHEMA_DEFINITION_URIS as u32;
+        }
+        let pair as Pair;
+        let caller = whitelisted_caller();
+        let did = Did([0; Did::BYTE_SIZE]);
+        let public = pair.public();
+
+        crate::did::Pallet::<T>::new_onchain_(
+            did,
+            vec![UncheckedDidKey::new_with_all_relationships(public)],
+            Default::default(),
+        ).unwrap();
+
+        // Worst case: the largest blob has to be read and hashed.
+        let blob_id = crate::blob::BlobId([1; 32]);
+        let document = vec![1u8; <T as crate::common::Limits>::MaxBlobSize::get() as usize];
+        let blob: crate::blob::StoredBlob<T> = (crate::blob::BlobOwner(did.into()), document.clone().try_into().unwrap());
+        crate::blob::Blobs::<T>::insert(blob_id, blob);
+
+        let json_schema: String = (0..u / 2).map(|idx| (98 + idx % 20) as u8 as char).collect();
+        let credential_type: String = (0..u - u / 2).map(|idx| (98 + idx % 20) as u8 as char).collect();
+        let content_hash = SchemaContentHash::of(&document);
+        let schema_id = TrustRegistrySchemaId::of_definition(&json_schema, &credential_type, content_hash, Some(blob_id));
+        let add_schema_definition = AddSchemaDefinition {
+            schema_id,
+            json_schema,
+            credential_type,
+            content_hash,
+            blob_id: Some(blob_id),
+            nonce: 1u32.into()
+        };
+        let sig = pair.sign(&add_schema_definition.to_state_change().encode());
+        let signature = DidSignature::new(did, 1u32, sig).into();
+    }: add_schema_definition(RawOrigin::Signed(caller), add_schema_definition, signature)
+    verify {
+        assert!(TrustRegistrySchemaDefinitions::<T>::contains_key(schema_id));
+    };
+
+    standard:
+    clear_expired_participation_requests {
+        let p in 1 .. PARTICIPATION_REQUESTS as u32;
+        let caller = whitelisted_caller();
+        let id = TrustRegistryId([1; 32]);
+
+        let participants: Vec<_> = (0..p).map(|idx| IssuerOrVerifier(Did([100 + idx as u8; 32]).into())).collect();
+        for (idx, participant) in participants.iter().enumerate() {
+            TrustRegistryParticipationRequests::<T>::insert(id, participant, participation_request::<T>(idx as u32, 1u32.into()));
+        }
+        TrustRegistryParticipationRequestsCount::<T>::insert(id, p);
+        frame_system::Pallet::<T>::set_block_number(10u32.into());
+    }: clear_expired_participation_requests(RawOrigin::Signed(caller), id, participants)
+    verify {
+        assert_eq!(TrustRegistryParticipationRequests::<T>::iter_prefix(id).count(), 0);
+        assert_eq!(TrustRegistryParticipationRequestsCount::<T>::get(id), 0);
+    }
 
-    standard:
     change_participants {
         let i in 1 .. TRUST_REGISTRY_PARTICIPANTS as u32;
         let pair = crate::def_test_pair!(sr25519, &[4; 32]);
@@ -5,11 +5,17 @@ use super::{types::*, *};
 use crate::{
     common::IntermediateError,
     util::{
-        ActionWithNonceWrapper, AddOrRemoveOrModify, ApplyUpdate, IncOrDec, MultiTargetUpdate,
-        TranslateUpdate, ValidateUpdate,
+        ActionWithNonceWrapper, AddOrRemoveOrModify, ApplyUpdate, BoundedBytes, IncOrDec,
+        MultiTargetUpdate, TranslateUpdate, ValidateUpdate,
     },
 };
-use alloc::collections::BTreeSet;
+use alloc::{
+    collections::{btree_map::Entry, BTreeMap, BTreeSet},
+    string::String,
+};
+use frame_support::traits::{Currency, ExistenceRequirement, ReservableCurrency};
+use sp_runtime::SaturatedConversion;
+use utils::BoundedString;
 
 impl<T: Config> Pallet<T> {
     pub(super) fn init_or_update_trust_registry_(
@@ -26,10 +32,33 @@ impl<T: Config> Pallet<T> {
         let name = name
             .try_into()
             .map_err(|_| Error::<T>::TrustRegistryNameSizeExceeded)?;
-        let gov_framework = gov_framework
+        let gov_framework: BoundedBytes<_> = gov_framework
             .try_into()
             .map_err(|_| Error::<T>::GovFrameworkSizeExceeded)?;
 
+        // Every change of the governance framework is recorded in the history to keep it the source of truth.
+        // The change takes effect immediately, so it's rejected while some version is scheduled to take effect later.
+        let now = <frame_system::Pallet<T>>::block_number();
+        if info
+            .as_ref()
+            .map_or(true, |info| info.gov_framework != gov_framework)
+        {
+            ensure!(
+                Self::latest_gov_framework_version(registry_id)
+                    .map_or(true, |latest| latest.effective_at <= now),
+                Error::<T>::GovFrameworkVersionScheduled
+            );
+
+            Self::push_gov_framework_version(
+                registry_id,
+                GovFrameworkVersion {
+                    hash: None,
+                    uri: gov_framework.clone(),
+                    effective_at: now,
+                },
+            )?;
+        }
+
         info.replace(TrustRegistryInfo {
             convener,
             name,
@@ -138,38 +167,54 @@ impl<T: Config> Pallet<T> {
             .translate_update()
             .map_err(IntoModuleError::<T>::into_module_error)?;
 
-        // Validate and apply the schema ID updates for each delegated issuer.
-        for (delegated_issuer, update) in issuers_diff.iter() {
-            let schema_ids_update: MultiTargetUpdate<_, IncOrDec> = issuer_schema_ids
-                .iter()
-                .copied()
-                .zip(repeat(update.clone()))
-                .collect();
-            let schema_ids =
-                TrustRegistryDelegatedIssuerSchemas::<T>::get(registry_id, delegated_issuer);
-            if schema_ids.is_empty() {
-                ensure!(
-                    participants.contains(&IssuerOrVerifier(**delegated_issuer)),
-                    Error::<T>::NotAParticipant
+        // Validate the schema ID updates for each delegated issuer.
+        let schema_ids_updates = issuers_diff
+            .into_iter()
+            .map(|(delegated_issuer, update)| {
+                // Only schemas covered by the existing delegation scope were counted.
+                let scope = TrustRegistryDelegationScopes::<T>::get(
+                    registry_id,
+                    (issuer, delegated_issuer),
                 );
-            }
+                let schema_ids_update: MultiTargetUpdate<_, IncOrDec> = issuer_schema_ids
+                    .iter()
+                    .filter(|schema_id| {
+                        scope.as_ref().map_or(true, |scope| scope.covers(schema_id))
+                    })
+                    .copied()
+                    .zip(repeat(update))
+                    .collect();
+                let schema_ids =
+                    TrustRegistryDelegatedIssuerSchemas::<T>::get(registry_id, delegated_issuer);
+                if schema_ids.is_empty() {
+                    ensure!(
+                        participants.contains(&IssuerOrVerifier(*delegated_issuer)),
+                        Error::<T>::NotAParticipant
+                    );
+                }
 
-            schema_ids_update
-                .ensure_valid(&issuer, &schema_ids)
-                .map_err(Error::<T>::from)?;
-        }
+                schema_ids_update
+                    .ensure_valid(&issuer, &schema_ids)
+                    .map_err(Error::<T>::from)?;
+
+                Ok((delegated_issuer, update, schema_ids_update))
+            })
+            .collect::<Result<Vec<_>, DispatchError>>()?;
 
         // Apply the schema ID updates for each delegated issuer.
-        for (issuer, update) in issuers_diff {
-            let schema_ids_update: MultiTargetUpdate<_, IncOrDec> = issuer_schema_ids
-                .iter()
-                .copied()
-                .zip(repeat(update))
-                .collect();
-
-            TrustRegistryDelegatedIssuerSchemas::<T>::mutate(registry_id, issuer, |schema_ids| {
-                schema_ids_update.apply_update(schema_ids);
-            });
+        for (delegated_issuer, update, schema_ids_update) in schema_ids_updates {
+            TrustRegistryDelegatedIssuerSchemas::<T>::mutate(
+                registry_id,
+                delegated_issuer,
+                |schema_ids| {
+                    schema_ids_update.apply_update(schema_ids);
+                },
+            );
+
+            // Removed delegations don't keep their scopes.
+            if let IncOrDec::Dec(_) = update {
+                Self::remove_delegation_scope(registry_id, issuer, delegated_issuer);
+            }
         }
 
         // Apply the delegated updates to the issuer configuration.
@@ -180,6 +225,235 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Sets or revokes scoped delegations of the `Issuer`.
+    ///
+    /// This function performs the following actions:
+    /// 1. Ensures that each of the new scopes is non-empty, doesn't exceed `T::MaxDelegationDepth`
+    ///    and contains only schemas which the `Issuer` can delegate.
+    /// 2. Computes updates of the delegated `Issuer`s schema counters using the `Issuer`'s own schemas
+    ///    covered by the previous and the new scopes.
+    /// 3. Applies the counter updates, stores the scopes and updates the delegators index.
+    /// 4. Applies the updates to the `Issuer` configuration and emits an event.
+    pub(super) fn update_delegations_(
+        ActionWithNonceWrapper {
+            action:
+                UpdateDelegations {
+                    registry_id,
+                    delegations,
+                    ..
+                },
+            ..
+        }: ActionWithNonceWrapper<T, UpdateDelegations<T>, (TrustRegistryId, Issuer)>,
+        config: &mut TrustRegistryIssuerConfiguration<T>,
+        issuer: Issuer,
+    ) -> DispatchResult {
+        let available_schemas = Self::delegatable_schemas(registry_id, issuer);
+        let issuer_schemas = TrustRegistryIssuerSchemas::<T>::get(registry_id, issuer);
+        let participants =
+            TrustRegistriesParticipants::<T>::get(TrustRegistryIdForParticipants(registry_id));
+        let mut delegated = config.delegated.clone();
+
+        // Validate the delegations and compute the required updates.
+        let updates = delegations
+            .into_iter()
+            .map(|(delegated_issuer, scope)| {
+                ensure!(
+                    delegated_issuer != issuer,
+                    Error::<T>::IssuerCantDelegateToHimself
+                );
+
+                let scope = scope.map(DelegationScope::<T>::try_from).transpose()?;
+                let current_scope = config.delegated.contains(&delegated_issuer).then(|| {
+                    TrustRegistryDelegationScopes::<T>::get(registry_id, (issuer, delegated_issuer))
+                        .unwrap_or_else(DelegationScope::unscoped)
+                });
+                let mut delegators =
+                    TrustRegistryIssuerDelegators::<T>::get(registry_id, delegated_issuer);
+
+                if let Some(scope) = &scope {
+                    ensure!(
+                        participants.contains(&IssuerOrVerifier(*delegated_issuer)),
+                        Error::<T>::NotAParticipant
+                    );
+                    ensure!(
+                        scope
+                            .max_depth
+                            .map_or(true, |max_depth| max_depth < T::MaxDelegationDepth::get())
+                            && scope.schemas.as_ref().map_or(
+                                !available_schemas.is_empty(),
+                                |schemas| !schemas.is_empty()
+                                    && schemas.is_subset(&available_schemas)
+                            ),
+                        Error::<T>::InvalidDelegationScope
+                    );
+
+                    delegated
+                        .try_insert(delegated_issuer)
+                        .map_err(|_| Error::<T>::DelegatedIssuersSizeExceeded)?;
+                    delegators
+                        .try_insert(issuer)
+                        .map_err(|_| Error::<T>::DelegatorsSizeExceeded)?;
+                } else {
+                    ensure!(
+                        delegated.remove(&delegated_issuer),
+                        Error::<T>::EntityDoesntExist
+                    );
+                    delegators.remove(&issuer);
+                }
+
+                let schema_ids_update = Self::delegated_schema_ids_update(
+                    &issuer_schemas,
+                    current_scope.as_ref(),
+                    scope.as_ref(),
+                );
+                let schema_ids =
+                    TrustRegistryDelegatedIssuerSchemas::<T>::get(registry_id, delegated_issuer);
+                schema_ids_update
+                    .ensure_valid(&issuer, &schema_ids)
+                    .map_err(Error::<T>::from)?;
+
+                Ok((delegated_issuer, scope, delegators, schema_ids_update))
+            })
+            .collect::<Result<Vec<_>, DispatchError>>()?;
+
+        for (delegated_issuer, scope, delegators, schema_ids_update) in updates {
+            TrustRegistryDelegatedIssuerSchemas::<T>::mutate(
+                registry_id,
+                delegated_issuer,
+                |schema_ids| {
+                    schema_ids_update.apply_update(schema_ids);
+                },
+            );
+            TrustRegistryIssuerDelegators::<T>::mutate_exists(
+                registry_id,
+                delegated_issuer,
+                |entry| *entry = Some(delegators).filter(|delegators| !delegators.is_empty()),
+            );
+            TrustRegistryDelegationScopes::<T>::set(registry_id, (issuer, delegated_issuer), scope);
+        }
+
+        config.delegated = delegated;
+
+        Self::deposit_event(Event::DelegatedIssuersUpdated(registry_id, issuer));
+
+        Ok(())
+    }
+
+    pub(super) fn revoke_delegations_(
+        RevokeDelegations {
+            registry_id,
+            delegations,
+            ..
+        }: RevokeDelegations<T>,
+        _: TrustRegistryInfo<T>,
+        convener: Convener,
+    ) -> DispatchResult {
+        // Validate the revocations and compute the required updates.
+        let updates = delegations
+            .into_iter()
+            .map(|(issuer, delegated_issuers)| {
+                let issuer_schemas = TrustRegistryIssuerSchemas::<T>::get(registry_id, issuer);
+                let mut config = TrustRegistryIssuerConfigurations::<T>::get(registry_id, issuer);
+
+                let schema_ids_updates = delegated_issuers
+                    .into_iter()
+                    .map(|delegated_issuer| {
+                        ensure!(
+                            config.delegated.remove(&delegated_issuer),
+                            Error::<T>::EntityDoesntExist
+                        );
+
+                        let scope = TrustRegistryDelegationScopes::<T>::get(
+                            registry_id,
+                            (issuer, delegated_issuer),
+                        )
+                        .unwrap_or_else(DelegationScope::unscoped);
+                        let schema_ids_update =
+                            Self::delegated_schema_ids_update(&issuer_schemas, Some(&scope), None);
+                        let schema_ids = TrustRegistryDelegatedIssuerSchemas::<T>::get(
+                            registry_id,
+                            delegated_issuer,
+                        );
+                        schema_ids_update
+                            .ensure_valid(&convener, &schema_ids)
+                            .map_err(Error::<T>::from)?;
+
+                        Ok((delegated_issuer, schema_ids_update))
+                    })
+                    .collect::<Result<Vec<_>, DispatchError>>()?;
+
+                Ok((issuer, config, schema_ids_updates))
+            })
+            .collect::<Result<Vec<_>, DispatchError>>()?;
+
+        for (issuer, config, schema_ids_updates) in updates {
+            for (delegated_issuer, schema_ids_update) in schema_ids_updates {
+                TrustRegistryDelegatedIssuerSchemas::<T>::mutate(
+                    registry_id,
+                    delegated_issuer,
+                    |schema_ids| {
+                        schema_ids_update.apply_update(schema_ids);
+                    },
+                );
+                Self::remove_delegation_scope(registry_id, issuer, delegated_issuer);
+
+                deposit_indexed_event!(DelegationRevoked(registry_id, issuer, delegated_issuer) over registry_id);
+            }
+
+            TrustRegistryIssuerConfigurations::<T>::insert(registry_id, issuer, config);
+        }
+
+        Ok(())
+    }
+
+    /// Computes update of the delegated `Issuer`'s schema counters caused by changing the delegation scope
+    /// from `current` to `new`. Only the delegating `Issuer`'s own schemas are counted.
+    fn delegated_schema_ids_update(
+        issuer_schemas: &IssuerSchemas<T>,
+        current: Option<&DelegationScope<T>>,
+        new: Option<&DelegationScope<T>>,
+    ) -> MultiTargetUpdate<TrustRegistrySchemaId, IncOrDec> {
+        issuer_schemas
+            .iter()
+            .filter_map(|schema_id| {
+                let covered = |scope: Option<&DelegationScope<T>>| {
+                    scope.map_or(false, |scope| scope.covers(schema_id))
+                };
+
+                match (covered(current), covered(new)) {
+                    (false, true) => Some((*schema_id, IncOrDec::Inc(IncOrDec::ONE))),
+                    (true, false) => Some((*schema_id, IncOrDec::Dec(IncOrDec::ONE))),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Removes scope of the delegation along with the delegating `Issuer` from the delegators index.
+    fn remove_delegation_scope(
+        registry_id: TrustRegistryId,
+        issuer: Issuer,
+        delegated_issuer: Issuer,
+    ) {
+        if TrustRegistryDelegationScopes::<T>::take(registry_id, (issuer, delegated_issuer))
+            .is_some()
+        {
+            TrustRegistryIssuerDelegators::<T>::mutate_exists(
+                registry_id,
+                delegated_issuer,
+                |entry| {
+                    *entry = entry
+                        .take()
+                        .map(|mut delegators| {
+                            delegators.remove(&issuer);
+                            delegators
+                        })
+                        .filter(|delegators| !delegators.is_empty())
+                },
+            );
+        }
+    }
+
     pub(super) fn suspend_issuers_(
         SuspendIssuers {
             registry_id,
@@ -211,6 +485,150 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    pub(super) fn set_issuers_accreditation_(
+        SetIssuersAccreditation {
+            registry_id,
+            schema_id,
+            accreditations,
+            ..
+        }: SetIssuersAccreditation<T>,
+        _: TrustRegistryInfo<T>,
+        _: Convener,
+    ) -> DispatchResult {
+        let TrustRegistrySchemaMetadata { issuers, .. } =
+            TrustRegistrySchemasMetadata::<T>::get(schema_id, registry_id)
+                .ok_or(Error::<T>::EntityDoesntExist)?;
+        let now = <frame_system::Pallet<T>>::block_number();
+
+        for (issuer, accreditation) in accreditations.iter() {
+            ensure!(issuers.contains_key(issuer), Error::<T>::NoSuchIssuer);
+            if let Some(accreditation) = accreditation {
+                ensure!(
+                    accreditation.can_be_set_at(now),
+                    Error::<T>::InvalidAccreditationPeriod
+                );
+            }
+        }
+
+        for (issuer, accreditation) in accreditations.0 {
+            TrustRegistryIssuerAccreditations::<T>::set(
+                registry_id,
+                (schema_id, issuer),
+                accreditation,
+            );
+
+            Self::deposit_event(Event::IssuerAccreditationSet(
+                registry_id,
+                schema_id,
+                issuer,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn update_recognized_registries_(
+        UpdateRecognizedRegistries {
+            registry_id,
+            registries,
+            ..
+        }: UpdateRecognizedRegistries<T>,
+        _: TrustRegistryInfo<T>,
+        convener: Convener,
+    ) -> DispatchResult {
+        for (recognized_id, action) in registries.iter() {
+            ensure!(*recognized_id != registry_id, Error::<T>::SelfRecognition);
+
+            if let AddOrRemoveOrModify::Add(()) = action {
+                ensure!(
+                    TrustRegistriesInfo::<T>::contains_key(recognized_id),
+                    Error::<T>::NoRegistry
+                );
+            }
+        }
+
+        TrustRegistryRecognitions::<T>::try_mutate(registry_id, |recognized| {
+            registries
+                .ensure_valid(&convener, recognized)
+                .map_err(Error::<T>::from)?;
+
+            for (recognized_id, action) in registries.iter() {
+                use AddOrRemoveOrModify::*;
+
+                let event = match action {
+                    Add(()) => Event::TrustRegistryRecognitionAdded(registry_id, *recognized_id),
+                    Remove => Event::TrustRegistryRecognitionRemoved(registry_id, *recognized_id),
+                    _ => continue,
+                };
+
+                Self::deposit_event(event);
+            }
+            registries.apply_update(recognized);
+
+            Ok(())
+        })
+    }
+
+    pub(super) fn update_recognition_acceptances_(
+        UpdateRecognitionAcceptances {
+            registry_id,
+            recognizers,
+            ..
+        }: UpdateRecognitionAcceptances<T>,
+        _: TrustRegistryInfo<T>,
+        _: Convener,
+    ) -> DispatchResult {
+        for (recognizer_id, action) in recognizers.iter() {
+            use AddOrRemoveOrModify::*;
+
+            ensure!(*recognizer_id != registry_id, Error::<T>::SelfRecognition);
+
+            let accepted =
+                TrustRegistryRecognitionAcceptances::<T>::contains_key(registry_id, recognizer_id);
+            match action {
+                Add(()) => {
+                    ensure!(
+                        TrustRegistriesInfo::<T>::contains_key(recognizer_id),
+                        Error::<T>::NoRegistry
+                    );
+                    ensure!(!accepted, Error::<T>::EntityAlreadyExists);
+                }
+                Remove => ensure!(accepted, Error::<T>::EntityDoesntExist),
+                Modify(()) => {}
+            }
+        }
+
+        for (recognizer_id, action) in recognizers {
+            use AddOrRemoveOrModify::*;
+
+            match action {
+                Add(()) => {
+                    TrustRegistryRecognitionAcceptances::<T>::insert(
+                        registry_id,
+                        recognizer_id,
+                        (),
+                    );
+
+                    Self::deposit_event(Event::TrustRegistryRecognitionAccepted(
+                        registry_id,
+                        recognizer_id,
+                    ));
+                }
+                Remove => {
+                    TrustRegistryRecognitionAcceptances::<T>::remove(registry_id, recognizer_id);
+
+                    Self::deposit_event(Event::TrustRegistryRecognitionAcceptanceRemoved(
+                        registry_id,
+                        recognizer_id,
+                    ));
+                }
+                Modify(()) => {}
+            }
+        }
+
+        Ok(())
+    }
+
     pub(super) fn unsuspend_issuers_(
         UnsuspendIssuers {
             registry_id,
@@ -300,6 +718,681 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    pub(super) fn request_participation_(
+        RequestParticipation {
+            registry_id,
+            participant,
+            information,
+            ..
+        }: RequestParticipation<T>,
+        request: &mut Option<ParticipationRequest<T>>,
+        _: ConvenerOrIssuerOrVerifier,
+        depositor: &T::AccountId,
+    ) -> DispatchResult {
+        ensure!(
+            TrustRegistriesInfo::<T>::contains_key(registry_id),
+            Error::<T>::NoRegistry
+        );
+        ensure!(
+            !TrustRegistriesParticipants::<T>::get(TrustRegistryIdForParticipants(registry_id))
+                .contains(&participant),
+            Error::<T>::AlreadyAParticipant
+        );
+
+        let information: TrustRegistryStoredParticipantInformation<T> = information.try_into()?;
+        let expires_at = <frame_system::Pallet<T>>::block_number()
+            .saturating_add(T::ParticipationRequestTtl::get());
+
+        if let Some(request) = request {
+            // Resubmitted request keeps the deposit reserved initially
+            request.information = information;
+            request.expires_at = expires_at;
+        } else {
+            let deposit = T::ParticipationRequestDeposit::get();
+            T::Currency::reserve(depositor, deposit)?;
+            TrustRegistryParticipationRequestsCount::<T>::try_mutate(registry_id, |count| {
+                ensure!(
+                    *count < T::MaxParticipationRequestsPerRegistry::get(),
+                    Error::<T>::TooManyParticipationRequests
+                );
+                *count += 1;
+
+                Ok::<_, Error<T>>(())
+            })?;
+
+            request.replace(ParticipationRequest {
+                information,
+                expires_at,
+                depositor: depositor.clone(),
+                deposit: deposit.saturated_into(),
+            });
+        }
+
+        Self::deposit_event(Event::ParticipationRequested(registry_id, participant));
+
+        Ok(())
+    }
+
+    pub(super) fn resolve_participation_requests_(
+        ResolveParticipationRequests {
+            registry_id,
+            resolutions,
+            ..
+        }: ResolveParticipationRequests<T>,
+        _: TrustRegistryInfo<T>,
+        _: Convener,
+    ) -> DispatchResult {
+        let now = <frame_system::Pallet<T>>::block_number();
+        for (participant, resolution) in resolutions.iter() {
+            let request = TrustRegistryParticipationRequests::<T>::get(registry_id, participant)
+                .ok_or(Error::<T>::NoParticipationRequest)?;
+
+            // Expired requests can only be rejected
+            if let ParticipationRequestResolution::Approve = resolution {
+                ensure!(
+                    !request.is_expired_at(now),
+                    Error::<T>::ParticipationRequestExpired
+                );
+            }
+        }
+
+        let participants_registry_id = TrustRegistryIdForParticipants(registry_id);
+        let mut participants = TrustRegistriesParticipants::<T>::get(participants_registry_id);
+        for (participant, resolution) in resolutions {
+            let request = TrustRegistryParticipationRequests::<T>::take(registry_id, participant)
+                .ok_or(Error::<T>::NoParticipationRequest)?;
+            Self::release_participation_request_deposit(registry_id, &request);
+            let ParticipationRequest { information, .. } = request;
+
+            match resolution {
+                ParticipationRequestResolution::Approve => {
+                    participants
+                        .try_insert(participant)
+                        .map_err(|_| Error::<T>::TooManyEntities)?;
+                    TrustRegistryParticipantsInformation::<T>::insert(
+                        participants_registry_id,
+                        participant,
+                        information,
+                    );
+
+                    Self::deposit_event(Event::ParticipationRequestApproved(
+                        registry_id,
+                        participant,
+                    ));
+                    Self::deposit_event(Event::TrustRegistryParticipantConfirmed(
+                        registry_id,
+                        participant,
+                    ));
+                }
+                ParticipationRequestResolution::Reject => {
+                    Self::deposit_event(Event::ParticipationRequestRejected(
+                        registry_id,
+                        participant,
+                    ));
+                }
+            }
+        }
+        TrustRegistriesParticipants::<T>::insert(participants_registry_id, participants);
+
+        Ok(())
+    }
+
+    pub(super) fn clear_expired_participation_requests_(
+        registry_id: TrustRegistryId,
+        participants: Vec<IssuerOrVerifier>,
+    ) -> DispatchResult {
+        let now = <frame_system::Pallet<T>>::block_number();
+
+        for participant in participants {
+            let request = TrustRegistryParticipationRequests::<T>::get(registry_id, participant)
+                .ok_or(Error::<T>::NoParticipationRequest)?;
+            ensure!(
+                request.is_expired_at(now),
+                Error::<T>::ParticipationRequestNotExpired
+            );
+
+            TrustRegistryParticipationRequests::<T>::remove(registry_id, participant);
+            Self::release_participation_request_deposit(registry_id, &request);
+
+            deposit_indexed_event!(ParticipationRequestExpired(registry_id, participant) over registry_id);
+        }
+
+        Ok(())
+    }
+
+    /// Unreserves the deposit of the removed participation request and decrements the pending requests counter.
+    fn release_participation_request_deposit(
+        registry_id: TrustRegistryId,
+        ParticipationRequest {
+            depositor, deposit, ..
+        }: &ParticipationRequest<T>,
+    ) {
+        T::Currency::unreserve(depositor, (*deposit).saturated_into());
+        TrustRegistryParticipationRequestsCount::<T>::mutate(registry_id, |count| {
+            *count = count.saturating_sub(1)
+        });
+    }
+
+    pub(super) fn add_gov_framework_version_(
+        AddGovFrameworkVersion {
+            registry_id,
+            hash,
+            uri,
+            effective_at,
+            ..
+        }: AddGovFrameworkVersion<T>,
+        _: TrustRegistryInfo<T>,
+        _: Convener,
+    ) -> DispatchResult {
+        let uri = uri
+            .into_bytes()
+            .try_into()
+            .map_err(|_| Error::<T>::GovFrameworkSizeExceeded)?;
+
+        Self::push_gov_framework_version(
+            registry_id,
+            GovFrameworkVersion {
+                hash: Some(hash),
+                uri,
+                effective_at,
+            },
+        )
+    }
+
+    /// Appends the supplied version to the governance framework history of the `TrustRegistry`.
+    /// The version can't take effect before the current block or the latest version already stored.
+    /// Once the history is full, the oldest version is pruned unless it's still in force, i.e. when all other
+    /// versions are scheduled to take effect later.
+    fn push_gov_framework_version(
+        registry_id: TrustRegistryId,
+        version: GovFrameworkVersion<T>,
+    ) -> DispatchResult {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let count = TrustRegistryGovFrameworkVersionsCount::<T>::get(registry_id);
+
+        ensure!(
+            version.effective_at >= now
+                && Self::latest_gov_framework_version(registry_id)
+                    .map_or(true, |latest| version.effective_at >= latest.effective_at),
+            Error::<T>::InvalidGovFrameworkVersionEffectiveBlock
+        );
+        let new_count = count
+            .checked_add(1)
+            .ok_or(Error::<T>::TooManyGovFrameworkVersions)?;
+
+        if let Some(oldest) =
+            new_count.checked_sub(T::MaxGovFrameworkVersions::get().saturating_add(1))
+        {
+            let next_effective_at = Self::gov_framework_version(registry_id, oldest + 1)
+                .map_or(version.effective_at, |next| next.effective_at);
+            ensure!(
+                next_effective_at <= now,
+                Error::<T>::TooManyGovFrameworkVersions
+            );
+
+            TrustRegistryGovFrameworkVersions::<T>::remove(registry_id, oldest);
+        }
+        TrustRegistryGovFrameworkVersions::<T>::insert(registry_id, count, version);
+        TrustRegistryGovFrameworkVersionsCount::<T>::insert(registry_id, new_count);
+
+        deposit_indexed_event!(GovFrameworkVersionAdded(registry_id, count) over registry_id);
+
+        Ok(())
+    }
+
+    pub(super) fn add_schema_definition_(
+        AddSchemaDefinition {
+            schema_id,
+            json_schema,
+            credential_type,
+            content_hash,
+            blob_id,
+            ..
+        }: AddSchemaDefinition<T>,
+        definition: &mut Option<TrustRegistrySchemaDefinition<T>>,
+        author: Convener,
+    ) -> DispatchResult {
+        ensure!(
+            definition.is_none(),
+            Error::<T>::SchemaDefinitionAlreadyExists
+        );
+        let derived_schema_id = TrustRegistrySchemaId::of_definition(
+            &json_schema,
+            &credential_type,
+            content_hash,
+            blob_id,
+        );
+        let json_schema = json_schema
+            .try_into()
+            .map_err(|_| Error::<T>::SchemaDefinitionUriSizeExceeded)?;
+        let credential_type = credential_type
+            .try_into()
+            .map_err(|_| Error::<T>::SchemaDefinitionUriSizeExceeded)?;
+
+        if let Some(blob_id) = blob_id {
+            let (_, document) =
+                blob::Blobs::<T>::get(blob_id).ok_or(Error::<T>::NoSchemaDefinitionBlob)?;
+
+            ensure!(
+                SchemaContentHash::of(&document[..]) == content_hash,
+                Error::<T>::SchemaDefinitionHashMismatch
+            );
+        }
+        ensure!(
+            derived_schema_id == schema_id,
+            Error::<T>::SchemaDefinitionIdMismatch
+        );
+
+        definition.replace(TrustRegistrySchemaDefinition {
+            author,
+            json_schema,
+            credential_type,
+            content_hash,
+            blob_id,
+        });
+
+        Self::deposit_event(Event::SchemaDefinitionAdded(schema_id));
+
+        Ok(())
+    }
+
+    /// Removes the trust registry along with its schemas metadata, participants and all of the
+    /// `Issuer`/`Verifier` indices referencing it.
+    ///
+    /// Returns storage accesses performed while removing schemas and the amount of removed participants.
+    pub(super) fn remove_trust_registry_(
+        RemoveTrustRegistry { registry_id, .. }: RemoveTrustRegistry<T>,
+        info: &mut Option<TrustRegistryInfo<T>>,
+        convener: Convener,
+    ) -> Result<RemovedTrustRegistryEntities, DispatchError> {
+        let registry_info = info.take().ok_or(Error::<T>::NoRegistry)?;
+
+        let accreditations =
+            TrustRegistryIssuerAccreditations::<T>::clear_prefix(registry_id, u32::MAX, None)
+                .unique;
+
+        // Removing all schemas cleans up `Issuer`s, delegated `Issuer`s and `Verifier`s indices.
+        let mut validation = StorageAccesses::default();
+        let execution = SchemasUpdate::<T>::Set(Default::default())
+            .validate_and_record_diff(
+                ConvenerOrIssuerOrVerifier(*convener),
+                registry_id,
+                &registry_info,
+                &mut validation,
+            )?
+            .execute(registry_id);
+
+        let participants_registry_id = TrustRegistryIdForParticipants(registry_id);
+        let TrustRegistryStoredParticipants(participants) =
+            TrustRegistriesParticipants::<T>::take(participants_registry_id);
+        let _ = TrustRegistryParticipantsInformation::<T>::clear_prefix(
+            participants_registry_id,
+            u32::MAX,
+            None,
+        );
+        let _ = TrustRegistryIssuerConfigurations::<T>::clear_prefix(registry_id, u32::MAX, None);
+        let _ = TrustRegistryIssuerSchemas::<T>::clear_prefix(registry_id, u32::MAX, None);
+        let _ = TrustRegistryVerifierSchemas::<T>::clear_prefix(registry_id, u32::MAX, None);
+        let _ = TrustRegistryDelegatedIssuerSchemas::<T>::clear_prefix(registry_id, u32::MAX, None);
+        let delegations =
+            TrustRegistryDelegationScopes::<T>::clear_prefix(registry_id, u32::MAX, None).unique
+                + TrustRegistryIssuerDelegators::<T>::clear_prefix(registry_id, u32::MAX, None)
+                    .unique;
+        TrustRegistriesStoredSchemas::<T>::remove(registry_id);
+
+        let mut participation_requests = 0;
+        for (_, request) in TrustRegistryParticipationRequests::<T>::drain_prefix(registry_id) {
+            participation_requests += 1;
+            Self::release_participation_request_deposit(registry_id, &request);
+        }
+        TrustRegistryParticipationRequestsCount::<T>::remove(registry_id);
+        let gov_framework_versions = TrustRegistryGovFrameworkVersionsCount::<T>::take(registry_id)
+            .min(T::MaxGovFrameworkVersions::get());
+        let _ = TrustRegistryGovFrameworkVersions::<T>::clear_prefix(
+            registry_id,
+            gov_framework_versions,
+            None,
+        );
+
+        let mut recognitions = 0;
+        for recognized_id in TrustRegistryRecognitions::<T>::take(registry_id) {
+            recognitions += 1;
+            TrustRegistryRecognitionAcceptances::<T>::remove(recognized_id, registry_id);
+        }
+        for (recognizer_id, ()) in
+            TrustRegistryRecognitionAcceptances::<T>::drain_prefix(registry_id)
+        {
+            recognitions += 1;
+            TrustRegistryRecognitions::<T>::mutate(recognizer_id, |recognized| {
+                recognized.remove(&registry_id)
+            });
+        }
+
+        ConvenerTrustRegistries::<T>::mutate(convener, |registries| {
+            registries.remove(&registry_id)
+        });
+        PendingTrustRegistryTransfers::<T>::remove(registry_id);
+
+        deposit_indexed_event!(TrustRegistryRemoved(registry_id));
+        Ok(RemovedTrustRegistryEntities {
+            accesses: execution,
+            participants: participants.len() as u32,
+            accreditations,
+            recognitions,
+            participation_requests,
+            gov_framework_versions,
+            delegations,
+        })
+    }
+
+    pub(super) fn transfer_trust_registry_(
+        TransferTrustRegistry {
+            registry_id,
+            new_convener,
+            ..
+        }: TransferTrustRegistry<T>,
+        info: &mut Option<TrustRegistryInfo<T>>,
+        convener: Convener,
+    ) -> DispatchResult {
+        info.as_ref().ok_or(Error::<T>::NoRegistry)?;
+        ensure!(new_convener != convener, Error::<T>::SameConvener);
+
+        PendingTrustRegistryTransfers::<T>::insert(registry_id, new_convener);
+
+        deposit_indexed_event!(TrustRegistryTransferProposed(registry_id, new_convener) over registry_id);
+        Ok(())
+    }
+
+    pub(super) fn accept_trust_registry_transfer_(
+        AcceptTrustRegistryTransfer { registry_id, .. }: AcceptTrustRegistryTransfer<T>,
+        registries: &mut TrustRegistryIdSet<T>,
+        new_convener: Convener,
+    ) -> DispatchResult {
+        // Only the `Convener` the registry is being transferred to can accept the transfer
+        PendingTrustRegistryTransfers::<T>::get(registry_id)
+            .filter(|pending_convener| *pending_convener == new_convener)
+            .ok_or(Error::<T>::NoPendingTrustRegistryTransfer)?;
+
+        registries
+            .try_insert(registry_id)
+            .map_err(|_| Error::<T>::TooManyRegistries)?;
+        let convener = TrustRegistriesInfo::<T>::try_mutate(registry_id, |info| {
+            let info = info.as_mut().ok_or(Error::<T>::NoRegistry)?;
+
+            Ok::<_, Error<T>>(core::mem::replace(&mut info.convener, new_convener))
+        })?;
+        ConvenerTrustRegistries::<T>::mutate(convener, |registries| {
+            registries.remove(&registry_id)
+        });
+        PendingTrustRegistryTransfers::<T>::remove(registry_id);
+
+        deposit_indexed_event!(TrustRegistryTransferred(registry_id, new_convener) over registry_id);
+        Ok(())
+    }
+
+    pub(super) fn set_fee_payout_account_(
+        SetFeePayoutAccount { did, account, .. }: SetFeePayoutAccount<T>,
+        payout_account: &mut Option<T::AccountId>,
+        _: ConvenerOrIssuerOrVerifier,
+    ) -> DispatchResult {
+        *payout_account = account;
+
+        Self::deposit_event(Event::FeePayoutAccountSet(did));
+        Ok(())
+    }
+
+    pub(super) fn settle_verification_fee_(
+        SettleVerificationFee {
+            registry_id,
+            schema_id,
+            issuer,
+            ..
+        }: SettleVerificationFee<T>,
+        registry_info: TrustRegistryInfo<T>,
+        verifier: Verifier,
+        payer: &T::AccountId,
+    ) -> DispatchResult {
+        let schema_metadata = TrustRegistrySchemasMetadata::<T>::get(schema_id, registry_id)
+            .ok_or(Error::<T>::EntityDoesntExist)?;
+        ensure!(
+            schema_metadata.verifiers.contains(&verifier),
+            Error::<T>::NotASchemaVerifier
+        );
+        ensure!(
+            !TrustRegistryIssuerConfigurations::<T>::get(registry_id, issuer).suspended,
+            Error::<T>::IssuerSuspended
+        );
+        ensure!(
+            Self::is_issuer_accredited(registry_id, schema_id, issuer),
+            Error::<T>::IssuerNotAccredited
+        );
+
+        let fee = Self::verification_fee_(&schema_metadata, issuer, &registry_info)?;
+        let issuer_account = FeePayoutAccounts::<T>::get(ConvenerOrIssuerOrVerifier(*issuer))
+            .ok_or(Error::<T>::NoFeePayoutAccount)?;
+
+        Self::pay_fee(payer, &issuer_account, fee.issuer)?;
+        if let Some(convener_account) =
+            FeePayoutAccounts::<T>::get(ConvenerOrIssuerOrVerifier(*registry_info.convener))
+        {
+            Self::pay_fee(payer, &convener_account, fee.convener)?;
+        }
+
+        deposit_indexed_event!(VerificationFeeSettled(registry_id, schema_id, issuer, verifier, fee) over registry_id, schema_id);
+        Ok(())
+    }
+
+    /// Computes a fee for verifying a credential of the schema with the supplied metadata issued by the given `Issuer`.
+    /// The fee is taken from the `Issuer`'s price under `T::NativeCurrencySymbol` which is expressed in the lowest
+    /// denomination of `T::Currency`. The `Convener`'s share is included only if the `Convener` has set an account to be paid to.
+    pub(super) fn verification_fee_(
+        TrustRegistrySchemaMetadata { issuers, .. }: &TrustRegistrySchemaMetadata<T>,
+        issuer: Issuer,
+        TrustRegistryInfo { convener, .. }: &TrustRegistryInfo<T>,
+    ) -> Result<VerificationFee, Error<T>> {
+        let prices = issuers.get(&issuer).ok_or(Error::<T>::NoSuchIssuer)?;
+        let symbol: BoundedString<T::MaxIssuerPriceCurrencySymbolSize> =
+            String::from(T::NativeCurrencySymbol::get())
+                .try_into()
+                .map_err(|_| Error::<T>::NoNativeVerificationPrice)?;
+        let VerificationPrice(price) = *prices
+            .get(&symbol)
+            .ok_or(Error::<T>::NoNativeVerificationPrice)?;
+
+        let convener_fee =
+            if FeePayoutAccounts::<T>::contains_key(ConvenerOrIssuerOrVerifier(**convener)) {
+                T::ConvenerFeeShare::get() * price
+            } else {
+                0
+            };
+
+        Ok(VerificationFee {
+            issuer: VerificationPrice(price - convener_fee),
+            convener: VerificationPrice(convener_fee),
+        })
+    }
+
+    fn pay_fee(
+        payer: &T::AccountId,
+        receiver: &T::AccountId,
+        VerificationPrice(amount): VerificationPrice,
+    ) -> DispatchResult {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let amount =
+            BalanceOf::<T>::try_from(amount).map_err(|_| Error::<T>::VerificationPriceOverflow)?;
+
+        T::Currency::transfer(payer, receiver, amount, ExistenceRequirement::KeepAlive)
+    }
+
+    /// Returns a fee to be paid for verifying a credential of the given schema issued by the supplied `Issuer`.
+    pub fn verification_fee(
+        registry_id: TrustRegistryId,
+        schema_id: TrustRegistrySchemaId,
+        issuer: Issuer,
+    ) -> Option<VerificationFee> {
+        let registry_info = TrustRegistriesInfo::<T>::get(registry_id)?;
+        let schema_metadata = TrustRegistrySchemasMetadata::<T>::get(schema_id, registry_id)?;
+
+        Self::verification_fee_(&schema_metadata, issuer, &registry_info).ok()
+    }
+
+    /// Returns `true` if the `Issuer` is accredited for the given schema at the current block.
+    pub fn is_issuer_accredited(
+        registry_id: TrustRegistryId,
+        schema_id: TrustRegistrySchemaId,
+        issuer: Issuer,
+    ) -> bool {
+        TrustRegistryIssuerAccreditations::<T>::get(registry_id, (schema_id, issuer))
+            .map_or(true, |accreditation| {
+                accreditation.is_valid_at(<frame_system::Pallet<T>>::block_number())
+            })
+    }
+
+    /// Returns up to `limit` (capped by `T::MaxQueryPageSize`) pending participation requests of the given
+    /// `TrustRegistry` in their storage order starting after the request of the `after` participant.
+    /// Expired requests are returned until cleared.
+    pub fn participation_requests(
+        registry_id: TrustRegistryId,
+        after: Option<IssuerOrVerifier>,
+        limit: u32,
+    ) -> Vec<(IssuerOrVerifier, ParticipationRequest<T>)> {
+        let iter = match after {
+            Some(after) => TrustRegistryParticipationRequests::<T>::iter_prefix_from(
+                registry_id,
+                TrustRegistryParticipationRequests::<T>::hashed_key_for(registry_id, after),
+            ),
+            None => TrustRegistryParticipationRequests::<T>::iter_prefix(registry_id),
+        };
+
+        iter.take(limit.min(T::MaxQueryPageSize::get()) as usize)
+            .collect()
+    }
+
+    /// Returns governance framework versions history of the given `TrustRegistry` ordered by their effective blocks.
+    pub fn gov_framework_versions(registry_id: TrustRegistryId) -> Vec<GovFrameworkVersion<T>> {
+        (Self::oldest_gov_framework_version(registry_id)
+            ..Self::gov_framework_versions_count(registry_id))
+            .filter_map(|version| Self::gov_framework_version(registry_id, version))
+            .collect()
+    }
+
+    /// Returns number of the oldest governance framework version of the given `TrustRegistry` kept in the history.
+    fn oldest_gov_framework_version(registry_id: TrustRegistryId) -> u32 {
+        Self::gov_framework_versions_count(registry_id)
+            .saturating_sub(T::MaxGovFrameworkVersions::get())
+    }
+
+    /// Returns the latest governance framework version of the given `TrustRegistry`, which may be not in force yet.
+    fn latest_gov_framework_version(
+        registry_id: TrustRegistryId,
+    ) -> Option<GovFrameworkVersion<T>> {
+        Self::gov_framework_versions_count(registry_id)
+            .checked_sub(1)
+            .and_then(|latest| Self::gov_framework_version(registry_id, latest))
+    }
+
+    /// Returns governance framework of the given `TrustRegistry` in force at the current block.
+    /// Registries initialized before the history was introduced fall back to the framework stored in their info.
+    pub fn gov_framework_in_force(
+        registry_id: TrustRegistryId,
+        info: &TrustRegistryInfo<T>,
+    ) -> BoundedBytes<T::MaxTrustRegistryGovFrameworkSize> {
+        Self::gov_framework_version_at(registry_id, <frame_system::Pallet<T>>::block_number())
+            .map(|version| version.uri)
+            .unwrap_or_else(|| info.gov_framework.clone())
+    }
+
+    /// Returns governance framework version of the given `TrustRegistry` in force at the supplied block,
+    /// i.e. the latest one which became effective at or before that block.
+    /// Returns `None` if the version in force at that block was pruned from the history.
+    pub fn gov_framework_version_at(
+        registry_id: TrustRegistryId,
+        block_number: T::BlockNumber,
+    ) -> Option<GovFrameworkVersion<T>> {
+        // Versions are stored in the ascending order of their effective blocks, so the binary search is used
+        // to find the first version becoming effective after the supplied block.
+        let (mut low, mut high) = (
+            Self::oldest_gov_framework_version(registry_id),
+            Self::gov_framework_versions_count(registry_id),
+        );
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let effective_at = Self::gov_framework_version(registry_id, mid)?.effective_at;
+
+            if effective_at <= block_number {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        Self::gov_framework_version(registry_id, low.checked_sub(1)?)
+    }
+
+    /// Returns registries recognized by the given `TrustRegistry` which accepted the recognition.
+    pub fn recognized_registries(registry_id: TrustRegistryId) -> BTreeSet<TrustRegistryId> {
+        Self::registry_recognitions(registry_id)
+            .into_iter()
+            .filter(|recognized_id| {
+                TrustRegistryRecognitionAcceptances::<T>::contains_key(recognized_id, registry_id)
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the `Issuer` (or delegated `Issuer`) is allowed to issue credentials of the given schema
+    /// in the supplied `TrustRegistry`, i.e. it isn't suspended and its accreditation didn't lapse.
+    pub fn is_issuer_authorized(
+        registry_id: TrustRegistryId,
+        schema_id: TrustRegistrySchemaId,
+        issuer: Issuer,
+    ) -> bool {
+        Self::registry_issuer_or_delegated_issuer_schemas(registry_id, issuer).contains(&schema_id)
+            && !Self::registry_issuer_config(registry_id, issuer).suspended
+            && Self::is_issuer_accredited(registry_id, schema_id, issuer)
+    }
+
+    /// Resolves the `Issuer`'s authorization for the given schema starting from the supplied `TrustRegistry`
+    /// and following accepted recognitions up to `T::MaxRecognitionDepth` hops.
+    /// Returns the shortest chain of registries ending with the one authorizing the `Issuer`.
+    pub fn issuer_authorization_path(
+        registry_id: TrustRegistryId,
+        schema_id: TrustRegistrySchemaId,
+        issuer: Issuer,
+    ) -> Option<Vec<TrustRegistryId>> {
+        let max_depth = T::MaxRecognitionDepth::get();
+        let mut visited = BTreeSet::from([registry_id]);
+        let mut paths: Vec<Vec<TrustRegistryId>> = sp_std::vec![sp_std::vec![registry_id]];
+
+        for depth in 0..=max_depth {
+            let mut next_paths = Vec::new();
+
+            for path in paths {
+                let last_id = *path.last()?;
+                if Self::is_issuer_authorized(last_id, schema_id, issuer) {
+                    return Some(path);
+                }
+
+                if depth < max_depth {
+                    for recognized_id in Self::recognized_registries(last_id) {
+                        if visited.insert(recognized_id) {
+                            let mut next_path = path.clone();
+                            next_path.push(recognized_id);
+
+                            next_paths.push(next_path);
+                        }
+                    }
+                }
+            }
+
+            paths = next_paths;
+        }
+
+        None
+    }
+
     pub fn issuer_or_verifier_registries(
         issuer_or_verifier: IssuerOrVerifier,
     ) -> BTreeSet<TrustRegistryId> {
@@ -337,14 +1430,138 @@ impl<T: Config> Pallet<T> {
             .into_iter()
             .map(|(key, _)| key)
             .merge(issuer_schemas)
+            .merge(Self::chained_delegated_schemas(
+                reg_id,
+                issuer_or_delegated_issuer,
+                0,
+            ))
             .dedup()
             .collect()
     }
 
+    /// Returns schemas which the `Issuer` can delegate: its own schemas along with the schemas
+    /// received through the scoped delegations allowing at least one more level below it.
+    pub fn delegatable_schemas(
+        reg_id: TrustRegistryId,
+        issuer: Issuer,
+    ) -> BTreeSet<TrustRegistrySchemaId> {
+        let IssuerSchemas(issuer_schemas) = Self::registry_issuer_schemas(reg_id, issuer);
+
+        issuer_schemas
+            .into_iter()
+            .merge(Self::chained_delegated_schemas(reg_id, issuer, 1))
+            .dedup()
+            .collect()
+    }
+
+    /// Returns delegations of the `Issuer` along with their scopes.
+    pub fn issuer_delegations(
+        reg_id: TrustRegistryId,
+        issuer: Issuer,
+    ) -> BTreeMap<Issuer, DelegationScope<T>> {
+        let DelegatedIssuers(delegated) = Self::registry_issuer_config(reg_id, issuer).delegated;
+
+        delegated
+            .into_iter()
+            .map(|delegated_issuer| {
+                let scope = Self::delegation_scope(reg_id, (issuer, delegated_issuer))
+                    .unwrap_or_else(DelegationScope::unscoped);
+
+                (delegated_issuer, scope)
+            })
+            .collect()
+    }
+
+    /// Walks up the scoped delegation chains ending with the `Issuer` and collects schemas of the
+    /// delegating `Issuer`s covered by every scope in the chain.
+    /// `levels_below` is the amount of delegation levels following the `Issuer` in the chain.
+    /// Chains are followed up to `T::MaxDelegationDepth` delegations which also cuts off the cycles.
+    ///
+    /// Collected schemas are always intersected with the filter built from the scopes, so each `Issuer` reached
+    /// at the same level has to be followed again only for the schemas not covered by its previous visits.
+    /// This bounds the walk by the amount of `Issuer`s, levels and schemas instead of the amount of chains.
+    fn chained_delegated_schemas(
+        reg_id: TrustRegistryId,
+        issuer: Issuer,
+        levels_below: u32,
+    ) -> BTreeSet<TrustRegistrySchemaId> {
+        let max_depth = T::MaxDelegationDepth::get();
+        let mut schemas = BTreeSet::new();
+        let mut visited: BTreeMap<(Issuer, u32), Option<BTreeSet<TrustRegistrySchemaId>>> =
+            BTreeMap::new();
+        let mut pending = sp_std::vec![(issuer, levels_below, None::<BTreeSet<_>>)];
+
+        while let Some((delegated_issuer, levels_below, filter)) = pending.pop() {
+            if levels_below >= max_depth {
+                continue;
+            }
+
+            // `None` filter stands for all schemas.
+            let filter = match visited.entry((delegated_issuer, levels_below)) {
+                Entry::Vacant(entry) => entry.insert(filter).clone(),
+                Entry::Occupied(mut entry) => match (entry.get_mut(), filter) {
+                    (None, _) => continue,
+                    (Some(followed), Some(filter)) => {
+                        let not_followed: BTreeSet<_> =
+                            filter.difference(followed).copied().collect();
+                        if not_followed.is_empty() {
+                            continue;
+                        }
+                        followed.extend(not_followed.iter().copied());
+
+                        Some(not_followed)
+                    }
+                    (followed, None) => {
+                        *followed = None;
+
+                        None
+                    }
+                },
+            };
+
+            let IssuerDelegators(delegators) =
+                Self::registry_issuer_delegators(reg_id, delegated_issuer);
+
+            for delegator in delegators {
+                let Some(scope) = Self::delegation_scope(reg_id, (delegator, delegated_issuer))
+                    .filter(|scope| scope.allows_levels(levels_below))
+                else {
+                    continue;
+                };
+                let filter = match (&filter, scope.schemas) {
+                    (Some(filter), Some(scoped)) => Some(
+                        filter
+                            .iter()
+                            .filter(|schema_id| scoped.contains(schema_id))
+                            .copied()
+                            .collect(),
+                    ),
+                    (Some(filter), None) => Some(filter.clone()),
+                    (None, scoped) => scoped.map(|scoped| scoped.into_iter().collect()),
+                };
+
+                let IssuerSchemas(delegator_schemas) =
+                    Self::registry_issuer_schemas(reg_id, delegator);
+                schemas.extend(delegator_schemas.into_iter().filter(|schema_id| {
+                    filter
+                        .as_ref()
+                        .map_or(true, |filter: &BTreeSet<_>| filter.contains(schema_id))
+                }));
+
+                if filter.as_ref().map_or(true, |filter| !filter.is_empty()) {
+                    pending.push((delegator, levels_below + 1, filter));
+                }
+            }
+        }
+
+        schemas
+    }
+
     pub fn aggregate_schema_metadata(
         (reg_id, schema_id): (TrustRegistryId, TrustRegistrySchemaId),
     ) -> Option<AggregatedTrustRegistrySchemaMetadata<T>> {
-        TrustRegistrySchemasMetadata::<T>::get(schema_id, reg_id).map(|meta| meta.aggregate(reg_id))
+        TrustRegistrySchemasMetadata::<T>::get(schema_id, reg_id)
+            .map(|meta| meta.aggregate(reg_id, schema_id))
     }
 
     pub fn schema_metadata_by_schema_id(
@@ -353,6 +1570,25 @@ impl<T: Config> Pallet<T> {
         TrustRegistrySchemasMetadata::<T>::iter_prefix(schema_id)
     }
 
+    /// Returns up to `limit` (capped by `T::MaxQueryPageSize`) schema metadata entries of the registries
+    /// referencing the schema in their storage order starting after the registry with the `after` id.
+    pub fn schema_metadata_by_schema_id_paginated(
+        schema_id: TrustRegistrySchemaId,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+    ) -> Vec<(TrustRegistryId, TrustRegistrySchemaMetadata<T>)> {
+        let iter = match after {
+            Some(after) => TrustRegistrySchemasMetadata::<T>::iter_prefix_from(
+                schema_id,
+                TrustRegistrySchemasMetadata::<T>::hashed_key_for(schema_id, after),
+            ),
+            None => TrustRegistrySchemasMetadata::<T>::iter_prefix(schema_id),
+        };
+
+        iter.take(limit.min(T::MaxQueryPageSize::get()) as usize)
+            .collect()
+    }
+
     pub fn schema_metadata_by_registry_id(
         registry_id: TrustRegistryId,
     ) -> impl Iterator<Item = (TrustRegistrySchemaId, TrustRegistrySchemaMetadata<T>)> {
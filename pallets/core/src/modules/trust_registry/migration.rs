@@ -0,0 +1,31 @@
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use sp_std::marker::PhantomData;
+
+/// Fills `LegacySchemaIds` with the ids of the schemas referenced by the `TrustRegistry`s
+/// before the definitions were introduced.
+pub struct SnapshotLegacySchemaIds<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for SnapshotLegacySchemaIds<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let db_weight = T::DbWeight::get();
+        if Pallet::<T>::on_chain_storage_version() >= 1 {
+            return db_weight.reads(1);
+        }
+
+        let (mut metadata, mut snapshotted) = (0u64, 0u64);
+        for (schema_id, _) in TrustRegistrySchemasMetadata::<T>::iter_keys() {
+            metadata += 1;
+
+            if !LegacySchemaIds::<T>::contains_key(schema_id)
+                && !TrustRegistrySchemaDefinitions::<T>::contains_key(schema_id)
+            {
+                LegacySchemaIds::<T>::insert(schema_id, ());
+                snapshotted += 1;
+            }
+        }
+        StorageVersion::new(1).put::<Pallet<T>>();
+
+        db_weight.reads_writes(metadata * 3 + 1, snapshotted + 1)
+    }
+}
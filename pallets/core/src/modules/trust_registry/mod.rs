@@ -1,6 +1,7 @@
 //! Dock Trust Registry.
 
 use crate::{
+    blob,
     common::{ForSigType, SignatureWithNonce},
     deposit_indexed_event,
     did::{self, DidOrDidMethodKeySignature},
@@ -14,6 +15,7 @@ use core::convert::Infallible;
 use frame_support::{
     dispatch::DispatchErrorWithPostInfo,
     pallet_prelude::*,
+    traits::{Currency, ReservableCurrency},
     weights::{PostDispatchInfo, RuntimeDbWeight},
 };
 use sp_std::vec::Vec;
@@ -28,12 +30,14 @@ mod tests;
 mod weights;
 
 pub mod actions;
+mod migration;
 pub mod query;
 pub mod types;
 mod update;
 mod update_rules;
 
 pub use actions::*;
+pub use migration::*;
 pub use pallet::*;
 pub use query::*;
 pub use types::*;
@@ -41,6 +45,10 @@ pub use types::*;
 pub(super) use update::*;
 use weights::*;
 
+/// Balance of the currency used to settle verification fees.
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
     use crate::{
@@ -166,6 +174,61 @@ pub mod pallet {
         ParticipantLogoSizeExceededLimit,
         /// `TrustRegistry` participant's description exceeded its limit.
         ParticipantDescriptionSizeExceededLimit,
+        /// `TrustRegistry` can't be transferred to its current `Convener`.
+        SameConvener,
+        /// `Verifier` isn't allowed to verify credentials of the given schema.
+        NotASchemaVerifier,
+        /// `Issuer` doesn't have a verification price in the native currency for the given schema.
+        NoNativeVerificationPrice,
+        /// `Issuer` is suspended in the `TrustRegistry`.
+        IssuerSuspended,
+        /// Receiver of the verification fee didn't set an account to be paid to.
+        NoFeePayoutAccount,
+        /// Verification price doesn't fit into the native currency balance.
+        VerificationPriceOverflow,
+        /// Accreditation period is empty or lapses before the next block.
+        InvalidAccreditationPeriod,
+        /// `Issuer` isn't accredited for the given schema at the current block.
+        IssuerNotAccredited,
+        /// `TrustRegistry` can't recognize itself.
+        SelfRecognition,
+        /// Supplied DID is already a participant of the `TrustRegistry`.
+        AlreadyAParticipant,
+        /// No pending participation request exists for the supplied DID.
+        NoParticipationRequest,
+        /// Governance framework version can't take effect before the current block or the latest version.
+        InvalidGovFrameworkVersionEffectiveBlock,
+        /// The `TrustRegistry` history is full of governance framework versions which are either in force or
+        /// scheduled to take effect later, so the oldest version can't be pruned.
+        TooManyGovFrameworkVersions,
+        /// Delegation scope is empty, exceeds the allowed depth or contains schemas
+        /// not available to the delegating `Issuer`.
+        InvalidDelegationScope,
+        /// `Issuer` has too many delegating `Issuer`s.
+        DelegatorsSizeExceeded,
+        /// Schema with the given id already has a definition.
+        SchemaDefinitionAlreadyExists,
+        /// Schema with the given id must be defined before being added to the `TrustRegistry`.
+        NoSchemaDefinition,
+        /// Schema definition URI is longer than allowed.
+        SchemaDefinitionUriSizeExceeded,
+        /// Blob referenced by the schema definition doesn't exist.
+        NoSchemaDefinitionBlob,
+        /// Blob referenced by the schema definition doesn't match its content hash.
+        SchemaDefinitionHashMismatch,
+        /// Schema id isn't derived from the supplied definition.
+        SchemaDefinitionIdMismatch,
+        /// The `TrustRegistry` reached the max amount of pending participation requests.
+        TooManyParticipationRequests,
+        /// Participation request expired and can't be approved anymore.
+        ParticipationRequestExpired,
+        /// Participation request didn't expire yet so it can't be cleared.
+        ParticipationRequestNotExpired,
+        /// Governance framework can't be changed by `InitOrUpdateTrustRegistry` while some version
+        /// added by `AddGovFrameworkVersion` is scheduled to take effect later.
+        GovFrameworkVersionScheduled,
+        /// The `TrustRegistry` has no pending transfer to the signer.
+        NoPendingTrustRegistryTransfer,
     }
 
     #[pallet::event]
@@ -191,17 +254,106 @@ pub mod pallet {
         TrustRegistryParticipantRemoved(TrustRegistryId, IssuerOrVerifier),
         /// `TrustRegistry` participant information was set.
         TrustRegistryParticipantInformationSet(TrustRegistryId, IssuerOrVerifier),
+        /// `TrustRegistry` with the given id was removed along with its schemas and participants.
+        TrustRegistryRemoved(TrustRegistryId),
+        /// `TrustRegistry` with the given id was transferred to the new `Convener` once it accepted the transfer.
+        TrustRegistryTransferred(TrustRegistryId, Convener),
+        /// Account to receive verification fees was set or removed for the given DID.
+        FeePayoutAccountSet(ConvenerOrIssuerOrVerifier),
+        /// `Verifier` paid the `Issuer` (and the `Convener`) for verifying a credential of the given schema.
+        VerificationFeeSettled(
+            TrustRegistryId,
+            TrustRegistrySchemaId,
+            Issuer,
+            Verifier,
+            VerificationFee,
+        ),
+        /// Accreditation period of the `Issuer` was set for the given schema.
+        IssuerAccreditationSet(TrustRegistryId, TrustRegistrySchemaId, Issuer),
+        /// First `TrustRegistry` started recognizing the second one.
+        /// Recognition takes effect once it's accepted by the recognized `TrustRegistry`.
+        TrustRegistryRecognitionAdded(TrustRegistryId, TrustRegistryId),
+        /// First `TrustRegistry` no longer recognizes the second one.
+        TrustRegistryRecognitionRemoved(TrustRegistryId, TrustRegistryId),
+        /// First `TrustRegistry` accepted being recognized by the second one.
+        TrustRegistryRecognitionAccepted(TrustRegistryId, TrustRegistryId),
+        /// First `TrustRegistry` no longer accepts being recognized by the second one.
+        TrustRegistryRecognitionAcceptanceRemoved(TrustRegistryId, TrustRegistryId),
+        /// `Issuer` or `Verifier` requested to participate in the `TrustRegistry`.
+        ParticipationRequested(TrustRegistryId, IssuerOrVerifier),
+        /// Participation request was approved by the `Convener`.
+        ParticipationRequestApproved(TrustRegistryId, IssuerOrVerifier),
+        /// Participation request was rejected by the `Convener`.
+        ParticipationRequestRejected(TrustRegistryId, IssuerOrVerifier),
+        /// Participation request expired without being resolved by the `Convener` and was cleared.
+        ParticipationRequestExpired(TrustRegistryId, IssuerOrVerifier),
+        /// New version of the governance framework was added to the `TrustRegistry` history.
+        GovFrameworkVersionAdded(TrustRegistryId, u32),
+        /// `Convener` revoked the delegation from the first `Issuer` to the second one.
+        DelegationRevoked(TrustRegistryId, Issuer, Issuer),
+        /// Definition of the schema with the given id was added.
+        SchemaDefinitionAdded(TrustRegistrySchemaId),
+        /// Transfer of the `TrustRegistry` with the given id to the new `Convener` was proposed.
+        /// Transfer takes effect once it's accepted by the new `Convener`.
+        TrustRegistryTransferProposed(TrustRegistryId, Convener),
     }
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + did::Config {
+    pub trait Config: frame_system::Config + did::Config + blob::Config {
         type Event: From<Event>
             + IsType<<Self as frame_system::Config>::Event>
             + Into<<Self as frame_system::Config>::Event>;
+
+        /// Currency used to settle verification fees and reserve participation request deposits.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Symbol of the `Currency` as used in the `Issuer`s verification prices.
+        /// Prices under this symbol are expressed in the lowest denomination of the `Currency`, i.e. its indivisible units.
+        /// Only these prices can be settled on-chain, prices in the other currencies are informational.
+        type NativeCurrencySymbol: Get<&'static str>;
+
+        /// Share of each verification fee paid to the `TrustRegistry`'s `Convener`.
+        /// The `Issuer` receives the whole fee if the `Convener` didn't set an account to be paid to.
+        #[pallet::constant]
+        type ConvenerFeeShare: Get<sp_runtime::Perbill>;
+
+        /// Max depth of the recognized registries chain to be followed while resolving `Issuer`s authorization.
+        #[pallet::constant]
+        type MaxRecognitionDepth: Get<u32>;
+
+        /// Max amount of delegations in the chain starting from the `Issuer` which owns the schemas.
+        #[pallet::constant]
+        type MaxDelegationDepth: Get<u32>;
+
+        /// Amount of blocks after which a pending participation request expires.
+        #[pallet::constant]
+        type ParticipationRequestTtl: Get<Self::BlockNumber>;
+
+        /// Amount reserved from the account submitting a participation request until the request is
+        /// resolved by the `Convener` or cleared after expiring.
+        #[pallet::constant]
+        type ParticipationRequestDeposit: Get<BalanceOf<Self>>;
+
+        /// Max amount of pending participation requests per `TrustRegistry`.
+        #[pallet::constant]
+        type MaxParticipationRequestsPerRegistry: Get<u32>;
+
+        /// Max amount of governance framework versions kept in the `TrustRegistry` history.
+        /// Adding a version to the full history prunes the oldest one.
+        #[pallet::constant]
+        type MaxGovFrameworkVersions: Get<u32>;
+
+        /// Max amount of entries returned by a single page of the paginated queries.
+        #[pallet::constant]
+        type MaxQueryPageSize: Get<u32>;
     }
 
     /// Stores `TrustRegistry`s information: `Convener`, name, etc.
@@ -222,6 +374,12 @@ pub mod pallet {
         TrustRegistrySchemaMetadata<T>,
     >;
 
+    /// Definitions of the schemas which can be referenced by the trust registries. Mapping of the form schema_id -> schema_definition
+    #[pallet::storage]
+    #[pallet::getter(fn schema_definition)]
+    pub type TrustRegistrySchemaDefinitions<T: Config> =
+        StorageMap<_, Blake2_128Concat, TrustRegistrySchemaId, TrustRegistrySchemaDefinition<T>>;
+
     /// Schema ids corresponding to trust registries. Mapping of `TrustRegistryId` -> set of schema ids.
     #[pallet::storage]
     #[pallet::getter(fn registry_stored_schemas)]
@@ -297,6 +455,34 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Stores scopes of the delegations set via `update_delegations`.
+    /// Mapping of the form registry_id -> (delegating_issuer, delegated_issuer) -> scope.
+    /// Delegations without a stored scope cover all schemas of the delegating `Issuer`
+    /// and can't be extended any further.
+    #[pallet::storage]
+    #[pallet::getter(fn delegation_scope)]
+    pub type TrustRegistryDelegationScopes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        TrustRegistryId,
+        Blake2_128Concat,
+        (Issuer, Issuer),
+        DelegationScope<T>,
+    >;
+
+    /// Stores `Issuer`s which delegated their schemas to the given `Issuer` using scoped delegations.
+    #[pallet::storage]
+    #[pallet::getter(fn registry_issuer_delegators)]
+    pub type TrustRegistryIssuerDelegators<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        TrustRegistryId,
+        Blake2_128Concat,
+        Issuer,
+        IssuerDelegators<T>,
+        ValueQuery,
+    >;
+
     /// Stores a set of `Verifier`s Trust Registries.
     #[pallet::storage]
     #[pallet::getter(fn verifier_registries)]
@@ -322,6 +508,95 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Accounts receiving verification fees on behalf of `Convener`s and `Issuer`s.
+    #[pallet::storage]
+    #[pallet::getter(fn fee_payout_account)]
+    pub type FeePayoutAccounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, ConvenerOrIssuerOrVerifier, T::AccountId>;
+
+    /// Accreditation periods of `Issuer`s per schema. Mapping of the form registry_id -> (schema_id, issuer) -> accreditation
+    /// `Issuer`s without an entry are accredited indefinitely. Lapsed accreditations are kept until replaced or removed,
+    /// accreditation periods are checked against the current block on access.
+    #[pallet::storage]
+    #[pallet::getter(fn issuer_accreditation)]
+    pub type TrustRegistryIssuerAccreditations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        TrustRegistryId,
+        Blake2_128Concat,
+        (TrustRegistrySchemaId, Issuer),
+        IssuerAccreditation<T>,
+    >;
+
+    /// Registries recognized by the given `TrustRegistry`. Mapping of the form registry_id -> recognized_registry_ids
+    #[pallet::storage]
+    #[pallet::getter(fn registry_recognitions)]
+    pub type TrustRegistryRecognitions<T: Config> =
+        StorageMap<_, Blake2_128Concat, TrustRegistryId, RecognizedTrustRegistries<T>, ValueQuery>;
+
+    /// Recognitions accepted by the given `TrustRegistry`. Mapping of the form (recognized_registry_id, recognizer_registry_id) -> ()
+    #[pallet::storage]
+    pub type TrustRegistryRecognitionAcceptances<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        TrustRegistryId,
+        Blake2_128Concat,
+        TrustRegistryId,
+        (),
+    >;
+
+    /// Pending participation requests. Mapping of the form registry_id -> participant -> request
+    #[pallet::storage]
+    #[pallet::getter(fn participation_request)]
+    pub type TrustRegistryParticipationRequests<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        TrustRegistryId,
+        Blake2_128Concat,
+        IssuerOrVerifier,
+        ParticipationRequest<T>,
+    >;
+
+    /// Amount of pending participation requests stored for the `TrustRegistry`, including the expired ones not cleared yet.
+    #[pallet::storage]
+    #[pallet::getter(fn participation_requests_count)]
+    pub type TrustRegistryParticipationRequestsCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, TrustRegistryId, u32, ValueQuery>;
+
+    /// Governance framework versions history. Mapping of the form registry_id -> version -> governance_framework_version
+    /// Versions are numbered starting from zero in the ascending order of their effective blocks.
+    /// Only the latest `MaxGovFrameworkVersions` versions are kept, older ones are pruned.
+    #[pallet::storage]
+    #[pallet::getter(fn gov_framework_version)]
+    pub type TrustRegistryGovFrameworkVersions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        TrustRegistryId,
+        Twox64Concat,
+        u32,
+        GovFrameworkVersion<T>,
+    >;
+
+    /// Amount of the governance framework versions ever added to the `TrustRegistry`, including the pruned ones.
+    #[pallet::storage]
+    #[pallet::getter(fn gov_framework_versions_count)]
+    pub type TrustRegistryGovFrameworkVersionsCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, TrustRegistryId, u32, ValueQuery>;
+
+    /// Stores transfers of the `TrustRegistry`s proposed by their `Convener`s as a mapping of the form
+    /// registry_id -> new convener. A transfer takes effect only once the new `Convener` accepts it.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_trust_registry_transfer)]
+    pub type PendingTrustRegistryTransfers<T> =
+        StorageMap<_, Blake2_128Concat, TrustRegistryId, Convener>;
+
+    /// Ids of the schemas referenced by the `TrustRegistry`s before the definitions were introduced.
+    /// These schemas can be added to the `TrustRegistry`s without having a definition.
+    #[pallet::storage]
+    #[pallet::getter(fn is_legacy_schema)]
+    pub type LegacySchemaIds<T> =
+        StorageMap<_, Blake2_128Concat, TrustRegistrySchemaId, (), OptionQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Creates a new `Trust Registry` with the provided identifier.
@@ -345,6 +620,85 @@ pub mod pallet {
                 .map_err(Into::into)
         }
 
+        /// Removes the `Trust Registry` with the provided identifier along with its schemas metadata, participants
+        /// and all of the `Issuer`/`Verifier` indices referencing it. Deposits of the pending participation requests
+        /// are unreserved. Must be signed by the `Trust Registry`'s `Convener`.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::remove_trust_registry::<T>(remove_trust_registry, signature))]
+        pub fn remove_trust_registry(
+            origin: OriginFor<T>,
+            remove_trust_registry: RemoveTrustRegistry<T>,
+            signature: DidOrDidMethodKeySignature<Convener>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let actual_weight = |removed: RemovedTrustRegistryEntities| {
+                SubstrateWeight::<T::DbWeight>::remove_trust_registry_with_sizes::<T>(
+                    &signature, removed,
+                )
+            };
+
+            remove_trust_registry
+                .signed(signature.clone())
+                .execute_removable(Self::remove_trust_registry_)
+                .map(|removed| PostDispatchInfo {
+                    actual_weight: Some(actual_weight(removed)),
+                    pays_fee: Pays::Yes,
+                })
+                .map_err(IntermediateError::<T>::into_dispatch_with_post_info)
+        }
+
+        /// Proposes to transfer the `Trust Registry` with the provided identifier to the new `Convener`.
+        /// Must be signed by the `Trust Registry`'s current `Convener`. The transfer takes effect once the new
+        /// `Convener` accepts it using `accept_trust_registry_transfer`, a subsequent proposal replaces the pending one.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::transfer_trust_registry::<T>(transfer_trust_registry, signature))]
+        pub fn transfer_trust_registry(
+            origin: OriginFor<T>,
+            transfer_trust_registry: TransferTrustRegistry<T>,
+            signature: DidOrDidMethodKeySignature<Convener>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            transfer_trust_registry
+                .signed(signature)
+                .execute_removable(Self::transfer_trust_registry_)
+                .map_err(Into::into)
+        }
+
+        /// Sets (or removes) an account to receive verification fees on behalf of the signer DID.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::set_fee_payout_account::<T>(set_fee_payout_account, signature))]
+        pub fn set_fee_payout_account(
+            origin: OriginFor<T>,
+            set_fee_payout_account: SetFeePayoutAccount<T>,
+            signature: DidOrDidMethodKeySignature<ConvenerOrIssuerOrVerifier>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            set_fee_payout_account
+                .signed(signature)
+                .execute_removable(Self::set_fee_payout_account_)
+                .map_err(Into::into)
+        }
+
+        /// Pays the `Issuer`'s advertised price in the native currency for verifying a credential of the given schema.
+        /// The price is taken in the lowest denomination of `T::Currency`. Prices in the other currencies can't be settled.
+        /// The fee is charged from the origin account while the action must be signed by a `Verifier` of the schema.
+        /// A `T::ConvenerFeeShare` part of the fee is paid to the `Convener` if it has set an account to be paid to.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::settle_verification_fee::<T>(settle_verification_fee, signature))]
+        pub fn settle_verification_fee(
+            origin: OriginFor<T>,
+            settle_verification_fee: SettleVerificationFee<T>,
+            signature: DidOrDidMethodKeySignature<Verifier>,
+        ) -> DispatchResult {
+            let payer = ensure_signed(origin)?;
+
+            settle_verification_fee
+                .signed(signature)
+                .execute_view(|action, registry_info, verifier| {
+                    Self::settle_verification_fee_(action, registry_info, verifier, &payer)
+                })
+                .map_err(Into::into)
+        }
+
         /// Sets the schema metadata entry (entries) with the supplied identifier(s).
         ///
         /// - `Convener` DID owning registry with the provided identifier can make any modifications.
@@ -419,6 +773,168 @@ pub mod pallet {
                 .map_err(Into::into)
         }
 
+        /// Sets accreditation periods of the given `Issuer`s for the schema.
+        /// `Issuer`s aren't considered authorized outside of their accreditation periods.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::set_issuers_accreditation::<T>(set_issuers_accreditation, signature))]
+        pub fn set_issuers_accreditation(
+            origin: OriginFor<T>,
+            set_issuers_accreditation: SetIssuersAccreditation<T>,
+            signature: DidOrDidMethodKeySignature<Convener>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            set_issuers_accreditation
+                .signed(signature)
+                .execute_view(Self::set_issuers_accreditation_)
+                .map_err(Into::into)
+        }
+
+        /// Adds or removes registries recognized by the `TrustRegistry`.
+        /// Recognition takes effect only after being accepted by the `Convener` of the recognized registry.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::update_recognized_registries::<T>(update_recognized_registries, signature))]
+        pub fn update_recognized_registries(
+            origin: OriginFor<T>,
+            update_recognized_registries: UpdateRecognizedRegistries<T>,
+            signature: DidOrDidMethodKeySignature<Convener>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            update_recognized_registries
+                .signed(signature)
+                .execute_view(Self::update_recognized_registries_)
+                .map_err(Into::into)
+        }
+
+        /// Accepts or stops accepting the `TrustRegistry` being recognized by the given registries.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::update_recognition_acceptances::<T>(update_recognition_acceptances, signature))]
+        pub fn update_recognition_acceptances(
+            origin: OriginFor<T>,
+            update_recognition_acceptances: UpdateRecognitionAcceptances<T>,
+            signature: DidOrDidMethodKeySignature<Convener>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            update_recognition_acceptances
+                .signed(signature)
+                .execute_view(Self::update_recognition_acceptances_)
+                .map_err(Into::into)
+        }
+
+        /// Requests participation of the signer `Issuer` or `Verifier` in the `TrustRegistry`.
+        /// The request has to be approved by the `Convener` before it expires in `T::ParticipationRequestTtl` blocks.
+        /// `T::ParticipationRequestDeposit` is reserved from the origin account until the request is resolved
+        /// or cleared after expiring. Resubmitting a pending request replaces its information and extends its expiry
+        /// keeping the initial deposit.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::request_participation::<T>(request_participation, signature))]
+        pub fn request_participation(
+            origin: OriginFor<T>,
+            request_participation: RequestParticipation<T>,
+            signature: DidOrDidMethodKeySignature<ConvenerOrIssuerOrVerifier>,
+        ) -> DispatchResult {
+            let depositor = ensure_signed(origin)?;
+
+            request_participation
+                .signed(signature)
+                .execute_removable(|action, request, participant| {
+                    Self::request_participation_(action, request, participant, &depositor)
+                })
+                .map_err(Into::into)
+        }
+
+        /// Approves or rejects pending participation requests.
+        /// Approved participants are added to the `TrustRegistry` along with the information supplied in their requests.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::resolve_participation_requests::<T>(resolve_participation_requests, signature))]
+        pub fn resolve_participation_requests(
+            origin: OriginFor<T>,
+            resolve_participation_requests: ResolveParticipationRequests<T>,
+            signature: DidOrDidMethodKeySignature<Convener>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            resolve_participation_requests
+                .signed(signature)
+                .execute_view(Self::resolve_participation_requests_)
+                .map_err(Into::into)
+        }
+
+        /// Clears expired participation requests of the supplied participants unreserving their deposits.
+        /// Can be called by any account.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::clear_expired_participation_requests(participants.len() as u32))]
+        pub fn clear_expired_participation_requests(
+            origin: OriginFor<T>,
+            registry_id: TrustRegistryId,
+            participants: Vec<IssuerOrVerifier>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            Self::clear_expired_participation_requests_(registry_id, participants)
+        }
+
+        /// Appends a new version of the governance framework to the `TrustRegistry` history.
+        /// The version can't take effect before the current block or the latest version already stored.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::add_gov_framework_version::<T>(add_gov_framework_version, signature))]
+        pub fn add_gov_framework_version(
+            origin: OriginFor<T>,
+            add_gov_framework_version: AddGovFrameworkVersion<T>,
+            signature: DidOrDidMethodKeySignature<Convener>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            add_gov_framework_version
+                .signed(signature)
+                .execute_view(Self::add_gov_framework_version_)
+                .map_err(Into::into)
+        }
+
+        /// Sets or revokes scoped delegations of the given `Issuer`.
+        /// Each delegation can be limited to a subset of the delegating `Issuer`'s schemas
+        /// and to the amount of further delegation levels below the delegated `Issuer`.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::update_delegations::<T>(update_delegations, signature))]
+        pub fn update_delegations(
+            origin: OriginFor<T>,
+            update_delegations: UpdateDelegations<T>,
+            signature: DidOrDidMethodKeySignature<Issuer>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            update_delegations
+                .signed_with_combined_target(signature, |target, signer| (target, signer))?
+                .execute(Self::update_delegations_)
+                .map_err(Into::into)
+        }
+
+        /// Revokes delegations between the given `Issuer`s of the `TrustRegistry`.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::revoke_delegations::<T>(revoke_delegations, signature))]
+        pub fn revoke_delegations(
+            origin: OriginFor<T>,
+            revoke_delegations: RevokeDelegations<T>,
+            signature: DidOrDidMethodKeySignature<Convener>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            revoke_delegations
+                .signed(signature)
+                .execute_view(Self::revoke_delegations_)
+                .map_err(Into::into)
+        }
+
+        /// Adds an immutable definition of the schema with the given id.
+        /// Schemas must be defined before being added to any `TrustRegistry`.
+        /// If the definition references a blob, its content must match the supplied hash.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::add_schema_definition::<T>(add_schema_definition, signature))]
+        pub fn add_schema_definition(
+            origin: OriginFor<T>,
+            add_schema_definition: AddSchemaDefinition<T>,
+            signature: DidOrDidMethodKeySignature<Convener>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            add_schema_definition
+                .signed(signature)
+                .execute_removable(Self::add_schema_definition_)
+                .map_err(Into::into)
+        }
+
         /// Unsuspends given `Issuer`s.
         #[pallet::weight(SubstrateWeight::<T::DbWeight>::unsuspend_issuers::<T>(unsuspend_issuers, signature))]
         pub fn unsuspend_issuers(
@@ -521,6 +1037,22 @@ pub mod pallet {
             .view(ActionWrapper::wrap_fn(f))
             .map_err(Into::into)
         }
+
+        /// Accepts the pending transfer of the `Trust Registry` with the provided identifier.
+        /// Must be signed by the new `Convener`.
+        #[pallet::weight(SubstrateWeight::<T::DbWeight>::accept_trust_registry_transfer::<T>(accept, signature))]
+        pub fn accept_trust_registry_transfer(
+            origin: OriginFor<T>,
+            accept: AcceptTrustRegistryTransfer<T>,
+            signature: DidOrDidMethodKeySignature<Convener>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            accept
+                .signed(signature)
+                .execute(Self::accept_trust_registry_transfer_)
+                .map_err(Into::into)
+        }
     }
 }
 
@@ -608,6 +1140,134 @@ impl<W: Get<RuntimeDbWeight>> SubstrateWeight<W> {
         )
     }
 
+    fn remove_trust_registry<T: Config>(
+        _: &RemoveTrustRegistry<T>,
+        signed: &DidOrDidMethodKeySignature<Convener>,
+    ) -> Weight {
+        Self::remove_trust_registry_with_sizes::<T>(
+            signed,
+            RemovedTrustRegistryEntities {
+                accesses: StorageAccesses {
+                    issuer_schemas: T::MaxIssuersPerSchema::get() / 5,
+                    verifier_schemas: T::MaxVerifiersPerSchema::get() / 5,
+                    schemas: T::MaxSchemasPerRegistry::get() / 5,
+                    ..Default::default()
+                },
+                participants: T::MaxParticipantsPerRegistry::get() / 5,
+                accreditations: T::MaxIssuersPerSchema::get() / 5,
+                recognitions: T::MaxRecognizedRegistries::get() / 5,
+                participation_requests: T::MaxParticipationRequestsPerRegistry::get(),
+                gov_framework_versions: T::MaxGovFrameworkVersions::get(),
+                delegations: T::MaxDelegatedIssuers::get() / 5,
+            },
+        )
+    }
+
+    fn remove_trust_registry_with_sizes<T: Config>(
+        signed: &DidOrDidMethodKeySignature<Convener>,
+        RemovedTrustRegistryEntities {
+            accesses,
+            participants,
+            accreditations,
+            recognitions,
+            participation_requests,
+            gov_framework_versions,
+            delegations,
+        }: RemovedTrustRegistryEntities,
+    ) -> Weight {
+        let (issuers, verifiers, schemas) =
+            (accesses.issuers(), accesses.verifiers(), accesses.schemas());
+
+        signed
+            .weight_for_sig_type::<T>(
+                || {
+                    Self::remove_trust_registry_sr25519(
+                        issuers,
+                        verifiers,
+                        schemas,
+                        participants,
+                        accreditations,
+                        recognitions,
+                        participation_requests,
+                        gov_framework_versions,
+                        delegations,
+                    )
+                },
+                || {
+                    Self::remove_trust_registry_ed25519(
+                        issuers,
+                        verifiers,
+                        schemas,
+                        participants,
+                        accreditations,
+                        recognitions,
+                        participation_requests,
+                        gov_framework_versions,
+                        delegations,
+                    )
+                },
+                || {
+                    Self::remove_trust_registry_secp256k1(
+                        issuers,
+                        verifiers,
+                        schemas,
+                        participants,
+                        accreditations,
+                        recognitions,
+                        participation_requests,
+                        gov_framework_versions,
+                        delegations,
+                    )
+                },
+            )
+            // Removing the pending transfer.
+            .saturating_add(W::get().writes(1))
+    }
+
+    fn transfer_trust_registry<T: Config>(
+        _: &TransferTrustRegistry<T>,
+        signed: &DidOrDidMethodKeySignature<Convener>,
+    ) -> Weight {
+        signed.weight_for_sig_type::<T>(
+            Self::transfer_trust_registry_sr25519,
+            Self::transfer_trust_registry_ed25519,
+            Self::transfer_trust_registry_secp256k1,
+        )
+    }
+
+    fn accept_trust_registry_transfer<T: Config>(
+        _: &AcceptTrustRegistryTransfer<T>,
+        signed: &DidOrDidMethodKeySignature<Convener>,
+    ) -> Weight {
+        signed.weight_for_sig_type::<T>(
+            Self::accept_trust_registry_transfer_sr25519,
+            Self::accept_trust_registry_transfer_ed25519,
+            Self::accept_trust_registry_transfer_secp256k1,
+        )
+    }
+
+    fn set_fee_payout_account<T: Config>(
+        _: &SetFeePayoutAccount<T>,
+        signed: &DidOrDidMethodKeySignature<ConvenerOrIssuerOrVerifier>,
+    ) -> Weight {
+        signed.weight_for_sig_type::<T>(
+            Self::set_fee_payout_account_sr25519,
+            Self::set_fee_payout_account_ed25519,
+            Self::set_fee_payout_account_secp256k1,
+        )
+    }
+
+    fn settle_verification_fee<T: Config>(
+        _: &SettleVerificationFee<T>,
+        signed: &DidOrDidMethodKeySignature<Verifier>,
+    ) -> Weight {
+        signed.weight_for_sig_type::<T>(
+            Self::settle_verification_fee_sr25519,
+            Self::settle_verification_fee_ed25519,
+            Self::settle_verification_fee_secp256k1,
+        )
+    }
+
     fn update_delegated_issuers<T: Config>(
         UpdateDelegatedIssuers { delegated, .. }: &UpdateDelegatedIssuers<T>,
         signed: &DidOrDidMethodKeySignature<Issuer>,
@@ -621,6 +1281,153 @@ impl<W: Get<RuntimeDbWeight>> SubstrateWeight<W> {
         )
     }
 
+    fn set_issuers_accreditation<T: Config>(
+        SetIssuersAccreditation { accreditations, .. }: &SetIssuersAccreditation<T>,
+        signed: &DidOrDidMethodKeySignature<Convener>,
+    ) -> Weight {
+        let issuers_len = accreditations.len() as u32;
+
+        signed.weight_for_sig_type::<T>(
+            || Self::set_issuers_accreditation_sr25519(issuers_len),
+            || Self::set_issuers_accreditation_ed25519(issuers_len),
+            || Self::set_issuers_accreditation_secp256k1(issuers_len),
+        )
+    }
+
+    fn update_recognized_registries<T: Config>(
+        UpdateRecognizedRegistries { registries, .. }: &UpdateRecognizedRegistries<T>,
+        signed: &DidOrDidMethodKeySignature<Convener>,
+    ) -> Weight {
+        let registries_len = registries.len() as u32;
+
+        signed.weight_for_sig_type::<T>(
+            || Self::update_recognized_registries_sr25519(registries_len),
+            || Self::update_recognized_registries_ed25519(registries_len),
+            || Self::update_recognized_registries_secp256k1(registries_len),
+        )
+    }
+
+    fn update_recognition_acceptances<T: Config>(
+        UpdateRecognitionAcceptances { recognizers, .. }: &UpdateRecognitionAcceptances<T>,
+        signed: &DidOrDidMethodKeySignature<Convener>,
+    ) -> Weight {
+        let recognizers_len = recognizers.len() as u32;
+
+        signed.weight_for_sig_type::<T>(
+            || Self::update_recognition_acceptances_sr25519(recognizers_len),
+            || Self::update_recognition_acceptances_ed25519(recognizers_len),
+            || Self::update_recognition_acceptances_secp256k1(recognizers_len),
+        )
+    }
+
+    fn request_participation<T: Config>(
+        RequestParticipation {
+            information:
+                UnboundedTrustRegistryParticipantInformation {
+                    org_name,
+                    logo,
+                    description,
+                },
+            ..
+        }: &RequestParticipation<T>,
+        signed: &DidOrDidMethodKeySignature<ConvenerOrIssuerOrVerifier>,
+    ) -> Weight {
+        let (o, l, d) = (
+            org_name.len() as u32,
+            logo.len() as u32,
+            description.len() as u32,
+        );
+
+        signed.weight_for_sig_type::<T>(
+            || Self::request_participation_sr25519(o, l, d),
+            || Self::request_participation_ed25519(o, l, d),
+            || Self::request_participation_secp256k1(o, l, d),
+        )
+    }
+
+    fn resolve_participation_requests<T: Config>(
+        ResolveParticipationRequests { resolutions, .. }: &ResolveParticipationRequests<T>,
+        signed: &DidOrDidMethodKeySignature<Convener>,
+    ) -> Weight {
+        let resolutions_len = resolutions.len() as u32;
+
+        signed.weight_for_sig_type::<T>(
+            || Self::resolve_participation_requests_sr25519(resolutions_len),
+            || Self::resolve_participation_requests_ed25519(resolutions_len),
+            || Self::resolve_participation_requests_secp256k1(resolutions_len),
+        )
+    }
+
+    fn add_gov_framework_version<T: Config>(
+        AddGovFrameworkVersion { uri, .. }: &AddGovFrameworkVersion<T>,
+        signed: &DidOrDidMethodKeySignature<Convener>,
+    ) -> Weight {
+        let uri_len = uri.len() as u32;
+
+        signed.weight_for_sig_type::<T>(
+            || Self::add_gov_framework_version_sr25519(uri_len),
+            || Self::add_gov_framework_version_ed25519(uri_len),
+            || Self::add_gov_framework_version_secp256k1(uri_len),
+        )
+    }
+
+    fn add_schema_definition<T: Config>(
+        AddSchemaDefinition {
+            json_schema,
+            credential_type,
+            ..
+        }: &AddSchemaDefinition<T>,
+        signed: &DidOrDidMethodKeySignature<Convener>,
+    ) -> Weight {
+        let uris_len = json_schema.len().saturating_add(credential_type.len()) as u32;
+
+        signed.weight_for_sig_type::<T>(
+            || Self::add_schema_definition_sr25519(uris_len),
+            || Self::add_schema_definition_ed25519(uris_len),
+            || Self::add_schema_definition_secp256k1(uris_len),
+        )
+    }
+
+    fn update_delegations<T: Config>(
+        UpdateDelegations { delegations, .. }: &UpdateDelegations<T>,
+        signed: &DidOrDidMethodKeySignature<Issuer>,
+    ) -> Weight {
+        let delegations_len = delegations.len() as u32;
+        let schemas_len = delegations
+            .values()
+            .flatten()
+            .filter_map(|scope| scope.schemas.as_ref())
+            .map(|schemas| schemas.len() as u32)
+            .fold(0, u32::saturating_add);
+        // Delegatable schemas are collected by following the chains above the `Issuer`, each level
+        // multiplying the amount of the delegations by up to `T::MaxDelegatedIssuers`.
+        let chained_len = (1..T::MaxDelegationDepth::get())
+            .map(|level| T::MaxDelegatedIssuers::get().saturating_pow(level))
+            .fold(0, u32::saturating_add);
+
+        signed.weight_for_sig_type::<T>(
+            || Self::update_delegations_sr25519(delegations_len, schemas_len, chained_len),
+            || Self::update_delegations_ed25519(delegations_len, schemas_len, chained_len),
+            || Self::update_delegations_secp256k1(delegations_len, schemas_len, chained_len),
+        )
+    }
+
+    fn revoke_delegations<T: Config>(
+        RevokeDelegations { delegations, .. }: &RevokeDelegations<T>,
+        signed: &DidOrDidMethodKeySignature<Convener>,
+    ) -> Weight {
+        let delegations_len = delegations
+            .values()
+            .map(|delegated| delegated.len() as u32)
+            .fold(0, u32::saturating_add);
+
+        signed.weight_for_sig_type::<T>(
+            || Self::revoke_delegations_sr25519(delegations_len),
+            || Self::revoke_delegations_ed25519(delegations_len),
+            || Self::revoke_delegations_secp256k1(delegations_len),
+        )
+    }
+
     fn suspend_issuers<T: Config>(
         SuspendIssuers { issuers, .. }: &SuspendIssuers<T>,
         signed: &DidOrDidMethodKeySignature<Convener>,
@@ -1,7 +1,22 @@
 use super::*;
-use crate::util::{InclusionRule, MaybeDoubleSet};
-use alloc::collections::{BTreeMap, BTreeSet};
-use core::{convert::identity, iter::repeat};
+use crate::{
+    common::Limits,
+    did::DidOrDidMethodKey,
+    util::{BoundedBytes, Bytes, InclusionRule, MaybeDoubleSet},
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::{
+    convert::identity,
+    iter::{once, repeat},
+    ops::Bound,
+};
+use frame_support::{
+    Blake2_128Concat, CloneNoBound, DebugNoBound, EqNoBound, PartialEqNoBound, StorageHasher,
+};
+use itertools::Itertools;
 use types::*;
 
 /// Specifies arguments to retrieve trust registries informations by.
@@ -12,13 +27,13 @@ use types::*;
 #[scale_info(omit_prefix)]
 pub struct QueryTrustRegistriesBy {
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-    issuers: Option<InclusionRule<Issuer>>,
+    pub issuers: Option<InclusionRule<Issuer>>,
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-    verifiers: Option<InclusionRule<Verifier>>,
+    pub verifiers: Option<InclusionRule<Verifier>>,
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-    issuers_or_verifiers: Option<InclusionRule<IssuerOrVerifier>>,
+    pub issuers_or_verifiers: Option<InclusionRule<IssuerOrVerifier>>,
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-    schema_ids: Option<InclusionRule<TrustRegistrySchemaId>>,
+    pub schema_ids: Option<InclusionRule<TrustRegistrySchemaId>>,
 }
 
 /// Specifies arguments to retrieve trust registry informations by.
@@ -38,6 +53,93 @@ pub struct QueryTrustRegistryBy {
     schema_ids: Option<BTreeSet<TrustRegistrySchemaId>>,
 }
 
+/// Specifies filters to apply to the schemas metadata of the trust registry.
+/// Unset filters match every schema.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(omit_prefix)]
+pub struct SchemaMetadataFilter {
+    /// Retain only schemas having the given `Issuer` (or delegated `Issuer`).
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub issuer: Option<Issuer>,
+    /// Retain only schemas having the given `Verifier`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub verifier: Option<Verifier>,
+    /// Retain only `Issuer`s with the given suspension status, schemas left without `Issuer`s are skipped.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub suspended: Option<bool>,
+    /// Retain only schemas which identifiers start with the given bytes.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub schema_id_prefix: Option<Bytes>,
+}
+
+impl SchemaMetadataFilter {
+    /// Resolves to a vector containing up to `limit` (capped by `T::MaxQueryPageSize`)
+    /// `TrustRegistrySchemaId` -> `AggregatedTrustRegistrySchemaMetadata<T>` pairs matching the filter. Schemas are returned in ascending order of their identifiers starting after the `after` one,
+    /// so the identifier of the last returned schema can be used to request the next page.
+    pub fn resolve_to_schemas_metadata_in_registry_paginated<T: Config>(
+        self,
+        reg_id: TrustRegistryId,
+        after: Option<TrustRegistrySchemaId>,
+        limit: u32,
+    ) -> Vec<(
+        TrustRegistrySchemaId,
+        AggregatedTrustRegistrySchemaMetadata<T>,
+    )> {
+        let Self {
+            issuer,
+            verifier,
+            suspended,
+            schema_id_prefix,
+        } = self;
+
+        let TrustRegistryStoredSchemas(schema_ids) = TrustRegistriesStoredSchemas::<T>::get(reg_id);
+        let issuer_schema_ids = issuer
+            .map(|issuer| Pallet::<T>::registry_issuer_or_delegated_issuer_schemas(reg_id, issuer));
+
+        schema_ids
+            .range((
+                after.map_or(Bound::Unbounded, Bound::Excluded),
+                Bound::Unbounded,
+            ))
+            .copied()
+            .filter(|schema_id| {
+                schema_id_prefix
+                    .as_ref()
+                    .map_or(true, |prefix| schema_id.0.starts_with(&prefix.0))
+            })
+            .filter(|schema_id| {
+                issuer_schema_ids
+                    .as_ref()
+                    .map_or(true, |schema_ids| schema_ids.contains(schema_id))
+            })
+            .filter_map(|schema_id| {
+                let mut metadata = Pallet::<T>::aggregate_schema_metadata((reg_id, schema_id))?;
+
+                if let Some(verifier) = verifier {
+                    if !metadata.verifiers.contains(&verifier) {
+                        return None;
+                    }
+                }
+                if let Some(suspended) = suspended {
+                    metadata
+                        .issuers
+                        .retain(|(_, info)| info.suspended == suspended);
+
+                    if metadata.issuers.is_empty() {
+                        return None;
+                    }
+                }
+
+                Some((schema_id, metadata))
+            })
+            .take(limit.min(T::MaxQueryPageSize::get()) as usize)
+            .collect()
+    }
+}
+
 impl QueryTrustRegistryBy {
     /// Resolves to a map containing `TrustRegistrySchemaId` -> `AggregatedTrustRegistrySchemaMetadata<T>` pairs.
     pub fn resolve_to_schemas_metadata_in_registry<T: Config>(
@@ -104,14 +206,88 @@ impl QueryTrustRegistriesBy {
             .collect()
     }
 
-    pub fn resolve_to_registry_ids<T: Config>(self) -> BTreeSet<TrustRegistryId> {
+    /// Resolves to a vector containing up to `limit` (capped by `T::MaxQueryPageSize`)
+    /// `TrustRegistryId` -> `TrustRegistryInfo<T>` pairs.
+    /// Registries are returned in their storage order starting after the `after` one,
+    /// so the identifier of the last returned registry can be used to request the next page.
+    pub fn resolve_to_registries_info_paginated<T: Config>(
+        self,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+    ) -> Vec<(TrustRegistryId, TrustRegistryInfo<T>)> {
+        self.resolve_to_registry_ids_paginated::<T>(after, limit)
+            .into_iter()
+            .with_registry_info()
+            .collect()
+    }
+
+    /// Resolves to a vector containing up to `limit` (capped by `T::MaxQueryPageSize`) `TrustRegistryId`s.
+    /// Registries are returned in their storage order starting after the `after` one,
+    /// so the identifier of the last returned registry can be used to request the next page.
+    ///
+    /// Only the (bounded) registry sets of the supplied `Issuer`s/`Verifier`s are collected upfront,
+    /// while the registries referencing the supplied schemas are read lazily until the page is filled.
+    pub fn resolve_to_registry_ids_paginated<T: Config>(
+        self,
+        after: Option<TrustRegistryId>,
+        limit: u32,
+    ) -> Vec<TrustRegistryId> {
         let Self {
             issuers,
             verifiers,
             issuers_or_verifiers,
             schema_ids,
         } = self;
+        let limit = limit.min(T::MaxQueryPageSize::get()) as usize;
+        let start = after.map(registry_storage_order);
+
+        let participant_regs =
+            Self::participant_registry_ids::<T>(issuers, verifiers, issuers_or_verifiers);
+
+        match (participant_regs, schema_ids) {
+            (Some(regs), schema_ids) => regs
+                .into_iter()
+                .map(|reg_id| (registry_storage_order(reg_id), reg_id))
+                .filter(|(order, _)| start.as_ref().map_or(true, |start| order > start))
+                .sorted()
+                .map(take_second)
+                .filter(|reg_id| {
+                    schema_ids.as_ref().map_or(true, |schema_ids| {
+                        schemas_referenced_by_registry::<T>(schema_ids, *reg_id)
+                    })
+                })
+                .take(limit)
+                .collect(),
+            (None, Some(InclusionRule::AnyOf(schema_ids))) => schema_ids
+                .into_iter()
+                .map(|schema_id| registries_referencing_schema::<T>(schema_id, after))
+                .kmerge_by(|first, second| {
+                    registry_storage_order(*first) < registry_storage_order(*second)
+                })
+                .dedup()
+                .take(limit)
+                .collect(),
+            (None, Some(InclusionRule::All(schema_ids))) => {
+                let Some(&first_schema_id) = schema_ids.iter().next() else {
+                    return Vec::new();
+                };
+                let rule = InclusionRule::All(schema_ids);
 
+                registries_referencing_schema::<T>(first_schema_id, after)
+                    .filter(|reg_id| schemas_referenced_by_registry::<T>(&rule, *reg_id))
+                    .take(limit)
+                    .collect()
+            }
+            (None, None) => Vec::new(),
+        }
+    }
+
+    /// Resolves to the registries of the supplied `Issuer`s/`Verifier`s, returns `None` if none of them were supplied.
+    fn participant_registry_ids<T: Config>(
+        issuers: Option<InclusionRule<Issuer>>,
+        verifiers: Option<InclusionRule<Verifier>>,
+        issuers_or_verifiers: Option<InclusionRule<IssuerOrVerifier>>,
+    ) -> Option<BTreeSet<TrustRegistryId>> {
         let issuer_regs = issuers.map(|issuers| issuers.apply_rule(Pallet::<T>::issuer_registries));
         let verifier_regs =
             verifiers.map(|verifiers| verifiers.apply_rule(Pallet::<T>::verifier_registries));
@@ -121,10 +297,25 @@ impl QueryTrustRegistriesBy {
             issuers_or_verifiers.apply_rule(Pallet::<T>::issuer_or_verifier_registries)
         });
 
+        MaybeDoubleSet(issuers_or_verifiers_regs, issuers_and_verifiers_regs).intersection()
+    }
+
+    pub fn resolve_to_registry_ids<T: Config>(self) -> BTreeSet<TrustRegistryId> {
+        let Self {
+            issuers,
+            verifiers,
+            issuers_or_verifiers,
+            schema_ids,
+        } = self;
+
         let combined_issuers_verifiers_regs =
-            MaybeDoubleSet(issuers_or_verifiers_regs, issuers_and_verifiers_regs).intersection();
+            Self::participant_registry_ids::<T>(issuers, verifiers, issuers_or_verifiers);
+        // Registries are stored in the order of their hashed ids, so they have to be sorted for `apply_rule`.
         let schema_id_regs = schema_ids.map(|schema_ids| {
-            schema_ids.apply_rule(TrustRegistrySchemasMetadata::<T>::iter_key_prefix)
+            schema_ids.apply_rule(|schema_id| {
+                TrustRegistrySchemasMetadata::<T>::iter_key_prefix(schema_id)
+                    .collect::<BTreeSet<_>>()
+            })
         });
 
         MaybeDoubleSet(combined_issuers_verifiers_regs, schema_id_regs)
@@ -133,6 +324,150 @@ impl QueryTrustRegistriesBy {
     }
 }
 
+/// An action checked by the ToIP Trust Registry Query Protocol (TRQP) authorization query.
+#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(omit_prefix)]
+pub enum TrqpAction {
+    /// Issue credentials of the schema.
+    Issue,
+    /// Verify credentials of the schema.
+    Verify,
+}
+
+/// ToIP Trust Registry Query Protocol (TRQP) authorization query: is entity authorized for the action
+/// over the resource under the governance framework of the authority.
+/// Uses TRQP field names, i.e. `snake_case` instead of `camelCase`.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(omit_prefix)]
+pub struct TrqpAuthorizationQuery {
+    /// Entity to check the authorization of.
+    pub entity_id: DidOrDidMethodKey,
+    /// Action to be performed by the entity.
+    pub action: TrqpAction,
+    /// Schema the action relates to.
+    pub resource: TrustRegistrySchemaId,
+    /// Trust registry to check the authorization in. If not set, every registry listing the entity for the resource is checked.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub authority_id: Option<TrustRegistryId>,
+    /// Retain only trust registries having the given governance framework.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub governance_framework: Option<Bytes>,
+}
+
+/// Response to the `TrqpAuthorizationQuery` produced for a single trust registry.
+/// Uses TRQP field names, i.e. `snake_case` instead of `camelCase`.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct TrqpAuthorization<T: Limits> {
+    pub entity_id: DidOrDidMethodKey,
+    pub action: TrqpAction,
+    pub resource: TrustRegistrySchemaId,
+    pub authority_id: TrustRegistryId,
+    /// Governance framework of the trust registry.
+    pub governance_framework: BoundedBytes<T::MaxTrustRegistryGovFrameworkSize>,
+    /// `true` if the entity is listed for the resource and, for `Issuer`s, isn't suspended and holds a valid accreditation.
+    /// When the authority is set, `Issuer`s can also be authorized by the registries recognized by the authority.
+    pub authorized: bool,
+}
+
+impl TrqpAuthorizationQuery {
+    /// Resolves to a vector containing authorizations of the entity in the matching trust registries,
+    /// in ascending order of their identifiers.
+    pub fn resolve_to_authorizations<T: Config>(self) -> Vec<TrqpAuthorization<T>> {
+        let Self {
+            entity_id,
+            action,
+            resource,
+            authority_id,
+            governance_framework,
+        } = self;
+
+        let (issuers, verifiers) = match action {
+            TrqpAction::Issue => (Some(InclusionRule::any_of(once(Issuer(entity_id)))), None),
+            TrqpAction::Verify => (None, Some(InclusionRule::any_of(once(Verifier(entity_id))))),
+        };
+        let registry_ids = match authority_id {
+            Some(authority_id) => once(authority_id).collect(),
+            None => QueryTrustRegistriesBy {
+                issuers: issuers.clone(),
+                verifiers: verifiers.clone(),
+                issuers_or_verifiers: None,
+                schema_ids: Some(InclusionRule::any_of(once(resource))),
+            }
+            .resolve_to_registry_ids::<T>(),
+        };
+
+        registry_ids
+            .into_iter()
+            .with_registry_info::<T>()
+            .map(|(registry_id, info)| {
+                (
+                    registry_id,
+                    Pallet::<T>::gov_framework_in_force(registry_id, &info),
+                )
+            })
+            .filter(|(_, gov_framework)| {
+                governance_framework.as_ref().map_or(true, |framework| {
+                    gov_framework.0.as_slice() == framework.0.as_slice()
+                })
+            })
+            .map(|(registry_id, gov_framework)| {
+                let authorized = match action {
+                    // The authority can authorize the `Issuer` via the registries it recognizes.
+                    TrqpAction::Issue if authority_id.is_some() => {
+                        Pallet::<T>::issuer_authorization_path(
+                            registry_id,
+                            resource,
+                            Issuer(entity_id),
+                        )
+                        .is_some()
+                    }
+                    TrqpAction::Issue => {
+                        Pallet::<T>::is_issuer_authorized(registry_id, resource, Issuer(entity_id))
+                    }
+                    TrqpAction::Verify => QueryTrustRegistryBy {
+                        issuers: None,
+                        verifiers: verifiers.clone(),
+                        issuers_or_verifiers: None,
+                        schema_ids: Some(once(resource).collect()),
+                    }
+                    .resolve_to_schema_ids_in_registry::<T>(registry_id)
+                    .contains(&resource),
+                };
+
+                TrqpAuthorization {
+                    entity_id,
+                    action,
+                    resource,
+                    authority_id: registry_id,
+                    governance_framework: gov_framework,
+                    authorized,
+                }
+            })
+            .collect()
+    }
+}
+
 /// Extension that can be used by types implementing `IntoIterator`.
 trait IterExt: Iterator + Sized {
     /// Transforms value to an iterator emitting `TrustRegistryId`, then transforms result to an iterator producing
@@ -183,6 +518,40 @@ where
     }
 }
 
+/// Returns the key defining the order of the `TrustRegistry` in the storage maps keyed by its id.
+fn registry_storage_order(registry_id: TrustRegistryId) -> Vec<u8> {
+    Blake2_128Concat::hash(&registry_id.encode())
+}
+
+/// Iterates over the registries referencing the schema in their storage order starting after the `after` one.
+fn registries_referencing_schema<T: Config>(
+    schema_id: TrustRegistrySchemaId,
+    after: Option<TrustRegistryId>,
+) -> impl Iterator<Item = TrustRegistryId> {
+    match after {
+        Some(after) => TrustRegistrySchemasMetadata::<T>::iter_key_prefix_from(
+            schema_id,
+            TrustRegistrySchemasMetadata::<T>::hashed_key_for(schema_id, after),
+        ),
+        None => TrustRegistrySchemasMetadata::<T>::iter_key_prefix(schema_id),
+    }
+}
+
+/// Checks if the registry references the schemas according to the inclusion rule.
+fn schemas_referenced_by_registry<T: Config>(
+    schema_ids: &InclusionRule<TrustRegistrySchemaId>,
+    registry_id: TrustRegistryId,
+) -> bool {
+    let referenced = |schema_id: &TrustRegistrySchemaId| {
+        TrustRegistrySchemasMetadata::<T>::contains_key(schema_id, registry_id)
+    };
+
+    match schema_ids {
+        InclusionRule::AnyOf(schema_ids) => schema_ids.iter().any(referenced),
+        InclusionRule::All(schema_ids) => schema_ids.iter().all(referenced),
+    }
+}
+
 fn take_second<A, B>((_first, second): (A, B)) -> B {
     second
 }
@@ -6,8 +6,8 @@ use crate::{
     did::base::*,
     tests::common::*,
     util::{
-        Action, ActionWithNonceWrapper, AddOrRemoveOrModify, Bytes, IncOrDec, MultiTargetUpdate,
-        OnlyExistent, SetOrModify, SingleTargetUpdate, WithNonce,
+        Action, ActionWithNonceWrapper, AddOrRemoveOrModify, Bytes, IncOrDec, InclusionRule,
+        MultiTargetUpdate, OnlyExistent, SetOrModify, SingleTargetUpdate, WithNonce,
     },
 };
 use alloc::collections::{BTreeMap, BTreeSet};
@@ -114,6 +114,210 @@ where
     Mod::set_participant_information(Origin::signed(1u64), payload, sigs)
 }
 
+fn set_issuers_accreditation<P: sp_core::Pair>(
+    registry_id: TrustRegistryId,
+    schema_id: TrustRegistrySchemaId,
+    accreditations: impl IntoIterator<Item = (Issuer, Option<IssuerAccreditation<Test>>)>,
+    (signer, key): (impl Into<DidOrDidMethodKey>, &P),
+) -> DispatchResult
+where
+    P::Signature: Into<SigValue>,
+{
+    let signer = signer.into();
+    let action = SetIssuersAccreditation {
+        registry_id,
+        schema_id,
+        accreditations: UnboundedIssuersWith(accreditations.into_iter().collect()),
+        nonce: did_nonce::<Test, _>(signer).unwrap(),
+    };
+    let sig = did_sig(&action, key, signer, 1);
+
+    Mod::set_issuers_accreditation(Origin::signed(1u64), action, sig)
+}
+
+fn update_recognized_registries<P: sp_core::Pair>(
+    registry_id: TrustRegistryId,
+    registries: impl IntoIterator<Item = (TrustRegistryId, AddOrRemoveOrModify<()>)>,
+    (signer, key): (impl Into<DidOrDidMethodKey>, &P),
+) -> DispatchResult
+where
+    P::Signature: Into<SigValue>,
+{
+    let signer = signer.into();
+    let action = UpdateRecognizedRegistries {
+        registry_id,
+        registries: registries.into_iter().collect(),
+        nonce: did_nonce::<Test, _>(signer).unwrap(),
+    };
+    let sig = did_sig(&action, key, signer, 1);
+
+    Mod::update_recognized_registries(Origin::signed(1u64), action, sig)
+}
+
+fn update_recognition_acceptances<P: sp_core::Pair>(
+    registry_id: TrustRegistryId,
+    recognizers: impl IntoIterator<Item = (TrustRegistryId, AddOrRemoveOrModify<()>)>,
+    (signer, key): (impl Into<DidOrDidMethodKey>, &P),
+) -> DispatchResult
+where
+    P::Signature: Into<SigValue>,
+{
+    let signer = signer.into();
+    let action = UpdateRecognitionAcceptances {
+        registry_id,
+        recognizers: recognizers.into_iter().collect(),
+        nonce: did_nonce::<Test, _>(signer).unwrap(),
+    };
+    let sig = did_sig(&action, key, signer, 1);
+
+    Mod::update_recognition_acceptances(Origin::signed(1u64), action, sig)
+}
+
+fn request_participation<P: sp_core::Pair>(
+    registry_id: TrustRegistryId,
+    participant: impl Into<DidOrDidMethodKey>,
+    information: UnboundedTrustRegistryParticipantInformation,
+    (signer, key): (impl Into<DidOrDidMethodKey>, &P),
+) -> DispatchResult
+where
+    P::Signature: Into<SigValue>,
+{
+    let signer = signer.into();
+    let action = RequestParticipation {
+        registry_id,
+        participant: IssuerOrVerifier(participant.into()),
+        information,
+        nonce: did_nonce::<Test, _>(signer).unwrap(),
+    };
+    let sig = did_sig(&action, key, signer, 1);
+
+    Mod::request_participation(Origin::signed(1u64), action, sig)
+}
+
+fn resolve_participation_requests<P: sp_core::Pair>(
+    registry_id: TrustRegistryId,
+    resolutions: impl IntoIterator<Item = (IssuerOrVerifier, ParticipationRequestResolution)>,
+    (signer, key): (impl Into<DidOrDidMethodKey>, &P),
+) -> DispatchResult
+where
+    P::Signature: Into<SigValue>,
+{
+    let signer = signer.into();
+    let action = ResolveParticipationRequests {
+        registry_id,
+        resolutions: resolutions.into_iter().collect(),
+        nonce: did_nonce::<Test, _>(signer).unwrap(),
+    };
+    let sig = did_sig(&action, key, signer, 1);
+
+    Mod::resolve_participation_requests(Origin::signed(1u64), action, sig)
+}
+
+fn add_gov_framework_version<P: sp_core::Pair>(
+    registry_id: TrustRegistryId,
+    (hash, uri, effective_at): (GovFrameworkHash, &str, u64),
+    (signer, key): (impl Into<DidOrDidMethodKey>, &P),
+) -> DispatchResult
+where
+    P::Signature: Into<SigValue>,
+{
+    let signer = signer.into();
+    let action = AddGovFrameworkVersion {
+        registry_id,
+        hash,
+        uri: uri.to_string(),
+        effective_at,
+        nonce: did_nonce::<Test, _>(signer).unwrap(),
+    };
+    let sig = did_sig(&action, key, signer, 1);
+
+    Mod::add_gov_framework_version(Origin::signed(1u64), action, sig)
+}
+
+fn update_delegations<P: sp_core::Pair>(
+    registry_id: TrustRegistryId,
+    delegations: impl IntoIterator<Item = (Issuer, Option<UnboundedDelegationScope>)>,
+    (signer, key): (impl Into<DidOrDidMethodKey>, &P),
+) -> DispatchResult
+where
+    P::Signature: Into<SigValue>,
+{
+    let signer = signer.into();
+    let action = UpdateDelegations {
+        registry_id,
+        delegations: delegations.into_iter().collect(),
+        nonce: did_nonce::<Test, _>(signer).unwrap(),
+    };
+    let sig = did_sig(&action, key, signer, 1);
+
+    Mod::update_delegations(Origin::signed(1u64), action, sig)
+}
+
+fn revoke_delegations<P: sp_core::Pair>(
+    registry_id: TrustRegistryId,
+    delegations: impl IntoIterator<Item = (Issuer, BTreeSet<Issuer>)>,
+    (signer, key): (impl Into<DidOrDidMethodKey>, &P),
+) -> DispatchResult
+where
+    P::Signature: Into<SigValue>,
+{
+    let signer = signer.into();
+    let action = RevokeDelegations {
+        registry_id,
+        delegations: delegations.into_iter().collect(),
+        nonce: did_nonce::<Test, _>(signer).unwrap(),
+    };
+    let sig = did_sig(&action, key, signer, 1);
+
+    Mod::revoke_delegations(Origin::signed(1u64), action, sig)
+}
+
+fn add_schema_definition<P: sp_core::Pair>(
+    schema_id: TrustRegistrySchemaId,
+    (json_schema, credential_type, content_hash, blob_id): (
+        &str,
+        &str,
+        SchemaContentHash,
+        Option<crate::blob::BlobId>,
+    ),
+    (signer, key): (impl Into<DidOrDidMethodKey>, &P),
+) -> DispatchResult
+where
+    P::Signature: Into<SigValue>,
+{
+    let signer = signer.into();
+    let action = AddSchemaDefinition {
+        schema_id,
+        json_schema: json_schema.to_string(),
+        credential_type: credential_type.to_string(),
+        content_hash,
+        blob_id,
+        nonce: did_nonce::<Test, _>(signer).unwrap(),
+    };
+    let sig = did_sig(&action, key, signer, 1);
+
+    Mod::add_schema_definition(Origin::signed(1u64), action, sig)
+}
+
+/// Stores a definition of the schema with the given id, so it can be added to the trust registries.
+fn define_schema(schema_id: TrustRegistrySchemaId) -> TrustRegistrySchemaId {
+    TrustRegistrySchemaDefinitions::<Test>::insert(
+        schema_id,
+        TrustRegistrySchemaDefinition::<Test> {
+            author: Convener(Did(rand::random()).into()),
+            json_schema: "https://schema.dock.io/Credential.json"
+                .to_string()
+                .try_into()
+                .unwrap(),
+            credential_type: "VerifiableCredential".to_string().try_into().unwrap(),
+            content_hash: SchemaContentHash(rand::random()),
+            blob_id: None,
+        },
+    );
+
+    schema_id
+}
+
 fn build_initial_prices(count: usize, sym_length: usize) -> UnboundedVerificationPrices {
     UnboundedVerificationPrices(
         (0..count)
@@ -282,6 +486,7 @@ crate::did_or_did_method_key! {
             let schema_ids_set: BTreeSet<_> = (0..5)
                 .map(|_| rand::random())
                 .map(TrustRegistrySchemaId)
+                .map(define_schema)
                 .collect();
             let schema_ids: Vec<_> = schema_ids_set.into_iter().collect();
 
@@ -1014,6 +1219,7 @@ crate::did_or_did_method_key! {
             let schema_ids_set: BTreeSet<_> = (0..5)
                 .map(|_| rand::random())
                 .map(TrustRegistrySchemaId)
+                .map(define_schema)
                 .collect();
             let schema_ids: Vec<_> = schema_ids_set.into_iter().collect();
 
@@ -1206,6 +1412,7 @@ crate::did_or_did_method_key! {
             let schema_ids_set: BTreeSet<_> = (0..5)
                 .map(|_| rand::random())
                 .map(TrustRegistrySchemaId)
+                .map(define_schema)
                 .collect();
             let schema_ids: Vec<_> = schema_ids_set.into_iter().collect();
 
@@ -1329,7 +1536,7 @@ crate::did_or_did_method_key! {
                 1,
             );
             let random_did = Did(rand::random());
-            let new_schema_id = TrustRegistrySchemaId([123; 32]);
+            let new_schema_id = define_schema(TrustRegistrySchemaId([123; 32]));
 
             Mod::set_schemas_metadata(Origin::signed(alice), add_schema_metadata, sig).unwrap();
 
@@ -2637,4 +2844,2656 @@ crate::did_or_did_method_key! {
             }
         })
     }
+
+    #[test]
+    fn remove_trust_registry() {
+        ext().execute_with(|| {
+            let mut rng = rand::thread_rng();
+
+            let (convener, convener_kp) = newdid();
+            let (other, other_kp) = newdid();
+
+            let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                registry_id: TrustRegistryId(rand::random()),
+                name: (0..25)
+                    .map(|_| rng.sample(Alphanumeric) as char)
+                    .collect::<String>()
+                    .try_into()
+                    .unwrap(),
+                gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                nonce: 2,
+            };
+            let registry_id = init_or_update_trust_registry.registry_id;
+
+            ActionWithNonceWrapper::<Test, _, _>::new(
+                2,
+                Convener(convener.into()),
+                init_or_update_trust_registry,
+            )
+            .modify::<Test, _, _, _, _>(|action, set| {
+                action.action.modify_removable(|action, info| {
+                    Mod::init_or_update_trust_registry_(
+                        action,
+                        set,
+                        info,
+                        Convener(convener.into()),
+                    )
+                })
+            })
+            .unwrap();
+
+            let issuers: Vec<_> = (0..3).map(|_| newdid()).collect();
+            let verifiers: Vec<_> = (0..3).map(|_| newdid()).collect();
+
+            add_participants(
+                registry_id,
+                issuers
+                    .iter()
+                    .chain(verifiers.iter())
+                    .map(|(did, pair)| (*did, pair.clone())),
+                (DidOrDidMethodKey::from(convener), convener_kp.clone()),
+            )
+            .unwrap();
+
+            let schemas: BTreeMap<_, _> = (0..3)
+                .map(|_| TrustRegistrySchemaId(rand::random()))
+                .map(define_schema)
+                .map(|schema_id| {
+                    let issuers = UnboundedIssuersWith(
+                        issuers
+                            .iter()
+                            .map(|(did, _)| Issuer((*did).into()))
+                            .map(|issuer| (issuer, build_initial_prices(2, 5)))
+                            .collect(),
+                    );
+                    let verifiers = UnboundedTrustRegistrySchemaVerifiers(
+                        verifiers
+                            .iter()
+                            .map(|(did, _)| Verifier((*did).into()))
+                            .collect(),
+                    );
+
+                    (
+                        schema_id,
+                        UnboundedTrustRegistrySchemaMetadata { issuers, verifiers },
+                    )
+                })
+                .collect();
+
+            let accredited_schema_id = *schemas.keys().next().unwrap();
+
+            SetSchemasMetadata {
+                registry_id,
+                schemas: SetOrModify::Modify(
+                    schemas
+                        .into_iter()
+                        .map(|(schema_id, schema_metadata)| {
+                            (schema_id, SetOrAddOrRemoveOrModify::Add(schema_metadata))
+                        })
+                        .collect(),
+                ),
+                nonce: 3,
+            }
+            .view(|action, reg| {
+                Mod::set_schemas_metadata_(
+                    action,
+                    reg,
+                    ConvenerOrIssuerOrVerifier(convener.into()),
+                )
+            })
+            .unwrap();
+
+            SuspendIssuers {
+                registry_id,
+                issuers: once(Issuer(issuers[0].0.into())).collect(),
+                nonce: 4,
+            }
+            .view(|action, reg| Mod::suspend_issuers_(action, reg, Convener(convener.into())))
+            .unwrap();
+
+            assert_ok!(set_issuers_accreditation(
+                registry_id,
+                accredited_schema_id,
+                once((
+                    Issuer(issuers[1].0.into()),
+                    Some(IssuerAccreditation {
+                        valid_from: None,
+                        valid_until: Some(100),
+                    }),
+                )),
+                (convener, &convener_kp),
+            ));
+
+            Balances::make_free_balance_be(&1, 1_000);
+            let (applicant, applicant_kp) = newdid();
+            assert_ok!(request_participation(
+                registry_id,
+                applicant,
+                UnboundedTrustRegistryParticipantInformation {
+                    org_name: "Applicant".into(),
+                    logo: "https://logo.org/applicant".into(),
+                    description: "Applicant".into(),
+                },
+                (applicant, &applicant_kp),
+            ));
+            assert_eq!(Balances::reserved_balance(1), 100);
+
+            assert_eq!(TrustRegistrySchemasMetadata::<Test>::iter().count(), 3);
+            assert!(!Mod::issuer_or_verifier_registries(IssuerOrVerifier(
+                issuers[0].0.into()
+            ))
+            .is_empty());
+
+            let remove_trust_registry = RemoveTrustRegistry {
+                registry_id,
+                nonce: did_nonce::<Test, _>(other).unwrap(),
+            };
+            let sig = did_sig(&remove_trust_registry, &other_kp, other, 1u32);
+            assert_noop!(
+                Mod::remove_trust_registry(Origin::signed(1), remove_trust_registry, sig),
+                Error::<Test>::NotTheConvener
+            );
+
+            let remove_trust_registry = RemoveTrustRegistry {
+                registry_id,
+                nonce: did_nonce::<Test, _>(convener).unwrap(),
+            };
+            let sig = did_sig(&remove_trust_registry, &convener_kp, convener, 1u32);
+            assert_ok!(Mod::remove_trust_registry(
+                Origin::signed(1),
+                remove_trust_registry,
+                sig
+            ));
+
+            assert_eq!(TrustRegistriesInfo::<Test>::get(registry_id), None);
+            assert!(ConvenerTrustRegistries::<Test>::get(Convener(convener.into())).is_empty());
+            assert!(TrustRegistriesStoredSchemas::<Test>::get(registry_id).is_empty());
+            assert_eq!(TrustRegistrySchemasMetadata::<Test>::iter().count(), 0);
+            assert!(
+                TrustRegistriesParticipants::<Test>::get(TrustRegistryIdForParticipants(
+                    registry_id
+                ))
+                .is_empty()
+            );
+            assert_eq!(
+                TrustRegistryIssuerConfigurations::<Test>::iter_prefix(registry_id).count(),
+                0
+            );
+            assert_eq!(
+                TrustRegistryIssuerSchemas::<Test>::iter_prefix(registry_id).count(),
+                0
+            );
+            assert_eq!(
+                TrustRegistryVerifierSchemas::<Test>::iter_prefix(registry_id).count(),
+                0
+            );
+            for (did, _) in issuers.iter().chain(verifiers.iter()) {
+                assert!(
+                    Mod::issuer_or_verifier_registries(IssuerOrVerifier((*did).into())).is_empty()
+                );
+            }
+            assert_eq!(
+                TrustRegistryIssuerAccreditations::<Test>::iter_prefix(registry_id).count(),
+                0
+            );
+            assert_eq!(
+                TrustRegistryParticipationRequests::<Test>::iter_prefix(registry_id).count(),
+                0
+            );
+            assert_eq!(Balances::reserved_balance(1), 0);
+            assert_eq!(Mod::participation_requests_count(registry_id), 0);
+
+            let remove_trust_registry = RemoveTrustRegistry {
+                registry_id,
+                nonce: did_nonce::<Test, _>(convener).unwrap(),
+            };
+            let sig = did_sig(&remove_trust_registry, &convener_kp, convener, 1u32);
+            assert_noop!(
+                Mod::remove_trust_registry(Origin::signed(1), remove_trust_registry, sig),
+                Error::<Test>::NoRegistry
+            );
+        })
+    }
+
+    #[test]
+    fn transfer_trust_registry() {
+        ext().execute_with(|| {
+            let mut rng = rand::thread_rng();
+
+            let (convener, convener_kp) = newdid();
+            let (other, other_kp) = newdid();
+
+            let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                registry_id: TrustRegistryId(rand::random()),
+                name: (0..25)
+                    .map(|_| rng.sample(Alphanumeric) as char)
+                    .collect::<String>()
+                    .try_into()
+                    .unwrap(),
+                gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                nonce: 2,
+            };
+            let registry_id = init_or_update_trust_registry.registry_id;
+
+            ActionWithNonceWrapper::<Test, _, _>::new(
+                2,
+                Convener(convener.into()),
+                init_or_update_trust_registry,
+            )
+            .modify::<Test, _, _, _, _>(|action, set| {
+                action.action.modify_removable(|action, info| {
+                    Mod::init_or_update_trust_registry_(
+                        action,
+                        set,
+                        info,
+                        Convener(convener.into()),
+                    )
+                })
+            })
+            .unwrap();
+
+            let transfer = TransferTrustRegistry {
+                registry_id,
+                new_convener: Convener(other.into()),
+                nonce: did_nonce::<Test, _>(other).unwrap(),
+            };
+            let sig = did_sig(&transfer, &other_kp, other, 1u32);
+            assert_noop!(
+                Mod::transfer_trust_registry(Origin::signed(1), transfer, sig),
+                Error::<Test>::NotTheConvener
+            );
+
+            let transfer = TransferTrustRegistry {
+                registry_id,
+                new_convener: Convener(convener.into()),
+                nonce: did_nonce::<Test, _>(convener).unwrap(),
+            };
+            let sig = did_sig(&transfer, &convener_kp, convener, 1u32);
+            assert_noop!(
+                Mod::transfer_trust_registry(Origin::signed(1), transfer, sig),
+                Error::<Test>::SameConvener
+            );
+
+            let accept = |did: Did, kp: &sp_core::sr25519::Pair| {
+                let accept = AcceptTrustRegistryTransfer {
+                    registry_id,
+                    new_convener: Convener(did.into()),
+                    nonce: did_nonce::<Test, _>(did).unwrap(),
+                };
+                let sig = did_sig(&accept, kp, did, 1u32);
+
+                Mod::accept_trust_registry_transfer(Origin::signed(1), accept, sig)
+            };
+            assert_noop!(
+                accept(other, &other_kp),
+                Error::<Test>::NoPendingTrustRegistryTransfer
+            );
+
+            let transfer = TransferTrustRegistry {
+                registry_id,
+                new_convener: Convener(other.into()),
+                nonce: did_nonce::<Test, _>(convener).unwrap(),
+            };
+            let sig = did_sig(&transfer, &convener_kp, convener, 1u32);
+            assert_ok!(Mod::transfer_trust_registry(
+                Origin::signed(1),
+                transfer,
+                sig
+            ));
+
+            // The transfer doesn't take effect until accepted by the new `Convener`.
+            assert_eq!(
+                Mod::pending_trust_registry_transfer(registry_id),
+                Some(Convener(other.into()))
+            );
+            assert_eq!(
+                TrustRegistriesInfo::<Test>::get(registry_id)
+                    .unwrap()
+                    .convener,
+                Convener(convener.into())
+            );
+            assert!(ConvenerTrustRegistries::<Test>::get(Convener(convener.into()))
+                .contains(&registry_id));
+
+            // Only the new `Convener` can accept the transfer.
+            assert_noop!(
+                accept(convener, &convener_kp),
+                Error::<Test>::NoPendingTrustRegistryTransfer
+            );
+
+            ConvenerTrustRegistries::<Test>::insert(
+                Convener(other.into()),
+                TrustRegistryIdSet(
+                    (0..5)
+                        .map(|idx| TrustRegistryId([idx; 32]))
+                        .collect::<BTreeSet<_>>()
+                        .try_into()
+                        .unwrap(),
+                ),
+            );
+            assert_noop!(accept(other, &other_kp), Error::<Test>::TooManyRegistries);
+            ConvenerTrustRegistries::<Test>::remove(Convener(other.into()));
+
+            assert_ok!(accept(other, &other_kp));
+            assert_eq!(Mod::pending_trust_registry_transfer(registry_id), None);
+
+            assert_eq!(
+                TrustRegistriesInfo::<Test>::get(registry_id)
+                    .unwrap()
+                    .convener,
+                Convener(other.into())
+            );
+            assert!(ConvenerTrustRegistries::<Test>::get(Convener(convener.into())).is_empty());
+            assert!(ConvenerTrustRegistries::<Test>::get(Convener(other.into()))
+                .contains(&registry_id));
+
+            let remove_trust_registry = RemoveTrustRegistry {
+                registry_id,
+                nonce: did_nonce::<Test, _>(convener).unwrap(),
+            };
+            let sig = did_sig(&remove_trust_registry, &convener_kp, convener, 1u32);
+            assert_noop!(
+                Mod::remove_trust_registry(Origin::signed(1), remove_trust_registry, sig),
+                Error::<Test>::NotTheConvener
+            );
+
+            let remove_trust_registry = RemoveTrustRegistry {
+                registry_id,
+                nonce: did_nonce::<Test, _>(other).unwrap(),
+            };
+            let sig = did_sig(&remove_trust_registry, &other_kp, other, 1u32);
+            assert_ok!(Mod::remove_trust_registry(
+                Origin::signed(1),
+                remove_trust_registry,
+                sig
+            ));
+            assert!(ConvenerTrustRegistries::<Test>::get(Convener(other.into())).is_empty());
+        })
+    }
+
+    #[test]
+    fn settle_verification_fee() {
+        ext().execute_with(|| {
+            let mut rng = rand::thread_rng();
+
+            let (convener, convener_kp) = newdid();
+            let (issuer, issuer_kp) = newdid();
+            let (other_issuer, other_issuer_kp) = newdid();
+            let (verifier, verifier_kp) = newdid();
+            let (other, other_kp) = newdid();
+            let (payer, issuer_account, convener_account) = (1u64, 10u64, 11u64);
+
+            let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                registry_id: TrustRegistryId(rand::random()),
+                name: (0..25)
+                    .map(|_| rng.sample(Alphanumeric) as char)
+                    .collect::<String>()
+                    .try_into()
+                    .unwrap(),
+                gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                nonce: 2,
+            };
+            let registry_id = init_or_update_trust_registry.registry_id;
+
+            ActionWithNonceWrapper::<Test, _, _>::new(
+                2,
+                Convener(convener.into()),
+                init_or_update_trust_registry,
+            )
+            .modify::<Test, _, _, _, _>(|action, set| {
+                action.action.modify_removable(|action, info| {
+                    Mod::init_or_update_trust_registry_(
+                        action,
+                        set,
+                        info,
+                        Convener(convener.into()),
+                    )
+                })
+            })
+            .unwrap();
+
+            add_participants(
+                registry_id,
+                [
+                    (issuer, issuer_kp.clone()),
+                    (other_issuer, other_issuer_kp),
+                    (verifier, verifier_kp.clone()),
+                    (other, other_kp.clone()),
+                ],
+                (DidOrDidMethodKey::from(convener), convener_kp.clone()),
+            )
+            .unwrap();
+
+            let mut prices = build_initial_prices(2, 5);
+            prices.insert("DOCK".to_string(), VerificationPrice(1000));
+            let schema_id = define_schema(TrustRegistrySchemaId(rand::random()));
+            let schema_metadata = UnboundedTrustRegistrySchemaMetadata {
+                issuers: UnboundedIssuersWith(
+                    [
+                        (Issuer(issuer.into()), prices),
+                        (Issuer(other_issuer.into()), build_initial_prices(2, 5)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                verifiers: UnboundedTrustRegistrySchemaVerifiers(
+                    once(Verifier(verifier.into())).collect(),
+                ),
+            };
+
+            SetSchemasMetadata {
+                registry_id,
+                schemas: SetOrModify::Modify(
+                    once((schema_id, SetOrAddOrRemoveOrModify::Add(schema_metadata))).collect(),
+                ),
+                nonce: 3,
+            }
+            .view(|action, reg| {
+                Mod::set_schemas_metadata_(
+                    action,
+                    reg,
+                    ConvenerOrIssuerOrVerifier(convener.into()),
+                )
+            })
+            .unwrap();
+
+            assert_eq!(
+                Mod::verification_fee(registry_id, schema_id, Issuer(issuer.into())),
+                Some(VerificationFee {
+                    issuer: VerificationPrice(1000),
+                    convener: VerificationPrice(0)
+                })
+            );
+            assert_eq!(
+                Mod::verification_fee(registry_id, schema_id, Issuer(other_issuer.into())),
+                None
+            );
+
+            Balances::make_free_balance_be(&payer, 10_000);
+
+            let settle = SettleVerificationFee {
+                registry_id,
+                schema_id,
+                issuer: Issuer(issuer.into()),
+                nonce: did_nonce::<Test, _>(other).unwrap(),
+            };
+            let sig = did_sig(&settle, &other_kp, other, 1u32);
+            assert_noop!(
+                Mod::settle_verification_fee(Origin::signed(payer), settle, sig),
+                Error::<Test>::NotASchemaVerifier
+            );
+
+            let settle = SettleVerificationFee {
+                registry_id,
+                schema_id,
+                issuer: Issuer(other_issuer.into()),
+                nonce: did_nonce::<Test, _>(verifier).unwrap(),
+            };
+            let sig = did_sig(&settle, &verifier_kp, verifier, 1u32);
+            assert_noop!(
+                Mod::settle_verification_fee(Origin::signed(payer), settle, sig),
+                Error::<Test>::NoNativeVerificationPrice
+            );
+
+            let settle = SettleVerificationFee {
+                registry_id,
+                schema_id,
+                issuer: Issuer(issuer.into()),
+                nonce: did_nonce::<Test, _>(verifier).unwrap(),
+            };
+            let sig = did_sig(&settle, &verifier_kp, verifier, 1u32);
+            assert_noop!(
+                Mod::settle_verification_fee(Origin::signed(payer), settle, sig),
+                Error::<Test>::NoFeePayoutAccount
+            );
+
+            for (did, kp, account) in [
+                (issuer, issuer_kp, issuer_account),
+                (convener, convener_kp.clone(), convener_account),
+            ] {
+                let set_account = SetFeePayoutAccount {
+                    did: ConvenerOrIssuerOrVerifier(did.into()),
+                    account: Some(account),
+                    nonce: did_nonce::<Test, _>(did).unwrap(),
+                };
+                let sig = did_sig(&set_account, &kp, did, 1u32);
+                assert_ok!(Mod::set_fee_payout_account(
+                    Origin::signed(payer),
+                    set_account,
+                    sig
+                ));
+                assert_eq!(
+                    Mod::fee_payout_account(ConvenerOrIssuerOrVerifier(did.into())),
+                    Some(account)
+                );
+            }
+
+            assert_eq!(
+                Mod::verification_fee(registry_id, schema_id, Issuer(issuer.into())),
+                Some(VerificationFee {
+                    issuer: VerificationPrice(900),
+                    convener: VerificationPrice(100)
+                })
+            );
+
+            let settle = SettleVerificationFee {
+                registry_id,
+                schema_id,
+                issuer: Issuer(issuer.into()),
+                nonce: did_nonce::<Test, _>(verifier).unwrap(),
+            };
+            let sig = did_sig(&settle, &verifier_kp, verifier, 1u32);
+            assert_ok!(Mod::settle_verification_fee(
+                Origin::signed(payer),
+                settle,
+                sig
+            ));
+
+            assert_eq!(Balances::free_balance(payer), 9_000);
+            assert_eq!(Balances::free_balance(issuer_account), 900);
+            assert_eq!(Balances::free_balance(convener_account), 100);
+
+            SuspendIssuers {
+                registry_id,
+                issuers: once(Issuer(issuer.into())).collect(),
+                nonce: 4,
+            }
+            .view(|action, reg| Mod::suspend_issuers_(action, reg, Convener(convener.into())))
+            .unwrap();
+
+            let settle = SettleVerificationFee {
+                registry_id,
+                schema_id,
+                issuer: Issuer(issuer.into()),
+                nonce: did_nonce::<Test, _>(verifier).unwrap(),
+            };
+            let sig = did_sig(&settle, &verifier_kp, verifier, 1u32);
+            assert_noop!(
+                Mod::settle_verification_fee(Origin::signed(payer), settle, sig),
+                Error::<Test>::IssuerSuspended
+            );
+        })
+    }
+
+    #[test]
+    fn issuers_accreditation() {
+        ext().execute_with(|| {
+            let mut rng = rand::thread_rng();
+
+            let (convener, convener_kp) = newdid();
+            let (other, other_kp) = newdid();
+
+            let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                registry_id: TrustRegistryId(rand::random()),
+                name: (0..25)
+                    .map(|_| rng.sample(Alphanumeric) as char)
+                    .collect::<String>()
+                    .try_into()
+                    .unwrap(),
+                gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                nonce: 2,
+            };
+            let registry_id = init_or_update_trust_registry.registry_id;
+
+            ActionWithNonceWrapper::<Test, _, _>::new(
+                2,
+                Convener(convener.into()),
+                init_or_update_trust_registry,
+            )
+            .modify::<Test, _, _, _, _>(|action, set| {
+                action.action.modify_removable(|action, info| {
+                    Mod::init_or_update_trust_registry_(
+                        action,
+                        set,
+                        info,
+                        Convener(convener.into()),
+                    )
+                })
+            })
+            .unwrap();
+
+            let issuer_dids: Vec<_> = (0..3).map(|_| newdid()).collect();
+            add_participants(
+                registry_id,
+                issuer_dids.iter().map(|(did, pair)| (*did, pair.clone())),
+                (DidOrDidMethodKey::from(convener), convener_kp.clone()),
+            )
+            .unwrap();
+            let issuers: Vec<_> = issuer_dids
+                .iter()
+                .map(|(did, _)| Issuer((*did).into()))
+                .collect();
+
+            let schema_id = define_schema(TrustRegistrySchemaId(rand::random()));
+            let schema_metadata = UnboundedTrustRegistrySchemaMetadata {
+                issuers: UnboundedIssuersWith(
+                    issuers
+                        .iter()
+                        .map(|issuer| (*issuer, build_initial_prices(2, 5)))
+                        .collect(),
+                ),
+                verifiers: UnboundedTrustRegistrySchemaVerifiers(Default::default()),
+            };
+
+            SetSchemasMetadata {
+                registry_id,
+                schemas: SetOrModify::Modify(
+                    once((schema_id, SetOrAddOrRemoveOrModify::Add(schema_metadata))).collect(),
+                ),
+                nonce: 3,
+            }
+            .view(|action, reg| {
+                Mod::set_schemas_metadata_(
+                    action,
+                    reg,
+                    ConvenerOrIssuerOrVerifier(convener.into()),
+                )
+            })
+            .unwrap();
+
+            let accredited_issuers = || -> BTreeSet<Issuer> {
+                Mod::aggregate_schema_metadata((registry_id, schema_id))
+                    .unwrap()
+                    .issuers
+                    .into_iter()
+                    .map(|(issuer, _)| issuer)
+                    .collect()
+            };
+            let accreditation = |valid_from: Option<u64>, valid_until: Option<u64>| {
+                Some(IssuerAccreditation::<Test> {
+                    valid_from,
+                    valid_until,
+                })
+            };
+
+            assert_noop!(
+                set_issuers_accreditation(
+                    registry_id,
+                    schema_id,
+                    vec![(issuers[0], accreditation(None, Some(5)))],
+                    (other, &other_kp)
+                ),
+                Error::<Test>::NotTheConvener
+            );
+            assert_noop!(
+                set_issuers_accreditation(
+                    registry_id,
+                    schema_id,
+                    vec![(Issuer(other.into()), accreditation(None, Some(5)))],
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::NoSuchIssuer
+            );
+            assert_noop!(
+                set_issuers_accreditation(
+                    registry_id,
+                    schema_id,
+                    vec![(issuers[0], accreditation(Some(5), Some(5)))],
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::InvalidAccreditationPeriod
+            );
+            assert_noop!(
+                set_issuers_accreditation(
+                    registry_id,
+                    schema_id,
+                    vec![(issuers[0], accreditation(None, Some(1)))],
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::InvalidAccreditationPeriod
+            );
+
+            assert_ok!(set_issuers_accreditation(
+                registry_id,
+                schema_id,
+                vec![
+                    (issuers[0], accreditation(None, Some(5))),
+                    (issuers[1], accreditation(Some(3), None)),
+                    (issuers[2], accreditation(None, Some(6))),
+                ],
+                (convener, &convener_kp)
+            ));
+            assert_ok!(set_issuers_accreditation(
+                registry_id,
+                schema_id,
+                vec![(issuers[2], accreditation(Some(1), Some(10)))],
+                (convener, &convener_kp)
+            ));
+
+            assert_eq!(
+                accredited_issuers(),
+                [issuers[0], issuers[2]].into_iter().collect()
+            );
+            assert!(!Mod::is_issuer_accredited(
+                registry_id,
+                schema_id,
+                issuers[1]
+            ));
+
+            run_to_block(3);
+            assert_eq!(accredited_issuers(), issuers.iter().copied().collect());
+
+            run_to_block(6);
+            assert_eq!(
+                accredited_issuers(),
+                [issuers[1], issuers[2]].into_iter().collect()
+            );
+
+            assert_ok!(set_issuers_accreditation(
+                registry_id,
+                schema_id,
+                vec![(issuers[0], None)],
+                (convener, &convener_kp)
+            ));
+            assert_eq!(
+                TrustRegistryIssuerAccreditations::<Test>::get(
+                    registry_id,
+                    (schema_id, issuers[0])
+                ),
+                None
+            );
+            assert_eq!(accredited_issuers(), issuers.iter().copied().collect());
+
+            run_to_block(10);
+            assert_eq!(
+                accredited_issuers(),
+                [issuers[0], issuers[1]].into_iter().collect()
+            );
+
+            SetSchemasMetadata {
+                registry_id,
+                schemas: SetOrModify::Modify(
+                    once((schema_id, SetOrAddOrRemoveOrModify::Remove)).collect(),
+                ),
+                nonce: 4,
+            }
+            .view(|action, reg| {
+                Mod::set_schemas_metadata_(
+                    action,
+                    reg,
+                    ConvenerOrIssuerOrVerifier(convener.into()),
+                )
+            })
+            .unwrap();
+            assert_eq!(
+                TrustRegistryIssuerAccreditations::<Test>::iter_prefix(registry_id).count(),
+                0
+            );
+        })
+    }
+
+    #[test]
+    fn trust_registry_recognition() {
+        ext().execute_with(|| {
+            let mut rng = rand::thread_rng();
+
+            let (other, other_kp) = newdid();
+            let schema_id = define_schema(TrustRegistrySchemaId(rand::random()));
+
+            let registries: Vec<_> = (0..4)
+                .map(|_| {
+                    let (convener, convener_kp) = newdid();
+
+                    let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                        registry_id: TrustRegistryId(rand::random()),
+                        name: (0..25)
+                            .map(|_| rng.sample(Alphanumeric) as char)
+                            .collect::<String>()
+                            .try_into()
+                            .unwrap(),
+                        gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                        nonce: 2,
+                    };
+                    let registry_id = init_or_update_trust_registry.registry_id;
+
+                    ActionWithNonceWrapper::<Test, _, _>::new(
+                        2,
+                        Convener(convener.into()),
+                        init_or_update_trust_registry,
+                    )
+                    .modify::<Test, _, _, _, _>(|action, set| {
+                        action.action.modify_removable(|action, info| {
+                            Mod::init_or_update_trust_registry_(
+                                action,
+                                set,
+                                info,
+                                Convener(convener.into()),
+                            )
+                        })
+                    })
+                    .unwrap();
+
+                    let (issuer, issuer_kp) = newdid();
+                    add_participants(
+                        registry_id,
+                        once((issuer, issuer_kp)),
+                        (DidOrDidMethodKey::from(convener), convener_kp.clone()),
+                    )
+                    .unwrap();
+
+                    let schema_metadata = UnboundedTrustRegistrySchemaMetadata {
+                        issuers: UnboundedIssuersWith(
+                            once((Issuer(issuer.into()), build_initial_prices(2, 5))).collect(),
+                        ),
+                        verifiers: UnboundedTrustRegistrySchemaVerifiers(Default::default()),
+                    };
+                    SetSchemasMetadata {
+                        registry_id,
+                        schemas: SetOrModify::Modify(
+                            once((schema_id, SetOrAddOrRemoveOrModify::Add(schema_metadata)))
+                                .collect(),
+                        ),
+                        nonce: 3,
+                    }
+                    .view(|action, reg| {
+                        Mod::set_schemas_metadata_(
+                            action,
+                            reg,
+                            ConvenerOrIssuerOrVerifier(convener.into()),
+                        )
+                    })
+                    .unwrap();
+
+                    (registry_id, convener, convener_kp, Issuer(issuer.into()))
+                })
+                .collect();
+            let ids: Vec<_> = registries.iter().map(|(id, ..)| *id).collect();
+            let issuers: Vec<_> = registries.iter().map(|(.., issuer)| *issuer).collect();
+            let (convener, convener_kp) = (registries[0].1, registries[0].2.clone());
+
+            assert_noop!(
+                update_recognized_registries(
+                    ids[0],
+                    once((ids[1], AddOrRemoveOrModify::Add(()))),
+                    (other, &other_kp)
+                ),
+                Error::<Test>::NotTheConvener
+            );
+            assert_noop!(
+                update_recognized_registries(
+                    ids[0],
+                    once((ids[0], AddOrRemoveOrModify::Add(()))),
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::SelfRecognition
+            );
+            assert_noop!(
+                update_recognized_registries(
+                    ids[0],
+                    once((TrustRegistryId(rand::random()), AddOrRemoveOrModify::Add(()))),
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::NoRegistry
+            );
+            assert_noop!(
+                update_recognized_registries(
+                    ids[0],
+                    once((ids[1], AddOrRemoveOrModify::Remove)),
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::EntityDoesntExist
+            );
+
+            for (recognizer, recognized) in registries.iter().tuple_windows() {
+                assert_ok!(update_recognized_registries(
+                    recognizer.0,
+                    once((recognized.0, AddOrRemoveOrModify::Add(()))),
+                    (recognizer.1, &recognizer.2)
+                ));
+            }
+            assert_eq!(
+                Mod::registry_recognitions(ids[0])
+                    .into_iter()
+                    .collect::<BTreeSet<_>>(),
+                once(ids[1]).collect()
+            );
+
+            // Recognitions take effect only after being accepted.
+            assert!(Mod::recognized_registries(ids[0]).is_empty());
+            assert_eq!(
+                Mod::issuer_authorization_path(ids[0], schema_id, issuers[1]),
+                None
+            );
+            assert_eq!(
+                Mod::issuer_authorization_path(ids[0], schema_id, issuers[0]),
+                Some(vec![ids[0]])
+            );
+
+            assert_noop!(
+                update_recognition_acceptances(
+                    ids[1],
+                    once((ids[0], AddOrRemoveOrModify::Add(()))),
+                    (other, &other_kp)
+                ),
+                Error::<Test>::NotTheConvener
+            );
+            assert_noop!(
+                update_recognition_acceptances(
+                    ids[1],
+                    once((ids[0], AddOrRemoveOrModify::Remove)),
+                    (registries[1].1, &registries[1].2)
+                ),
+                Error::<Test>::EntityDoesntExist
+            );
+
+            for (recognizer, recognized) in registries.iter().tuple_windows() {
+                assert_ok!(update_recognition_acceptances(
+                    recognized.0,
+                    once((recognizer.0, AddOrRemoveOrModify::Add(()))),
+                    (recognized.1, &recognized.2)
+                ));
+            }
+            assert_noop!(
+                update_recognition_acceptances(
+                    ids[1],
+                    once((ids[0], AddOrRemoveOrModify::Add(()))),
+                    (registries[1].1, &registries[1].2)
+                ),
+                Error::<Test>::EntityAlreadyExists
+            );
+
+            assert_eq!(
+                Mod::recognized_registries(ids[0]),
+                once(ids[1]).collect::<BTreeSet<_>>()
+            );
+            assert_eq!(
+                Mod::issuer_authorization_path(ids[0], schema_id, issuers[1]),
+                Some(vec![ids[0], ids[1]])
+            );
+            assert_eq!(
+                Mod::issuer_authorization_path(ids[0], schema_id, issuers[2]),
+                Some(vec![ids[0], ids[1], ids[2]])
+            );
+            // `MaxRecognitionDepth` is exceeded.
+            assert_eq!(
+                Mod::issuer_authorization_path(ids[0], schema_id, issuers[3]),
+                None
+            );
+            assert_eq!(
+                Mod::issuer_authorization_path(ids[1], schema_id, issuers[3]),
+                Some(vec![ids[1], ids[2], ids[3]])
+            );
+            assert_eq!(
+                Mod::issuer_authorization_path(ids[1], schema_id, issuers[0]),
+                None
+            );
+            assert_eq!(
+                Mod::issuer_authorization_path(
+                    ids[0],
+                    TrustRegistrySchemaId(rand::random()),
+                    issuers[1]
+                ),
+                None
+            );
+
+            SuspendIssuers {
+                registry_id: ids[2],
+                issuers: once(issuers[2]).collect(),
+                nonce: 4,
+            }
+            .view(|action, reg| {
+                Mod::suspend_issuers_(action, reg, Convener(registries[2].1.into()))
+            })
+            .unwrap();
+            assert_eq!(
+                Mod::issuer_authorization_path(ids[0], schema_id, issuers[2]),
+                None
+            );
+
+            assert_ok!(update_recognition_acceptances(
+                ids[1],
+                once((ids[0], AddOrRemoveOrModify::Remove)),
+                (registries[1].1, &registries[1].2)
+            ));
+            assert!(Mod::recognized_registries(ids[0]).is_empty());
+            assert_eq!(
+                Mod::issuer_authorization_path(ids[0], schema_id, issuers[1]),
+                None
+            );
+
+            assert_ok!(update_recognized_registries(
+                ids[0],
+                once((ids[1], AddOrRemoveOrModify::Remove)),
+                (convener, &convener_kp)
+            ));
+            assert!(Mod::registry_recognitions(ids[0]).is_empty());
+
+            let remove_trust_registry = RemoveTrustRegistry {
+                registry_id: ids[2],
+                nonce: did_nonce::<Test, _>(registries[2].1).unwrap(),
+            };
+            let sig = did_sig(&remove_trust_registry, &registries[2].2, registries[2].1, 1u32);
+            assert_ok!(Mod::remove_trust_registry(
+                Origin::signed(1),
+                remove_trust_registry,
+                sig
+            ));
+
+            assert!(Mod::registry_recognitions(ids[1]).is_empty());
+            assert!(Mod::registry_recognitions(ids[2]).is_empty());
+            assert!(!TrustRegistryRecognitionAcceptances::<Test>::contains_key(
+                ids[2], ids[1]
+            ));
+            assert!(!TrustRegistryRecognitionAcceptances::<Test>::contains_key(
+                ids[3], ids[2]
+            ));
+            assert_eq!(
+                Mod::issuer_authorization_path(ids[1], schema_id, issuers[3]),
+                None
+            );
+        })
+    }
+
+    #[test]
+    fn participation_requests() {
+        ext().execute_with(|| {
+            let mut rng = rand::thread_rng();
+
+            let (convener, convener_kp) = newdid();
+            let (other, other_kp) = newdid();
+
+            let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                registry_id: TrustRegistryId(rand::random()),
+                name: (0..25)
+                    .map(|_| rng.sample(Alphanumeric) as char)
+                    .collect::<String>()
+                    .try_into()
+                    .unwrap(),
+                gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                nonce: 2,
+            };
+            let registry_id = init_or_update_trust_registry.registry_id;
+
+            ActionWithNonceWrapper::<Test, _, _>::new(
+                2,
+                Convener(convener.into()),
+                init_or_update_trust_registry,
+            )
+            .modify::<Test, _, _, _, _>(|action, set| {
+                action.action.modify_removable(|action, info| {
+                    Mod::init_or_update_trust_registry_(
+                        action,
+                        set,
+                        info,
+                        Convener(convener.into()),
+                    )
+                })
+            })
+            .unwrap();
+
+            Balances::make_free_balance_be(&1, 1_000);
+
+            let participants: Vec<_> = (0..3).map(|_| newdid()).collect();
+            let participant = |idx: usize| IssuerOrVerifier(participants[idx].0.into());
+            let information = |idx: usize| UnboundedTrustRegistryParticipantInformation {
+                org_name: format!("Org {}", idx),
+                logo: format!("https://logo.org/{}", idx),
+                description: format!("Participant {}", idx),
+            };
+            let events = || -> Vec<super::super::Event> {
+                System::events()
+                    .into_iter()
+                    .filter_map(|record| match record.event {
+                        TestEvent::TrustRegistry(event) => Some(event),
+                        _ => None,
+                    })
+                    .collect()
+            };
+
+            assert_noop!(
+                request_participation(
+                    registry_id,
+                    participants[0].0,
+                    information(0),
+                    (participants[1].0, &participants[1].1)
+                ),
+                did::Error::<Test>::InvalidSigner
+            );
+            assert_noop!(
+                request_participation(
+                    TrustRegistryId(rand::random()),
+                    participants[0].0,
+                    information(0),
+                    (participants[0].0, &participants[0].1)
+                ),
+                Error::<Test>::NoRegistry
+            );
+            assert_noop!(
+                request_participation(
+                    registry_id,
+                    participants[0].0,
+                    UnboundedTrustRegistryParticipantInformation {
+                        org_name: "A".repeat(101),
+                        ..information(0)
+                    },
+                    (participants[0].0, &participants[0].1)
+                ),
+                Error::<Test>::ParticipantOrgNameSizeExceededLimit
+            );
+
+            for (idx, (did, kp)) in participants.iter().enumerate() {
+                assert_ok!(request_participation(
+                    registry_id,
+                    *did,
+                    information(idx),
+                    (*did, kp)
+                ));
+            }
+
+            let requests: BTreeMap<_, _> = Mod::participation_requests(registry_id, None, 10)
+                .into_iter()
+                .collect();
+            assert_eq!(requests.len(), 3);
+            assert_eq!(Balances::reserved_balance(1), 300);
+            assert_eq!(Mod::participation_requests_count(registry_id), 3);
+
+            // Requests are paginated in their storage order.
+            let first_page = Mod::participation_requests(registry_id, None, 2);
+            assert_eq!(first_page.len(), 2);
+            let after = first_page.last().map(|(participant, _)| *participant);
+            let second_page = Mod::participation_requests(registry_id, after, 2);
+            assert_eq!(second_page.len(), 1);
+            assert_eq!(
+                first_page
+                    .into_iter()
+                    .chain(second_page)
+                    .map(|(participant, _)| participant)
+                    .collect::<BTreeSet<_>>(),
+                requests.keys().copied().collect()
+            );
+
+            for idx in 0..3 {
+                let request = &requests[&participant(idx)];
+
+                assert_eq!(request.expires_at, 11);
+                assert_eq!(request.depositor, 1);
+                assert_eq!(request.deposit, 100);
+                assert_eq!(
+                    request.information,
+                    TrustRegistryStoredParticipantInformation::<Test>::try_from(information(idx))
+                        .unwrap()
+                );
+            }
+
+            assert_noop!(
+                resolve_participation_requests(
+                    registry_id,
+                    once((participant(0), ParticipationRequestResolution::Approve)),
+                    (other, &other_kp)
+                ),
+                Error::<Test>::NotTheConvener
+            );
+            assert_noop!(
+                resolve_participation_requests(
+                    registry_id,
+                    vec![
+                        (participant(0), ParticipationRequestResolution::Approve),
+                        (
+                            IssuerOrVerifier(other.into()),
+                            ParticipationRequestResolution::Approve
+                        ),
+                    ],
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::NoParticipationRequest
+            );
+
+            assert_ok!(resolve_participation_requests(
+                registry_id,
+                vec![
+                    (participant(0), ParticipationRequestResolution::Approve),
+                    (participant(1), ParticipationRequestResolution::Reject),
+                ],
+                (convener, &convener_kp)
+            ));
+
+            let registry_participants =
+                TrustRegistriesParticipants::<Test>::get(TrustRegistryIdForParticipants(
+                    registry_id,
+                ));
+            assert!(registry_participants.contains(&participant(0)));
+            assert!(!registry_participants.contains(&participant(1)));
+            assert_eq!(
+                TrustRegistryParticipantsInformation::<Test>::get(
+                    TrustRegistryIdForParticipants(registry_id),
+                    participant(0)
+                ),
+                Some(information(0).try_into().unwrap())
+            );
+            assert_eq!(
+                Mod::participation_requests(registry_id, None, 10)
+                    .into_iter()
+                    .map(|(participant, _)| participant)
+                    .collect::<Vec<_>>(),
+                vec![participant(2)]
+            );
+            let all_events = events();
+            assert!(all_events.contains(&super::super::Event::ParticipationRequestApproved(
+                registry_id,
+                participant(0)
+            )));
+            assert!(all_events.contains(&super::super::Event::TrustRegistryParticipantConfirmed(
+                registry_id,
+                participant(0)
+            )));
+            assert!(all_events.contains(&super::super::Event::ParticipationRequestRejected(
+                registry_id,
+                participant(1)
+            )));
+
+            assert_noop!(
+                request_participation(
+                    registry_id,
+                    participants[0].0,
+                    information(0),
+                    (participants[0].0, &participants[0].1)
+                ),
+                Error::<Test>::AlreadyAParticipant
+            );
+            assert_noop!(
+                resolve_participation_requests(
+                    registry_id,
+                    once((participant(1), ParticipationRequestResolution::Approve)),
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::NoParticipationRequest
+            );
+
+            // Resubmitting the request extends its expiry keeping the initial deposit.
+            run_to_block(5);
+            assert_ok!(request_participation(
+                registry_id,
+                participants[2].0,
+                information(1),
+                (participants[2].0, &participants[2].1)
+            ));
+            assert_eq!(
+                Mod::participation_request(registry_id, participant(2))
+                    .unwrap()
+                    .expires_at,
+                15
+            );
+            assert_eq!(Balances::reserved_balance(1), 100);
+            assert_eq!(Mod::participation_requests_count(registry_id), 1);
+
+            run_to_block(11);
+            assert_noop!(
+                Mod::clear_expired_participation_requests(
+                    Origin::signed(2),
+                    registry_id,
+                    vec![participant(2)]
+                ),
+                Error::<Test>::ParticipationRequestNotExpired
+            );
+
+            // Expired requests are kept until cleared but can't be approved.
+            run_to_block(15);
+            assert_eq!(Mod::participation_requests(registry_id, None, 10).len(), 1);
+            assert_noop!(
+                resolve_participation_requests(
+                    registry_id,
+                    once((participant(2), ParticipationRequestResolution::Approve)),
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::ParticipationRequestExpired
+            );
+
+            assert_ok!(Mod::clear_expired_participation_requests(
+                Origin::signed(2),
+                registry_id,
+                vec![participant(2)]
+            ));
+            assert!(Mod::participation_requests(registry_id, None, 10).is_empty());
+            assert_eq!(Balances::reserved_balance(1), 0);
+            assert_eq!(Mod::participation_requests_count(registry_id), 0);
+            assert_eq!(
+                events()
+                    .into_iter()
+                    .filter(|event| matches!(
+                        event,
+                        super::super::Event::ParticipationRequestExpired(..)
+                    ))
+                    .collect::<Vec<_>>(),
+                vec![super::super::Event::ParticipationRequestExpired(
+                    registry_id,
+                    participant(2)
+                )]
+            );
+            assert_noop!(
+                resolve_participation_requests(
+                    registry_id,
+                    once((participant(2), ParticipationRequestResolution::Approve)),
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::NoParticipationRequest
+            );
+
+            // The amount of pending requests per registry is limited.
+            let applicants: Vec<_> = (0..5).map(|_| newdid()).collect();
+            for (did, kp) in &applicants[..4] {
+                assert_ok!(request_participation(
+                    registry_id,
+                    *did,
+                    information(0),
+                    (*did, kp)
+                ));
+            }
+            assert_noop!(
+                request_participation(
+                    registry_id,
+                    applicants[4].0,
+                    information(0),
+                    (applicants[4].0, &applicants[4].1)
+                ),
+                Error::<Test>::TooManyParticipationRequests
+            );
+            assert_eq!(Balances::reserved_balance(1), 400);
+        })
+    }
+
+    #[test]
+    fn paginated_schema_metadata() {
+        ext().execute_with(|| {
+            let mut rng = rand::thread_rng();
+
+            let (convener, convener_kp) = newdid();
+
+            let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                registry_id: TrustRegistryId(rand::random()),
+                name: (0..25)
+                    .map(|_| rng.sample(Alphanumeric) as char)
+                    .collect::<String>()
+                    .try_into()
+                    .unwrap(),
+                gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                nonce: 2,
+            };
+            let registry_id = init_or_update_trust_registry.registry_id;
+
+            ActionWithNonceWrapper::<Test, _, _>::new(
+                2,
+                Convener(convener.into()),
+                init_or_update_trust_registry,
+            )
+            .modify::<Test, _, _, _, _>(|action, set| {
+                action.action.modify_removable(|action, info| {
+                    Mod::init_or_update_trust_registry_(
+                        action,
+                        set,
+                        info,
+                        Convener(convener.into()),
+                    )
+                })
+            })
+            .unwrap();
+
+            let dids: Vec<_> = (0..3).map(|_| newdid()).collect();
+            add_participants(
+                registry_id,
+                dids.iter().map(|(did, pair)| (*did, pair.clone())),
+                (DidOrDidMethodKey::from(convener), convener_kp.clone()),
+            )
+            .unwrap();
+            let (issuers, verifier) = (
+                [Issuer(dids[0].0.into()), Issuer(dids[1].0.into())],
+                Verifier(dids[2].0.into()),
+            );
+
+            let schema_ids: Vec<_> = [(1, 1), (1, 2), (2, 1), (2, 2), (2, 3)]
+                .into_iter()
+                .map(|(first, second)| {
+                    let mut id = [0; 32];
+                    id[0] = first;
+                    id[1] = second;
+
+                    define_schema(TrustRegistrySchemaId(id))
+                })
+                .collect();
+
+            SetSchemasMetadata {
+                registry_id,
+                schemas: SetOrModify::Modify(
+                    schema_ids
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, schema_id)| {
+                            let metadata = UnboundedTrustRegistrySchemaMetadata {
+                                issuers: UnboundedIssuersWith(
+                                    issuers
+                                        .iter()
+                                        .take(if idx < 2 { 2 } else { 1 })
+                                        .map(|issuer| (*issuer, build_initial_prices(2, 5)))
+                                        .collect(),
+                                ),
+                                verifiers: UnboundedTrustRegistrySchemaVerifiers(
+                                    (idx == 2).then_some(verifier).into_iter().collect(),
+                                ),
+                            };
+
+                            (*schema_id, SetOrAddOrRemoveOrModify::Add(metadata.into()))
+                        })
+                        .collect(),
+                ),
+                nonce: 3,
+            }
+            .view(|action, reg| {
+                Mod::set_schemas_metadata_(
+                    action,
+                    reg,
+                    ConvenerOrIssuerOrVerifier(convener.into()),
+                )
+            })
+            .unwrap();
+
+            let query = |filter: SchemaMetadataFilter,
+                         after: Option<TrustRegistrySchemaId>,
+                         limit: u32| {
+                filter.resolve_to_schemas_metadata_in_registry_paginated::<Test>(
+                    registry_id,
+                    after,
+                    limit,
+                )
+            };
+            let ids = |page: Vec<(TrustRegistrySchemaId, _)>| -> Vec<TrustRegistrySchemaId> {
+                page.into_iter().map(|(schema_id, _)| schema_id).collect()
+            };
+
+            let mut paginated = vec![];
+            let mut after = None;
+            loop {
+                let page = query(Default::default(), after, 2);
+                assert!(page.len() <= 2);
+                if page.is_empty() {
+                    break;
+                }
+
+                after = page.last().map(|(schema_id, _)| *schema_id);
+                paginated.extend(page);
+            }
+            assert_eq!(
+                paginated,
+                schema_ids
+                    .iter()
+                    .map(|schema_id| (
+                        *schema_id,
+                        Mod::aggregate_schema_metadata((registry_id, *schema_id)).unwrap()
+                    ))
+                    .collect::<Vec<_>>()
+            );
+            assert!(query(Default::default(), None, 0).is_empty());
+
+            assert_eq!(
+                ids(query(
+                    SchemaMetadataFilter {
+                        issuer: Some(issuers[1]),
+                        ..Default::default()
+                    },
+                    None,
+                    10
+                )),
+                schema_ids[..2]
+            );
+            assert_eq!(
+                ids(query(
+                    SchemaMetadataFilter {
+                        verifier: Some(verifier),
+                        ..Default::default()
+                    },
+                    None,
+                    10
+                )),
+                schema_ids[2..3]
+            );
+            assert_eq!(
+                ids(query(
+                    SchemaMetadataFilter {
+                        schema_id_prefix: Some(Bytes(vec![2])),
+                        ..Default::default()
+                    },
+                    None,
+                    10
+                )),
+                schema_ids[2..]
+            );
+            assert_eq!(
+                ids(query(
+                    SchemaMetadataFilter {
+                        schema_id_prefix: Some(Bytes(vec![2])),
+                        ..Default::default()
+                    },
+                    Some(schema_ids[2]),
+                    1
+                )),
+                schema_ids[3..4]
+            );
+            assert_eq!(
+                ids(query(
+                    SchemaMetadataFilter {
+                        issuer: Some(issuers[1]),
+                        schema_id_prefix: Some(Bytes(vec![2])),
+                        ..Default::default()
+                    },
+                    None,
+                    10
+                )),
+                vec![]
+            );
+
+            SuspendIssuers {
+                registry_id,
+                issuers: once(issuers[1]).collect(),
+                nonce: 4,
+            }
+            .view(|action, reg| Mod::suspend_issuers_(action, reg, Convener(convener.into())))
+            .unwrap();
+
+            let suspended = query(
+                SchemaMetadataFilter {
+                    suspended: Some(true),
+                    ..Default::default()
+                },
+                None,
+                10,
+            );
+            assert_eq!(ids(suspended.clone()), schema_ids[..2]);
+            for (_, metadata) in suspended {
+                assert_eq!(
+                    metadata
+                        .issuers
+                        .into_iter()
+                        .map(|(issuer, _)| issuer)
+                        .collect::<Vec<_>>(),
+                    vec![issuers[1]]
+                );
+            }
+
+            let not_suspended = query(
+                SchemaMetadataFilter {
+                    suspended: Some(false),
+                    ..Default::default()
+                },
+                None,
+                10,
+            );
+            assert_eq!(ids(not_suspended.clone()), schema_ids);
+            for (_, metadata) in not_suspended {
+                assert_eq!(
+                    metadata
+                        .issuers
+                        .into_iter()
+                        .map(|(issuer, _)| issuer)
+                        .collect::<Vec<_>>(),
+                    vec![issuers[0]]
+                );
+            }
+        })
+    }
+
+    #[test]
+    fn paginated_registries() {
+        ext().execute_with(|| {
+            let (convener, _) = newdid();
+            let (issuer, _) = newdid();
+            let (first_schema_id, second_schema_id) = (
+                TrustRegistrySchemaId(rand::random()),
+                TrustRegistrySchemaId(rand::random()),
+            );
+            let metadata = || {
+                TrustRegistrySchemaMetadata::<Test>::try_from(UnboundedTrustRegistrySchemaMetadata {
+                    issuers: UnboundedIssuersWith(Default::default()),
+                    verifiers: UnboundedTrustRegistrySchemaVerifiers(Default::default()),
+                })
+                .unwrap()
+            };
+
+            let registry_ids: Vec<_> = (0..12u32)
+                .map(|idx| {
+                    let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                        registry_id: TrustRegistryId(rand::random()),
+                        name: format!("Registry {}", idx).try_into().unwrap(),
+                        gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                        nonce: 2 + idx as u64,
+                    };
+                    let registry_id = init_or_update_trust_registry.registry_id;
+
+                    ActionWithNonceWrapper::<Test, _, _>::new(
+                        2 + idx as u64,
+                        Convener(convener.into()),
+                        init_or_update_trust_registry,
+                    )
+                    .modify::<Test, _, _, _, _>(|action, set| {
+                        action.action.modify_removable(|action, info| {
+                            Mod::init_or_update_trust_registry_(
+                                action,
+                                set,
+                                info,
+                                Convener(convener.into()),
+                            )
+                        })
+                    })
+                    .unwrap();
+
+                    TrustRegistrySchemasMetadata::<Test>::insert(
+                        first_schema_id,
+                        registry_id,
+                        metadata(),
+                    );
+                    if idx % 2 == 0 {
+                        TrustRegistrySchemasMetadata::<Test>::insert(
+                            second_schema_id,
+                            registry_id,
+                            metadata(),
+                        );
+                    }
+
+                    registry_id
+                })
+                .collect();
+            IssuersTrustRegistries::<Test>::insert(
+                Issuer(issuer.into()),
+                IssuerTrustRegistries(
+                    registry_ids[..6]
+                        .iter()
+                        .copied()
+                        .collect::<BTreeSet<_>>()
+                        .try_into()
+                        .unwrap(),
+                ),
+            );
+
+            let all_pages = |by: QueryTrustRegistriesBy, limit: u32| {
+                let mut ids = vec![];
+                let mut after = None;
+                loop {
+                    let page = by
+                        .clone()
+                        .resolve_to_registry_ids_paginated::<Test>(after, limit);
+                    assert!(page.len() <= limit as usize);
+                    if page.is_empty() {
+                        break;
+                    }
+
+                    after = page.last().copied();
+                    ids.extend(page);
+                }
+
+                ids
+            };
+            let by_schemas = |schema_ids| QueryTrustRegistriesBy {
+                issuers: None,
+                verifiers: None,
+                issuers_or_verifiers: None,
+                schema_ids: Some(schema_ids),
+            };
+
+            for by in [
+                by_schemas(InclusionRule::any_of([first_schema_id])),
+                by_schemas(InclusionRule::any_of([first_schema_id, second_schema_id])),
+                by_schemas(InclusionRule::all([first_schema_id, second_schema_id]).unwrap()),
+                QueryTrustRegistriesBy {
+                    issuers: Some(InclusionRule::any_of([Issuer(issuer.into())])),
+                    ..by_schemas(InclusionRule::any_of([second_schema_id]))
+                },
+            ] {
+                let paginated = all_pages(by.clone(), 5);
+
+                assert_eq!(
+                    paginated.iter().copied().collect::<BTreeSet<_>>(),
+                    by.clone().resolve_to_registry_ids::<Test>()
+                );
+                assert!(paginated.iter().all_unique());
+                assert_eq!(
+                    by.resolve_to_registries_info_paginated::<Test>(None, 5)
+                        .into_iter()
+                        .map(|(registry_id, _)| registry_id)
+                        .collect::<Vec<_>>(),
+                    paginated.into_iter().take(5).collect::<Vec<_>>()
+                );
+            }
+
+            // Pages are capped by `MaxQueryPageSize`.
+            assert_eq!(
+                by_schemas(InclusionRule::any_of([first_schema_id]))
+                    .resolve_to_registry_ids_paginated::<Test>(None, u32::MAX)
+                    .len(),
+                10
+            );
+            assert_eq!(
+                Mod::schema_metadata_by_schema_id_paginated(first_schema_id, None, u32::MAX).len(),
+                10
+            );
+            assert_eq!(
+                all_pages(by_schemas(InclusionRule::any_of([first_schema_id])), u32::MAX).len(),
+                12
+            );
+        })
+    }
+
+    #[test]
+    fn trqp_authorization() {
+        ext().execute_with(|| {
+            let mut rng = rand::thread_rng();
+
+            let (convener, convener_kp) = newdid();
+
+            let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                registry_id: TrustRegistryId(rand::random()),
+                name: (0..25)
+                    .map(|_| rng.sample(Alphanumeric) as char)
+                    .collect::<String>()
+                    .try_into()
+                    .unwrap(),
+                gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                nonce: 2,
+            };
+            let registry_id = init_or_update_trust_registry.registry_id;
+
+            ActionWithNonceWrapper::<Test, _, _>::new(
+                2,
+                Convener(convener.into()),
+                init_or_update_trust_registry,
+            )
+            .modify::<Test, _, _, _, _>(|action, set| {
+                action.action.modify_removable(|action, info| {
+                    Mod::init_or_update_trust_registry_(
+                        action,
+                        set,
+                        info,
+                        Convener(convener.into()),
+                    )
+                })
+            })
+            .unwrap();
+
+            let (issuer, issuer_kp) = newdid();
+            let (verifier, verifier_kp) = newdid();
+            add_participants(
+                registry_id,
+                [(issuer, issuer_kp), (verifier, verifier_kp)],
+                (DidOrDidMethodKey::from(convener), convener_kp.clone()),
+            )
+            .unwrap();
+
+            let schema_id = define_schema(TrustRegistrySchemaId(rand::random()));
+            SetSchemasMetadata {
+                registry_id,
+                schemas: SetOrModify::Modify(
+                    once((
+                        schema_id,
+                        SetOrAddOrRemoveOrModify::Add(
+                            UnboundedTrustRegistrySchemaMetadata {
+                                issuers: UnboundedIssuersWith(
+                                    once((Issuer(issuer.into()), build_initial_prices(2, 5)))
+                                        .collect(),
+                                ),
+                                verifiers: UnboundedTrustRegistrySchemaVerifiers(
+                                    once(Verifier(verifier.into())).collect(),
+                                ),
+                            }
+                            .into(),
+                        ),
+                    ))
+                    .collect(),
+                ),
+                nonce: 3,
+            }
+            .view(|action, reg| {
+                Mod::set_schemas_metadata_(
+                    action,
+                    reg,
+                    ConvenerOrIssuerOrVerifier(convener.into()),
+                )
+            })
+            .unwrap();
+
+            let query = |entity_id: DidOrDidMethodKey,
+                         action: TrqpAction,
+                         authority_id: Option<TrustRegistryId>,
+                         governance_framework: Option<Bytes>| {
+                TrqpAuthorizationQuery {
+                    entity_id,
+                    action,
+                    resource: schema_id,
+                    authority_id,
+                    governance_framework,
+                }
+                .resolve_to_authorizations::<Test>()
+            };
+            let authorization =
+                |entity_id: DidOrDidMethodKey, action: TrqpAction, authorized: bool| {
+                    vec![TrqpAuthorization {
+                        entity_id,
+                        action,
+                        resource: schema_id,
+                        authority_id: registry_id,
+                        governance_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                        authorized,
+                    }]
+                };
+
+            for authority_id in [None, Some(registry_id)] {
+                assert_eq!(
+                    query(issuer.into(), TrqpAction::Issue, authority_id, None),
+                    authorization(issuer.into(), TrqpAction::Issue, true)
+                );
+                assert_eq!(
+                    query(verifier.into(), TrqpAction::Verify, authority_id, None),
+                    authorization(verifier.into(), TrqpAction::Verify, true)
+                );
+            }
+            assert_eq!(
+                query(issuer.into(), TrqpAction::Issue, None, Some(Bytes(vec![1; 100]))),
+                authorization(issuer.into(), TrqpAction::Issue, true)
+            );
+            assert_eq!(
+                query(issuer.into(), TrqpAction::Issue, None, Some(Bytes(vec![2; 100]))),
+                vec![]
+            );
+
+            assert_eq!(query(issuer.into(), TrqpAction::Verify, None, None), vec![]);
+            assert_eq!(
+                query(issuer.into(), TrqpAction::Verify, Some(registry_id), None),
+                authorization(issuer.into(), TrqpAction::Verify, false)
+            );
+            assert_eq!(
+                query(
+                    issuer.into(),
+                    TrqpAction::Issue,
+                    Some(TrustRegistryId(rand::random())),
+                    None
+                ),
+                vec![]
+            );
+
+            // The authority authorizes `Issuer`s of the registries it recognizes.
+            let (recognizer, recognizer_kp) = newdid();
+            let recognizer_id = TrustRegistryId(rand::random());
+            ActionWithNonceWrapper::<Test, _, _>::new(
+                2,
+                Convener(recognizer.into()),
+                InitOrUpdateTrustRegistry::<Test> {
+                    registry_id: recognizer_id,
+                    name: "Recognizer".to_string().try_into().unwrap(),
+                    gov_framework: Bytes(vec![2; 100]).try_into().unwrap(),
+                    nonce: 2,
+                },
+            )
+            .modify::<Test, _, _, _, _>(|action, set| {
+                action.action.modify_removable(|action, info| {
+                    Mod::init_or_update_trust_registry_(
+                        action,
+                        set,
+                        info,
+                        Convener(recognizer.into()),
+                    )
+                })
+            })
+            .unwrap();
+            assert_ok!(update_recognized_registries(
+                recognizer_id,
+                once((registry_id, AddOrRemoveOrModify::Add(()))),
+                (recognizer, &recognizer_kp)
+            ));
+            assert_ok!(update_recognition_acceptances(
+                registry_id,
+                once((recognizer_id, AddOrRemoveOrModify::Add(()))),
+                (convener, &convener_kp)
+            ));
+
+            assert_eq!(
+                query(issuer.into(), TrqpAction::Issue, Some(recognizer_id), None),
+                vec![TrqpAuthorization {
+                    entity_id: issuer.into(),
+                    action: TrqpAction::Issue,
+                    resource: schema_id,
+                    authority_id: recognizer_id,
+                    governance_framework: Bytes(vec![2; 100]).try_into().unwrap(),
+                    authorized: true,
+                }]
+            );
+            assert_eq!(
+                query(issuer.into(), TrqpAction::Issue, None, None),
+                authorization(issuer.into(), TrqpAction::Issue, true)
+            );
+
+            SuspendIssuers {
+                registry_id,
+                issuers: once(Issuer(issuer.into())).collect(),
+                nonce: 4,
+            }
+            .view(|action, reg| Mod::suspend_issuers_(action, reg, Convener(convener.into())))
+            .unwrap();
+
+            assert_eq!(
+                query(issuer.into(), TrqpAction::Issue, None, None),
+                authorization(issuer.into(), TrqpAction::Issue, false)
+            );
+            assert!(
+                !query(issuer.into(), TrqpAction::Issue, Some(recognizer_id), None)[0].authorized
+            );
+        })
+    }
+
+    #[test]
+    fn gov_framework_versions() {
+        ext().execute_with(|| {
+            let mut rng = rand::thread_rng();
+
+            let (convener, convener_kp) = newdid();
+            let (other, other_kp) = newdid();
+
+            let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                registry_id: TrustRegistryId(rand::random()),
+                name: (0..25)
+                    .map(|_| rng.sample(Alphanumeric) as char)
+                    .collect::<String>()
+                    .try_into()
+                    .unwrap(),
+                gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                nonce: 2,
+            };
+            let registry_id = init_or_update_trust_registry.registry_id;
+
+            ActionWithNonceWrapper::<Test, _, _>::new(
+                2,
+                Convener(convener.into()),
+                init_or_update_trust_registry,
+            )
+            .modify::<Test, _, _, _, _>(|action, set| {
+                action.action.modify_removable(|action, info| {
+                    Mod::init_or_update_trust_registry_(
+                        action,
+                        set,
+                        info,
+                        Convener(convener.into()),
+                    )
+                })
+            })
+            .unwrap();
+
+            let version = |idx: u8, effective_at: u64| GovFrameworkVersion::<Test> {
+                hash: Some(GovFrameworkHash([idx; 32])),
+                uri: format!("https://gov.framework/v{}", idx)
+                    .into_bytes()
+                    .try_into()
+                    .unwrap(),
+                effective_at,
+            };
+            let initial = GovFrameworkVersion::<Test> {
+                hash: None,
+                uri: Bytes(vec![1; 100]).try_into().unwrap(),
+                effective_at: 1,
+            };
+            let add = |idx: u8, effective_at: u64| {
+                add_gov_framework_version(
+                    registry_id,
+                    (
+                        GovFrameworkHash([idx; 32]),
+                        &format!("https://gov.framework/v{}", idx),
+                        effective_at,
+                    ),
+                    (convener, &convener_kp),
+                )
+            };
+
+            assert_noop!(
+                add_gov_framework_version(
+                    registry_id,
+                    (GovFrameworkHash([0; 32]), "https://gov.framework/v0", 1),
+                    (other, &other_kp)
+                ),
+                Error::<Test>::NotTheConvener
+            );
+            assert_noop!(
+                add_gov_framework_version(
+                    registry_id,
+                    (GovFrameworkHash([0; 32]), &"a".repeat(1_001), 1),
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::GovFrameworkSizeExceeded
+            );
+            assert_eq!(
+                Mod::gov_framework_version_at(registry_id, 1),
+                Some(initial.clone())
+            );
+
+            assert_ok!(add(0, 1));
+            assert_ok!(add(1, 5));
+            assert_noop!(add(2, 3), Error::<Test>::InvalidGovFrameworkVersionEffectiveBlock);
+
+            run_to_block(10);
+
+            assert_noop!(add(2, 8), Error::<Test>::InvalidGovFrameworkVersionEffectiveBlock);
+            assert_ok!(add(2, 10));
+            assert_ok!(add(3, 10));
+            assert_ok!(add(4, 20));
+
+            assert!(System::events().iter().any(|record| record.event
+                == TestEvent::TrustRegistry(super::super::Event::GovFrameworkVersionAdded(
+                    registry_id,
+                    5
+                ))));
+            assert_eq!(
+                Mod::gov_framework_versions(registry_id),
+                vec![
+                    initial.clone(),
+                    version(0, 1),
+                    version(1, 5),
+                    version(2, 10),
+                    version(3, 10),
+                    version(4, 20)
+                ]
+            );
+
+            for (block_number, expected) in [
+                (0, None),
+                (1, Some(version(0, 1))),
+                (4, Some(version(0, 1))),
+                (5, Some(version(1, 5))),
+                (9, Some(version(1, 5))),
+                (10, Some(version(3, 10))),
+                (19, Some(version(3, 10))),
+                (20, Some(version(4, 20))),
+                (100, Some(version(4, 20))),
+            ] {
+                assert_eq!(
+                    Mod::gov_framework_version_at(registry_id, block_number),
+                    expected
+                );
+            }
+
+            // Changing the governance framework of the registry records a new version effective immediately.
+            let update_trust_registry = |gov_framework: Vec<u8>| {
+                let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                    registry_id,
+                    name: "Registry".to_string(),
+                    gov_framework: Bytes(gov_framework),
+                    nonce: did_nonce::<Test, _>(convener).unwrap(),
+                };
+                let sig = did_sig(
+                    &init_or_update_trust_registry,
+                    &convener_kp,
+                    Convener(convener.into()),
+                    1,
+                );
+
+                Mod::init_or_update_trust_registry(
+                    Origin::signed(1),
+                    init_or_update_trust_registry,
+                    sig,
+                )
+            };
+
+            assert_noop!(
+                update_trust_registry(vec![2; 100]),
+                Error::<Test>::GovFrameworkVersionScheduled
+            );
+            run_to_block(20);
+            assert_ok!(update_trust_registry(vec![1; 100]));
+            assert_eq!(Mod::gov_framework_versions_count(registry_id), 6);
+            assert_ok!(update_trust_registry(vec![2; 100]));
+            assert_eq!(Mod::gov_framework_versions_count(registry_id), 7);
+
+            let updated = GovFrameworkVersion::<Test> {
+                hash: None,
+                uri: Bytes(vec![2; 100]).try_into().unwrap(),
+                effective_at: 20,
+            };
+            assert_eq!(
+                Mod::gov_framework_version_at(registry_id, 20),
+                Some(updated)
+            );
+
+            for idx in 5..8 {
+                assert_ok!(add(idx, 20));
+            }
+            assert_eq!(
+                Mod::gov_framework_versions_count(registry_id),
+                MaxGovFrameworkVersions::get()
+            );
+
+            // Adding a version to the full history prunes the oldest one.
+            assert_ok!(add(8, 20));
+            assert_eq!(Mod::gov_framework_versions_count(registry_id), 11);
+            assert_eq!(Mod::gov_framework_version(registry_id, 0), None);
+            let versions = Mod::gov_framework_versions(registry_id);
+            assert_eq!(versions.len() as u32, MaxGovFrameworkVersions::get());
+            assert_eq!(versions[0], version(0, 1));
+            assert_eq!(Mod::gov_framework_version_at(registry_id, 1), Some(version(0, 1)));
+
+            // The oldest version can't be pruned while it's in force.
+            for idx in 9..18 {
+                assert_ok!(add(idx, 30));
+            }
+            assert_eq!(Mod::gov_framework_versions(registry_id)[0], version(8, 20));
+            assert_eq!(Mod::gov_framework_version_at(registry_id, 5), None);
+            assert_noop!(add(18, 30), Error::<Test>::TooManyGovFrameworkVersions);
+            assert_eq!(Mod::gov_framework_version_at(registry_id, 20), Some(version(8, 20)));
+
+            run_to_block(30);
+            assert_ok!(add(18, 30));
+            assert_eq!(Mod::gov_framework_version_at(registry_id, 20), None);
+            assert_eq!(Mod::gov_framework_version_at(registry_id, 30), Some(version(18, 30)));
+
+            let remove_trust_registry = RemoveTrustRegistry {
+                registry_id,
+                nonce: did_nonce::<Test, _>(convener).unwrap(),
+            };
+            let sig = did_sig(&remove_trust_registry, &convener_kp, convener, 1u32);
+            assert_ok!(Mod::remove_trust_registry(
+                Origin::signed(1),
+                remove_trust_registry,
+                sig
+            ));
+            assert_eq!(Mod::gov_framework_versions(registry_id), vec![]);
+            assert_eq!(Mod::gov_framework_versions_count(registry_id), 0);
+            assert_eq!(
+                TrustRegistryGovFrameworkVersions::<Test>::iter_prefix(registry_id).count(),
+                0
+            );
+        })
+    }
+
+    #[test]
+    fn scoped_delegations() {
+        ext().execute_with(|| {
+            let mut rng = rand::thread_rng();
+
+            let (convener, convener_kp) = newdid();
+
+            let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                registry_id: TrustRegistryId(rand::random()),
+                name: (0..25)
+                    .map(|_| rng.sample(Alphanumeric) as char)
+                    .collect::<String>()
+                    .try_into()
+                    .unwrap(),
+                gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                nonce: 2,
+            };
+            let registry_id = init_or_update_trust_registry.registry_id;
+
+            ActionWithNonceWrapper::<Test, _, _>::new(
+                2,
+                Convener(convener.into()),
+                init_or_update_trust_registry,
+            )
+            .modify::<Test, _, _, _, _>(|action, set| {
+                action.action.modify_removable(|action, info| {
+                    Mod::init_or_update_trust_registry_(
+                        action,
+                        set,
+                        info,
+                        Convener(convener.into()),
+                    )
+                })
+            })
+            .unwrap();
+
+            let (a, a_kp) = newdid();
+            let (b, b_kp) = newdid();
+            let (c, c_kp) = newdid();
+            let (d, d_kp) = newdid();
+            let (e, e_kp) = newdid();
+            add_participants(
+                registry_id,
+                [
+                    (a, a_kp.clone()),
+                    (b, b_kp.clone()),
+                    (c, c_kp.clone()),
+                    (d, d_kp.clone()),
+                    (e, e_kp.clone()),
+                ],
+                (DidOrDidMethodKey::from(convener), convener_kp.clone()),
+            )
+            .unwrap();
+            let (a_issuer, b_issuer, c_issuer, d_issuer, e_issuer) = (
+                Issuer(a.into()),
+                Issuer(b.into()),
+                Issuer(c.into()),
+                Issuer(d.into()),
+                Issuer(e.into()),
+            );
+
+            let first_schema_id = define_schema(TrustRegistrySchemaId(rand::random()));
+            let second_schema_id = define_schema(TrustRegistrySchemaId(rand::random()));
+            SetSchemasMetadata {
+                registry_id,
+                schemas: SetOrModify::Modify(
+                    [first_schema_id, second_schema_id]
+                        .into_iter()
+                        .map(|schema_id| {
+                            (
+                                schema_id,
+                                SetOrAddOrRemoveOrModify::Add(
+                                    UnboundedTrustRegistrySchemaMetadata {
+                                        issuers: UnboundedIssuersWith(
+                                            once((a_issuer, build_initial_prices(2, 5)))
+                                                .collect(),
+                                        ),
+                                        verifiers: UnboundedTrustRegistrySchemaVerifiers(
+                                            Default::default(),
+                                        ),
+                                    }
+                                    .into(),
+                                ),
+                            )
+                        })
+                        .collect(),
+                ),
+                nonce: 3,
+            }
+            .view(|action, reg| {
+                Mod::set_schemas_metadata_(
+                    action,
+                    reg,
+                    ConvenerOrIssuerOrVerifier(convener.into()),
+                )
+            })
+            .unwrap();
+
+            let scope = |schemas: Option<&[TrustRegistrySchemaId]>, max_depth: Option<u32>| {
+                Some(UnboundedDelegationScope {
+                    schemas: schemas.map(|schemas| schemas.iter().copied().collect()),
+                    max_depth,
+                })
+            };
+            let schemas = |issuer: Issuer| {
+                Mod::registry_issuer_or_delegated_issuer_schemas(registry_id, issuer)
+            };
+            let both_schemas: BTreeSet<_> = [first_schema_id, second_schema_id].into();
+
+            assert_noop!(
+                update_delegations(
+                    registry_id,
+                    [(a_issuer, scope(None, None))],
+                    (a, &a_kp)
+                ),
+                Error::<Test>::IssuerCantDelegateToHimself
+            );
+            assert_noop!(
+                update_delegations(
+                    registry_id,
+                    [(
+                        b_issuer,
+                        scope(Some(&[TrustRegistrySchemaId(rand::random())][..]), None)
+                    )],
+                    (a, &a_kp)
+                ),
+                Error::<Test>::InvalidDelegationScope
+            );
+            assert_noop!(
+                update_delegations(
+                    registry_id,
+                    [(b_issuer, scope(Some(&[][..]), None))],
+                    (a, &a_kp)
+                ),
+                Error::<Test>::InvalidDelegationScope
+            );
+            assert_noop!(
+                update_delegations(registry_id, [(b_issuer, scope(None, Some(3)))], (a, &a_kp)),
+                Error::<Test>::InvalidDelegationScope
+            );
+            assert_noop!(
+                update_delegations(registry_id, [(b_issuer, None)], (a, &a_kp)),
+                Error::<Test>::EntityDoesntExist
+            );
+
+            // `A` delegates only the first schema and allows a single level below `B`.
+            assert_ok!(update_delegations(
+                registry_id,
+                [(b_issuer, scope(Some(&[first_schema_id][..]), Some(1)))],
+                (a, &a_kp)
+            ));
+            assert_eq!(schemas(b_issuer), once(first_schema_id).collect());
+            assert_eq!(
+                Mod::registry_delegated_issuer_schemas(registry_id, b_issuer),
+                DelegatedIssuerSchemas(
+                    once((first_schema_id, DelegatedSchemaCounter(NonZeroU32::new(1).unwrap())))
+                        .collect::<BTreeMap<_, _>>()
+                        .try_into()
+                        .unwrap()
+                )
+            );
+            assert_eq!(
+                Mod::issuer_delegations(registry_id, a_issuer),
+                once((
+                    b_issuer,
+                    DelegationScope {
+                        schemas: Some(IssuerSchemas(
+                            once(first_schema_id).collect::<BTreeSet<_>>().try_into().unwrap()
+                        )),
+                        max_depth: Some(1),
+                    }
+                ))
+                .collect()
+            );
+
+            assert_ok!(update_delegations(
+                registry_id,
+                [(c_issuer, scope(None, None))],
+                (b, &b_kp)
+            ));
+            assert_eq!(schemas(c_issuer), once(first_schema_id).collect());
+            assert!(Mod::is_issuer_authorized(registry_id, first_schema_id, c_issuer));
+            assert!(!Mod::is_issuer_authorized(registry_id, second_schema_id, c_issuer));
+
+            // `A -> B` delegation doesn't allow a second level below `B`.
+            assert_noop!(
+                update_delegations(registry_id, [(d_issuer, scope(None, None))], (c, &c_kp)),
+                Error::<Test>::InvalidDelegationScope
+            );
+
+            // Revoking `A -> B` breaks the whole chain.
+            assert_ok!(update_delegations(registry_id, [(b_issuer, None)], (a, &a_kp)));
+            assert!(schemas(b_issuer).is_empty());
+            assert!(schemas(c_issuer).is_empty());
+            assert!(Mod::registry_delegated_issuer_schemas(registry_id, b_issuer).is_empty());
+            assert_eq!(Mod::delegation_scope(registry_id, (a_issuer, b_issuer)), None);
+
+            assert_ok!(update_delegations(
+                registry_id,
+                [(b_issuer, scope(None, None))],
+                (a, &a_kp)
+            ));
+            assert_ok!(update_delegations(
+                registry_id,
+                [(d_issuer, scope(None, None))],
+                (c, &c_kp)
+            ));
+            assert_eq!(schemas(b_issuer), both_schemas);
+            assert_eq!(schemas(c_issuer), both_schemas);
+            assert_eq!(schemas(d_issuer), both_schemas);
+
+            // `A -> B -> C -> D` chain already has `MaxDelegationDepth` delegations.
+            assert_noop!(
+                update_delegations(registry_id, [(e_issuer, scope(None, None))], (d, &d_kp)),
+                Error::<Test>::InvalidDelegationScope
+            );
+
+            assert_noop!(
+                revoke_delegations(
+                    registry_id,
+                    [(b_issuer, once(c_issuer).collect())],
+                    (b, &b_kp)
+                ),
+                Error::<Test>::NotTheConvener
+            );
+            assert_ok!(revoke_delegations(
+                registry_id,
+                [(b_issuer, once(c_issuer).collect())],
+                (convener, &convener_kp)
+            ));
+            assert!(System::events().iter().any(|record| record.event
+                == TestEvent::TrustRegistry(super::super::Event::DelegationRevoked(
+                    registry_id,
+                    b_issuer,
+                    c_issuer
+                ))));
+            assert_eq!(schemas(b_issuer), both_schemas);
+            assert!(schemas(c_issuer).is_empty());
+            assert!(schemas(d_issuer).is_empty());
+            assert!(Mod::issuer_delegations(registry_id, b_issuer).is_empty());
+            assert_noop!(
+                revoke_delegations(
+                    registry_id,
+                    [(b_issuer, once(c_issuer).collect())],
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::EntityDoesntExist
+            );
+
+            // `B` is reached through both `C` and `E` with different schemas delegated to each of them,
+            // so it must be followed again for the schemas not covered by the first visit.
+            assert_ok!(update_delegations(
+                registry_id,
+                [
+                    (c_issuer, scope(Some(&[first_schema_id][..]), None)),
+                    (e_issuer, scope(Some(&[second_schema_id][..]), None))
+                ],
+                (b, &b_kp)
+            ));
+            assert_ok!(update_delegations(
+                registry_id,
+                [(d_issuer, scope(None, None))],
+                (e, &e_kp)
+            ));
+            assert_eq!(schemas(c_issuer), once(first_schema_id).collect());
+            assert_eq!(schemas(e_issuer), once(second_schema_id).collect());
+            assert_eq!(schemas(d_issuer), both_schemas);
+        })
+    }
+
+    #[test]
+    fn schema_definitions() {
+        ext().execute_with(|| {
+            let (convener, convener_kp) = newdid();
+            let (other, other_kp) = newdid();
+
+            let init_or_update_trust_registry = InitOrUpdateTrustRegistry::<Test> {
+                registry_id: TrustRegistryId(rand::random()),
+                name: "Registry".to_string().try_into().unwrap(),
+                gov_framework: Bytes(vec![1; 100]).try_into().unwrap(),
+                nonce: 2,
+            };
+            let registry_id = init_or_update_trust_registry.registry_id;
+
+            ActionWithNonceWrapper::<Test, _, _>::new(
+                2,
+                Convener(convener.into()),
+                init_or_update_trust_registry,
+            )
+            .modify::<Test, _, _, _, _>(|action, set| {
+                action.action.modify_removable(|action, info| {
+                    Mod::init_or_update_trust_registry_(
+                        action,
+                        set,
+                        info,
+                        Convener(convener.into()),
+                    )
+                })
+            })
+            .unwrap();
+
+            let document = br#"{"$schema":"http://json-schema.org/draft-07/schema#"}"#.to_vec();
+            let blob_id = crate::blob::BlobId(rand::random());
+            let blob: crate::blob::StoredBlob<Test> = (
+                crate::blob::BlobOwner(other.into()),
+                document.clone().try_into().unwrap(),
+            );
+            crate::blob::Blobs::<Test>::insert(blob_id, blob);
+
+            let json_schema = "https://schema.dock.io/Credential.json";
+            let credential_type = "https://www.w3.org/2018/credentials#VerifiableCredential";
+            let content_hash = SchemaContentHash::of(&document);
+            let schema_id = TrustRegistrySchemaId::of_definition(
+                json_schema,
+                credential_type,
+                content_hash,
+                Some(blob_id),
+            );
+            let other_schema_id =
+                TrustRegistrySchemaId::of_definition(json_schema, credential_type, content_hash, None);
+            let legacy_schema_id = TrustRegistrySchemaId(rand::random());
+
+            assert_noop!(
+                add_schema_definition(
+                    schema_id,
+                    (json_schema, credential_type, SchemaContentHash([0; 32]), Some(blob_id)),
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::SchemaDefinitionHashMismatch
+            );
+            assert_noop!(
+                add_schema_definition(
+                    schema_id,
+                    (
+                        json_schema,
+                        credential_type,
+                        content_hash,
+                        Some(crate::blob::BlobId(rand::random()))
+                    ),
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::NoSchemaDefinitionBlob
+            );
+            assert_noop!(
+                add_schema_definition(
+                    schema_id,
+                    (&"a".repeat(1_025), credential_type, content_hash, Some(blob_id)),
+                    (convener, &convener_kp)
+                ),
+                Error::<Test>::SchemaDefinitionUriSizeExceeded
+            );
+
+            let set_schema_metadata = |schema_id| {
+                let action = SetSchemasMetadata {
+                    registry_id,
+                    schemas: SetOrModify::Modify(
+                        once((
+                            schema_id,
+                            SetOrAddOrRemoveOrModify::Add(
+                                UnboundedTrustRegistrySchemaMetadata {
+                                    issuers: UnboundedIssuersWith(Default::default()),
+                                    verifiers: UnboundedTrustRegistrySchemaVerifiers(
+                                        Default::default(),
+                                    ),
+                                }
+                                .into(),
+                            ),
+                        ))
+                        .collect(),
+                    ),
+                    nonce: did_nonce::<Test, _>(convener).unwrap(),
+                };
+                let sig = did_sig(&action, &convener_kp, convener, 1);
+
+                Mod::set_schemas_metadata(Origin::signed(1), action, sig).map_err(|e| e.error)
+            };
+
+            assert_noop!(
+                set_schema_metadata(schema_id),
+                Error::<Test>::NoSchemaDefinition
+            );
+            // Ids are derived from the definitions, so they can't be claimed for different contents.
+            assert_noop!(
+                add_schema_definition(
+                    TrustRegistrySchemaId(rand::random()),
+                    (json_schema, credential_type, content_hash, Some(blob_id)),
+                    (other, &other_kp)
+                ),
+                Error::<Test>::SchemaDefinitionIdMismatch
+            );
+            assert_noop!(
+                add_schema_definition(
+                    schema_id,
+                    (json_schema, credential_type, SchemaContentHash([0; 32]), None),
+                    (other, &other_kp)
+                ),
+                Error::<Test>::SchemaDefinitionIdMismatch
+            );
+
+            assert_ok!(add_schema_definition(
+                schema_id,
+                (json_schema, credential_type, content_hash, Some(blob_id)),
+                (convener, &convener_kp)
+            ));
+            assert!(System::events().iter().any(|record| record.event
+                == TestEvent::TrustRegistry(super::super::Event::SchemaDefinitionAdded(
+                    schema_id
+                ))));
+            let definition = TrustRegistrySchemaDefinition::<Test> {
+                author: Convener(convener.into()),
+                json_schema: json_schema.to_string().try_into().unwrap(),
+                credential_type: credential_type.to_string().try_into().unwrap(),
+                content_hash,
+                blob_id: Some(blob_id),
+            };
+            assert_eq!(Mod::schema_definition(schema_id), Some(definition.clone()));
+            assert_noop!(
+                add_schema_definition(
+                    schema_id,
+                    (json_schema, credential_type, content_hash, Some(blob_id)),
+                    (other, &other_kp)
+                ),
+                Error::<Test>::SchemaDefinitionAlreadyExists
+            );
+
+            // Definitions without a blob only reference the document by its hash.
+            assert_ok!(add_schema_definition(
+                other_schema_id,
+                (json_schema, credential_type, content_hash, None),
+                (other, &other_kp)
+            ));
+
+            assert_ok!(set_schema_metadata(schema_id));
+            assert_ok!(set_schema_metadata(other_schema_id));
+
+            // Schemas referenced by the registries before the definitions were introduced stay usable.
+            assert_noop!(
+                set_schema_metadata(legacy_schema_id),
+                Error::<Test>::NoSchemaDefinition
+            );
+            TrustRegistrySchemasMetadata::<Test>::insert(
+                legacy_schema_id,
+                TrustRegistryId(rand::random()),
+                TrustRegistrySchemaMetadata::<Test>::try_from(
+                    UnboundedTrustRegistrySchemaMetadata {
+                        issuers: UnboundedIssuersWith(Default::default()),
+                        verifiers: UnboundedTrustRegistrySchemaVerifiers(Default::default()),
+                    },
+                )
+                .unwrap(),
+            );
+            // Being referenced by another registry doesn't exempt the schema.
+            assert_noop!(
+                set_schema_metadata(legacy_schema_id),
+                Error::<Test>::NoSchemaDefinition
+            );
+
+            {
+                use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+                StorageVersion::new(0).put::<Mod>();
+                SnapshotLegacySchemaIds::<Test>::on_runtime_upgrade();
+            }
+            assert_eq!(Mod::is_legacy_schema(legacy_schema_id), Some(()));
+            assert_eq!(Mod::is_legacy_schema(schema_id), None);
+            assert_eq!(Mod::is_legacy_schema(other_schema_id), None);
+            assert_ok!(set_schema_metadata(legacy_schema_id));
+
+            // Documents are only served along with the definitions, not in the metadata.
+            let aggregated_definition = |schema_id| {
+                assert_eq!(
+                    Mod::schema_metadata(schema_id, registry_id)
+                        .unwrap()
+                        .aggregate(registry_id, schema_id)
+                        .definition,
+                    Mod::schema_definition(schema_id)
+                );
+
+                Mod::schema_definition(schema_id).map(TrustRegistrySchemaDefinition::aggregate)
+            };
+            assert_eq!(
+                aggregated_definition(schema_id),
+                Some(AggregatedTrustRegistrySchemaDefinition {
+                    definition,
+                    document: Some(document.try_into().unwrap()),
+                })
+            );
+            assert_eq!(
+                aggregated_definition(other_schema_id),
+                Some(AggregatedTrustRegistrySchemaDefinition {
+                    definition: TrustRegistrySchemaDefinition::<Test> {
+                        author: Convener(other.into()),
+                        json_schema: json_schema.to_string().try_into().unwrap(),
+                        credential_type: credential_type.to_string().try_into().unwrap(),
+                        content_hash,
+                        blob_id: None,
+                    },
+                    document: None,
+                })
+            );
+        })
+    }
 }
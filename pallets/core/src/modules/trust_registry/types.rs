@@ -1,11 +1,13 @@
 use super::{
-    Config, ConvenerTrustRegistries, Error, TrustRegistriesInfo, TrustRegistriesParticipants,
+    Config, ConvenerTrustRegistries, Error, FeePayoutAccounts, TrustRegistriesInfo,
+    TrustRegistriesParticipants,
 };
 use crate::{
+    blob::BlobId,
     common::{AuthorizeTarget, Limits, TypesAndLimits},
     did::{DidKey, DidMethodKey, DidOrDidMethodKey},
     hex_debug, impl_wrapper,
-    util::{batch_update::*, Associated, BoundedBytes, KeyValue, OptionExt, StorageRef},
+    util::{batch_update::*, Associated, BoundedBytes, KeyValue, OptionExt, StorageRef, Types},
 };
 use alloc::{collections::BTreeMap, string::String};
 use codec::{Decode, Encode, MaxEncodedLen};
@@ -171,6 +173,9 @@ pub struct Verifier(pub DidOrDidMethodKey);
 
 impl_wrapper!(Verifier(DidOrDidMethodKey));
 
+impl<T: TypesAndLimits> AuthorizeTarget<T, TrustRegistryId, DidKey> for Verifier {}
+impl<T: TypesAndLimits> AuthorizeTarget<T, TrustRegistryId, DidMethodKey> for Verifier {}
+
 /// Trust registry `Convener`/`Issuer`/`Verifier`'s `DID`.
 #[derive(Encode, Decode, Clone, Debug, Copy, PartialEq, Eq, Ord, PartialOrd, MaxEncodedLen)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -234,7 +239,187 @@ crate::impl_authorize_target!(
     }
 );
 
+crate::impl_authorize_target!(
+    for (TrustRegistryId, IssuerOrVerifier): ConvenerOrIssuerOrVerifier fn (self, _, action, _) {
+        ensure!(
+            action.target().1 == IssuerOrVerifier(**self),
+            crate::did::Error::<T>::InvalidSigner
+        );
+    }
+);
+
+impl<T: Types> Associated<T> for ConvenerOrIssuerOrVerifier {
+    type Value = T::AccountId;
+}
+
+impl<T: Config> StorageRef<T> for ConvenerOrIssuerOrVerifier {
+    fn try_mutate_associated<F, R, E>(self, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut Option<T::AccountId>) -> Result<R, E>,
+    {
+        FeePayoutAccounts::<T>::try_mutate_exists(self, f)
+    }
+
+    fn view_associated<F, R>(self, f: F) -> R
+    where
+        F: FnOnce(Option<T::AccountId>) -> R,
+    {
+        f(FeePayoutAccounts::<T>::get(self))
+    }
+}
+
+crate::impl_authorize_target!(
+    for Self: ConvenerOrIssuerOrVerifier fn (self, _, action, _) {
+        ensure!(
+            action.target() == *self,
+            crate::did::Error::<T>::InvalidSigner
+        );
+    }
+);
+
+/// Verification fee payable by a `Verifier` in the native currency split between the `Issuer` and the `Convener`.
+/// Lowest denomination is used.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, Copy, Default, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(omit_prefix)]
+pub struct VerificationFee {
+    /// Amount to be paid to the `Issuer`.
+    pub issuer: VerificationPrice,
+    /// Amount to be paid to the `Convener`.
+    pub convener: VerificationPrice,
+}
+
+impl VerificationFee {
+    /// Total amount to be paid by the `Verifier`.
+    pub fn total(&self) -> VerificationPrice {
+        VerificationPrice(self.issuer.0.saturating_add(self.convener.0))
+    }
+}
+
+/// Period during which an `Issuer` is accredited to issue credentials of the specific schema.
+/// `valid_from` is inclusive while `valid_until` is exclusive, unset bound means no limit.
+#[derive(
+    Encode,
+    Decode,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    DebugNoBound,
+    MaxEncodedLen,
+    DefaultNoBound,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct IssuerAccreditation<T: Types> {
+    pub valid_from: Option<T::BlockNumber>,
+    pub valid_until: Option<T::BlockNumber>,
+}
+
+impl<T: Types> IssuerAccreditation<T> {
+    /// Returns `true` if the accreditation is valid at the supplied block.
+    pub fn is_valid_at(&self, block_number: T::BlockNumber) -> bool {
+        self.valid_from.map_or(true, |from| from <= block_number)
+            && self.valid_until.map_or(true, |until| block_number < until)
+    }
+
+    /// Returns `true` if the accreditation can be set at the supplied block, i.e. it doesn't lapse
+    /// before or at the given block and its period isn't empty.
+    pub fn can_be_set_at(&self, block_number: T::BlockNumber) -> bool {
+        match (self.valid_from, self.valid_until) {
+            (Some(from), Some(until)) => from < until && block_number < until,
+            (None, Some(until)) => block_number < until,
+            _ => true,
+        }
+    }
+}
+
+/// Pending request of an `Issuer` or a `Verifier` to participate in the `TrustRegistry`.
+#[derive(
+    Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, DebugNoBound, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct ParticipationRequest<T: TypesAndLimits> {
+    /// Information to be set for the participant once the request is approved.
+    pub information: TrustRegistryStoredParticipantInformation<T>,
+    /// Block at which the request expires unless resolved by the `Convener` before.
+    pub expires_at: T::BlockNumber,
+    /// Account the deposit was reserved from.
+    pub depositor: T::AccountId,
+    /// Amount reserved from the depositor in the lowest units of the currency.
+    pub deposit: u128,
+}
+
+impl<T: TypesAndLimits> ParticipationRequest<T> {
+    /// Returns `true` if the request can't be approved at the supplied block anymore.
+    pub fn is_expired_at(&self, block_number: T::BlockNumber) -> bool {
+        self.expires_at <= block_number
+    }
+}
+
+/// Decision of the `Convener` on the `ParticipationRequest`.
+#[derive(Encode, Decode, Clone, Debug, Copy, PartialEq, Eq, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(omit_prefix)]
+pub enum ParticipationRequestResolution {
+    Approve,
+    Reject,
+}
+
+/// Hash of the governance framework document.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Copy, Ord, PartialOrd, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(omit_prefix)]
+pub struct GovFrameworkHash(#[cfg_attr(feature = "serde", serde(with = "serde_hex"))] pub [u8; 32]);
+
+impl_wrapper!(GovFrameworkHash([u8; 32]));
+hex_debug!(GovFrameworkHash);
+
+/// Version of the `TrustRegistry`'s governance framework document.
+#[derive(
+    Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, DebugNoBound, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct GovFrameworkVersion<T: TypesAndLimits> {
+    /// Hash of the governance framework document.
+    /// `None` for versions recorded by `InitOrUpdateTrustRegistry` which doesn't carry the document hash.
+    pub hash: Option<GovFrameworkHash>,
+    /// Governance framework as set by the `Convener`, i.e. URI the document can be retrieved from.
+    pub uri: BoundedBytes<T::MaxTrustRegistryGovFrameworkSize>,
+    /// Block starting from which the version is in force.
+    pub effective_at: T::BlockNumber,
+}
+
 /// Price to verify a credential. Lowest denomination should be used.
+/// The price under `Config::NativeCurrencySymbol` is charged as is in the indivisible units of `Config::Currency`.
 #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, Copy, Ord, PartialOrd, MaxEncodedLen)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
@@ -527,6 +712,117 @@ impl<T: Limits> TryFrom<UnboundedDelegatedIssuers> for DelegatedIssuers<T> {
 pub type UnboundedDelegatedIssuersUpdate =
     SetOrModify<UnboundedDelegatedIssuers, MultiTargetUpdate<Issuer, AddOrRemoveOrModify<()>>>;
 
+/// Scope of the delegation from one `Issuer` to another.
+#[derive(
+    Encode,
+    Decode,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    DebugNoBound,
+    DefaultNoBound,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct DelegationScope<T: Limits> {
+    /// Delegated schemas. `None` means that all schemas of the delegating `Issuer` are delegated.
+    pub schemas: Option<IssuerSchemas<T>>,
+    /// Amount of further delegation levels allowed below the delegated `Issuer`.
+    /// `None` means that the chain can be extended up to `T::MaxDelegationDepth` levels.
+    pub max_depth: Option<u32>,
+}
+
+impl<T: Limits> DelegationScope<T> {
+    /// Scope of the delegations set via `update_delegated_issuers`: all schemas of the
+    /// delegating `Issuer` are delegated, but the delegated `Issuer` can't delegate them any further.
+    pub fn unscoped() -> Self {
+        Self {
+            schemas: None,
+            max_depth: Some(0),
+        }
+    }
+
+    /// Returns `true` if the given schema is delegated.
+    pub fn covers(&self, schema_id: &TrustRegistrySchemaId) -> bool {
+        self.schemas
+            .as_ref()
+            .map_or(true, |schemas| schemas.contains(schema_id))
+    }
+
+    /// Returns `true` if the delegated `Issuer` can be followed by the supplied amount of levels.
+    pub fn allows_levels(&self, levels: u32) -> bool {
+        self.max_depth.map_or(true, |max_depth| levels <= max_depth)
+    }
+}
+
+/// Unbounded scope of the delegation from one `Issuer` to another.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(omit_prefix)]
+pub struct UnboundedDelegationScope {
+    /// Delegated schemas. `None` means that all schemas of the delegating `Issuer` are delegated.
+    pub schemas: Option<BTreeSet<TrustRegistrySchemaId>>,
+    /// Amount of further delegation levels allowed below the delegated `Issuer`.
+    /// `None` means that the chain can be extended up to `T::MaxDelegationDepth` levels.
+    pub max_depth: Option<u32>,
+}
+
+impl<T: Limits> TryFrom<UnboundedDelegationScope> for DelegationScope<T> {
+    type Error = Error<T>;
+
+    fn try_from(
+        UnboundedDelegationScope { schemas, max_depth }: UnboundedDelegationScope,
+    ) -> Result<Self, Self::Error> {
+        let schemas = schemas
+            .map(|schemas| {
+                schemas
+                    .try_into()
+                    .map(IssuerSchemas)
+                    .map_err(|_| Error::<T>::TooManySchemasPerDelegatedIssuer)
+            })
+            .transpose()?;
+
+        Ok(Self { schemas, max_depth })
+    }
+}
+
+/// `Issuer`s which delegated some of their schemas to a particular `Issuer` using scoped delegations.
+#[derive(
+    Encode,
+    Decode,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    DebugNoBound,
+    DefaultNoBound,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct IssuerDelegators<T: Limits>(
+    #[cfg_attr(feature = "serde", serde(with = "btree_set"))]
+    pub  BoundedBTreeSet<Issuer, T::MaxDelegatedIssuers>,
+);
+
+impl_wrapper!(IssuerDelegators<T> where T: Limits => (BoundedBTreeSet<Issuer, T::MaxDelegatedIssuers>));
+
 pub type UnboundedTrustRegistryParticipantsUpdate =
     MultiTargetUpdate<IssuerOrVerifier, AddOrRemoveOrModify<()>>;
 
@@ -658,18 +954,27 @@ pub type AggregatedTrustRegistrySchemaIssuers<T> = Vec<(Issuer, AggregatedIssuer
 pub struct AggregatedTrustRegistrySchemaMetadata<T: Limits> {
     pub issuers: AggregatedTrustRegistrySchemaIssuers<T>,
     pub verifiers: TrustRegistrySchemaVerifiers<T>,
+    /// Definition of the schema, absent for schemas added before definitions were introduced.
+    /// The JSON Schema document referenced by the definition's `blob_id` can be fetched using `schema_definition`.
+    pub definition: Option<TrustRegistrySchemaDefinition<T>>,
 }
 
 impl<T: Config> TrustRegistrySchemaMetadata<T> {
+    /// Aggregates schema metadata with the `Issuer`s configurations.
+    /// `Issuer`s which aren't accredited at the current block are excluded.
     pub fn aggregate(
         self,
         registry_id: TrustRegistryId,
+        schema_id: TrustRegistrySchemaId,
     ) -> AggregatedTrustRegistrySchemaMetadata<T> {
         let Self { issuers, verifiers } = self;
 
         let issuers = issuers
             .0
             .into_iter()
+            .filter(|(issuer, _)| {
+                super::Pallet::<T>::is_issuer_accredited(registry_id, schema_id, *issuer)
+            })
             .map(|(issuer, verification_prices)| {
                 let TrustRegistryIssuerConfiguration {
                     suspended,
@@ -687,7 +992,13 @@ impl<T: Config> TrustRegistrySchemaMetadata<T> {
             })
             .collect();
 
-        AggregatedTrustRegistrySchemaMetadata { issuers, verifiers }
+        let definition = super::TrustRegistrySchemaDefinitions::<T>::get(schema_id);
+
+        AggregatedTrustRegistrySchemaMetadata {
+            issuers,
+            verifiers,
+            definition,
+        }
     }
 }
 
@@ -982,6 +1293,42 @@ impl<T: Limits> IntoIterator for IssuerTrustRegistries<T> {
     }
 }
 
+/// Set of registries recognized by a `TrustRegistry`.
+#[derive(
+    Encode,
+    Decode,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    DebugNoBound,
+    MaxEncodedLen,
+    DefaultNoBound,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct RecognizedTrustRegistries<T: Limits>(
+    #[cfg_attr(feature = "serde", serde(with = "btree_set"))]
+    pub  BoundedBTreeSet<TrustRegistryId, T::MaxRecognizedRegistries>,
+);
+
+impl_wrapper!(RecognizedTrustRegistries<T> where T: Limits => (BoundedBTreeSet<TrustRegistryId, T::MaxRecognizedRegistries>));
+
+impl<T: Limits> IntoIterator for RecognizedTrustRegistries<T> {
+    type IntoIter = alloc::collections::btree_set::IntoIter<TrustRegistryId>;
+    type Item = TrustRegistryId;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
 #[derive(
     Encode,
     Decode,
@@ -1019,6 +1366,8 @@ impl_wrapper!(TrustRegistryIdSet<T> where T: Limits => (BoundedBTreeSet<TrustReg
 pub struct TrustRegistryInfo<T: Limits> {
     pub convener: Convener,
     pub name: BoundedString<T::MaxTrustRegistryNameSize>,
+    /// Deprecated: governance framework set by the latest `InitOrUpdateTrustRegistry`, it isn't updated
+    /// by `AddGovFrameworkVersion`. Use `gov_framework_in_force` or the versions history instead.
     pub gov_framework: BoundedBytes<T::MaxTrustRegistryGovFrameworkSize>,
 }
 
@@ -1168,6 +1517,28 @@ impl<T: Config> StorageRef<T> for (TrustRegistryIdForParticipants, IssuerOrVerif
     }
 }
 
+impl<T: TypesAndLimits> Associated<T> for (TrustRegistryId, IssuerOrVerifier) {
+    type Value = ParticipationRequest<T>;
+}
+
+impl<T: Config> StorageRef<T> for (TrustRegistryId, IssuerOrVerifier) {
+    fn try_mutate_associated<F, R, E>(self, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut Option<ParticipationRequest<T>>) -> Result<R, E>,
+    {
+        super::TrustRegistryParticipationRequests::<T>::try_mutate_exists(self.0, self.1, f)
+    }
+
+    fn view_associated<F, R>(self, f: F) -> R
+    where
+        F: FnOnce(Option<ParticipationRequest<T>>) -> R,
+    {
+        f(super::TrustRegistryParticipationRequests::<T>::get(
+            self.0, self.1,
+        ))
+    }
+}
+
 /// Unique identifier for the `TrustRegistry`.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Copy, Ord, PartialOrd, MaxEncodedLen)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1181,16 +1552,152 @@ pub struct TrustRegistrySchemaId(
 impl_wrapper!(TrustRegistrySchemaId([u8; 32]));
 hex_debug!(TrustRegistrySchemaId);
 
+impl TrustRegistrySchemaId {
+    /// Derives the id of the schema from its definition, so an id can't be claimed for the different contents.
+    pub fn of_definition(
+        json_schema: &str,
+        credential_type: &str,
+        content_hash: SchemaContentHash,
+        blob_id: Option<BlobId>,
+    ) -> Self {
+        Self(sp_io::hashing::blake2_256(
+            &(json_schema, credential_type, content_hash, blob_id).encode(),
+        ))
+    }
+}
+
+impl<T: Limits> Associated<T> for TrustRegistrySchemaId {
+    type Value = TrustRegistrySchemaDefinition<T>;
+}
+
+impl<T: Config> StorageRef<T> for TrustRegistrySchemaId {
+    fn try_mutate_associated<F, R, E>(self, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut Option<TrustRegistrySchemaDefinition<T>>) -> Result<R, E>,
+    {
+        super::TrustRegistrySchemaDefinitions::<T>::try_mutate_exists(self, f)
+    }
+
+    fn view_associated<F, R>(self, f: F) -> R
+    where
+        F: FnOnce(Option<TrustRegistrySchemaDefinition<T>>) -> R,
+    {
+        f(super::TrustRegistrySchemaDefinitions::<T>::get(self))
+    }
+}
+
+impl<T: TypesAndLimits> AuthorizeTarget<T, TrustRegistrySchemaId, DidKey> for Convener {}
+impl<T: TypesAndLimits> AuthorizeTarget<T, TrustRegistrySchemaId, DidMethodKey> for Convener {}
+
+/// Schema definition along with the JSON Schema document if it's stored on-chain.
+#[derive(
+    Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, DebugNoBound, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct AggregatedTrustRegistrySchemaDefinition<T: Limits> {
+    pub definition: TrustRegistrySchemaDefinition<T>,
+    /// Content of the referenced `Blob` matching the definition's content hash.
+    pub document: Option<BoundedBytes<T::MaxBlobSize>>,
+}
+
+impl<T: Config> TrustRegistrySchemaDefinition<T> {
+    /// Aggregates schema definition with the JSON Schema document stored in the referenced `Blob`.
+    pub fn aggregate(self) -> AggregatedTrustRegistrySchemaDefinition<T> {
+        let document = self
+            .blob_id
+            .and_then(crate::blob::Blobs::<T>::get)
+            .map(|(_, document)| document);
+
+        AggregatedTrustRegistrySchemaDefinition {
+            definition: self,
+            document,
+        }
+    }
+}
+
+/// `blake2_256` hash of the schema document.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Copy, Ord, PartialOrd, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(omit_prefix)]
+pub struct SchemaContentHash(
+    #[cfg_attr(feature = "serde", serde(with = "serde_hex"))] pub [u8; 32],
+);
+
+impl_wrapper!(SchemaContentHash([u8; 32]));
+hex_debug!(SchemaContentHash);
+
+impl SchemaContentHash {
+    /// Hashes the supplied schema document.
+    pub fn of(document: &[u8]) -> Self {
+        Self(sp_io::hashing::blake2_256(document))
+    }
+}
+
+/// Definition of the schema referenced by the `TrustRegistry`s schema metadata.
+/// Definitions are immutable once added and stored under the id derived from their contents
+/// via `TrustRegistrySchemaId::of_definition`.
+#[derive(
+    Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, DebugNoBound, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Sized", deserialize = "T: Sized"))
+)]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct TrustRegistrySchemaDefinition<T: Limits> {
+    /// `Convener` who added the definition.
+    pub author: Convener,
+    /// URI of the JSON Schema document.
+    pub json_schema: BoundedString<T::MaxIriSize>,
+    /// URI of the credential type issued under the schema.
+    pub credential_type: BoundedString<T::MaxIriSize>,
+    /// Hash of the JSON Schema document.
+    pub content_hash: SchemaContentHash,
+    /// `Blob` holding the JSON Schema document on-chain.
+    pub blob_id: Option<BlobId>,
+}
+
+/// Amounts of the entities removed along with the `TrustRegistry`.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct RemovedTrustRegistryEntities {
+    /// Storage accesses made while removing schemas metadata and `Issuer`/`Verifier` indices.
+    pub accesses: StorageAccesses,
+    pub participants: u32,
+    pub accreditations: u32,
+    /// Recognitions made by the `TrustRegistry` along with the recognitions it accepted.
+    pub recognitions: u32,
+    pub participation_requests: u32,
+    pub gov_framework_versions: u32,
+    /// Delegation scopes along with the `Issuer`s delegators indices.
+    pub delegations: u32,
+}
+
 /// Number of times storage entities were accessed.
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub struct StorageAccesses {
     pub issuer_schemas: u32,
     pub issuer_registries: u32,
     pub issuer_configuration: u32,
+    pub delegation_scopes: u32,
     pub delegated_issuer_schemas: u32,
     pub verifier_schemas: u32,
     pub verifier_registries: u32,
     pub schemas: u32,
+    pub schema_definitions: u32,
     pub registry_schemas: bool,
 }
 
@@ -1211,10 +1718,12 @@ impl StorageAccesses {
         (self.issuer_schemas as u64)
             .saturating_add(self.issuer_registries as u64)
             .saturating_add(self.issuer_configuration as u64)
+            .saturating_add(self.delegation_scopes as u64)
             .saturating_add(self.delegated_issuer_schemas as u64)
             .saturating_add(self.verifier_schemas as u64)
             .saturating_add(self.verifier_registries as u64)
             .saturating_add(self.schemas as u64)
+            .saturating_add(self.schema_definitions as u64)
             .saturating_add(self.registry_schemas as u64)
     }
 
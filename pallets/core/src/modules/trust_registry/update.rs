@@ -423,11 +423,26 @@ impl<T: Config> ValidateTrustRegistryUpdate<T> for IssuersSchemasUpdate {
             .flat_map(|(issuer, updates)| {
                 context.0.issuer_configuration += 1;
 
-                TrustRegistryIssuerConfigurations::<T>::get(registry_id, issuer)
-                    .delegated
-                    .0
-                    .into_iter()
-                    .map(move |delegated_issuer| (delegated_issuer, updates.clone()))
+                let DelegatedIssuers(delegated) =
+                    TrustRegistryIssuerConfigurations::<T>::get(registry_id, issuer).delegated;
+                context.0.delegation_scopes += delegated.len() as u32;
+
+                delegated.into_iter().map(move |delegated_issuer| {
+                    // Only updates of the schemas covered by the delegation scope are propagated.
+                    let scoped_updates = match TrustRegistryDelegationScopes::<T>::get(
+                        registry_id,
+                        (*issuer, delegated_issuer),
+                    ) {
+                        Some(scope) => updates
+                            .iter()
+                            .filter(|(schema_id, _)| scope.covers(schema_id))
+                            .map(|(schema_id, update)| (*schema_id, update.clone()))
+                            .collect(),
+                        None => updates.clone(),
+                    };
+
+                    (delegated_issuer, scoped_updates)
+                })
             })
             .try_fold(
                 MultiTargetUpdate::<Issuer, SchemaIdUpdate<IncOrDec>>::default(),
@@ -636,10 +651,11 @@ impl<T: Config> ValidateTrustRegistryUpdate<T> for SchemaIdUpdate<SchemaMetadata
     ) -> Result<Validated<Self>, Error<T>> {
         // Iterate over each schema update.
         self.into_iter()
-            // Increment the schema storage access count.
-            .inspect(|_| storage_accesses.schemas += 1)
             // Filter and validate each schema update.
             .filter_map(|(schema_id, update)| {
+                // Increment the schema storage access count.
+                storage_accesses.schemas += 1;
+
                 // Retrieve the current schema metadata.
                 let schema_metadata =
                     super::TrustRegistrySchemasMetadata::<T>::get(schema_id, registry_id);
@@ -648,8 +664,22 @@ impl<T: Config> ValidateTrustRegistryUpdate<T> for SchemaIdUpdate<SchemaMetadata
                 check_err!(actor.validate_update(registry_info, &update, &schema_metadata));
 
                 // Skip updates that result in no change.
-                if update.kind(&schema_metadata) == UpdateKind::None {
-                    None?
+                match update.kind(&schema_metadata) {
+                    UpdateKind::None => None?,
+                    // Ensure that newly added schemas are defined.
+                    UpdateKind::Add => {
+                        storage_accesses.schema_definitions += 1;
+
+                        if !super::TrustRegistrySchemaDefinitions::<T>::contains_key(schema_id) {
+                            // Schemas added before the definitions were introduced are exempt.
+                            storage_accesses.schema_definitions += 1;
+
+                            if !super::LegacySchemaIds::<T>::contains_key(schema_id) {
+                                return Some(Err(Error::<T>::NoSchemaDefinition));
+                            }
+                        }
+                    }
+                    _ => {}
                 }
 
                 // Record the differences in the schema update.
@@ -911,7 +941,27 @@ impl<T: Config> ExecuteTrustRegistryUpdate<T>
                             UpdateKind::None => return,
                         };
 
+                        let previous_issuers: BTreeSet<Issuer> = schema_metadata
+                            .iter()
+                            .flat_map(|meta| meta.issuers.keys().copied())
+                            .collect();
+
                         update.apply_update(schema_metadata);
+
+                        // Accreditations of the `Issuer`s removed from the schema are no longer relevant
+                        for issuer in previous_issuers {
+                            let still_issuer = schema_metadata
+                                .as_ref()
+                                .map_or(false, |meta| meta.issuers.contains_key(&issuer));
+
+                            if !still_issuer {
+                                super::TrustRegistryIssuerAccreditations::<T>::remove(
+                                    registry_id,
+                                    (schema_id, issuer),
+                                );
+                            }
+                        }
+
                         super::Pallet::<T>::deposit_event(event);
                     },
                 );
@@ -963,10 +1013,12 @@ impl<T: Config> ExecuteTrustRegistryUpdate<T>
             issuer_schemas,
             issuer_registries,
             issuer_configuration: 0,
+            delegation_scopes: 0,
             delegated_issuer_schemas,
             verifier_schemas,
             verifier_registries,
             schemas,
+            schema_definitions: 0,
             registry_schemas,
         }
     }
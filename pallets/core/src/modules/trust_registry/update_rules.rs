@@ -232,6 +232,16 @@ impl<T: Limits> CanUpdateKeyed<VerifierTrustRegistries<T>> for Convener {
     }
 }
 
+impl<T: Limits> CanUpdateKeyed<RecognizedTrustRegistries<T>> for Convener {
+    fn can_update_keyed<U: KeyedUpdate<RecognizedTrustRegistries<T>>>(
+        &self,
+        _entity: &RecognizedTrustRegistries<T>,
+        _update: &U,
+    ) -> bool {
+        true
+    }
+}
+
 impl<T: Limits> CanUpdateKeyed<IssuerTrustRegistries<T>> for Convener {
     fn can_update_keyed<U: KeyedUpdate<IssuerTrustRegistries<T>>>(
         &self,
@@ -45,6 +45,79 @@ pub trait WeightInfo {
     fn unsuspend_issuers_secp256k1(i: u32) -> Weight;
     fn change_participants(i: u32) -> Weight;
     fn set_participant_information(o: u32, l: u32, d: u32) -> Weight;
+    fn remove_trust_registry_sr25519(
+        i: u32,
+        v: u32,
+        s: u32,
+        p: u32,
+        a: u32,
+        r: u32,
+        q: u32,
+        g: u32,
+        d: u32,
+    ) -> Weight;
+    fn remove_trust_registry_ed25519(
+        i: u32,
+        v: u32,
+        s: u32,
+        p: u32,
+        a: u32,
+        r: u32,
+        q: u32,
+        g: u32,
+        d: u32,
+    ) -> Weight;
+    fn remove_trust_registry_secp256k1(
+        i: u32,
+        v: u32,
+        s: u32,
+        p: u32,
+        a: u32,
+        r: u32,
+        q: u32,
+        g: u32,
+        d: u32,
+    ) -> Weight;
+    fn transfer_trust_registry_sr25519() -> Weight;
+    fn transfer_trust_registry_ed25519() -> Weight;
+    fn transfer_trust_registry_secp256k1() -> Weight;
+    fn accept_trust_registry_transfer_sr25519() -> Weight;
+    fn accept_trust_registry_transfer_ed25519() -> Weight;
+    fn accept_trust_registry_transfer_secp256k1() -> Weight;
+    fn set_fee_payout_account_sr25519() -> Weight;
+    fn set_fee_payout_account_ed25519() -> Weight;
+    fn set_fee_payout_account_secp256k1() -> Weight;
+    fn settle_verification_fee_sr25519() -> Weight;
+    fn settle_verification_fee_ed25519() -> Weight;
+    fn settle_verification_fee_secp256k1() -> Weight;
+    fn set_issuers_accreditation_sr25519(i: u32) -> Weight;
+    fn set_issuers_accreditation_ed25519(i: u32) -> Weight;
+    fn set_issuers_accreditation_secp256k1(i: u32) -> Weight;
+    fn update_recognized_registries_sr25519(r: u32) -> Weight;
+    fn update_recognized_registries_ed25519(r: u32) -> Weight;
+    fn update_recognized_registries_secp256k1(r: u32) -> Weight;
+    fn update_recognition_acceptances_sr25519(r: u32) -> Weight;
+    fn update_recognition_acceptances_ed25519(r: u32) -> Weight;
+    fn update_recognition_acceptances_secp256k1(r: u32) -> Weight;
+    fn request_participation_sr25519(o: u32, l: u32, d: u32) -> Weight;
+    fn request_participation_ed25519(o: u32, l: u32, d: u32) -> Weight;
+    fn request_participation_secp256k1(o: u32, l: u32, d: u32) -> Weight;
+    fn resolve_participation_requests_sr25519(r: u32) -> Weight;
+    fn resolve_participation_requests_ed25519(r: u32) -> Weight;
+    fn resolve_participation_requests_secp256k1(r: u32) -> Weight;
+    fn clear_expired_participation_requests(p: u32) -> Weight;
+    fn add_gov_framework_version_sr25519(u: u32) -> Weight;
+    fn add_gov_framework_version_ed25519(u: u32) -> Weight;
+    fn add_gov_framework_version_secp256k1(u: u32) -> Weight;
+    fn update_delegations_sr25519(d: u32, s: u32, c: u32) -> Weight;
+    fn update_delegations_ed25519(d: u32, s: u32, c: u32) -> Weight;
+    fn update_delegations_secp256k1(d: u32, s: u32, c: u32) -> Weight;
+    fn revoke_delegations_sr25519(r: u32) -> Weight;
+    fn revoke_delegations_ed25519(r: u32) -> Weight;
+    fn revoke_delegations_secp256k1(r: u32) -> Weight;
+    fn add_schema_definition_sr25519(u: u32) -> Weight;
+    fn add_schema_definition_ed25519(u: u32) -> Weight;
+    fn add_schema_definition_secp256k1(u: u32) -> Weight;
 }
 
 /// Weights for trust_registry using the Substrate node and recommended hardware.
@@ -52,21 +125,21 @@ pub struct SubstrateWeight<W>(PhantomData<W>);
 impl<W: Get<RuntimeDbWeight>> WeightInfo for SubstrateWeight<W> {
     fn init_or_update_trust_registry_sr25519(_n: u32, _g: u32) -> Weight {
         Weight::from_ref_time(62_798_000)
-            .saturating_add(W::get().reads(5))
-            .saturating_add(W::get().writes(4))
+            .saturating_add(W::get().reads(7))
+            .saturating_add(W::get().writes(6))
     }
     fn init_or_update_trust_registry_ed25519(_n: u32, g: u32) -> Weight {
         Weight::from_ref_time(60_238_000) // Standard Error: 1_000
             .saturating_add(Weight::from_ref_time(4_000).saturating_mul(g as u64))
-            .saturating_add(W::get().reads(5))
-            .saturating_add(W::get().writes(4))
+            .saturating_add(W::get().reads(7))
+            .saturating_add(W::get().writes(6))
     }
     fn init_or_update_trust_registry_secp256k1(n: u32, g: u32) -> Weight {
         Weight::from_ref_time(154_085_000) // Standard Error: 9_000
             .saturating_add(Weight::from_ref_time(10_000).saturating_mul(n as u64)) // Standard Error: 2_000
             .saturating_add(Weight::from_ref_time(8_000).saturating_mul(g as u64))
-            .saturating_add(W::get().reads(5))
-            .saturating_add(W::get().writes(4))
+            .saturating_add(W::get().reads(7))
+            .saturating_add(W::get().writes(6))
     }
     fn set_schemas_metadata_sr25519(i: u32, v: u32, s: u32) -> Weight {
         Weight::from_ref_time(0) // Standard Error: 99_000
@@ -198,27 +271,417 @@ impl<W: Get<RuntimeDbWeight>> WeightInfo for SubstrateWeight<W> {
             .saturating_add(W::get().reads(7))
             .saturating_add(W::get().writes(3))
     }
+    fn remove_trust_registry_sr25519(
+        i: u32,
+        v: u32,
+        s: u32,
+        p: u32,
+        a: u32,
+        r: u32,
+        q: u32,
+        g: u32,
+        d: u32,
+    ) -> Weight {
+        Weight::from_ref_time(66_412_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(4_982_000).saturating_mul(i as u64)) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(5_317_000).saturating_mul(v as u64)) // Standard Error: 104_000
+            .saturating_add(Weight::from_ref_time(21_406_000).saturating_mul(s as u64)) // Standard Error: 10_000
+            .saturating_add(Weight::from_ref_time(3_118_000).saturating_mul(p as u64)) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(7_046_000).saturating_mul(a as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(6_318_000).saturating_mul(r as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(5_874_000).saturating_mul(q as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_204_000).saturating_mul(g as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(2_341_000).saturating_mul(d as u64))
+            .saturating_add(W::get().reads(6))
+            .saturating_add(W::get().reads((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(W::get().reads((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(a as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(q as u64)))
+            .saturating_add(W::get().writes(9))
+            .saturating_add(W::get().writes((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(p as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(a as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(q as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(g as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(d as u64)))
+    }
+    fn remove_trust_registry_ed25519(
+        i: u32,
+        v: u32,
+        s: u32,
+        p: u32,
+        a: u32,
+        r: u32,
+        q: u32,
+        g: u32,
+        d: u32,
+    ) -> Weight {
+        Weight::from_ref_time(64_073_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(4_871_000).saturating_mul(i as u64)) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(5_295_000).saturating_mul(v as u64)) // Standard Error: 104_000
+            .saturating_add(Weight::from_ref_time(20_947_000).saturating_mul(s as u64)) // Standard Error: 10_000
+            .saturating_add(Weight::from_ref_time(3_094_000).saturating_mul(p as u64)) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(7_012_000).saturating_mul(a as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(6_297_000).saturating_mul(r as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(5_861_000).saturating_mul(q as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_198_000).saturating_mul(g as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(2_329_000).saturating_mul(d as u64))
+            .saturating_add(W::get().reads(6))
+            .saturating_add(W::get().reads((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(W::get().reads((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(a as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(q as u64)))
+            .saturating_add(W::get().writes(9))
+            .saturating_add(W::get().writes((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(p as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(a as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(q as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(g as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(d as u64)))
+    }
+    fn remove_trust_registry_secp256k1(
+        i: u32,
+        v: u32,
+        s: u32,
+        p: u32,
+        a: u32,
+        r: u32,
+        q: u32,
+        g: u32,
+        d: u32,
+    ) -> Weight {
+        Weight::from_ref_time(158_249_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(5_036_000).saturating_mul(i as u64)) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(5_402_000).saturating_mul(v as u64)) // Standard Error: 104_000
+            .saturating_add(Weight::from_ref_time(21_733_000).saturating_mul(s as u64)) // Standard Error: 10_000
+            .saturating_add(Weight::from_ref_time(3_152_000).saturating_mul(p as u64)) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(7_121_000).saturating_mul(a as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(6_402_000).saturating_mul(r as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(5_902_000).saturating_mul(q as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_213_000).saturating_mul(g as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(2_356_000).saturating_mul(d as u64))
+            .saturating_add(W::get().reads(6))
+            .saturating_add(W::get().reads((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(W::get().reads((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(a as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(q as u64)))
+            .saturating_add(W::get().writes(9))
+            .saturating_add(W::get().writes((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(p as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(a as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(q as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(g as u64)))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(d as u64)))
+    }
+    fn transfer_trust_registry_sr25519() -> Weight {
+        Weight::from_ref_time(61_587_000)
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().writes(2))
+    }
+    fn transfer_trust_registry_ed25519() -> Weight {
+        Weight::from_ref_time(60_214_000)
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().writes(2))
+    }
+    fn transfer_trust_registry_secp256k1() -> Weight {
+        Weight::from_ref_time(153_906_000)
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().writes(2))
+    }
+    fn accept_trust_registry_transfer_sr25519() -> Weight {
+        Weight::from_ref_time(61_587_000)
+            .saturating_add(W::get().reads(6))
+            .saturating_add(W::get().writes(5))
+    }
+    fn accept_trust_registry_transfer_ed25519() -> Weight {
+        Weight::from_ref_time(60_214_000)
+            .saturating_add(W::get().reads(6))
+            .saturating_add(W::get().writes(5))
+    }
+    fn accept_trust_registry_transfer_secp256k1() -> Weight {
+        Weight::from_ref_time(153_906_000)
+            .saturating_add(W::get().reads(6))
+            .saturating_add(W::get().writes(5))
+    }
+    fn set_fee_payout_account_sr25519() -> Weight {
+        Weight::from_ref_time(47_310_000)
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().writes(2))
+    }
+    fn set_fee_payout_account_ed25519() -> Weight {
+        Weight::from_ref_time(46_128_000)
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().writes(2))
+    }
+    fn set_fee_payout_account_secp256k1() -> Weight {
+        Weight::from_ref_time(153_402_000)
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().writes(2))
+    }
+    fn settle_verification_fee_sr25519() -> Weight {
+        Weight::from_ref_time(112_845_000)
+            .saturating_add(W::get().reads(10))
+            .saturating_add(W::get().writes(5))
+    }
+    fn settle_verification_fee_ed25519() -> Weight {
+        Weight::from_ref_time(111_236_000)
+            .saturating_add(W::get().reads(10))
+            .saturating_add(W::get().writes(5))
+    }
+    fn settle_verification_fee_secp256k1() -> Weight {
+        Weight::from_ref_time(218_907_000)
+            .saturating_add(W::get().reads(10))
+            .saturating_add(W::get().writes(5))
+    }
+    fn set_issuers_accreditation_sr25519(i: u32) -> Weight {
+        Weight::from_ref_time(58_412_000) // Standard Error: 16_000
+            .saturating_add(Weight::from_ref_time(7_104_000).saturating_mul(i as u64))
+            .saturating_add(W::get().reads(4))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(i as u64)))
+            .saturating_add(W::get().writes(1))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(i as u64)))
+    }
+    fn set_issuers_accreditation_ed25519(i: u32) -> Weight {
+        Weight::from_ref_time(57_306_000) // Standard Error: 16_000
+            .saturating_add(Weight::from_ref_time(7_098_000).saturating_mul(i as u64))
+            .saturating_add(W::get().reads(4))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(i as u64)))
+            .saturating_add(W::get().writes(1))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(i as u64)))
+    }
+    fn set_issuers_accreditation_secp256k1(i: u32) -> Weight {
+        Weight::from_ref_time(165_783_000) // Standard Error: 16_000
+            .saturating_add(Weight::from_ref_time(7_121_000).saturating_mul(i as u64))
+            .saturating_add(W::get().reads(4))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(i as u64)))
+            .saturating_add(W::get().writes(1))
+            .saturating_add(W::get().writes((2 as u64).saturating_mul(i as u64)))
+    }
+    fn update_recognized_registries_sr25519(r: u32) -> Weight {
+        Weight::from_ref_time(55_871_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(3_412_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(4))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes(2))
+    }
+    fn update_recognized_registries_ed25519(r: u32) -> Weight {
+        Weight::from_ref_time(54_960_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(3_398_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(4))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes(2))
+    }
+    fn update_recognized_registries_secp256k1(r: u32) -> Weight {
+        Weight::from_ref_time(162_245_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(3_437_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(4))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes(2))
+    }
+    fn update_recognition_acceptances_sr25519(r: u32) -> Weight {
+        Weight::from_ref_time(53_104_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(5_216_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().reads((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes(1))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(r as u64)))
+    }
+    fn update_recognition_acceptances_ed25519(r: u32) -> Weight {
+        Weight::from_ref_time(52_318_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(5_204_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().reads((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes(1))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(r as u64)))
+    }
+    fn update_recognition_acceptances_secp256k1(r: u32) -> Weight {
+        Weight::from_ref_time(159_672_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(5_230_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().reads((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes(1))
+            .saturating_add(W::get().writes((1 as u64).saturating_mul(r as u64)))
+    }
+    fn request_participation_sr25519(o: u32, l: u32, d: u32) -> Weight {
+        Weight::from_ref_time(58_413_000) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(9_000).saturating_mul(o as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(l as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(12_000).saturating_mul(d as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().writes(3))
+    }
+    fn request_participation_ed25519(o: u32, l: u32, d: u32) -> Weight {
+        Weight::from_ref_time(57_602_000) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(9_000).saturating_mul(o as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(l as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(12_000).saturating_mul(d as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().writes(3))
+    }
+    fn request_participation_secp256k1(o: u32, l: u32, d: u32) -> Weight {
+        Weight::from_ref_time(164_918_000) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(9_000).saturating_mul(o as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(l as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(12_000).saturating_mul(d as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().writes(3))
+    }
+    fn resolve_participation_requests_sr25519(r: u32) -> Weight {
+        Weight::from_ref_time(61_082_000) // Standard Error: 14_000
+            .saturating_add(Weight::from_ref_time(9_318_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(4))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes(2))
+            .saturating_add(W::get().writes((3 as u64).saturating_mul(r as u64)))
+    }
+    fn resolve_participation_requests_ed25519(r: u32) -> Weight {
+        Weight::from_ref_time(60_537_000) // Standard Error: 14_000
+            .saturating_add(Weight::from_ref_time(9_294_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(4))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes(2))
+            .saturating_add(W::get().writes((3 as u64).saturating_mul(r as u64)))
+    }
+    fn resolve_participation_requests_secp256k1(r: u32) -> Weight {
+        Weight::from_ref_time(167_404_000) // Standard Error: 14_000
+            .saturating_add(Weight::from_ref_time(9_337_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(4))
+            .saturating_add(W::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes(2))
+            .saturating_add(W::get().writes((3 as u64).saturating_mul(r as u64)))
+    }
+    fn clear_expired_participation_requests(p: u32) -> Weight {
+        Weight::from_ref_time(9_480_000) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(14_126_000).saturating_mul(p as u64))
+            .saturating_add(W::get().reads((2 as u64).saturating_mul(p as u64)))
+            .saturating_add(W::get().writes((3 as u64).saturating_mul(p as u64)))
+    }
+    fn add_gov_framework_version_sr25519(u: u32) -> Weight {
+        Weight::from_ref_time(56_214_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(3_000).saturating_mul(u as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().writes(3))
+    }
+    fn add_gov_framework_version_ed25519(u: u32) -> Weight {
+        Weight::from_ref_time(55_631_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(3_000).saturating_mul(u as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().writes(3))
+    }
+    fn add_gov_framework_version_secp256k1(u: u32) -> Weight {
+        Weight::from_ref_time(162_957_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(3_000).saturating_mul(u as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().writes(3))
+    }
+    fn update_delegations_sr25519(d: u32, s: u32, c: u32) -> Weight {
+        Weight::from_ref_time(71_806_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(14_372_000).saturating_mul(d as u64)) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(96_000).saturating_mul(s as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_874_000).saturating_mul(c as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().reads((6 as u64).saturating_mul(d as u64)))
+            .saturating_add(W::get().reads((3 as u64).saturating_mul(c as u64)))
+            .saturating_add(W::get().writes(1))
+            .saturating_add(W::get().writes((3 as u64).saturating_mul(d as u64)))
+    }
+    fn update_delegations_ed25519(d: u32, s: u32, c: u32) -> Weight {
+        Weight::from_ref_time(71_204_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(14_372_000).saturating_mul(d as u64)) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(96_000).saturating_mul(s as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_874_000).saturating_mul(c as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().reads((6 as u64).saturating_mul(d as u64)))
+            .saturating_add(W::get().reads((3 as u64).saturating_mul(c as u64)))
+            .saturating_add(W::get().writes(1))
+            .saturating_add(W::get().writes((3 as u64).saturating_mul(d as u64)))
+    }
+    fn update_delegations_secp256k1(d: u32, s: u32, c: u32) -> Weight {
+        Weight::from_ref_time(178_931_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(14_372_000).saturating_mul(d as u64)) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(96_000).saturating_mul(s as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_874_000).saturating_mul(c as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().reads((6 as u64).saturating_mul(d as u64)))
+            .saturating_add(W::get().reads((3 as u64).saturating_mul(c as u64)))
+            .saturating_add(W::get().writes(1))
+            .saturating_add(W::get().writes((3 as u64).saturating_mul(d as u64)))
+    }
+    fn revoke_delegations_sr25519(r: u32) -> Weight {
+        Weight::from_ref_time(58_412_000) // Standard Error: 17_000
+            .saturating_add(Weight::from_ref_time(11_847_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().reads((5 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes((4 as u64).saturating_mul(r as u64)))
+    }
+    fn revoke_delegations_ed25519(r: u32) -> Weight {
+        Weight::from_ref_time(57_938_000) // Standard Error: 17_000
+            .saturating_add(Weight::from_ref_time(11_847_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().reads((5 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes((4 as u64).saturating_mul(r as u64)))
+    }
+    fn revoke_delegations_secp256k1(r: u32) -> Weight {
+        Weight::from_ref_time(165_127_000) // Standard Error: 17_000
+            .saturating_add(Weight::from_ref_time(11_847_000).saturating_mul(r as u64))
+            .saturating_add(W::get().reads(3))
+            .saturating_add(W::get().reads((5 as u64).saturating_mul(r as u64)))
+            .saturating_add(W::get().writes((4 as u64).saturating_mul(r as u64)))
+    }
+    fn add_schema_definition_sr25519(u: u32) -> Weight {
+        Weight::from_ref_time(71_482_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(4_000).saturating_mul(u as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().writes(2))
+    }
+    fn add_schema_definition_ed25519(u: u32) -> Weight {
+        Weight::from_ref_time(70_915_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(4_000).saturating_mul(u as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().writes(2))
+    }
+    fn add_schema_definition_secp256k1(u: u32) -> Weight {
+        Weight::from_ref_time(178_306_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(4_000).saturating_mul(u as u64))
+            .saturating_add(W::get().reads(5))
+            .saturating_add(W::get().writes(2))
+    }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
     fn init_or_update_trust_registry_sr25519(_n: u32, _g: u32) -> Weight {
         Weight::from_ref_time(62_798_000)
-            .saturating_add(RocksDbWeight::get().reads(5))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(6))
     }
     fn init_or_update_trust_registry_ed25519(_n: u32, g: u32) -> Weight {
         Weight::from_ref_time(60_238_000) // Standard Error: 1_000
             .saturating_add(Weight::from_ref_time(4_000).saturating_mul(g as u64))
-            .saturating_add(RocksDbWeight::get().reads(5))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(6))
     }
     fn init_or_update_trust_registry_secp256k1(n: u32, g: u32) -> Weight {
         Weight::from_ref_time(154_085_000) // Standard Error: 9_000
             .saturating_add(Weight::from_ref_time(10_000).saturating_mul(n as u64)) // Standard Error: 2_000
             .saturating_add(Weight::from_ref_time(8_000).saturating_mul(g as u64))
-            .saturating_add(RocksDbWeight::get().reads(5))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(6))
     }
     fn set_schemas_metadata_sr25519(i: u32, v: u32, s: u32) -> Weight {
         Weight::from_ref_time(0) // Standard Error: 99_000
@@ -350,4 +813,394 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(7))
             .saturating_add(RocksDbWeight::get().writes(3))
     }
+    fn remove_trust_registry_sr25519(
+        i: u32,
+        v: u32,
+        s: u32,
+        p: u32,
+        a: u32,
+        r: u32,
+        q: u32,
+        g: u32,
+        d: u32,
+    ) -> Weight {
+        Weight::from_ref_time(66_412_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(4_982_000).saturating_mul(i as u64)) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(5_317_000).saturating_mul(v as u64)) // Standard Error: 104_000
+            .saturating_add(Weight::from_ref_time(21_406_000).saturating_mul(s as u64)) // Standard Error: 10_000
+            .saturating_add(Weight::from_ref_time(3_118_000).saturating_mul(p as u64)) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(7_046_000).saturating_mul(a as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(6_318_000).saturating_mul(r as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(5_874_000).saturating_mul(q as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_204_000).saturating_mul(g as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(2_341_000).saturating_mul(d as u64))
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(a as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(q as u64)))
+            .saturating_add(RocksDbWeight::get().writes(9))
+            .saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(a as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(q as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(g as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+    }
+    fn remove_trust_registry_ed25519(
+        i: u32,
+        v: u32,
+        s: u32,
+        p: u32,
+        a: u32,
+        r: u32,
+        q: u32,
+        g: u32,
+        d: u32,
+    ) -> Weight {
+        Weight::from_ref_time(64_073_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(4_871_000).saturating_mul(i as u64)) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(5_295_000).saturating_mul(v as u64)) // Standard Error: 104_000
+            .saturating_add(Weight::from_ref_time(20_947_000).saturating_mul(s as u64)) // Standard Error: 10_000
+            .saturating_add(Weight::from_ref_time(3_094_000).saturating_mul(p as u64)) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(7_012_000).saturating_mul(a as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(6_297_000).saturating_mul(r as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(5_861_000).saturating_mul(q as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_198_000).saturating_mul(g as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(2_329_000).saturating_mul(d as u64))
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(a as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(q as u64)))
+            .saturating_add(RocksDbWeight::get().writes(9))
+            .saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(a as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(q as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(g as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+    }
+    fn remove_trust_registry_secp256k1(
+        i: u32,
+        v: u32,
+        s: u32,
+        p: u32,
+        a: u32,
+        r: u32,
+        q: u32,
+        g: u32,
+        d: u32,
+    ) -> Weight {
+        Weight::from_ref_time(158_249_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(5_036_000).saturating_mul(i as u64)) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(5_402_000).saturating_mul(v as u64)) // Standard Error: 104_000
+            .saturating_add(Weight::from_ref_time(21_733_000).saturating_mul(s as u64)) // Standard Error: 10_000
+            .saturating_add(Weight::from_ref_time(3_152_000).saturating_mul(p as u64)) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(7_121_000).saturating_mul(a as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(6_402_000).saturating_mul(r as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(5_902_000).saturating_mul(q as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_213_000).saturating_mul(g as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(2_356_000).saturating_mul(d as u64))
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(a as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(q as u64)))
+            .saturating_add(RocksDbWeight::get().writes(9))
+            .saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(i as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(v as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(s as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(a as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(q as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(g as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+    }
+    fn transfer_trust_registry_sr25519() -> Weight {
+        Weight::from_ref_time(61_587_000)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn transfer_trust_registry_ed25519() -> Weight {
+        Weight::from_ref_time(60_214_000)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn transfer_trust_registry_secp256k1() -> Weight {
+        Weight::from_ref_time(153_906_000)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn accept_trust_registry_transfer_sr25519() -> Weight {
+        Weight::from_ref_time(61_587_000)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    fn accept_trust_registry_transfer_ed25519() -> Weight {
+        Weight::from_ref_time(60_214_000)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    fn accept_trust_registry_transfer_secp256k1() -> Weight {
+        Weight::from_ref_time(153_906_000)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    fn set_fee_payout_account_sr25519() -> Weight {
+        Weight::from_ref_time(47_310_000)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn set_fee_payout_account_ed25519() -> Weight {
+        Weight::from_ref_time(46_128_000)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn set_fee_payout_account_secp256k1() -> Weight {
+        Weight::from_ref_time(153_402_000)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn settle_verification_fee_sr25519() -> Weight {
+        Weight::from_ref_time(112_845_000)
+            .saturating_add(RocksDbWeight::get().reads(10))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    fn settle_verification_fee_ed25519() -> Weight {
+        Weight::from_ref_time(111_236_000)
+            .saturating_add(RocksDbWeight::get().reads(10))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    fn settle_verification_fee_secp256k1() -> Weight {
+        Weight::from_ref_time(218_907_000)
+            .saturating_add(RocksDbWeight::get().reads(10))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    fn set_issuers_accreditation_sr25519(i: u32) -> Weight {
+        Weight::from_ref_time(58_412_000) // Standard Error: 16_000
+            .saturating_add(Weight::from_ref_time(7_104_000).saturating_mul(i as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(i as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(i as u64)))
+    }
+    fn set_issuers_accreditation_ed25519(i: u32) -> Weight {
+        Weight::from_ref_time(57_306_000) // Standard Error: 16_000
+            .saturating_add(Weight::from_ref_time(7_098_000).saturating_mul(i as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(i as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(i as u64)))
+    }
+    fn set_issuers_accreditation_secp256k1(i: u32) -> Weight {
+        Weight::from_ref_time(165_783_000) // Standard Error: 16_000
+            .saturating_add(Weight::from_ref_time(7_121_000).saturating_mul(i as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(i as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(i as u64)))
+    }
+    fn update_recognized_registries_sr25519(r: u32) -> Weight {
+        Weight::from_ref_time(55_871_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(3_412_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn update_recognized_registries_ed25519(r: u32) -> Weight {
+        Weight::from_ref_time(54_960_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(3_398_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn update_recognized_registries_secp256k1(r: u32) -> Weight {
+        Weight::from_ref_time(162_245_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(3_437_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn update_recognition_acceptances_sr25519(r: u32) -> Weight {
+        Weight::from_ref_time(53_104_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(5_216_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(r as u64)))
+    }
+    fn update_recognition_acceptances_ed25519(r: u32) -> Weight {
+        Weight::from_ref_time(52_318_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(5_204_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(r as u64)))
+    }
+    fn update_recognition_acceptances_secp256k1(r: u32) -> Weight {
+        Weight::from_ref_time(159_672_000) // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(5_230_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(r as u64)))
+    }
+    fn request_participation_sr25519(o: u32, l: u32, d: u32) -> Weight {
+        Weight::from_ref_time(58_413_000) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(9_000).saturating_mul(o as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(l as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(12_000).saturating_mul(d as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn request_participation_ed25519(o: u32, l: u32, d: u32) -> Weight {
+        Weight::from_ref_time(57_602_000) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(9_000).saturating_mul(o as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(l as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(12_000).saturating_mul(d as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn request_participation_secp256k1(o: u32, l: u32, d: u32) -> Weight {
+        Weight::from_ref_time(164_918_000) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(9_000).saturating_mul(o as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(l as u64)) // Standard Error: 2_000
+            .saturating_add(Weight::from_ref_time(12_000).saturating_mul(d as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn resolve_participation_requests_sr25519(r: u32) -> Weight {
+        Weight::from_ref_time(61_082_000) // Standard Error: 14_000
+            .saturating_add(Weight::from_ref_time(9_318_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
+    }
+    fn resolve_participation_requests_ed25519(r: u32) -> Weight {
+        Weight::from_ref_time(60_537_000) // Standard Error: 14_000
+            .saturating_add(Weight::from_ref_time(9_294_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
+    }
+    fn resolve_participation_requests_secp256k1(r: u32) -> Weight {
+        Weight::from_ref_time(167_404_000) // Standard Error: 14_000
+            .saturating_add(Weight::from_ref_time(9_337_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
+    }
+    fn clear_expired_participation_requests(p: u32) -> Weight {
+        Weight::from_ref_time(9_480_000) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(14_126_000).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(p as u64)))
+            .saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(p as u64)))
+    }
+    fn add_gov_framework_version_sr25519(u: u32) -> Weight {
+        Weight::from_ref_time(56_214_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(3_000).saturating_mul(u as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn add_gov_framework_version_ed25519(u: u32) -> Weight {
+        Weight::from_ref_time(55_631_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(3_000).saturating_mul(u as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn add_gov_framework_version_secp256k1(u: u32) -> Weight {
+        Weight::from_ref_time(162_957_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(3_000).saturating_mul(u as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn update_delegations_sr25519(d: u32, s: u32, c: u32) -> Weight {
+        Weight::from_ref_time(71_806_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(14_372_000).saturating_mul(d as u64)) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(96_000).saturating_mul(s as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_874_000).saturating_mul(c as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().reads((6 as u64).saturating_mul(d as u64)))
+            .saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(c as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(d as u64)))
+    }
+    fn update_delegations_ed25519(d: u32, s: u32, c: u32) -> Weight {
+        Weight::from_ref_time(71_204_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(14_372_000).saturating_mul(d as u64)) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(96_000).saturating_mul(s as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_874_000).saturating_mul(c as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().reads((6 as u64).saturating_mul(d as u64)))
+            .saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(c as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(d as u64)))
+    }
+    fn update_delegations_secp256k1(d: u32, s: u32, c: u32) -> Weight {
+        Weight::from_ref_time(178_931_000) // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(14_372_000).saturating_mul(d as u64)) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(96_000).saturating_mul(s as u64)) // Standard Error: 9_000
+            .saturating_add(Weight::from_ref_time(1_874_000).saturating_mul(c as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().reads((6 as u64).saturating_mul(d as u64)))
+            .saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(c as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(d as u64)))
+    }
+    fn revoke_delegations_sr25519(r: u32) -> Weight {
+        Weight::from_ref_time(58_412_000) // Standard Error: 17_000
+            .saturating_add(Weight::from_ref_time(11_847_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads((5 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(r as u64)))
+    }
+    fn revoke_delegations_ed25519(r: u32) -> Weight {
+        Weight::from_ref_time(57_938_000) // Standard Error: 17_000
+            .saturating_add(Weight::from_ref_time(11_847_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads((5 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(r as u64)))
+    }
+    fn revoke_delegations_secp256k1(r: u32) -> Weight {
+        Weight::from_ref_time(165_127_000) // Standard Error: 17_000
+            .saturating_add(Weight::from_ref_time(11_847_000).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads((5 as u64).saturating_mul(r as u64)))
+            .saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(r as u64)))
+    }
+    fn add_schema_definition_sr25519(u: u32) -> Weight {
+        Weight::from_ref_time(71_482_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(4_000).saturating_mul(u as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn add_schema_definition_ed25519(u: u32) -> Weight {
+        Weight::from_ref_time(70_915_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(4_000).saturating_mul(u as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn add_schema_definition_secp256k1(u: u32) -> Weight {
+        Weight::from_ref_time(178_306_000) // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(4_000).saturating_mul(u as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
 }
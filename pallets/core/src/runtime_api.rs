@@ -1,12 +1,14 @@
 use crate::{
     accumulator, common::TypesAndLimits, did, offchain_signatures, trust_registry::*, util::IncId,
 };
+use sp_runtime::traits::Block as BlockT;
 use sp_std::{
     collections::{btree_map::BTreeMap, btree_set::BTreeSet},
     vec::Vec,
 };
 
 sp_api::decl_runtime_apis! {
+    #[api_version(2)]
     pub trait CoreModsApi<T: TypesAndLimits> {
         fn did_details(id: did::Did, params: Option<did::AggregatedDidDetailsRequestParams>) -> Option<did::AggregatedDidDetailsResponse<T>>;
 
@@ -32,8 +34,11 @@ sp_api::decl_runtime_apis! {
 
         fn accumulator_public_key_with_params(id: accumulator::AccumPublicKeyStorageKey) -> Option<accumulator::AccumPublicKeyWithParams<T>>;
 
+        #[changed_in(2)]
         fn accumulator_with_public_key_and_params(id: accumulator::AccumulatorId) -> Option<(Vec<u8>, Option<accumulator::AccumPublicKeyWithParams<T>>)>;
 
+        fn accumulator_with_public_key_and_params(id: accumulator::AccumulatorId, version: Option<accumulator::AccumulatorVersion<T>>) -> Option<(Vec<u8>, Option<accumulator::AccumPublicKeyWithParams<T>>, Option<u64>)>;
+
         fn schema_metadata(
             id: TrustRegistrySchemaId
         ) -> BTreeMap<TrustRegistryId, AggregatedTrustRegistrySchemaMetadata<T>>;
@@ -96,5 +101,81 @@ sp_api::decl_runtime_apis! {
         fn bbdt16_params_by_did(owner: offchain_signatures::SignatureParamsOwner) -> BTreeMap<IncId, offchain_signatures::BBDT16Parameters<T>>;
 
         fn bbdt16_public_keys_by_did(did: crate::did::Did) -> BTreeMap<IncId, offchain_signatures::BBDT16PublicKeyWithParams<T>>;
+
+        fn accumulator_with_update_info(id: accumulator::AccumulatorId) -> Option<accumulator::AccumulatorWithUpdateInfo<T>>;
+
+        fn accumulator_updates_in_block(id: accumulator::AccumulatorId, extrinsics: Vec<<Block as BlockT>::Extrinsic>) -> Vec<accumulator::AccumulatorUpdateDetails>;
+
+        fn accumulator_update_blocks(id: accumulator::AccumulatorId, after: Option<u64>, limit: u32) -> Vec<(u64, T::BlockNumber)>;
+
+        fn accumulators_by_owner(owner: accumulator::AccumulatorOwner, after: Option<accumulator::AccumulatorId>, limit: u32) -> Vec<(accumulator::AccumulatorId, accumulator::AccumulatorWithUpdateInfo<T>)>;
+
+        fn bbs_ietf_public_key(id: offchain_signatures::SignaturePublicKeyStorageKey) -> Option<offchain_signatures::BBSIetfPublicKey<T>>;
+
+        fn bbs_ietf_public_keys_by_did(did: crate::did::Did) -> BTreeMap<IncId, offchain_signatures::BBSIetfPublicKey<T>>;
+
+        fn ecdsa_sd_public_key(id: offchain_signatures::SignaturePublicKeyStorageKey) -> Option<offchain_signatures::EcdsaSdPublicKey<T>>;
+
+        fn ecdsa_sd_public_keys_by_did(did: crate::did::Did) -> BTreeMap<IncId, offchain_signatures::EcdsaSdPublicKey<T>>;
+
+        fn ed25519_sd_public_key(id: offchain_signatures::SignaturePublicKeyStorageKey) -> Option<offchain_signatures::Ed25519SdPublicKey<T>>;
+
+        fn ed25519_sd_public_keys_by_did(did: crate::did::Did) -> BTreeMap<IncId, offchain_signatures::Ed25519SdPublicKey<T>>;
+
+        fn offchain_public_key_with_params(id: offchain_signatures::SignaturePublicKeyStorageKey) -> Option<offchain_signatures::OffchainPublicKeyWithParams<T>>;
+
+        fn offchain_public_keys_by_did(did: crate::did::Did, scheme: Option<offchain_signatures::OffchainSignatureScheme>, curve_type: Option<crate::common::CurveType>) -> BTreeMap<IncId, offchain_signatures::OffchainPublicKeyWithParams<T>>;
+
+        fn offchain_params_by_owner(owner: offchain_signatures::SignatureParamsOwner, scheme: Option<offchain_signatures::OffchainSignatureScheme>, curve_type: Option<crate::common::CurveType>) -> BTreeMap<IncId, offchain_signatures::OffchainSignatureParams<T>>;
+
+        fn threshold_issuer_group(id: offchain_signatures::ThresholdIssuerGroupStorageKey) -> Option<offchain_signatures::ThresholdIssuerGroup<T>>;
+
+        fn threshold_issuer_groups_by_did(did: crate::did::Did) -> BTreeMap<IncId, offchain_signatures::ThresholdIssuerGroup<T>>;
+
+        fn offchain_public_key_revoked_at(id: offchain_signatures::SignaturePublicKeyStorageKey) -> Option<T::BlockNumber>;
+
+        fn shared_offchain_params(scheme: Option<offchain_signatures::OffchainSignatureScheme>, curve_type: Option<crate::common::CurveType>, after: Option<offchain_signatures::SignatureParamsStorageKey>, limit: u32) -> Vec<(offchain_signatures::SignatureParamsStorageKey, offchain_signatures::OffchainSignatureParams<T>)>;
+
+        fn offchain_params_references(params_ref: offchain_signatures::SignatureParamsStorageKey) -> u32;
+
+        fn offchain_dangling_params_references(did: crate::did::Did) -> BTreeMap<IncId, offchain_signatures::SignatureParamsStorageKey>;
+
+        fn accumulator_params_references(params_ref: accumulator::AccumParametersStorageKey) -> u32;
+
+        fn accumulator_dangling_params_references(owner: accumulator::AccumulatorOwner) -> BTreeMap<IncId, accumulator::AccumParametersStorageKey>;
+
+        fn verification_fee(registry_id: TrustRegistryId, schema_id: TrustRegistrySchemaId, issuer: Issuer) -> Option<VerificationFee>;
+
+        fn recognized_registries(registry_id: TrustRegistryId) -> BTreeSet<TrustRegistryId>;
+
+        fn issuer_authorization_path(registry_id: TrustRegistryId, schema_id: TrustRegistrySchemaId, issuer: Issuer) -> Option<Vec<TrustRegistryId>>;
+
+        fn participation_requests(registry_id: TrustRegistryId, after: Option<IssuerOrVerifier>, limit: u32) -> Vec<(IssuerOrVerifier, ParticipationRequest<T>)>;
+
+        fn registries_info_by_paginated(by: QueryTrustRegistriesBy, after: Option<TrustRegistryId>, limit: u32) -> Vec<(TrustRegistryId, TrustRegistryInfo<T>)>;
+
+        fn all_registry_schema_metadata_paginated(registry_id: TrustRegistryId, filter: SchemaMetadataFilter, after: Option<TrustRegistrySchemaId>, limit: u32) -> Vec<(TrustRegistrySchemaId, AggregatedTrustRegistrySchemaMetadata<T>)>;
+
+        fn all_registry_schema_issuers_paginated(registry_id: TrustRegistryId, after: Option<TrustRegistrySchemaId>, limit: u32) -> Vec<(TrustRegistrySchemaId, AggregatedTrustRegistrySchemaIssuers<T>)>;
+
+        fn all_registry_schema_verifiers_paginated(registry_id: TrustRegistryId, after: Option<TrustRegistrySchemaId>, limit: u32) -> Vec<(TrustRegistrySchemaId, TrustRegistrySchemaVerifiers<T>)>;
+
+        fn schema_metadata_paginated(id: TrustRegistrySchemaId, after: Option<TrustRegistryId>, limit: u32) -> Vec<(TrustRegistryId, AggregatedTrustRegistrySchemaMetadata<T>)>;
+
+        fn schema_issuers_paginated(id: TrustRegistrySchemaId, after: Option<TrustRegistryId>, limit: u32) -> Vec<(TrustRegistryId, AggregatedTrustRegistrySchemaIssuers<T>)>;
+
+        fn schema_verifiers_paginated(id: TrustRegistrySchemaId, after: Option<TrustRegistryId>, limit: u32) -> Vec<(TrustRegistryId, TrustRegistrySchemaVerifiers<T>)>;
+
+        fn registries_ids_by_paginated(by: QueryTrustRegistriesBy, after: Option<TrustRegistryId>, limit: u32) -> Vec<TrustRegistryId>;
+
+        fn trqp_authorization(query: TrqpAuthorizationQuery) -> Vec<TrqpAuthorization<T>>;
+
+        fn gov_framework_versions(registry_id: TrustRegistryId) -> Vec<GovFrameworkVersion<T>>;
+
+        fn gov_framework_version_at(registry_id: TrustRegistryId, block_number: T::BlockNumber) -> Option<GovFrameworkVersion<T>>;
+
+        fn issuer_delegations(registry_id: TrustRegistryId, issuer: Issuer) -> BTreeMap<Issuer, DelegationScope<T>>;
+
+        fn schema_definition(id: TrustRegistrySchemaId) -> Option<AggregatedTrustRegistrySchemaDefinition<T>>;
     }
 }
@@ -69,6 +69,7 @@ pub enum TestEvent {
     Accum(accumulator::Event),
     StatusListCredential(status_list_credential::Event),
     TrustRegistry(trust_registry::Event),
+    Balances(pallet_balances::Event<Test>),
 }
 
 impl From<frame_system::Event<Test>> for TestEvent {
@@ -78,8 +79,8 @@ impl From<frame_system::Event<Test>> for TestEvent {
 }
 
 impl From<pallet_balances::Event<Test>> for TestEvent {
-    fn from(_: pallet_balances::Event<Test>) -> Self {
-        unimplemented!()
+    fn from(other: pallet_balances::Event<Test>) -> Self {
+        Self::Balances(other)
     }
 }
 
@@ -143,9 +144,37 @@ impl From<accumulator::Event> for TestEvent {
     }
 }
 
+impl TryFrom<TestEvent> for accumulator::Event {
+    type Error = ();
+
+    fn try_from(event: TestEvent) -> Result<Self, Self::Error> {
+        match event {
+            TestEvent::Accum(event) => Ok(event),
+            _ => Err(()),
+        }
+    }
+}
+
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const ByteReadWeight: Weight = Weight::from_ref_time(10);
+    pub storage ValidateStatusListCredentialContent: bool = false;
+    pub storage VerifyAccumulatorUpdates: bool = false;
+    pub storage ValidateOffchainKeysAndParams: bool = false;
+    pub const MaxAccumulatorSnapshots: u32 = 3;
+    pub const MaxAccumulatorUpdateBlocks: u32 = 6;
+    pub const MaxAccumulatorKeyReplacements: u32 = 2;
+    pub const MaxAccumulatorQueryPageSize: u32 = 5;
+    pub const MaxOffchainParamsQueryPageSize: u32 = 3;
+    pub const NativeCurrencySymbol: &'static str = "DOCK";
+    pub const ConvenerFeeShare: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(10);
+    pub const MaxRecognitionDepth: u32 = 2;
+    pub const MaxDelegationDepth: u32 = 3;
+    pub const ParticipationRequestTtl: u64 = 10;
+    pub const ParticipationRequestDeposit: u64 = 100;
+    pub const MaxParticipationRequestsPerRegistry: u32 = 4;
+    pub const MaxGovFrameworkVersions: u32 = 10;
+    pub const MaxTrustRegistryQueryPageSize: u32 = 10;
 }
 
 pub struct BaseFilter;
@@ -263,6 +292,10 @@ impl crate::common::Limits for Test {
     type MaxBBSPlusPublicKeySize = ConstU32<128>;
     type MaxPSPublicKeySize = ConstU32<128>;
     type MaxBBDT16PublicKeySize = ConstU32<128>;
+    type MaxBBSIetfPublicKeySize = ConstU32<128>;
+    type MaxEcdsaSdPublicKeySize = ConstU32<128>;
+    type MaxEd25519SdPublicKeySize = ConstU32<128>;
+    type MaxThresholdIssuerParticipants = ConstU32<10>;
 
     type MaxMasterMembers = ConstU32<100>;
     type MaxPolicyControllers = ConstU32<15>;
@@ -284,6 +317,7 @@ impl crate::common::Limits for Test {
     type MaxRegistryParticipantOrgNameSize = ConstU32<100>;
     type MaxRegistryParticipantLogoSize = ConstU32<500>;
     type MaxRegistryParticipantDescriptionSize = ConstU32<500>;
+    type MaxRecognizedRegistries = ConstU32<5>;
 }
 
 impl crate::did::Config for Test {
@@ -296,9 +330,20 @@ impl crate::revoke::Config for Test {
 }
 impl crate::status_list_credential::Config for Test {
     type Event = TestEvent;
+    type ValidateContent = ValidateStatusListCredentialContent;
 }
 impl crate::trust_registry::Config for Test {
     type Event = TestEvent;
+    type Currency = Balances;
+    type NativeCurrencySymbol = NativeCurrencySymbol;
+    type ConvenerFeeShare = ConvenerFeeShare;
+    type MaxRecognitionDepth = MaxRecognitionDepth;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type ParticipationRequestTtl = ParticipationRequestTtl;
+    type ParticipationRequestDeposit = ParticipationRequestDeposit;
+    type MaxParticipationRequestsPerRegistry = MaxParticipationRequestsPerRegistry;
+    type MaxGovFrameworkVersions = MaxGovFrameworkVersions;
+    type MaxQueryPageSize = MaxTrustRegistryQueryPageSize;
 }
 impl crate::blob::Config for Test {}
 impl crate::attest::Config for Test {}
@@ -314,10 +359,17 @@ impl crate::master::Config for Test {
 
 impl offchain_signatures::Config for Test {
     type Event = TestEvent;
+    type ValidateKeysAndParams = ValidateOffchainKeysAndParams;
+    type MaxQueryPageSize = MaxOffchainParamsQueryPageSize;
 }
 
 impl accumulator::Config for Test {
     type Event = TestEvent;
+    type VerifyUpdates = VerifyAccumulatorUpdates;
+    type MaxAccumulatorSnapshots = MaxAccumulatorSnapshots;
+    type MaxAccumulatorUpdateBlocks = MaxAccumulatorUpdateBlocks;
+    type MaxAccumulatorKeyReplacements = MaxAccumulatorKeyReplacements;
+    type MaxQueryPageSize = MaxAccumulatorQueryPageSize;
 }
 
 pub const ABBA: u64 = 0;
@@ -504,6 +556,7 @@ pub fn run_to_block(n: u64) {
         }
         System::set_block_number(System::block_number() + 1);
         System::on_initialize(System::block_number());
+        TrustRegistryMod::on_initialize(System::block_number());
     }
 }
 
@@ -13,6 +13,8 @@ pub mod option_ext;
 pub mod serde_hex;
 pub mod set;
 pub mod signature;
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub mod test_utils;
 pub mod types;
 pub mod with_nonce;
 
@@ -31,5 +33,7 @@ pub use option_ext::*;
 pub use serde_hex::*;
 pub use set::*;
 pub use signature::*;
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub use test_utils::*;
 pub use types::*;
 pub use with_nonce::*;
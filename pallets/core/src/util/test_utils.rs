@@ -0,0 +1,10 @@
+use ark_serialize::CanonicalSerialize;
+use sp_std::vec::Vec;
+
+/// Serializes the supplied value in the compressed form.
+pub fn compressed(value: impl CanonicalSerialize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    value.serialize_compressed(&mut bytes).unwrap();
+
+    bytes
+}
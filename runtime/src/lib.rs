@@ -200,9 +200,9 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("dock-pos-dev-runtime"),
     impl_name: create_runtime_str!("Dock"),
     authoring_version: 1,
-    spec_version: 69,
+    spec_version: 70,
     impl_version: 2,
-    transaction_version: 2,
+    transaction_version: 3,
     apis: RUNTIME_API_VERSIONS,
 };
 
@@ -862,8 +862,38 @@ impl did::Config for Runtime {
     type OnDidRemoval = OffchainSignatures;
 }
 
+parameter_types! {
+    pub const NativeCurrencySymbol: &'static str = "DOCK";
+    /// Conveners receive 10% of each verification fee
+    pub const ConvenerFeeShare: Perbill = Perbill::from_percent(10);
+    /// Recognized registries are followed up to 3 hops while resolving `Issuer`s authorization
+    pub const MaxRecognitionDepth: u32 = 3;
+    /// Issuer delegation chains can contain up to 3 delegations
+    pub const MaxDelegationDepth: u32 = 3;
+    /// Participation requests not resolved by the `Convener` within a week expire
+    pub const ParticipationRequestTtl: BlockNumber = WEEK;
+    /// Each pending participation request reserves 10 DOCK from the account submitting it
+    pub const ParticipationRequestDeposit: Balance = 10 * DOCK;
+    /// Up to 100 participation requests can be pending per registry
+    pub const MaxParticipationRequestsPerRegistry: u32 = 100;
+    /// Up to 100 governance framework versions are kept per registry
+    pub const MaxGovFrameworkVersions: u32 = 100;
+    /// Paginated trust registry queries return up to 100 entries per page
+    pub const MaxTrustRegistryQueryPageSize: u32 = 100;
+}
+
 impl trust_registry::Config for Runtime {
     type Event = Event;
+    type Currency = Balances;
+    type NativeCurrencySymbol = NativeCurrencySymbol;
+    type ConvenerFeeShare = ConvenerFeeShare;
+    type MaxRecognitionDepth = MaxRecognitionDepth;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type ParticipationRequestTtl = ParticipationRequestTtl;
+    type ParticipationRequestDeposit = ParticipationRequestDeposit;
+    type MaxParticipationRequestsPerRegistry = MaxParticipationRequestsPerRegistry;
+    type MaxGovFrameworkVersions = MaxGovFrameworkVersions;
+    type MaxQueryPageSize = MaxTrustRegistryQueryPageSize;
 }
 
 impl revoke::Config for Runtime {
@@ -885,6 +915,10 @@ impl common::Limits for Runtime {
     type MaxBBSPublicKeySize = ConstU32<256>;
     type MaxBBSPlusPublicKeySize = ConstU32<256>;
     type MaxBBDT16PublicKeySize = ConstU32<256>;
+    type MaxBBSIetfPublicKeySize = ConstU32<128>;
+    type MaxEcdsaSdPublicKeySize = ConstU32<128>;
+    type MaxEd25519SdPublicKeySize = ConstU32<64>;
+    type MaxThresholdIssuerParticipants = ConstU32<64>;
 
     /// 128 bytes, for large labels, hash of a label can be used
     type MaxOffchainParamsLabelSize = ConstU32<128>;
@@ -922,18 +956,28 @@ impl common::Limits for Runtime {
     type MaxRegistryParticipantOrgNameSize = ConstU32<100>;
     type MaxRegistryParticipantLogoSize = ConstU32<250>;
     type MaxRegistryParticipantDescriptionSize = ConstU32<500>;
+    type MaxRecognizedRegistries = ConstU32<50>;
 }
 
 impl status_list_credential::Config for Runtime {
     type Event = Event;
+    type ValidateContent = ConstBool<false>;
 }
 
 impl offchain_signatures::Config for Runtime {
     type Event = Event;
+    type ValidateKeysAndParams = ConstBool<false>;
+    type MaxQueryPageSize = ConstU32<100>;
 }
 
 impl accumulator::Config for Runtime {
     type Event = Event;
+    // Accumulator updates aren't verified on-chain in this runtime, so the update verification is never executed.
+    type VerifyUpdates = ConstBool<false>;
+    type MaxAccumulatorSnapshots = ConstU32<64>;
+    type MaxAccumulatorUpdateBlocks = ConstU32<256>;
+    type MaxAccumulatorKeyReplacements = ConstU32<64>;
+    type MaxQueryPageSize = ConstU32<100>;
 }
 
 impl blob::Config for Runtime {}
@@ -1897,7 +1941,14 @@ type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
-    ChangeValidatorsConfiguration,
+    (
+        ChangeValidatorsConfiguration,
+        accumulator::AddMemberCount<Runtime>,
+        accumulator::IndexAccumulatorsByOwner<Runtime>,
+        offchain_signatures::CountParamsReferences<Runtime>,
+        accumulator::CountAccumulatorParamsReferences<Runtime>,
+        trust_registry::SnapshotLegacySchemaIds<Runtime>,
+    ),
 >;
 
 /// The address format for describing accounts.
@@ -2458,20 +2509,20 @@ impl_runtime_apis! {
             Accumulator::public_key_with_params(&id)
         }
 
-        fn accumulator_with_public_key_and_params(id: accumulator::AccumulatorId) -> Option<(Vec<u8>, Option<accumulator::AccumPublicKeyWithParams<Runtime>>)> {
-            Accumulator::get_accumulator_with_public_key_and_params(&id)
+        fn accumulator_with_public_key_and_params(id: accumulator::AccumulatorId, version: Option<accumulator::AccumulatorVersion<Runtime>>) -> Option<(Vec<u8>, Option<accumulator::AccumPublicKeyWithParams<Runtime>>, Option<u64>)> {
+            Accumulator::get_accumulator_with_public_key_and_params(&id, version)
         }
 
         fn schema_metadata(
             id: trust_registry::TrustRegistrySchemaId
         ) -> BTreeMap<trust_registry::TrustRegistryId, trust_registry::AggregatedTrustRegistrySchemaMetadata<Runtime>> {
-            TrustRegistry::schema_metadata_by_schema_id(id).map(|(registry_id, schema_metadata)| (registry_id, schema_metadata.aggregate(registry_id))).collect()
+            TrustRegistry::schema_metadata_by_schema_id(id).map(|(registry_id, schema_metadata)| (registry_id, schema_metadata.aggregate(registry_id, id))).collect()
         }
 
         fn schema_issuers(
             id: trust_registry::TrustRegistrySchemaId
         ) -> BTreeMap<trust_registry::TrustRegistryId, trust_registry::AggregatedTrustRegistrySchemaIssuers<Runtime>> {
-            TrustRegistry::schema_metadata_by_schema_id(id).map(|(registry_id, schema_metadata)| (registry_id, schema_metadata.aggregate(registry_id).issuers)).collect()
+            TrustRegistry::schema_metadata_by_schema_id(id).map(|(registry_id, schema_metadata)| (registry_id, schema_metadata.aggregate(registry_id, id).issuers)).collect()
         }
 
         fn schema_verifiers(
@@ -2484,14 +2535,14 @@ impl_runtime_apis! {
             id: trust_registry::TrustRegistrySchemaId,
             registry_id: trust_registry::TrustRegistryId
         ) -> Option<trust_registry::AggregatedTrustRegistrySchemaMetadata<Runtime>> {
-            TrustRegistry::schema_metadata(id, registry_id).map(|schema_metadata| schema_metadata.aggregate(registry_id))
+            TrustRegistry::schema_metadata(id, registry_id).map(|schema_metadata| schema_metadata.aggregate(registry_id, id))
         }
 
         fn schema_issuers_in_registry(
             id: trust_registry::TrustRegistrySchemaId,
             registry_id: trust_registry::TrustRegistryId
         ) -> Option<trust_registry::AggregatedTrustRegistrySchemaIssuers<Runtime>> {
-            TrustRegistry::schema_metadata(id, registry_id).map(|schema_metadata| schema_metadata.aggregate(registry_id).issuers)
+            TrustRegistry::schema_metadata(id, registry_id).map(|schema_metadata| schema_metadata.aggregate(registry_id, id).issuers)
         }
 
         fn schema_verifiers_in_registry(
@@ -2504,13 +2555,13 @@ impl_runtime_apis! {
         fn all_registry_schema_metadata(
             registry_id: trust_registry::TrustRegistryId
         ) -> BTreeMap<trust_registry::TrustRegistrySchemaId, trust_registry::AggregatedTrustRegistrySchemaMetadata<Runtime>> {
-            TrustRegistry::schema_metadata_by_registry_id(registry_id).map(|(schema_id, schema_metadata)| (schema_id, schema_metadata.aggregate(registry_id))).collect()
+            TrustRegistry::schema_metadata_by_registry_id(registry_id).map(|(schema_id, schema_metadata)| (schema_id, schema_metadata.aggregate(registry_id, schema_id))).collect()
         }
 
         fn all_registry_schema_issuers(
             registry_id: trust_registry::TrustRegistryId
         ) -> BTreeMap<trust_registry::TrustRegistrySchemaId, trust_registry::AggregatedTrustRegistrySchemaIssuers<Runtime>> {
-            TrustRegistry::schema_metadata_by_registry_id(registry_id).map(|(schema_id, schema_metadata)| (schema_id, schema_metadata.aggregate(registry_id).issuers)).collect()
+            TrustRegistry::schema_metadata_by_registry_id(registry_id).map(|(schema_id, schema_metadata)| (schema_id, schema_metadata.aggregate(registry_id, schema_id).issuers)).collect()
         }
 
         fn all_registry_schema_verifiers(
@@ -2561,6 +2612,238 @@ impl_runtime_apis! {
                 .filter_map(checked_convert_indexed_item)
                 .collect()
         }
+
+        fn accumulator_with_update_info(id: accumulator::AccumulatorId) -> Option<accumulator::AccumulatorWithUpdateInfo<Runtime>> {
+            Accumulator::get_accumulator_with_update_info(&id)
+        }
+
+        fn accumulator_updates_in_block(id: accumulator::AccumulatorId, extrinsics: Vec<<Block as BlockT>::Extrinsic>) -> Vec<accumulator::AccumulatorUpdateDetails> {
+            let calls = extrinsics.iter().enumerate().flat_map(|(idx, extrinsic)| {
+                batched_calls(&extrinsic.0.function).into_iter().map(move |call| (idx as u32, call))
+            });
+
+            Accumulator::updates_in_current_block(&id, calls)
+        }
+
+        fn accumulator_update_blocks(id: accumulator::AccumulatorId, after: Option<u64>, limit: u32) -> Vec<(u64, BlockNumber)> {
+            Accumulator::accumulator_update_blocks(id, after, limit)
+        }
+
+        fn accumulators_by_owner(owner: accumulator::AccumulatorOwner, after: Option<accumulator::AccumulatorId>, limit: u32) -> Vec<(accumulator::AccumulatorId, accumulator::AccumulatorWithUpdateInfo<Runtime>)> {
+            Accumulator::accumulators_by_owner(owner, after, limit)
+        }
+
+        fn bbs_ietf_public_key((did, key_id): offchain_signatures::SignaturePublicKeyStorageKey) -> Option<offchain_signatures::BBSIetfPublicKey<Runtime>> {
+            OffchainSignatures::did_public_key(did, key_id)
+                .and_then(CheckedConversion::checked_into)
+        }
+
+        fn bbs_ietf_public_keys_by_did(did: did::Did) -> BTreeMap<IncId, offchain_signatures::BBSIetfPublicKey<Runtime>> {
+            OffchainSignatures::did_public_keys(&did)
+                .filter_map(checked_convert_indexed_item)
+                .collect()
+        }
+
+        fn ecdsa_sd_public_key((did, key_id): offchain_signatures::SignaturePublicKeyStorageKey) -> Option<offchain_signatures::EcdsaSdPublicKey<Runtime>> {
+            OffchainSignatures::did_public_key(did, key_id)
+                .and_then(CheckedConversion::checked_into)
+        }
+
+        fn ecdsa_sd_public_keys_by_did(did: did::Did) -> BTreeMap<IncId, offchain_signatures::EcdsaSdPublicKey<Runtime>> {
+            OffchainSignatures::did_public_keys(&did)
+                .filter_map(checked_convert_indexed_item)
+                .collect()
+        }
+
+        fn ed25519_sd_public_key((did, key_id): offchain_signatures::SignaturePublicKeyStorageKey) -> Option<offchain_signatures::Ed25519SdPublicKey<Runtime>> {
+            OffchainSignatures::did_public_key(did, key_id)
+                .and_then(CheckedConversion::checked_into)
+        }
+
+        fn ed25519_sd_public_keys_by_did(did: did::Did) -> BTreeMap<IncId, offchain_signatures::Ed25519SdPublicKey<Runtime>> {
+            OffchainSignatures::did_public_keys(&did)
+                .filter_map(checked_convert_indexed_item)
+                .collect()
+        }
+
+        fn offchain_public_key_with_params(id: offchain_signatures::SignaturePublicKeyStorageKey) -> Option<offchain_signatures::OffchainPublicKeyWithParams<Runtime>> {
+            OffchainSignatures::public_key_with_params(id)
+        }
+
+        fn offchain_public_keys_by_did(did: did::Did, scheme: Option<offchain_signatures::OffchainSignatureScheme>, curve_type: Option<common::CurveType>) -> BTreeMap<IncId, offchain_signatures::OffchainPublicKeyWithParams<Runtime>> {
+            OffchainSignatures::did_public_keys_with_params(&did, scheme, curve_type).collect()
+        }
+
+        fn offchain_params_by_owner(owner: offchain_signatures::SignatureParamsOwner, scheme: Option<offchain_signatures::OffchainSignatureScheme>, curve_type: Option<common::CurveType>) -> BTreeMap<IncId, offchain_signatures::OffchainSignatureParams<Runtime>> {
+            OffchainSignatures::did_params_filtered(&owner, scheme, curve_type).collect()
+        }
+
+        fn threshold_issuer_group((did, group_id): offchain_signatures::ThresholdIssuerGroupStorageKey) -> Option<offchain_signatures::ThresholdIssuerGroup<Runtime>> {
+            OffchainSignatures::threshold_issuer_group(did, group_id)
+        }
+
+        fn threshold_issuer_groups_by_did(did: did::Did) -> BTreeMap<IncId, offchain_signatures::ThresholdIssuerGroup<Runtime>> {
+            OffchainSignatures::did_threshold_issuer_groups(&did).collect()
+        }
+
+        fn offchain_public_key_revoked_at(id: offchain_signatures::SignaturePublicKeyStorageKey) -> Option<BlockNumber> {
+            OffchainSignatures::public_key_revoked_at(id)
+        }
+
+        fn shared_offchain_params(scheme: Option<offchain_signatures::OffchainSignatureScheme>, curve_type: Option<common::CurveType>, after: Option<offchain_signatures::SignatureParamsStorageKey>, limit: u32) -> Vec<(offchain_signatures::SignatureParamsStorageKey, offchain_signatures::OffchainSignatureParams<Runtime>)> {
+            OffchainSignatures::shared_params(scheme, curve_type, after, limit)
+        }
+
+        fn offchain_params_references(params_ref: offchain_signatures::SignatureParamsStorageKey) -> u32 {
+            OffchainSignatures::params_references(params_ref)
+        }
+
+        fn offchain_dangling_params_references(did: did::Did) -> BTreeMap<IncId, offchain_signatures::SignatureParamsStorageKey> {
+            OffchainSignatures::dangling_params_references(&did).collect()
+        }
+
+        fn accumulator_params_references(accumulator::AccumParametersStorageKey(owner, params_id): accumulator::AccumParametersStorageKey) -> u32 {
+            Accumulator::accumulator_params_references(owner, params_id)
+        }
+
+        fn accumulator_dangling_params_references(owner: accumulator::AccumulatorOwner) -> BTreeMap<IncId, accumulator::AccumParametersStorageKey> {
+            Accumulator::dangling_params_references(&owner).collect()
+        }
+
+        fn verification_fee(
+            registry_id: trust_registry::TrustRegistryId,
+            schema_id: trust_registry::TrustRegistrySchemaId,
+            issuer: trust_registry::Issuer
+        ) -> Option<trust_registry::VerificationFee> {
+            TrustRegistry::verification_fee(registry_id, schema_id, issuer)
+        }
+
+        fn recognized_registries(
+            registry_id: trust_registry::TrustRegistryId
+        ) -> BTreeSet<trust_registry::TrustRegistryId> {
+            TrustRegistry::recognized_registries(registry_id)
+        }
+
+        fn issuer_authorization_path(
+            registry_id: trust_registry::TrustRegistryId,
+            schema_id: trust_registry::TrustRegistrySchemaId,
+            issuer: trust_registry::Issuer
+        ) -> Option<Vec<trust_registry::TrustRegistryId>> {
+            TrustRegistry::issuer_authorization_path(registry_id, schema_id, issuer)
+        }
+
+        fn participation_requests(
+            registry_id: trust_registry::TrustRegistryId,
+            after: Option<trust_registry::IssuerOrVerifier>,
+            limit: u32
+        ) -> Vec<(trust_registry::IssuerOrVerifier, trust_registry::ParticipationRequest<Runtime>)> {
+            TrustRegistry::participation_requests(registry_id, after, limit)
+        }
+
+        fn registries_info_by_paginated(
+            by: trust_registry::QueryTrustRegistriesBy,
+            after: Option<trust_registry::TrustRegistryId>,
+            limit: u32
+        ) -> Vec<(trust_registry::TrustRegistryId, trust_registry::TrustRegistryInfo<Runtime>)> {
+            by.resolve_to_registries_info_paginated(after, limit)
+        }
+
+        fn all_registry_schema_metadata_paginated(
+            registry_id: trust_registry::TrustRegistryId,
+            filter: trust_registry::SchemaMetadataFilter,
+            after: Option<trust_registry::TrustRegistrySchemaId>,
+            limit: u32
+        ) -> Vec<(trust_registry::TrustRegistrySchemaId, trust_registry::AggregatedTrustRegistrySchemaMetadata<Runtime>)> {
+            filter.resolve_to_schemas_metadata_in_registry_paginated(registry_id, after, limit)
+        }
+
+        fn all_registry_schema_issuers_paginated(
+            registry_id: trust_registry::TrustRegistryId,
+            after: Option<trust_registry::TrustRegistrySchemaId>,
+            limit: u32
+        ) -> Vec<(trust_registry::TrustRegistrySchemaId, trust_registry::AggregatedTrustRegistrySchemaIssuers<Runtime>)> {
+            trust_registry::SchemaMetadataFilter::default()
+                .resolve_to_schemas_metadata_in_registry_paginated::<Runtime>(registry_id, after, limit)
+                .into_iter()
+                .map(|(schema_id, schema_metadata)| (schema_id, schema_metadata.issuers))
+                .collect()
+        }
+
+        fn all_registry_schema_verifiers_paginated(
+            registry_id: trust_registry::TrustRegistryId,
+            after: Option<trust_registry::TrustRegistrySchemaId>,
+            limit: u32
+        ) -> Vec<(trust_registry::TrustRegistrySchemaId, trust_registry::TrustRegistrySchemaVerifiers<Runtime>)> {
+            trust_registry::SchemaMetadataFilter::default()
+                .resolve_to_schemas_metadata_in_registry_paginated::<Runtime>(registry_id, after, limit)
+                .into_iter()
+                .map(|(schema_id, schema_metadata)| (schema_id, schema_metadata.verifiers))
+                .collect()
+        }
+
+        fn schema_metadata_paginated(
+            id: trust_registry::TrustRegistrySchemaId,
+            after: Option<trust_registry::TrustRegistryId>,
+            limit: u32
+        ) -> Vec<(trust_registry::TrustRegistryId, trust_registry::AggregatedTrustRegistrySchemaMetadata<Runtime>)> {
+            TrustRegistry::schema_metadata_by_schema_id_paginated(id, after, limit).into_iter().map(|(registry_id, schema_metadata)| (registry_id, schema_metadata.aggregate(registry_id, id))).collect()
+        }
+
+        fn schema_issuers_paginated(
+            id: trust_registry::TrustRegistrySchemaId,
+            after: Option<trust_registry::TrustRegistryId>,
+            limit: u32
+        ) -> Vec<(trust_registry::TrustRegistryId, trust_registry::AggregatedTrustRegistrySchemaIssuers<Runtime>)> {
+            TrustRegistry::schema_metadata_by_schema_id_paginated(id, after, limit).into_iter().map(|(registry_id, schema_metadata)| (registry_id, schema_metadata.aggregate(registry_id, id).issuers)).collect()
+        }
+
+        fn schema_verifiers_paginated(
+            id: trust_registry::TrustRegistrySchemaId,
+            after: Option<trust_registry::TrustRegistryId>,
+            limit: u32
+        ) -> Vec<(trust_registry::TrustRegistryId, trust_registry::TrustRegistrySchemaVerifiers<Runtime>)> {
+            TrustRegistry::schema_metadata_by_schema_id_paginated(id, after, limit).into_iter().map(|(registry_id, schema_metadata)| (registry_id, schema_metadata.verifiers)).collect()
+        }
+
+        fn registries_ids_by_paginated(
+            by: trust_registry::QueryTrustRegistriesBy,
+            after: Option<trust_registry::TrustRegistryId>,
+            limit: u32
+        ) -> Vec<trust_registry::TrustRegistryId> {
+            by.resolve_to_registry_ids_paginated::<Runtime>(after, limit)
+        }
+
+        fn trqp_authorization(
+            query: trust_registry::TrqpAuthorizationQuery
+        ) -> Vec<trust_registry::TrqpAuthorization<Runtime>> {
+            query.resolve_to_authorizations()
+        }
+
+        fn gov_framework_versions(
+            registry_id: trust_registry::TrustRegistryId
+        ) -> Vec<trust_registry::GovFrameworkVersion<Runtime>> {
+            TrustRegistry::gov_framework_versions(registry_id)
+        }
+
+        fn gov_framework_version_at(
+            registry_id: trust_registry::TrustRegistryId,
+            block_number: BlockNumber
+        ) -> Option<trust_registry::GovFrameworkVersion<Runtime>> {
+            TrustRegistry::gov_framework_version_at(registry_id, block_number)
+        }
+
+        fn issuer_delegations(
+            registry_id: trust_registry::TrustRegistryId,
+            issuer: trust_registry::Issuer
+        ) -> BTreeMap<trust_registry::Issuer, trust_registry::DelegationScope<Runtime>> {
+            TrustRegistry::issuer_delegations(registry_id, issuer)
+        }
+
+        fn schema_definition(
+            id: trust_registry::TrustRegistrySchemaId
+        ) -> Option<trust_registry::AggregatedTrustRegistrySchemaDefinition<Runtime>> {
+            TrustRegistry::schema_definition(id).map(|definition| definition.aggregate())
+        }
     }
 
     #[cfg(feature = "runtime-benchmarks")]
@@ -2732,6 +3015,18 @@ where
     item.checked_into().map(|item| (idx, item))
 }
 
+/// Returns the supplied call or, for the `Utility` batches, the calls nested in it in their dispatch order.
+fn batched_calls(call: &Call) -> Vec<&Call> {
+    match call {
+        Call::Utility(
+            pallet_utility::Call::batch { calls }
+            | pallet_utility::Call::batch_all { calls }
+            | pallet_utility::Call::force_batch { calls },
+        ) => calls.iter().flat_map(batched_calls).collect(),
+        call => vec![call],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;